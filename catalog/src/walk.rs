@@ -19,10 +19,9 @@ use rayon::prelude::*;
 use rusqlite::{params, Connection};
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
-use std::process::Command;
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use local::git::{get_repo_status_local, RemoteStatus};
+use local::git::{get_repo_status_local, git_command, RemoteStatus};
 use local::repo::{discover_repos, Repo};
 use local::subprocess::{run_checked, subprocess_timeout};
 
@@ -353,6 +352,7 @@ fn ahead_behind(status: &RemoteStatus) -> (Option<i64>, Option<i64>) {
         RemoteStatus::NoRemote
         | RemoteStatus::NoUpstream
         | RemoteStatus::DetachedHead
+        | RemoteStatus::NoBranch
         | RemoteStatus::Error(_) => (None, None),
     }
 }
@@ -385,13 +385,7 @@ fn canonical_path(path: &Path) -> PathBuf {
 /// Returns `(None, None)` for an empty repo or on any git error (LOCAL only).
 fn last_commit(repo_path: &Path) -> (Option<String>, Option<i64>) {
     let output = match run_checked(
-        Command::new("git").args([
-            "-C",
-            &repo_path.to_string_lossy(),
-            "log",
-            "-1",
-            "--format=%H%x09%ct",
-        ]),
+        git_command(repo_path).args(["log", "-1", "--format=%H%x09%ct"]),
         subprocess_timeout(),
     ) {
         Ok(o) if o.status.success() => o,
@@ -424,13 +418,7 @@ fn last_commit(repo_path: &Path) -> (Option<String>, Option<i64>) {
 /// containers all work). `None` when the repo was never fetched (no FETCH_HEAD).
 fn last_fetch_mtime(repo_path: &Path) -> Option<i64> {
     let output = run_checked(
-        Command::new("git").args([
-            "-C",
-            &repo_path.to_string_lossy(),
-            "rev-parse",
-            "--git-path",
-            "FETCH_HEAD",
-        ]),
+        git_command(repo_path).args(["rev-parse", "--git-path", "FETCH_HEAD"]),
         subprocess_timeout(),
     )
     .ok()?;
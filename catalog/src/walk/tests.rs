@@ -2,6 +2,7 @@ use super::*;
 use crate::db;
 use local::test_utils::create_minimal_test_repo;
 use std::fs;
+use std::process::Command;
 use tempfile::TempDir;
 
 /// Open a fresh catalog DB in a temp dir (no `$XDG_CACHE_HOME` dependency).
@@ -16,11 +16,11 @@
 //! worktree*. gx operates in that single worktree and never fans write commands
 //! out across the container's N worktrees.
 
+use crate::git::git_command;
 use crate::subprocess::{run_checked, subprocess_timeout};
 use eyre::{eyre, Result};
 use log::{debug, trace};
 use std::path::{Path, PathBuf};
-use std::process::Command;
 
 /// One row parsed from `git worktree list --porcelain`.
 #[derive(Debug, Clone)]
@@ -75,10 +75,7 @@ pub fn is_git_path(path: &Path) -> bool {
 pub fn resolve_worktrees(container: &Path) -> Result<Vec<Worktree>> {
     debug!("resolve_worktrees: container={}", container.display());
     let output = run_checked(
-        Command::new("git")
-            .arg("-C")
-            .arg(container)
-            .args(["worktree", "list", "--porcelain"]),
+        git_command(container).args(["worktree", "list", "--porcelain"]),
         subprocess_timeout(),
     )?;
     if !output.status.success() {
@@ -169,10 +166,7 @@ pub fn default_worktree(container: &Path) -> Result<PathBuf> {
 /// Returns `None` if HEAD is detached or git cannot resolve it.
 fn default_branch(container: &Path) -> Option<String> {
     let output = run_checked(
-        Command::new("git")
-            .arg("-C")
-            .arg(container)
-            .args(["symbolic-ref", "--short", "HEAD"]),
+        git_command(container).args(["symbolic-ref", "--short", "HEAD"]),
         subprocess_timeout(),
     )
     .ok()?;
@@ -193,10 +187,7 @@ fn default_branch(container: &Path) -> Option<String> {
 pub fn origin_url(path: &Path) -> Result<String> {
     debug!("origin_url: path={}", path.display());
     let output = run_checked(
-        Command::new("git")
-            .arg("-C")
-            .arg(path)
-            .args(["remote", "get-url", "origin"]),
+        git_command(path).args(["remote", "get-url", "origin"]),
         subprocess_timeout(),
     )?;
     if !output.status.success() {
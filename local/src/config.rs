@@ -25,6 +25,15 @@ fn xdg_config_dir() -> Option<PathBuf> {
     dirs::home_dir().map(|h| h.join(".config"))
 }
 
+/// The config file `Config::load` reads when no `--config` is given:
+/// `$XDG_CONFIG_HOME/gx/gx.yml`.
+pub fn default_config_path() -> Option<PathBuf> {
+    xdg_config_dir().map(|dir| {
+        dir.join(GX_PROJECT_NAME)
+            .join(format!("{GX_PROJECT_NAME}.yml"))
+    })
+}
+
 /// XDG data dir, honoring `$XDG_DATA_HOME` and falling back to `$HOME/.local/share`.
 ///
 /// We deliberately do NOT use the `dirs` config/data helpers: those honor
@@ -105,6 +114,19 @@ pub struct Config {
     /// splitting network vs local); absent = `DEFAULT_SUBPROCESS_TIMEOUT_SECS`.
     #[serde(rename = "subprocess-timeout-secs")]
     pub subprocess_timeout_secs: Option<u64>,
+    /// Retries for a clone or GitHub API call that fails transiently (rate
+    /// limit, dropped connection); auth and not-found errors never retry.
+    /// `--retries` overrides; absent = `DEFAULT_RETRIES`.
+    pub retries: Option<u32>,
+    /// Delay before the first retry, in milliseconds; doubles per retry.
+    /// Absent = `DEFAULT_RETRY_BACKOFF_MS`.
+    #[serde(rename = "retry-backoff-ms")]
+    pub retry_backoff_ms: Option<u64>,
+    /// Most `gh` (GitHub API) calls in flight at once, whatever `--parallel`
+    /// is; local git work is not limited. `--api-concurrency` overrides;
+    /// absent = `DEFAULT_API_CONCURRENCY`.
+    #[serde(rename = "api-concurrency")]
+    pub api_concurrency: Option<usize>,
     /// Confirm-gate threshold for the `review` finish-line ops (`approve`/
     /// `delete`) (design doc `2026-07-12-gx-production-hardening.md`, Phase 3).
     /// Absent block = `DEFAULT_CONFIRM_THRESHOLD`.
@@ -117,6 +139,149 @@ pub struct Config {
     /// Track B1): the subtree ceiling for the scope clamp and the staleness
     /// window that triggers an auto-walk. Absent block = `CatalogConfig::default()`.
     pub catalog: Option<CatalogConfig>,
+    /// Command aliases: `gx <name>` runs gx with the mapped argument vector
+    /// (`fe: [status, -p, frontend, --detailed]`). An alias only applies when
+    /// `<name>` is not a real subcommand.
+    pub aliases: Option<BTreeMap<String, Vec<String>>>,
+    /// Per-org overrides (`orgs: { tatari-tv: { default-pr-type: draft } }`),
+    /// layered over the global values by [`Config::for_org`].
+    pub orgs: Option<BTreeMap<String, OrgConfig>>,
+    /// Minimum `git`/`gh` versions checked at startup; a tool below its
+    /// minimum stops gx before any operation. Absent = the built-in minimums.
+    #[serde(rename = "min-tool-versions")]
+    pub min_tool_versions: Option<ToolVersionsConfig>,
+    /// `gx clone` defaults; `--protocol` overrides. Absent = SSH.
+    pub clone: Option<CloneConfig>,
+    /// The symbols `gx status` draws per repo state. Absent = the emoji theme.
+    pub theme: Option<ThemeConfig>,
+}
+
+/// The `theme:` block: a built-in base theme, then any per-state overrides.
+/// `ahead`/`behind` interpolate `{n}`, `diverged` `{ahead}` and `{behind}`,
+/// and `remote-error` `{e}` (the error's first three letters).
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct ThemeConfig {
+    pub name: Option<ThemeName>,
+    pub error: Option<String>,
+    pub untracked: Option<String>,
+    pub modified: Option<String>,
+    pub added: Option<String>,
+    pub deleted: Option<String>,
+    pub staged: Option<String>,
+    pub submodule: Option<String>,
+    pub up_to_date: Option<String>,
+    pub ahead: Option<String>,
+    pub behind: Option<String>,
+    pub diverged: Option<String>,
+    pub no_upstream: Option<String>,
+    pub no_branch: Option<String>,
+    pub remote_error: Option<String>,
+}
+
+/// The built-in themes: `emoji` (the default), `plain` (what `--no-emoji`
+/// draws) and `ascii` (7-bit only, for terminals without Unicode).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeName {
+    #[default]
+    Emoji,
+    Plain,
+    Ascii,
+}
+
+/// The `clone:` block.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct CloneConfig {
+    pub protocol: Option<CloneProtocol>,
+}
+
+/// The URL scheme `gx clone` uses for new clones: SSH (`git@github.com:`, the
+/// default) or HTTPS (`https://github.com/`, authenticated by the git
+/// credential helper, e.g. `gh auth setup-git`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CloneProtocol {
+    #[default]
+    Ssh,
+    Https,
+}
+
+impl std::str::FromStr for CloneProtocol {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "ssh" => Ok(CloneProtocol::Ssh),
+            "https" => Ok(CloneProtocol::Https),
+            other => Err(format!(
+                "unknown protocol '{other}' (expected ssh or https)"
+            )),
+        }
+    }
+}
+
+/// The `min-tool-versions:` block, for orgs that need newer tools than gx
+/// itself does.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct ToolVersionsConfig {
+    pub git: Option<String>,
+    pub gh: Option<String>,
+}
+
+/// The settings an `orgs.<name>` block may override; an absent key falls
+/// back to the global value.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct OrgConfig {
+    pub default_pr_type: Option<PrType>,
+    pub jobs: Option<String>,
+    pub max_depth: Option<usize>,
+    pub merge_method: Option<MergeMethod>,
+}
+
+/// Whether `gx create --pr` opens normal or draft PRs when `--draft` is not
+/// passed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PrType {
+    #[default]
+    Normal,
+    Draft,
+}
+
+/// How `gx review approve` merges a PR.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MergeMethod {
+    Merge,
+    #[default]
+    Squash,
+    Rebase,
+}
+
+impl MergeMethod {
+    /// The `gh pr merge` flag selecting this method.
+    pub fn gh_flag(self) -> &'static str {
+        match self {
+            MergeMethod::Merge => "--merge",
+            MergeMethod::Squash => "--squash",
+            MergeMethod::Rebase => "--rebase",
+        }
+    }
+}
+
+/// The org-dependent settings for one org: its `orgs.<name>` block layered
+/// over the global config. Built by [`Config::for_org`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EffectiveConfig {
+    pub default_pr_type: PrType,
+    /// Parallel jobs, with `nproc` already resolved.
+    pub jobs: Option<usize>,
+    pub max_depth: Option<usize>,
+    pub merge_method: MergeMethod,
 }
 
 /// The curated `gx-mcp` tool surface (design doc API Design > MCP tools). The
@@ -220,6 +385,9 @@ pub struct CreateConfig {
     pub confirm_threshold: Option<usize>,
     /// Settings for the `llm` change type (agent-per-repo propose/apply).
     pub llm: Option<LlmConfig>,
+    /// PR type for `--pr` without `--draft`; absent = normal.
+    #[serde(rename = "default-pr-type")]
+    pub default_pr_type: Option<PrType>,
 }
 
 impl Default for CreateConfig {
@@ -227,6 +395,7 @@ impl Default for CreateConfig {
         Self {
             confirm_threshold: Some(DEFAULT_CONFIRM_THRESHOLD),
             llm: Some(LlmConfig::default()),
+            default_pr_type: None,
         }
     }
 }
@@ -243,12 +412,16 @@ pub struct ReviewConfig {
     /// Prompt before approving/deleting when at least this many PRs are targeted.
     #[serde(rename = "confirm-threshold")]
     pub confirm_threshold: Option<usize>,
+    /// How `approve` merges; absent = squash.
+    #[serde(rename = "merge-method")]
+    pub merge_method: Option<MergeMethod>,
 }
 
 impl Default for ReviewConfig {
     fn default() -> Self {
         Self {
             confirm_threshold: Some(DEFAULT_CONFIRM_THRESHOLD),
+            merge_method: None,
         }
     }
 }
@@ -366,6 +539,19 @@ pub struct RepoDiscoveryConfig {
     pub max_depth: Option<usize>,
     #[serde(rename = "ignore-patterns")]
     pub ignore_patterns: Option<Vec<String>>,
+    /// Path fragments (`.cache`, `go/pkg/mod`) that prune discovery wherever
+    /// they appear in a walked path, unlike `ignore-patterns` which match a
+    /// single directory name.
+    #[serde(rename = "ignore-dirs")]
+    pub ignore_dirs: Option<Vec<String>>,
+    /// Parent-directory names never used as the owner when a repo's slug has
+    /// to be inferred from its location (no readable `origin`).
+    #[serde(rename = "slug-fallback-skip")]
+    pub slug_fallback_skip: Option<Vec<String>>,
+    /// Stop discovery after this many repos (a safety net for a `--cwd`
+    /// mistakenly pointed at `$HOME`). Unset means unlimited.
+    #[serde(rename = "max-repos")]
+    pub max_repos: Option<usize>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -408,9 +594,17 @@ impl Default for Config {
             github: Some(GithubConfig::default()),
             mcp: None,
             subprocess_timeout_secs: None,
+            retries: None,
+            retry_backoff_ms: None,
+            api_concurrency: None,
             review: Some(ReviewConfig::default()),
             cleanup: Some(CleanupConfig::default()),
             catalog: Some(CatalogConfig::default()),
+            aliases: None,
+            orgs: None,
+            min_tool_versions: None,
+            clone: None,
+            theme: None,
         }
     }
 }
@@ -421,6 +615,23 @@ impl Default for Config {
 /// fast, so this only bounds a genuinely wedged network op.
 pub const DEFAULT_SUBPROCESS_TIMEOUT_SECS: u64 = 300;
 
+/// Oldest `git` gx supports.
+pub const DEFAULT_MIN_GIT_VERSION: &str = "2.20.0";
+
+/// Oldest `gh` gx supports.
+pub const DEFAULT_MIN_GH_VERSION: &str = "2.0.0";
+
+/// Default retries after a transient clone/GitHub failure (three attempts in
+/// all, as the gh retry loop always made).
+pub const DEFAULT_RETRIES: u32 = 2;
+
+/// Default delay before the first retry, in milliseconds.
+pub const DEFAULT_RETRY_BACKOFF_MS: u64 = 1000;
+
+/// Default cap on concurrent `gh` calls: enough to keep a fleet run busy,
+/// few enough to stay clear of GitHub's secondary rate limits.
+pub const DEFAULT_API_CONCURRENCY: usize = 8;
+
 impl Default for OutputConfig {
     fn default() -> Self {
         Self {
@@ -445,6 +656,23 @@ impl Default for RepoDiscoveryConfig {
                 "dist".to_string(),
                 "vendor".to_string(),
             ]),
+            // Formerly hardcoded path-substring checks in is_ignored_directory.
+            ignore_dirs: Some(vec![
+                ".cache".to_string(),
+                ".local".to_string(),
+                ".nvm".to_string(),
+                "go/pkg/mod".to_string(),
+            ]),
+            // Formerly hardcoded in resolve_slug's parent-dir fallback.
+            slug_fallback_skip: Some(vec![
+                "repos".to_string(),
+                "src".to_string(),
+                "code".to_string(),
+                "projects".to_string(),
+                "workspace".to_string(),
+                "git".to_string(),
+            ]),
+            max_repos: None,
         }
     }
 }
@@ -472,6 +700,82 @@ impl Config {
             })
     }
 
+    /// Effective repo-discovery ignore dirs (path fragments): the configured
+    /// list, or the documented defaults when unset.
+    pub fn ignore_dirs(&self) -> Vec<String> {
+        self.repo_discovery
+            .as_ref()
+            .and_then(|rd| rd.ignore_dirs.clone())
+            .unwrap_or_else(|| {
+                RepoDiscoveryConfig::default()
+                    .ignore_dirs
+                    .unwrap_or_default()
+            })
+    }
+
+    /// Effective slug-fallback skip list: the configured list, or the
+    /// documented defaults when unset.
+    pub fn slug_fallback_skip(&self) -> Vec<String> {
+        self.repo_discovery
+            .as_ref()
+            .and_then(|rd| rd.slug_fallback_skip.clone())
+            .unwrap_or_else(|| {
+                RepoDiscoveryConfig::default()
+                    .slug_fallback_skip
+                    .unwrap_or_default()
+            })
+    }
+
+    /// Configured command aliases (`aliases`); empty when unset.
+    pub fn aliases(&self) -> BTreeMap<String, Vec<String>> {
+        self.aliases.clone().unwrap_or_default()
+    }
+
+    /// The org-dependent settings for a command that discovers repos before
+    /// it knows their orgs: the `--user-org` org's, else `default-user-org`'s
+    /// when either is given, else the global values.
+    pub fn for_user_org(&self, user_org: Option<&str>) -> EffectiveConfig {
+        self.for_org(
+            user_org
+                .or(self.default_user_org.as_deref())
+                .unwrap_or_default(),
+        )
+    }
+
+    /// The org-dependent settings for `org`: its `orgs.<org>` block, where
+    /// set, over the global values.
+    pub fn for_org(&self, org: &str) -> EffectiveConfig {
+        let overrides = self
+            .orgs
+            .as_ref()
+            .and_then(|orgs| orgs.get(org))
+            .cloned()
+            .unwrap_or_default();
+        EffectiveConfig {
+            default_pr_type: overrides
+                .default_pr_type
+                .or_else(|| self.create.as_ref().and_then(|c| c.default_pr_type))
+                .unwrap_or_default(),
+            jobs: overrides
+                .jobs
+                .as_deref()
+                .or(self.jobs.as_deref())
+                .and_then(crate::utils::parse_jobs),
+            max_depth: overrides
+                .max_depth
+                .or_else(|| self.repo_discovery.as_ref().and_then(|rd| rd.max_depth)),
+            merge_method: overrides
+                .merge_method
+                .or_else(|| self.review.as_ref().and_then(|r| r.merge_method))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Configured discovery repo limit (`repo-discovery.max-repos`), if any.
+    pub fn max_repos(&self) -> Option<usize> {
+        self.repo_discovery.as_ref().and_then(|rd| rd.max_repos)
+    }
+
     /// Effective confirm-threshold for the create command.
     pub fn confirm_threshold(&self) -> usize {
         self.create
@@ -542,6 +846,50 @@ impl Config {
         )
     }
 
+    /// Effective minimum version per required tool, as `(tool, version)`.
+    pub fn min_tool_versions(&self) -> Vec<(&'static str, String)> {
+        let configured = self.min_tool_versions.as_ref();
+        vec![
+            (
+                "git",
+                configured
+                    .and_then(|c| c.git.clone())
+                    .unwrap_or_else(|| DEFAULT_MIN_GIT_VERSION.to_string()),
+            ),
+            (
+                "gh",
+                configured
+                    .and_then(|c| c.gh.clone())
+                    .unwrap_or_else(|| DEFAULT_MIN_GH_VERSION.to_string()),
+            ),
+        ]
+    }
+
+    /// Effective `gx clone` protocol when `--protocol` is not passed.
+    pub fn clone_protocol(&self) -> CloneProtocol {
+        self.clone
+            .as_ref()
+            .and_then(|c| c.protocol)
+            .unwrap_or_default()
+    }
+
+    /// Effective retry policy for transient clone/GitHub failures.
+    pub fn retry_policy(&self) -> crate::utils::RetryPolicy {
+        crate::utils::RetryPolicy {
+            retries: self.retries.unwrap_or(DEFAULT_RETRIES),
+            base_delay: Duration::from_millis(
+                self.retry_backoff_ms.unwrap_or(DEFAULT_RETRY_BACKOFF_MS),
+            ),
+        }
+    }
+
+    /// Effective cap on concurrent `gh` calls (at least 1).
+    pub fn api_concurrency(&self) -> usize {
+        self.api_concurrency
+            .unwrap_or(DEFAULT_API_CONCURRENCY)
+            .max(1)
+    }
+
     /// Effective PR body template (`{commit_message}` is substituted).
     pub fn pr_body_template(&self) -> String {
         self.github
@@ -574,10 +922,7 @@ impl Config {
         // There is deliberately NO `./<project>.yml` CWD fallback - any directory
         // could otherwise reconfigure the tool (e.g. override a token-env
         // mapping) ([A23]).
-        if let Some(config_dir) = xdg_config_dir() {
-            let primary_config = config_dir
-                .join(GX_PROJECT_NAME)
-                .join(format!("{GX_PROJECT_NAME}.yml"));
+        if let Some(primary_config) = default_config_path() {
             if primary_config.exists() {
                 // A file that exists but fails to parse (a typo'd key under
                 // `deny_unknown_fields`, bad YAML, ...) must fail loudly, not
@@ -595,6 +940,136 @@ impl Config {
         Ok(Self::default())
     }
 
+    /// Apply `--set key=value` overrides, in order, for this invocation only.
+    /// `key` is a dotted path through the config (`repo-discovery.max-depth`;
+    /// `_` is accepted for `-`) and `value` is parsed as YAML, falling back to
+    /// a string (`jobs=16`). An unknown key or a value of the wrong type is an
+    /// error naming the override.
+    pub fn with_overrides(self, overrides: &[String]) -> Result<Self> {
+        let mut config = self;
+        for assignment in overrides {
+            config = config
+                .with_override(assignment)
+                .with_context(|| format!("Invalid --set {assignment:?}"))?;
+        }
+        Ok(config)
+    }
+
+    fn with_override(self, assignment: &str) -> Result<Self> {
+        let (key, raw) = assignment
+            .split_once('=')
+            .ok_or_else(|| eyre::eyre!("expected key=value"))?;
+        let path: Vec<&str> = key.trim().split('.').collect();
+        if path.iter().any(|segment| segment.is_empty()) {
+            return Err(eyre::eyre!("empty key segment in {key:?}"));
+        }
+        let base = serde_yaml::to_value(&self).context("Failed to serialize config")?;
+
+        let parsed = serde_yaml::from_str(raw).unwrap_or(serde_yaml::Value::Null);
+        let mut candidates = vec![parsed];
+        if !matches!(candidates[0], serde_yaml::Value::String(_)) {
+            candidates.push(serde_yaml::Value::String(raw.to_string()));
+        }
+        let mut first_error = None;
+        for value in candidates {
+            let mut merged = base.clone();
+            set_config_path(&mut merged, &[], &path, value);
+            match serde_yaml::from_value(merged) {
+                Ok(config) => return Ok(config),
+                Err(e) => {
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+        Err(first_error.map_or_else(|| eyre::eyre!("no value"), eyre::Report::new))
+    }
+
+    /// The file [`Config::load`] reads for `config_path`: the explicit path,
+    /// else the default location when a file exists there. `None` means the
+    /// built-in defaults apply.
+    pub fn source_path(config_path: Option<&PathBuf>) -> Option<PathBuf> {
+        match config_path {
+            Some(path) => Some(path.clone()),
+            None => default_config_path().filter(|path| path.exists()),
+        }
+    }
+
+    /// This config with every unset block and scalar filled in with the value
+    /// gx falls back to, so `gx config show` prints what is actually in
+    /// effect. Blocks with no default (`aliases`, `orgs`, `mcp`, `theme`,
+    /// `logging`) stay as configured.
+    pub fn resolved(mut self) -> Self {
+        self.jobs.get_or_insert_with(|| "nproc".to_string());
+        self.output.get_or_insert_with(OutputConfig::default);
+        self.repo_discovery
+            .get_or_insert_with(RepoDiscoveryConfig::default);
+        self.remote_status
+            .get_or_insert_with(RemoteStatusConfig::default);
+        self.create.get_or_insert_with(CreateConfig::default);
+        self.github.get_or_insert_with(GithubConfig::default);
+        self.review.get_or_insert_with(ReviewConfig::default);
+        self.cleanup.get_or_insert_with(CleanupConfig::default);
+        self.catalog.get_or_insert_with(CatalogConfig::default);
+        self.subprocess_timeout_secs
+            .get_or_insert(DEFAULT_SUBPROCESS_TIMEOUT_SECS);
+        self.retries.get_or_insert(DEFAULT_RETRIES);
+        self.retry_backoff_ms
+            .get_or_insert(DEFAULT_RETRY_BACKOFF_MS);
+        self.api_concurrency.get_or_insert(DEFAULT_API_CONCURRENCY);
+        let minimums: BTreeMap<&str, String> = self.min_tool_versions().into_iter().collect();
+        self.min_tool_versions = Some(ToolVersionsConfig {
+            git: minimums.get("git").cloned(),
+            gh: minimums.get("gh").cloned(),
+        });
+        self.clone = Some(CloneConfig {
+            protocol: Some(self.clone_protocol()),
+        });
+        self
+    }
+
+    /// Values that parse but gx cannot use (`gx config validate`); empty when
+    /// the config is sound. Unknown keys and wrongly typed values never get
+    /// this far: `deny_unknown_fields` fails the parse itself.
+    pub fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        let valid_jobs = |jobs: &str| crate::utils::parse_jobs(jobs).is_some_and(|n| n > 0);
+        if let Some(jobs) = self.jobs.as_deref().filter(|jobs| !valid_jobs(jobs)) {
+            problems.push(format!(
+                "jobs: {jobs:?} is neither a positive number nor \"nproc\""
+            ));
+        }
+        for (org, org_config) in self.orgs.iter().flatten() {
+            if let Some(jobs) = org_config.jobs.as_deref().filter(|jobs| !valid_jobs(jobs)) {
+                problems.push(format!(
+                    "orgs.{org}.jobs: {jobs:?} is neither a positive number nor \"nproc\""
+                ));
+            }
+        }
+        if self.subprocess_timeout_secs == Some(0) {
+            problems.push(
+                "subprocess-timeout-secs: 0 would kill every git/gh call at once".to_string(),
+            );
+        }
+        if let Some(minimums) = &self.min_tool_versions {
+            for (tool, version) in [("git", &minimums.git), ("gh", &minimums.gh)] {
+                let Some(version) = version else { continue };
+                let dotted = !version.is_empty()
+                    && version.split('.').all(|part| part.parse::<u32>().is_ok());
+                if !dotted {
+                    problems.push(format!(
+                        "min-tool-versions.{tool}: {version:?} is not a dotted version like \"2.20.0\""
+                    ));
+                }
+            }
+        }
+        for (name, expansion) in self.aliases.iter().flatten() {
+            if expansion.is_empty() {
+                problems.push(format!("aliases.{name}: expands to nothing"));
+            }
+        }
+        problems
+    }
+
     fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
         let content = fs::read_to_string(&path).context("Failed to read config file")?;
 
@@ -611,5 +1086,41 @@ impl Config {
     }
 }
 
+/// The config maps keyed by user-chosen names (an alias, an org) rather than
+/// schema fields, as the serialized key path to the map.
+const USER_KEYED_MAPS: &[&[&str]] = &[&["aliases"], &["orgs"], &["github", "token-env", "by-org"]];
+
+/// Set `value` at `path` in a serialized config, creating mappings along
+/// the way (an unset block serializes as null). `parent` is the key path
+/// already walked. Each segment matches an existing key as written, else
+/// with `_` read as `-` - except a key of a [`USER_KEYED_MAPS`] map, which
+/// is always taken as written (`orgs.my_org` stays `my_org`).
+fn set_config_path(
+    node: &mut serde_yaml::Value,
+    parent: &[String],
+    path: &[&str],
+    value: serde_yaml::Value,
+) {
+    let Some((segment, rest)) = path.split_first() else {
+        *node = value;
+        return;
+    };
+    if !node.is_mapping() {
+        *node = serde_yaml::Value::Mapping(serde_yaml::Mapping::new());
+    }
+    let mapping = node.as_mapping_mut().expect("just made a mapping");
+    let user_keyed = USER_KEYED_MAPS.iter().any(|map| *map == parent);
+    let key = if user_keyed || mapping.contains_key(*segment) {
+        segment.to_string()
+    } else {
+        segment.replace('_', "-")
+    };
+    let parent = [parent, std::slice::from_ref(&key)].concat();
+    let child = mapping
+        .entry(serde_yaml::Value::String(key))
+        .or_insert(serde_yaml::Value::Null);
+    set_config_path(child, &parent, rest, value);
+}
+
 #[cfg(test)]
 mod tests;
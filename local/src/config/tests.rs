@@ -54,6 +54,39 @@ fn test_config_defaults() {
     assert!(config
         .ignore_patterns()
         .contains(&"node_modules".to_string()));
+    assert!(config.ignore_dirs().contains(&".cache".to_string()));
+    assert!(config.slug_fallback_skip().contains(&"src".to_string()));
+}
+
+/// `ignore-dirs` / `slug-fallback-skip` replace their defaults when set.
+#[test]
+fn test_discovery_lists_override_defaults() {
+    let yaml = "repo-discovery:\n  ignore-dirs: [third_party]\n  slug-fallback-skip: [checkouts]\n";
+    let config: Config = serde_yaml::from_str(yaml).unwrap();
+    assert_eq!(config.ignore_dirs(), vec!["third_party".to_string()]);
+    assert_eq!(config.slug_fallback_skip(), vec!["checkouts".to_string()]);
+}
+
+/// `retries` / `retry-backoff-ms` default to three attempts at 1s and are
+/// overridable.
+#[test]
+fn test_retry_policy_defaults_and_override() {
+    let policy = Config::default().retry_policy();
+    assert_eq!(policy.attempts(), 3);
+    assert_eq!(policy.base_delay, std::time::Duration::from_millis(1000));
+
+    let config: Config = serde_yaml::from_str("retries: 5\nretry-backoff-ms: 250\n").unwrap();
+    let policy = config.retry_policy();
+    assert_eq!(policy.retries, 5);
+    assert_eq!(policy.base_delay, std::time::Duration::from_millis(250));
+}
+
+/// `max-repos` is unlimited by default and read from `repo-discovery`.
+#[test]
+fn test_max_repos_defaults_to_unlimited() {
+    assert_eq!(Config::default().max_repos(), None);
+    let config: Config = serde_yaml::from_str("repo-discovery:\n  max-repos: 50\n").unwrap();
+    assert_eq!(config.max_repos(), Some(50));
 }
 
 /// `deny_unknown_fields` must reject a typo'd top-level key loudly, naming it -
@@ -345,6 +378,59 @@ fn test_catalog_unknown_field_fails_loudly() {
     );
 }
 
+#[test]
+fn test_aliases_parse_as_argument_vectors() {
+    let yaml = "aliases:\n  fe: [status, -p, frontend, --detailed]\n";
+    let config: Config = serde_yaml::from_str(yaml).unwrap();
+    assert_eq!(
+        config.aliases()["fe"],
+        ["status", "-p", "frontend", "--detailed"]
+    );
+    assert!(Config::default().aliases().is_empty());
+}
+
+#[test]
+fn test_org_override_wins_over_the_global_value() {
+    let yaml = "jobs: \"8\"\nreview:\n  merge-method: rebase\norgs:\n  tatari-tv:\n    default-pr-type: draft\n    jobs: \"2\"\n    max-depth: 5\n";
+    let config: Config = serde_yaml::from_str(yaml).unwrap();
+    assert_eq!(
+        config.for_org("tatari-tv"),
+        EffectiveConfig {
+            default_pr_type: PrType::Draft,
+            jobs: Some(2),
+            max_depth: Some(5),
+            merge_method: MergeMethod::Rebase,
+        }
+    );
+}
+
+#[test]
+fn test_org_without_override_falls_back_to_global() {
+    let yaml = "jobs: \"8\"\norgs:\n  tatari-tv:\n    default-pr-type: draft\n";
+    let config: Config = serde_yaml::from_str(yaml).unwrap();
+    assert_eq!(
+        config.for_org("scottidler"),
+        EffectiveConfig {
+            default_pr_type: PrType::Normal,
+            jobs: Some(8),
+            max_depth: Some(3),
+            merge_method: MergeMethod::Squash,
+        }
+    );
+}
+
+#[test]
+fn test_for_user_org_falls_back_to_the_default_user_org() {
+    let yaml = "jobs: \"8\"\ndefault-user-org: tatari-tv\norgs:\n  tatari-tv:\n    jobs: \"2\"\n";
+    let config: Config = serde_yaml::from_str(yaml).unwrap();
+    assert_eq!(config.for_user_org(None).jobs, Some(2));
+    assert_eq!(config.for_user_org(Some("scottidler")).jobs, Some(8));
+    assert_eq!(
+        Config::default().for_user_org(None),
+        Config::default().for_org("")
+    );
+}
+
 /// `~` expansion: a bare `~` and a `~/...` path both resolve against `$HOME`;
 /// a path with no leading `~` passes through unchanged.
 #[test]
@@ -371,3 +457,102 @@ fn test_expand_tilde() {
     }
     drop(guard);
 }
+
+/// `min-tool-versions` overrides the built-in minimums per tool; an omitted
+/// tool keeps its default.
+#[test]
+fn test_min_tool_versions_defaults_and_overrides() {
+    let config = Config::default();
+    assert_eq!(
+        config.min_tool_versions(),
+        vec![
+            ("git", DEFAULT_MIN_GIT_VERSION.to_string()),
+            ("gh", DEFAULT_MIN_GH_VERSION.to_string()),
+        ]
+    );
+
+    let yaml = "min-tool-versions:\n  gh: 2.40.0\n";
+    let config: Config = serde_yaml::from_str(yaml).unwrap();
+    assert_eq!(
+        config.min_tool_versions(),
+        vec![
+            ("git", DEFAULT_MIN_GIT_VERSION.to_string()),
+            ("gh", "2.40.0".to_string()),
+        ]
+    );
+}
+
+/// `clone.protocol` picks the clone URL scheme; absent means SSH.
+#[test]
+fn test_clone_protocol_defaults_to_ssh() {
+    assert_eq!(Config::default().clone_protocol(), CloneProtocol::Ssh);
+
+    let config: Config = serde_yaml::from_str("clone:\n  protocol: https\n").unwrap();
+    assert_eq!(config.clone_protocol(), CloneProtocol::Https);
+
+    assert_eq!("HTTPS".parse::<CloneProtocol>(), Ok(CloneProtocol::Https));
+    assert!("git".parse::<CloneProtocol>().is_err());
+}
+
+/// `api-concurrency` caps concurrent gh calls; 0 is clamped to 1.
+#[test]
+fn test_api_concurrency_default_and_floor() {
+    assert_eq!(Config::default().api_concurrency(), DEFAULT_API_CONCURRENCY);
+    let config: Config = serde_yaml::from_str("api-concurrency: 0\n").unwrap();
+    assert_eq!(config.api_concurrency(), 1);
+}
+
+/// `--set key=value` overrides a field for one run; `_` reads as `-`, values
+/// parse as YAML with a string fallback, and bad keys/values are errors.
+#[test]
+fn test_with_overrides_sets_fields_and_rejects_bad_input() {
+    let config = Config::default()
+        .with_overrides(&[
+            "repo_discovery.max_depth=5".to_string(),
+            "jobs=16".to_string(),
+            "clone.protocol=https".to_string(),
+            "orgs.tatari-tv.default-pr-type=draft".to_string(),
+        ])
+        .unwrap();
+    assert_eq!(config.for_org("any").max_depth, Some(5));
+    assert_eq!(config.jobs.as_deref(), Some("16"));
+    assert_eq!(config.clone_protocol(), CloneProtocol::Https);
+    assert_eq!(config.for_org("tatari-tv").default_pr_type, PrType::Draft);
+    // Untouched fields keep their values.
+    assert_eq!(
+        config.subprocess_timeout().as_secs(),
+        DEFAULT_SUBPROCESS_TIMEOUT_SECS
+    );
+
+    // A user-chosen map key is taken as written; only schema fields read `_`
+    // as `-`.
+    let config = Config::default()
+        .with_overrides(&[
+            "orgs.my_org.default_pr_type=draft".to_string(),
+            "aliases.fe_all=[status, --all]".to_string(),
+        ])
+        .unwrap();
+    assert_eq!(config.for_org("my_org").default_pr_type, PrType::Draft);
+    assert_eq!(config.for_org("my-org").default_pr_type, PrType::default());
+    assert!(config.aliases().contains_key("fe_all"));
+
+    // Later overrides win.
+    let config = Config::default()
+        .with_overrides(&["retries=1".to_string(), "retries=4".to_string()])
+        .unwrap();
+    assert_eq!(config.retries, Some(4));
+
+    for bad in [
+        "repo-discovery.max-dpeth=5",
+        "nonsense=1",
+        "repo-discovery.max-depth=deep",
+        "clone.protocol=ftp",
+        "retries",
+        "repo-discovery..max-depth=1",
+    ] {
+        let err = Config::default()
+            .with_overrides(&[bad.to_string()])
+            .expect_err(bad);
+        assert!(format!("{err:#}").contains(bad), "{bad}: {err:#}");
+    }
+}
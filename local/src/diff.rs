@@ -1,6 +1,6 @@
 use colored::*;
 use eyre::Result;
-use regex::Regex;
+use regex::{NoExpand, Regex, RegexBuilder};
 use similar::{ChangeTag, TextDiff};
 
 /// Result of a substitution operation
@@ -20,6 +20,9 @@ pub enum SubstitutionResult {
     NoChange { matches: usize },
     /// File was not valid UTF-8 (binary); skipped rather than corrupted (see [A21]).
     SkippedBinary,
+    /// File did not parse as the structured format its extension names
+    /// (JSONC, multi-document YAML, ...); `set-key` skips it. Carries why.
+    SkippedUnparsable(String),
 }
 
 /// Generate a colored diff between original and updated content
@@ -95,6 +98,63 @@ pub fn apply_substitution(
     }
 }
 
+/// How a literal `sub` pattern matches. The default is a plain,
+/// case-sensitive substring match.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SubMatch {
+    /// Match regardless of case (`Hello` matches `hello`).
+    pub ignore_case: bool,
+    /// Only match where the pattern is a whole word: `test` does not match
+    /// inside `testing`.
+    pub whole_word: bool,
+}
+
+impl SubMatch {
+    fn is_literal(self) -> bool {
+        !self.ignore_case && !self.whole_word
+    }
+}
+
+/// [`apply_substitution`] with `matching` adjustments. The pattern and the
+/// replacement stay literal: a non-default `matching` compiles the escaped
+/// pattern into a regex and replaces without expanding `$` references.
+pub fn apply_substitution_matching(
+    content: &str,
+    pattern: &str,
+    replacement: &str,
+    matching: SubMatch,
+    buffer: usize,
+) -> Result<SubstitutionResult> {
+    if matching.is_literal() {
+        return Ok(apply_substitution(content, pattern, replacement, buffer));
+    }
+    let escaped = regex::escape(pattern);
+    let source = if matching.whole_word {
+        format!(r"\b{escaped}\b")
+    } else {
+        escaped
+    };
+    let regex = RegexBuilder::new(&source)
+        .case_insensitive(matching.ignore_case)
+        .build()?;
+    if !regex.is_match(content) {
+        return Ok(SubstitutionResult::NoMatches);
+    }
+    let matches = regex.find_iter(content).count();
+    let updated = regex
+        .replace_all(content, NoExpand(replacement))
+        .to_string();
+    if updated == content {
+        return Ok(SubstitutionResult::NoChange { matches });
+    }
+    let diff = generate_diff(content, &updated, buffer);
+    Ok(SubstitutionResult::Changed {
+        content: updated,
+        diff,
+        matches,
+    })
+}
+
 /// Apply a regex substitution to content and return result
 pub fn apply_regex_substitution(
     content: &str,
@@ -232,4 +292,71 @@ mod tests {
         let result = apply_regex_substitution(content, "[invalid", "replacement", 1);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_apply_substitution_matching_ignore_case() {
+        let content = "Hello world\nhello again\nHELLO";
+        let matching = SubMatch {
+            ignore_case: true,
+            ..Default::default()
+        };
+        let result = apply_substitution_matching(content, "hello", "Hi", matching, 1).unwrap();
+        match result {
+            SubstitutionResult::Changed {
+                content, matches, ..
+            } => {
+                assert_eq!(content, "Hi world\nHi again\nHi");
+                assert_eq!(matches, 3);
+            }
+            other => panic!("expected Changed, got {other:?}"),
+        }
+
+        // The default stays case-sensitive.
+        let result =
+            apply_substitution_matching(content, "hello", "Hi", SubMatch::default(), 1).unwrap();
+        assert!(matches!(
+            result,
+            SubstitutionResult::Changed { matches: 1, .. }
+        ));
+    }
+
+    #[test]
+    fn test_apply_substitution_matching_whole_word() {
+        let matching = SubMatch {
+            whole_word: true,
+            ..Default::default()
+        };
+        let result =
+            apply_substitution_matching("testing tested", "test", "check", matching, 1).unwrap();
+        assert!(matches!(result, SubstitutionResult::NoMatches));
+
+        let result =
+            apply_substitution_matching("a test, testing (test)", "test", "check", matching, 1)
+                .unwrap();
+        match result {
+            SubstitutionResult::Changed {
+                content, matches, ..
+            } => {
+                assert_eq!(content, "a check, testing (check)");
+                assert_eq!(matches, 2);
+            }
+            other => panic!("expected Changed, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_apply_substitution_matching_keeps_pattern_and_replacement_literal() {
+        let matching = SubMatch {
+            ignore_case: true,
+            ..Default::default()
+        };
+        let result = apply_substitution_matching("cost: A.B", "a.b", "$1.00", matching, 1).unwrap();
+        match result {
+            SubstitutionResult::Changed { content, .. } => assert_eq!(content, "cost: $1.00"),
+            other => panic!("expected Changed, got {other:?}"),
+        }
+        // `.` is not a wildcard.
+        let result = apply_substitution_matching("aXb", "a.b", "y", matching, 1).unwrap();
+        assert!(matches!(result, SubstitutionResult::NoMatches));
+    }
 }
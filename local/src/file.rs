@@ -11,7 +11,8 @@ use std::path::{Component, Path, PathBuf};
 ///
 /// Candidates come from git's index (`git ls-files --stage`), i.e. **tracked
 /// files only** (see design Q6). This makes `.git/` contents, gitignored files,
-/// untracked files, and submodule internals structurally unreachable. User
+/// untracked files, and submodule internals structurally unreachable; tracked
+/// files that are nonetheless gitignored are filtered out at match time. User
 /// glob patterns are matched against the returned *relative* paths, so glob
 /// metacharacters in the repo's absolute path can no longer corrupt matching
 /// ([A1], [A26]).
@@ -59,11 +60,27 @@ impl FileSet {
     /// sorted. Patterns are matched against relative paths with
     /// `require_literal_separator` so `*` does not cross directory boundaries
     /// (`**` does), matching shell/gitignore expectations.
+    ///
+    /// Tracked files the repo's `.gitignore` rules match (vendored trees,
+    /// force-added build output) are dropped; see [`FileSet::matching`] to keep
+    /// them.
     pub fn matching_any(repo_path: &Path, patterns: &[String]) -> Result<Vec<PathBuf>> {
+        Self::matching(repo_path, patterns, false)
+    }
+
+    /// [`FileSet::matching_any`] with control over ignored files: with
+    /// `include_ignored` set, tracked-but-ignored files are kept (the
+    /// `--no-gitignore` escape hatch).
+    pub fn matching(
+        repo_path: &Path,
+        patterns: &[String],
+        include_ignored: bool,
+    ) -> Result<Vec<PathBuf>> {
         debug!(
-            "FileSet::matching_any: repo_path={} patterns={:?}",
+            "FileSet::matching: repo_path={} patterns={:?} include_ignored={}",
             repo_path.display(),
-            patterns
+            patterns,
+            include_ignored
         );
         let candidates = Self::candidates(repo_path)?;
         let compiled = patterns
@@ -83,7 +100,18 @@ impl FileSet {
         matched.sort();
         matched.dedup();
 
-        debug!("FileSet::matching_any: {} matched", matched.len());
+        if !include_ignored && !matched.is_empty() {
+            let ignored = git::ignored_paths(repo_path, &matched)?;
+            if !ignored.is_empty() {
+                debug!(
+                    "FileSet::matching: skipping {} ignored files",
+                    ignored.len()
+                );
+                matched.retain(|path| !ignored.contains(path));
+            }
+        }
+
+        debug!("FileSet::matching: {} matched", matched.len());
         Ok(matched)
     }
 }
@@ -215,23 +243,20 @@ pub fn validate_new_file_path(repo_path: &Path, file_path: &str) -> Result<PathB
     Ok(full)
 }
 
-/// Apply a string substitution to a file
+/// Apply a string substitution to a file, matching as `matching` says
+/// (case-insensitive and/or whole-word; literal substring by default)
 pub fn apply_substitution_to_file(
     file_path: &Path,
     pattern: &str,
     replacement: &str,
+    matching: diff::SubMatch,
     buffer: usize,
 ) -> Result<crate::diff::SubstitutionResult> {
     let Some(content) = read_utf8_or_skip(file_path)? else {
         return Ok(diff::SubstitutionResult::SkippedBinary);
     };
 
-    Ok(diff::apply_substitution(
-        &content,
-        pattern,
-        replacement,
-        buffer,
-    ))
+    diff::apply_substitution_matching(&content, pattern, replacement, matching, buffer)
 }
 
 /// Apply a regex substitution to a file
@@ -263,9 +288,31 @@ pub fn read_utf8_or_skip(file_path: &Path) -> Result<Option<String>> {
     }
 }
 
+/// Normalize `content` to end with exactly one newline, keeping the file's
+/// line-ending style (CRLF if it has any, else LF). Content that is empty or
+/// only newlines becomes empty, as `end-of-file-fixer`-style linters expect.
+pub fn ensure_final_newline(content: &str) -> String {
+    let body = content.trim_end_matches(['\r', '\n']);
+    if body.is_empty() {
+        return String::new();
+    }
+    let eol = if content.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    };
+    format!("{body}{eol}")
+}
+
 /// Write content to a file atomically, creating parent directories if needed.
-pub fn write_file_content(file_path: &Path, content: &str) -> Result<()> {
-    atomic_write(file_path, content.as_bytes())?;
+/// With `final_newline`, the content is first normalized by
+/// [`ensure_final_newline`]; otherwise it is written byte-for-byte.
+pub fn write_file_content(file_path: &Path, content: &str, final_newline: bool) -> Result<()> {
+    if final_newline {
+        atomic_write(file_path, ensure_final_newline(content).as_bytes())?;
+    } else {
+        atomic_write(file_path, content.as_bytes())?;
+    }
     debug!("Wrote content to file: {}", file_path.display());
     Ok(())
 }
@@ -308,23 +355,30 @@ pub fn delete_file(file_path: &Path) -> Result<()> {
     Ok(())
 }
 
-/// Create a new file with content
+/// Create a new file with content. The content always gains a trailing
+/// newline if it lacks one; `final_newline` additionally collapses any run of
+/// trailing newlines to exactly one.
 pub fn create_file_with_content(
     file_path: &Path,
     content: &str,
     buffer: usize,
+    final_newline: bool,
 ) -> Result<(String, String)> {
-    // Ensure content has exactly one trailing newline
-    let mut file_content = content.to_string();
-    if !file_content.ends_with('\n') {
-        file_content.push('\n');
-    }
+    let file_content = if final_newline {
+        ensure_final_newline(content)
+    } else {
+        let mut file_content = content.to_string();
+        if !file_content.ends_with('\n') {
+            file_content.push('\n');
+        }
+        file_content
+    };
 
     // Generate diff from empty to new content
     let diff_output = diff::generate_diff("", &file_content, buffer);
 
     // Write the file
-    write_file_content(file_path, &file_content)?;
+    write_file_content(file_path, &file_content, false)?;
 
     Ok((file_content, diff_output))
 }
@@ -391,5 +445,196 @@ pub fn restore_backup(backup: &Path, original: &Path, mode: u32) -> Result<()> {
     Ok(())
 }
 
+/// A structured file `set-key` can edit, by extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StructuredFormat {
+    Json,
+    Yaml,
+}
+
+impl StructuredFormat {
+    /// `.json` is JSON, `.yaml`/`.yml` YAML; anything else is not editable.
+    pub fn for_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "json" => Some(StructuredFormat::Json),
+            "yaml" | "yml" => Some(StructuredFormat::Yaml),
+            _ => None,
+        }
+    }
+}
+
+/// Set the value at the dotted `key_path` (`scripts.build`) in a JSON or YAML
+/// file. `value` is parsed as a JSON literal (`true`, `3`, `{"a":1}`,
+/// `"1.2"`) and taken as a plain string when it isn't one; a key that holds
+/// a string now keeps a string (`1.10` stays `"1.10"`) unless `value` is
+/// JSON-quoted. A key missing along the path is `NoMatches`, unless `create`
+/// adds it (and any objects above it); stepping into a string, number or
+/// list entry that isn't there is an error. A file that doesn't parse is
+/// `SkippedUnparsable`. Key order is kept; JSON keeps its indent and trailing
+/// newline, while YAML is re-emitted, so its comments are lost.
+pub fn set_key_in_file(
+    file_path: &Path,
+    key_path: &str,
+    value: &str,
+    create: bool,
+    buffer: usize,
+) -> Result<diff::SubstitutionResult> {
+    let format = StructuredFormat::for_path(file_path).ok_or_else(|| {
+        eyre::eyre!(
+            "Not a JSON or YAML file (by extension): {}",
+            file_path.display()
+        )
+    })?;
+    let Some(content) = read_utf8_or_skip(file_path)? else {
+        return Ok(diff::SubstitutionResult::SkippedBinary);
+    };
+    set_key_in_content(&content, format, key_path, value, create, buffer)
+        .with_context(|| format!("Failed to set {key_path} in {}", file_path.display()))
+}
+
+fn set_key_in_content(
+    content: &str,
+    format: StructuredFormat,
+    key_path: &str,
+    value: &str,
+    create: bool,
+    buffer: usize,
+) -> Result<diff::SubstitutionResult> {
+    let parsed: Result<serde_json::Value, String> = match format {
+        StructuredFormat::Json => {
+            serde_json::from_str(content).map_err(|e| format!("invalid JSON: {e}"))
+        }
+        StructuredFormat::Yaml => {
+            serde_yaml::from_str(content).map_err(|e| format!("invalid YAML: {e}"))
+        }
+    };
+    let mut document = match parsed {
+        Ok(document) => document,
+        Err(reason) => return Ok(diff::SubstitutionResult::SkippedUnparsable(reason)),
+    };
+
+    let Some((slot, created)) = resolve_key_path(&mut document, key_path, create)? else {
+        return Ok(diff::SubstitutionResult::NoMatches);
+    };
+    let value = if slot.is_string() && !value.trim_start().starts_with('"') {
+        serde_json::Value::String(value.to_string())
+    } else {
+        serde_json::from_str(value).unwrap_or_else(|_| serde_json::Value::String(value.to_string()))
+    };
+    if !created && *slot == value {
+        return Ok(diff::SubstitutionResult::NoChange { matches: 1 });
+    }
+    *slot = value;
+
+    let updated = match format {
+        StructuredFormat::Json => render_json_like(&document, content)?,
+        StructuredFormat::Yaml => {
+            serde_yaml::to_string(&document).context("Failed to write YAML")?
+        }
+    };
+    Ok(diff::SubstitutionResult::Changed {
+        diff: diff::generate_diff(content, &updated, buffer),
+        content: updated,
+        matches: 1,
+    })
+}
+
+/// The slot at `key_path` in `document`, and whether `create` just added
+/// it; `None` when a key is missing and `create` is off. A numeric segment
+/// indexes an existing list entry.
+fn resolve_key_path<'a>(
+    document: &'a mut serde_json::Value,
+    key_path: &str,
+    create: bool,
+) -> Result<Option<(&'a mut serde_json::Value, bool)>> {
+    let segments: Vec<&str> = key_path.split('.').collect();
+    if segments.iter().any(|segment| segment.is_empty()) {
+        return Err(eyre::eyre!("Invalid key path '{key_path}'"));
+    }
+
+    let mut current = document;
+    let mut created = false;
+    for (i, segment) in segments.iter().enumerate() {
+        let walked = segments[..i].join(".");
+        let at = if walked.is_empty() {
+            "the top level".to_string()
+        } else {
+            format!("'{walked}'")
+        };
+        current = match current {
+            serde_json::Value::Object(map) => {
+                if !map.contains_key(*segment) {
+                    if !create {
+                        return Ok(None);
+                    }
+                    let last = i + 1 == segments.len();
+                    let empty = if last {
+                        serde_json::Value::Null
+                    } else {
+                        serde_json::Value::Object(serde_json::Map::new())
+                    };
+                    map.insert(segment.to_string(), empty);
+                    created = true;
+                }
+                map.get_mut(*segment).expect("present or just inserted")
+            }
+            serde_json::Value::Array(items) => {
+                let len = items.len();
+                match segment.parse::<usize>() {
+                    Ok(index) if index < len => &mut items[index],
+                    _ => {
+                        return Err(eyre::eyre!(
+                            "'{segment}' is not an index into the {len}-entry list at {at}"
+                        ))
+                    }
+                }
+            }
+            other => {
+                return Err(eyre::eyre!(
+                    "Cannot set '{segment}' under {at}: it is {}, not an object",
+                    json_type_name(other)
+                ))
+            }
+        };
+    }
+    Ok(Some((current, created)))
+}
+
+fn json_type_name(value: &serde_json::Value) -> &'static str {
+    match value {
+        serde_json::Value::Null => "null",
+        serde_json::Value::Bool(_) => "a boolean",
+        serde_json::Value::Number(_) => "a number",
+        serde_json::Value::String(_) => "a string",
+        serde_json::Value::Array(_) => "a list",
+        serde_json::Value::Object(_) => "an object",
+    }
+}
+
+/// Serialize `document` the way `original` was written: one line if it was,
+/// otherwise pretty with its indent (the first indented line's leading
+/// whitespace, default two spaces), and with its trailing newline, if any.
+fn render_json_like(document: &serde_json::Value, original: &str) -> Result<String> {
+    let mut rendered = if original.trim().contains('\n') {
+        let indent = original
+            .lines()
+            .skip(1)
+            .map(|line| &line[..line.len() - line.trim_start().len()])
+            .find(|indent| !indent.is_empty())
+            .unwrap_or("  ");
+        let mut out = Vec::new();
+        let formatter = serde_json::ser::PrettyFormatter::with_indent(indent.as_bytes());
+        let mut serializer = serde_json::Serializer::with_formatter(&mut out, formatter);
+        serde::Serialize::serialize(document, &mut serializer).context("Failed to write JSON")?;
+        String::from_utf8(out).context("Failed to write JSON")?
+    } else {
+        serde_json::to_string(document).context("Failed to write JSON")?
+    };
+    if original.ends_with('\n') {
+        rendered.push('\n');
+    }
+    Ok(rendered)
+}
+
 #[cfg(test)]
 mod tests;
@@ -1,4 +1,5 @@
 use super::*;
+use crate::diff::SubMatch;
 use crate::test_utils::run_git_command;
 use std::fs;
 use tempfile::TempDir;
@@ -140,6 +141,38 @@ fn test_matching_star_does_not_cross_directories() {
     assert_eq!(names, vec!["top.txt".to_string()]);
 }
 
+#[test]
+fn test_matching_skips_tracked_files_under_gitignored_dir() {
+    let temp = TempDir::new().unwrap();
+    let repo = temp.path();
+    git_init(repo);
+
+    write(&repo.join(".gitignore"), "vendor/\n");
+    write(&repo.join("config.json"), "{}");
+    git_commit_all(repo, "initial");
+    // Force-add a matching file inside the ignored directory: tracked, but
+    // covered by .gitignore.
+    write(&repo.join("vendor/pkg/package.json"), "{}");
+    let add = run_git_command(&["add", "-f", "vendor/pkg/package.json"], repo);
+    assert!(add.status.success(), "git add -f failed");
+    let commit = run_git_command(&["commit", "--quiet", "-m", "vendor"], repo);
+    assert!(commit.status.success(), "git commit failed");
+
+    let patterns = ["**/*.json".to_string()];
+    let matched = FileSet::matching_any(repo, &patterns).unwrap();
+    assert_eq!(matched, vec![PathBuf::from("config.json")]);
+
+    // --no-gitignore keeps it.
+    let matched = FileSet::matching(repo, &patterns, true).unwrap();
+    assert_eq!(
+        matched,
+        vec![
+            PathBuf::from("config.json"),
+            PathBuf::from("vendor/pkg/package.json"),
+        ]
+    );
+}
+
 #[test]
 fn test_candidates_excludes_symlinks() {
     let temp = TempDir::new().unwrap();
@@ -169,7 +202,7 @@ fn test_apply_substitution_skips_binary() {
     // Invalid UTF-8 bytes.
     fs::write(&file_path, [0xff, 0xfe, 0x00, 0x01, 0x80]).unwrap();
 
-    let result = apply_substitution_to_file(&file_path, "x", "y", 1).unwrap();
+    let result = apply_substitution_to_file(&file_path, "x", "y", SubMatch::default(), 1).unwrap();
     assert!(matches!(
         result,
         crate::diff::SubstitutionResult::SkippedBinary
@@ -188,7 +221,8 @@ fn test_match_count_multi_match() {
     let file_path = temp.path().join("multi.txt");
     fs::write(&file_path, "foo foo foo\nbar foo").unwrap();
 
-    let result = apply_substitution_to_file(&file_path, "foo", "qux", 1).unwrap();
+    let result =
+        apply_substitution_to_file(&file_path, "foo", "qux", SubMatch::default(), 1).unwrap();
     if let crate::diff::SubstitutionResult::Changed { matches, .. } = result {
         assert_eq!(matches, 4);
     } else {
@@ -311,7 +345,8 @@ fn test_apply_substitution_to_file() {
     let file_path = temp_dir.path().join("test.txt");
     fs::write(&file_path, "Hello world\nThis is a test\nHello again").unwrap();
 
-    let result = apply_substitution_to_file(&file_path, "Hello", "Hi", 1).unwrap();
+    let result =
+        apply_substitution_to_file(&file_path, "Hello", "Hi", SubMatch::default(), 1).unwrap();
     if let crate::diff::SubstitutionResult::Changed {
         content, matches, ..
     } = result
@@ -347,13 +382,41 @@ fn test_create_file_with_content() {
     let temp_dir = TempDir::new().unwrap();
     let file_path = temp_dir.path().join("new_file.txt");
 
-    let (content, diff) = create_file_with_content(&file_path, "Hello world", 1).unwrap();
+    let (content, diff) = create_file_with_content(&file_path, "Hello world", 1, false).unwrap();
     assert_eq!(content, "Hello world\n");
     assert!(!diff.is_empty());
     assert!(file_path.exists());
     assert_eq!(fs::read_to_string(&file_path).unwrap(), "Hello world\n");
 }
 
+#[test]
+fn test_ensure_final_newline() {
+    assert_eq!(ensure_final_newline("a"), "a\n");
+    assert_eq!(ensure_final_newline("a\n"), "a\n");
+    assert_eq!(ensure_final_newline("a\n\n\n"), "a\n");
+    assert_eq!(ensure_final_newline("a\r\nb\r\n\r\n"), "a\r\nb\r\n");
+    assert_eq!(ensure_final_newline(""), "");
+    assert_eq!(ensure_final_newline("\n\n"), "");
+}
+
+#[test]
+fn test_final_newline_flag_gates_writes() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("f.txt");
+
+    // Off: exact bytes, even without a trailing newline.
+    write_file_content(&file_path, "x", false).unwrap();
+    assert_eq!(fs::read_to_string(&file_path).unwrap(), "x");
+
+    write_file_content(&file_path, "x", true).unwrap();
+    assert_eq!(fs::read_to_string(&file_path).unwrap(), "x\n");
+
+    let added = temp_dir.path().join("added.txt");
+    let (content, _) = create_file_with_content(&added, "y\n\n", 1, true).unwrap();
+    assert_eq!(content, "y\n");
+    assert_eq!(fs::read_to_string(&added).unwrap(), "y\n");
+}
+
 #[test]
 fn test_delete_file() {
     let temp_dir = TempDir::new().unwrap();
@@ -370,7 +433,7 @@ fn test_write_file_content_with_nested_dirs() {
     let temp_dir = TempDir::new().unwrap();
     let file_path = temp_dir.path().join("nested").join("dir").join("file.txt");
 
-    write_file_content(&file_path, "nested content").unwrap();
+    write_file_content(&file_path, "nested content", false).unwrap();
     assert!(file_path.exists());
     assert_eq!(fs::read_to_string(&file_path).unwrap(), "nested content");
 }
@@ -430,3 +493,133 @@ fn test_restore_backup_restores_mode() {
         "restore_backup must reapply the mode captured at backup time"
     );
 }
+
+/// The updated content of a `set-key` edit that changed something.
+fn set_key(content: &str, format: StructuredFormat, key_path: &str, value: &str) -> String {
+    match set_key_in_content(content, format, key_path, value, true, 3).unwrap() {
+        diff::SubstitutionResult::Changed { content, .. } => content,
+        other => panic!("expected a change, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_set_key_replaces_an_existing_key_keeping_order_and_indent() {
+    let package = "{\n    \"name\": \"api\",\n    \"scripts\": {\n        \"build\": \"make\",\n        \"test\": \"make test\"\n    },\n    \"private\": true\n}\n";
+    let updated = set_key(package, StructuredFormat::Json, "scripts.build", "tsc -b");
+    assert_eq!(
+        updated,
+        "{\n    \"name\": \"api\",\n    \"scripts\": {\n        \"build\": \"tsc -b\",\n        \"test\": \"make test\"\n    },\n    \"private\": true\n}\n"
+    );
+
+    // A JSON literal value keeps its type; the same value again is no change.
+    let updated = set_key(package, StructuredFormat::Json, "private", "false");
+    assert!(updated.contains("\"private\": false"), "{updated}");
+    assert!(matches!(
+        set_key_in_content(package, StructuredFormat::Json, "private", "true", false, 3).unwrap(),
+        diff::SubstitutionResult::NoChange { matches: 1 }
+    ));
+}
+
+#[test]
+fn test_set_key_creates_nested_keys_only_when_asked() {
+    let config = "name: api\nci:\n  enabled: true\n";
+    assert!(matches!(
+        set_key_in_content(
+            config,
+            StructuredFormat::Yaml,
+            "ci.cache.key",
+            "v2",
+            false,
+            3
+        )
+        .unwrap(),
+        diff::SubstitutionResult::NoMatches
+    ));
+
+    let updated = set_key(config, StructuredFormat::Yaml, "ci.cache.key", "v2");
+    assert_eq!(
+        updated,
+        "name: api\nci:\n  enabled: true\n  cache:\n    key: v2\n"
+    );
+
+    let updated = set_key("{}", StructuredFormat::Json, "a.b", "\"1.2\"");
+    assert_eq!(updated, r#"{"a":{"b":"1.2"}}"#);
+}
+
+#[test]
+fn test_set_key_keeps_a_string_value_a_string() {
+    let package = "{\"version\": \"1.9\", \"port\": 80}";
+    let updated = set_key(package, StructuredFormat::Json, "version", "1.10");
+    assert_eq!(updated, r#"{"version":"1.10","port":80}"#);
+    // A non-string key still takes a JSON literal.
+    let updated = set_key(package, StructuredFormat::Json, "port", "8080");
+    assert_eq!(updated, r#"{"version":"1.9","port":8080}"#);
+    // JSON-quoted stays the explicit way to write a string anywhere.
+    let updated = set_key(package, StructuredFormat::Json, "port", "\"8080\"");
+    assert_eq!(updated, r#"{"version":"1.9","port":"8080"}"#);
+}
+
+#[test]
+fn test_set_key_skips_a_file_that_does_not_parse() {
+    // JSONC (comments) and multi-document YAML are not plain JSON/YAML.
+    let tsconfig = "{\n  // strict mode\n  \"strict\": true\n}\n";
+    assert!(matches!(
+        set_key_in_content(tsconfig, StructuredFormat::Json, "strict", "false", false, 3).unwrap(),
+        diff::SubstitutionResult::SkippedUnparsable(reason) if reason.starts_with("invalid JSON")
+    ));
+    let manifests = "kind: Service\n---\nkind: Deployment\n";
+    assert!(matches!(
+        set_key_in_content(manifests, StructuredFormat::Yaml, "kind", "Pod", false, 3).unwrap(),
+        diff::SubstitutionResult::SkippedUnparsable(_)
+    ));
+}
+
+#[test]
+fn test_set_key_reports_a_type_mismatch_along_the_path() {
+    let package = "{\"scripts\": \"make\", \"files\": [\"dist\"]}";
+    let err = set_key_in_content(
+        package,
+        StructuredFormat::Json,
+        "scripts.build",
+        "x",
+        true,
+        3,
+    )
+    .unwrap_err()
+    .to_string();
+    assert_eq!(
+        err,
+        "Cannot set 'build' under 'scripts': it is a string, not an object"
+    );
+
+    let err = set_key_in_content(package, StructuredFormat::Json, "files.3", "x", true, 3)
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("not an index into the 1-entry list at 'files'"),
+        "{err}"
+    );
+    assert!(set_key_in_content(package, StructuredFormat::Json, "a..b", "x", true, 3).is_err());
+}
+
+#[test]
+fn test_set_key_in_file_picks_the_format_by_extension() {
+    let temp = TempDir::new().unwrap();
+    let json = temp.path().join("package.json");
+    fs::write(&json, "{\n  \"version\": \"1.0.0\"\n}\n").unwrap();
+    match set_key_in_file(&json, "version", "1.1.0", false, 3).unwrap() {
+        diff::SubstitutionResult::Changed { content, diff, .. } => {
+            assert_eq!(content, "{\n  \"version\": \"1.1.0\"\n}\n");
+            assert!(diff.contains("1.1.0"), "{diff}");
+        }
+        other => panic!("expected a change, got {other:?}"),
+    }
+
+    let text = temp.path().join("notes.txt");
+    fs::write(&text, "version: 1").unwrap();
+    assert!(set_key_in_file(&text, "version", "2", false, 3).is_err());
+    assert_eq!(
+        StructuredFormat::for_path(Path::new("ci.YML")),
+        Some(StructuredFormat::Yaml)
+    );
+}
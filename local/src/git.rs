@@ -2,7 +2,179 @@ use crate::repo::Repo;
 use crate::subprocess::{run_checked, subprocess_timeout};
 use eyre::{Context, Result};
 use log::debug;
-use std::process::Command;
+use std::collections::HashMap;
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::sync::{LazyLock, Mutex};
+
+/// `git -C <repo_path>` with gx's non-interactive environment. Every git call
+/// against a repo is built here (or with [`noninteractive_git`] when there is
+/// no repo yet) so an auth failure fails fast instead of a credential prompt
+/// wedging a rayon worker until the subprocess timeout.
+pub fn git_command(repo_path: impl AsRef<Path>) -> Command {
+    let repo_path = repo_path.as_ref();
+    let mut cmd = git_with_ssh_command(batch_mode_ssh_command(Some(repo_path)));
+    cmd.arg("-C").arg(repo_path);
+    cmd
+}
+
+/// [`git_command`] with `--no-optional-locks`, for the read-only status
+/// path: git then skips the index refresh it would otherwise write back, so
+/// a status never takes `index.lock` from an editor or another git running
+/// in the same repo.
+pub fn read_only_git_command(repo_path: impl AsRef<Path>) -> Command {
+    let mut cmd = git_command(repo_path);
+    cmd.arg("--no-optional-locks");
+    cmd
+}
+
+/// Bare `git` with the environment [`git_command`] uses, for calls with no
+/// repo to `-C` into (`clone`, global `config`): `GIT_TERMINAL_PROMPT=0` turns
+/// off git's own username/password prompt and `GIT_SSH_COMMAND` runs the
+/// user's ssh with `BatchMode=yes` (no passphrase or host-key prompt).
+pub fn noninteractive_git() -> Command {
+    git_with_ssh_command(batch_mode_ssh_command(None))
+}
+
+fn git_with_ssh_command(ssh_command: String) -> Command {
+    let mut cmd = Command::new("git");
+    cmd.env("GIT_TERMINAL_PROMPT", "0")
+        .env("GIT_SSH_COMMAND", ssh_command);
+    cmd
+}
+
+/// One git invocation built on [`git_command`] (so `-C` always takes the path
+/// as-is and the non-interactive environment is always set), run under the
+/// subprocess timeout with failures surfaced one way: [`GitCmd::run`] turns a
+/// non-zero exit into `Failed to <what>: <git's stderr>`.
+pub struct GitCmd {
+    cmd: Command,
+}
+
+impl GitCmd {
+    /// `git -C <repo_path>`.
+    pub fn new(repo_path: impl AsRef<Path>) -> Self {
+        Self {
+            cmd: git_command(repo_path),
+        }
+    }
+
+    /// `git -C <repo_path> --no-optional-locks` ([`read_only_git_command`]).
+    pub fn read_only(repo_path: impl AsRef<Path>) -> Self {
+        Self {
+            cmd: read_only_git_command(repo_path),
+        }
+    }
+
+    /// `git` with no repo to `-C` into (`clone`).
+    pub fn without_repo() -> Self {
+        Self {
+            cmd: noninteractive_git(),
+        }
+    }
+
+    pub fn arg(mut self, arg: impl AsRef<OsStr>) -> Self {
+        self.cmd.arg(arg);
+        self
+    }
+
+    pub fn args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        self.cmd.args(args);
+        self
+    }
+
+    /// Run and return the raw output; only a spawn failure or timeout is an
+    /// `Err`, a non-zero exit is left to the caller.
+    pub fn output(mut self) -> Result<Output> {
+        run_checked(&mut self.cmd, subprocess_timeout())
+    }
+
+    /// Run and require success; `what` completes `Failed to ...` in the error.
+    pub fn run(self, what: &str) -> Result<Output> {
+        let output = self.output()?;
+        if output.status.success() {
+            Ok(output)
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(eyre::eyre!("Failed to {what}: {}", stderr.trim()))
+        }
+    }
+
+    /// [`GitCmd::run`] and return stdout, trimmed.
+    pub fn stdout(self, what: &str) -> Result<String> {
+        let output = self.run(what)?;
+        Ok(String::from_utf8(output.stdout)
+            .with_context(|| format!("Failed to {what}: git output is not UTF-8"))?
+            .trim()
+            .to_string())
+    }
+}
+
+/// The ssh command git would use for `repo_path`: `$GIT_SSH_COMMAND`, else
+/// the repo's effective `core.sshCommand` (local, then global and system),
+/// else plain `ssh`. With no repo only the global setting is read, so the
+/// current directory's repo never leaks into a clone. Resolved once per repo.
+pub fn configured_ssh_command(repo_path: Option<&Path>) -> String {
+    static SSH_COMMANDS: LazyLock<Mutex<HashMap<Option<PathBuf>, String>>> =
+        LazyLock::new(Default::default);
+
+    if let Ok(command) = std::env::var("GIT_SSH_COMMAND") {
+        if !command.trim().is_empty() {
+            debug!("Using SSH command from GIT_SSH_COMMAND: {command}");
+            return command;
+        }
+    }
+    let key = repo_path.map(Path::to_path_buf);
+    if let Some(command) = SSH_COMMANDS.lock().ok().and_then(|c| c.get(&key).cloned()) {
+        return command;
+    }
+
+    // Plain `git` here: `git_command` itself resolves through this function.
+    let mut lookup = Command::new("git");
+    match repo_path {
+        Some(path) => lookup.arg("-C").arg(path).arg("config"),
+        None => lookup.args(["config", "--global"]),
+    };
+    lookup.args(["--get", "core.sshCommand"]);
+    let configured = run_checked(&mut lookup, subprocess_timeout())
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .filter(|command| !command.is_empty());
+    let command = match configured {
+        Some(command) => {
+            debug!("Using SSH command from git config for {key:?}: {command}");
+            command
+        }
+        None => {
+            debug!("Using default SSH command for {key:?}");
+            "ssh".to_string()
+        }
+    };
+    if let Ok(mut cache) = SSH_COMMANDS.lock() {
+        cache.insert(key, command.clone());
+    }
+    command
+}
+
+/// [`configured_ssh_command`] with `-o BatchMode=yes` appended, unless the
+/// user's command already decides `BatchMode` itself.
+fn batch_mode_ssh_command(repo_path: Option<&Path>) -> String {
+    with_batch_mode(&configured_ssh_command(repo_path))
+}
+
+fn with_batch_mode(ssh_command: &str) -> String {
+    if ssh_command.to_lowercase().contains("batchmode") {
+        ssh_command.to_string()
+    } else {
+        format!("{ssh_command} -o BatchMode=yes")
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct RepoStatus {
@@ -13,6 +185,30 @@ pub struct RepoStatus {
     pub changes: StatusChanges,
     pub remote_status: RemoteStatus,
     pub error: Option<String>,
+    /// Lines added/removed across the working tree and index, when
+    /// `gx status --diff-stat` asked for it (`None` otherwise).
+    pub diff_stat: Option<(u32, u32)>,
+    /// Every local branch's ahead/behind, when `gx status --ahead-behind-all`
+    /// asked for it (`None` otherwise).
+    pub branch_sync: Option<Vec<BranchSync>>,
+    /// Submodules whose checked-out commit has moved off the recorded
+    /// pointer, when `gx status --include-submodules-in-status` asked for
+    /// them (0 otherwise). Those are then left out of `changes.modified`.
+    pub submodule_changes: u32,
+    /// HEAD's commit subject, for `gx status` in detailed mode (`None`
+    /// otherwise).
+    pub commit_subject: Option<String>,
+}
+
+/// One local branch's position relative to its upstream.
+#[derive(Debug, Clone)]
+pub struct BranchSync {
+    pub branch: String,
+    /// The configured upstream (`origin/main`); `None` when there is none.
+    pub upstream: Option<String>,
+    /// Ahead/behind against `upstream`; `NoUpstream` without one, `NoRemote`
+    /// when the upstream is configured but its ref is gone.
+    pub remote_status: RemoteStatus,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -21,7 +217,11 @@ pub struct StatusChanges {
     pub added: u32,
     pub deleted: u32,
     pub renamed: u32,
+    /// Untracked files outside any wholly-untracked directory.
     pub untracked: u32,
+    /// Wholly-untracked directories, which git collapses to one `dir/` entry
+    /// however many files they hold.
+    pub untracked_dirs: u32,
     pub staged: u32,
 }
 
@@ -34,6 +234,7 @@ pub enum RemoteStatus {
     NoRemote,           // 📍 No remote tracking branch
     NoUpstream,         // 📍 No upstream branch configured
     DetachedHead,       // 📍 Detached HEAD state
+    NoBranch,           // 🚫 Named branch (`status --branch`) absent locally
     Error(String),      // ❌ Error checking remote status
 }
 
@@ -51,9 +252,14 @@ impl StatusChanges {
             && self.added == 0
             && self.deleted == 0
             && self.renamed == 0
-            && self.untracked == 0
+            && !self.has_untracked()
             && self.staged == 0
     }
+
+    /// Whether anything at all is untracked, file or directory.
+    pub fn has_untracked(&self) -> bool {
+        self.untracked > 0 || self.untracked_dirs > 0
+    }
 }
 
 /// Compute a repository's status using ONLY local refs -- current branch,
@@ -82,6 +288,10 @@ pub fn get_repo_status_local(repo: &Repo) -> RepoStatus {
                 changes,
                 remote_status,
                 error: None,
+                diff_stat: None,
+                branch_sync: None,
+                submodule_changes: 0,
+                commit_subject: None,
             }
         }
         Err(e) => RepoStatus {
@@ -92,77 +302,83 @@ pub fn get_repo_status_local(repo: &Repo) -> RepoStatus {
             changes: StatusChanges::default(),
             remote_status,
             error: Some(e.to_string()),
+            diff_stat: None,
+            branch_sync: None,
+            submodule_changes: 0,
+            commit_subject: None,
         },
     }
 }
 
 /// Get current commit SHA (7 characters)
 pub fn get_current_commit_sha(repo: &Repo) -> Option<String> {
-    let output = run_checked(
-        Command::new("git").args([
-            "-C",
-            &repo.path.to_string_lossy(),
-            "rev-parse",
-            "--short=7",
-            "HEAD",
-        ]),
-        subprocess_timeout(),
-    )
-    .ok()?;
-
-    if output.status.success() {
-        let sha = String::from_utf8(output.stdout).ok()?;
-        Some(sha.trim().to_string())
-    } else {
-        None
-    }
+    GitCmd::new(&repo.path)
+        .args(["rev-parse", "--short=7", "HEAD"])
+        .stdout("read HEAD")
+        .ok()
 }
 
 /// Get the current branch name for a repository
 pub fn get_current_branch(repo: &Repo) -> Option<String> {
-    let output = run_checked(
-        Command::new("git")
-            .arg("-C")
-            .arg(&repo.path)
-            .arg("branch")
-            .arg("--show-current"),
-        subprocess_timeout(),
-    )
-    .ok()?;
+    let branch = GitCmd::read_only(&repo.path)
+        .args(["branch", "--show-current"])
+        .stdout("read the current branch")
+        .ok()?;
 
-    if output.status.success() {
-        let branch = String::from_utf8(output.stdout).ok()?.trim().to_string();
-
-        if !branch.is_empty() {
-            Some(branch)
-        } else {
-            // Fallback for detached HEAD
-            get_detached_head_info(repo)
-        }
+    if !branch.is_empty() {
+        Some(branch)
     } else {
-        None
+        // Fallback for detached HEAD
+        get_detached_head_info(repo)
     }
 }
 
 /// Get info for detached HEAD state
 fn get_detached_head_info(repo: &Repo) -> Option<String> {
-    let output = run_checked(
-        Command::new("git")
-            .arg("-C")
-            .arg(&repo.path)
-            .arg("rev-parse")
-            .arg("--short")
-            .arg("HEAD"),
-        subprocess_timeout(),
-    )
-    .ok()?;
+    let commit = GitCmd::new(&repo.path)
+        .args(["rev-parse", "--short", "HEAD"])
+        .stdout("read HEAD")
+        .ok()?;
+    Some(format!("HEAD@{commit}"))
+}
 
-    if output.status.success() {
-        let commit = String::from_utf8(output.stdout).ok()?.trim().to_string();
-        Some(format!("HEAD@{commit}"))
-    } else {
-        None
-    }
+/// The subject line of HEAD's commit (`git log -1 --format=%s`); `None` in a
+/// repo without commits.
+pub fn get_commit_subject(repo: &Repo) -> Option<String> {
+    GitCmd::new(&repo.path)
+        .args(["log", "-1", "--format=%s"])
+        .stdout("read HEAD's commit subject")
+        .ok()
+        .filter(|subject| !subject.is_empty())
+}
+
+/// The files git keeps in the git dir while an operation is half done, and
+/// the operation each one means, in the order they are checked.
+const IN_PROGRESS_MARKERS: &[(&str, &str)] = &[
+    ("rebase-merge", "rebase"),
+    ("rebase-apply", "rebase"),
+    ("MERGE_HEAD", "merge"),
+    ("CHERRY_PICK_HEAD", "cherry-pick"),
+    ("REVERT_HEAD", "revert"),
+    ("BISECT_LOG", "bisect"),
+];
+
+/// The operation left half done in the repo at `repo_path` (`rebase`,
+/// `merge`, `cherry-pick`, `revert` or `bisect`), so batch commands can
+/// leave it alone; `None` when there is none or the git dir can't be found.
+pub fn repo_operation_in_progress(repo_path: &Path) -> Option<String> {
+    let git_dir = GitCmd::new(repo_path)
+        .args(["rev-parse", "--absolute-git-dir"])
+        .stdout("find the git dir")
+        .ok()?;
+    operation_in_progress_in(Path::new(&git_dir))
+}
+
+fn operation_in_progress_in(git_dir: &Path) -> Option<String> {
+    IN_PROGRESS_MARKERS
+        .iter()
+        .find(|(marker, _)| git_dir.join(marker).exists())
+        .map(|(_, operation)| operation.to_string())
 }
 
 /// Parse `git status --porcelain=v1` output text into change counts.
@@ -170,9 +386,14 @@ fn get_detached_head_info(repo: &Repo) -> Option<String> {
 /// The single counting rule used everywhere ([A20]). Porcelain v1 lines are
 /// `XY <path>` where `X` is the index (staged) status and `Y` the worktree
 /// status:
-/// - `??` -> untracked
-/// - index `A` -> added; index `M`/`D`/`C` -> staged; index `R` -> renamed
-/// - worktree `M` -> modified; worktree `D` -> deleted
+/// - `??` -> untracked; a path ending in `/` is a wholly-untracked directory
+/// - an unmerged pair (`DD`, `AU`, `UD`, `UA`, `DU`, `AA`, `UU`) -> modified,
+///   once: neither column means staged/worktree there
+/// - index `A` -> added; index `M`/`T`/`D`/`C` -> staged; index `R` -> renamed
+/// - worktree `M`/`T` -> modified; worktree `D` -> deleted
+///
+/// Each column counts at most once per line, so `RM` is one rename plus one
+/// worktree modification; counts only ever go up.
 pub fn parse_porcelain_status(text: &str) -> StatusChanges {
     let mut changes = StatusChanges::default();
 
@@ -185,18 +406,32 @@ pub fn parse_porcelain_status(text: &str) -> StatusChanges {
         let worktree_status = chars.next().unwrap_or(' ');
 
         if index_status == '?' && worktree_status == '?' {
-            changes.untracked += 1;
+            // A quoted path (special characters) keeps its slash inside the quotes.
+            if line.trim_end_matches('"').ends_with('/') {
+                changes.untracked_dirs += 1;
+            } else {
+                changes.untracked += 1;
+            }
+            continue;
+        }
+
+        let unmerged = index_status == 'U'
+            || worktree_status == 'U'
+            || (index_status, worktree_status) == ('D', 'D')
+            || (index_status, worktree_status) == ('A', 'A');
+        if unmerged {
+            changes.modified += 1;
             continue;
         }
 
         match index_status {
             'A' => changes.added += 1,
-            'M' | 'D' | 'C' => changes.staged += 1,
+            'M' | 'T' | 'D' | 'C' => changes.staged += 1,
             'R' => changes.renamed += 1,
             _ => {}
         }
         match worktree_status {
-            'M' => changes.modified += 1,
+            'M' | 'T' => changes.modified += 1,
             'D' => changes.deleted += 1,
             _ => {}
         }
@@ -205,35 +440,126 @@ pub fn parse_porcelain_status(text: &str) -> StatusChanges {
     changes
 }
 
-/// Run `git status --porcelain=v1` in `repo_path` and return the output text.
-fn run_status_porcelain(repo_path: &std::path::Path) -> Result<String> {
-    let output = run_checked(
-        Command::new("git")
-            .arg("-C")
-            .arg(repo_path)
-            .arg("status")
-            .arg("--porcelain=v1"),
-        subprocess_timeout(),
-    )
-    .context("Failed to run git status")?;
+/// How `git status` reports untracked files (`-u<mode>`): `all` lists every
+/// file inside an untracked directory, `normal` (git's default) collapses the
+/// directory to one entry, and `no` skips the untracked scan entirely.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UntrackedFiles {
+    All,
+    #[default]
+    Normal,
+    No,
+}
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(eyre::eyre!("git status failed: {}", stderr));
+impl UntrackedFiles {
+    /// The `git status` flag for this mode.
+    pub fn as_arg(self) -> &'static str {
+        match self {
+            UntrackedFiles::All => "-uall",
+            UntrackedFiles::Normal => "-unormal",
+            UntrackedFiles::No => "-uno",
+        }
     }
+}
+
+impl std::str::FromStr for UntrackedFiles {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "all" => Ok(UntrackedFiles::All),
+            "normal" => Ok(UntrackedFiles::Normal),
+            "no" => Ok(UntrackedFiles::No),
+            other => Err(format!(
+                "unknown untracked-files mode '{other}' (expected all, normal or no)"
+            )),
+        }
+    }
+}
+
+/// Run `git status --porcelain=v1` in `repo_path` and return the output text.
+fn run_status_porcelain(repo_path: &std::path::Path, untracked: UntrackedFiles) -> Result<String> {
+    let output = status_porcelain_command(repo_path, untracked).run("run git status")?;
 
     // We only parse the leading `XY` status columns, never the path, so a lossy
     // conversion is safe and avoids aborting on a non-UTF-8 filename ([A21]).
     Ok(String::from_utf8_lossy(&output.stdout).into_owned())
 }
 
+fn status_porcelain_command(repo_path: &std::path::Path, untracked: UntrackedFiles) -> GitCmd {
+    GitCmd::read_only(repo_path).args(["status", "--porcelain=v1", untracked.as_arg()])
+}
+
 /// Get status changes by parsing git status --porcelain output
 pub fn get_status_changes(repo: &Repo) -> Result<StatusChanges> {
-    let changes = parse_porcelain_status(&run_status_porcelain(&repo.path)?);
-    debug!("Status for {}: {:?}", repo.name, changes);
+    get_status_changes_with_untracked(repo, UntrackedFiles::default())
+}
+
+/// [`get_status_changes`] with an explicit untracked-files mode
+/// (`gx status --untracked-files`).
+pub fn get_status_changes_with_untracked(
+    repo: &Repo,
+    untracked: UntrackedFiles,
+) -> Result<StatusChanges> {
+    let changes = parse_porcelain_status(&run_status_porcelain(&repo.path, untracked)?);
+    debug!("Status for {} ({untracked:?}): {:?}", repo.name, changes);
     Ok(changes)
 }
 
+/// Count the submodules whose checked-out commit differs from the one the
+/// superproject records (`git submodule status` marks them `+`). Porcelain v1
+/// shows such a moved pointer as a plain worktree `M`, indistinguishable from
+/// an edited file; this is how `gx status --include-submodules-in-status`
+/// tells them apart. Zero in a repo without submodules.
+pub fn count_moved_submodules(repo: &Repo) -> Result<u32> {
+    let output = GitCmd::read_only(&repo.path)
+        .args(["submodule", "status"])
+        .run("run git submodule status")?;
+    let moved = parse_submodule_status(&String::from_utf8_lossy(&output.stdout));
+    debug!("Moved submodules for {}: {moved}", repo.name);
+    Ok(moved)
+}
+
+/// Count the `+` (checked-out commit differs from the index) lines of
+/// `git submodule status`. ` ` (in sync), `-` (not initialized) and `U`
+/// (merge conflict, already counted as modified) are not moves.
+pub fn parse_submodule_status(output: &str) -> u32 {
+    output.lines().filter(|line| line.starts_with('+')).count() as u32
+}
+
+/// Lines added and removed in `repo`, unstaged plus staged, from
+/// `git diff --numstat` and `git diff --cached --numstat`.
+pub fn get_diff_stat(repo: &Repo) -> Result<(u32, u32)> {
+    let mut added = 0;
+    let mut removed = 0;
+    for cached in [false, true] {
+        let output = GitCmd::read_only(&repo.path)
+            .arg("diff")
+            .args(cached.then_some("--cached"))
+            .arg("--numstat")
+            .run("run git diff --numstat")?;
+        let (a, r) = parse_numstat(&String::from_utf8_lossy(&output.stdout));
+        added += a;
+        removed += r;
+    }
+    debug!("Diff stat for {}: +{added}/-{removed}", repo.name);
+    Ok((added, removed))
+}
+
+/// Sum the `added<TAB>removed<TAB>path` lines of `git diff --numstat`.
+/// Binary files report `-` for both counts and are left out of the totals.
+pub fn parse_numstat(output: &str) -> (u32, u32) {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split('\t');
+            let added = fields.next()?.parse::<u32>().ok()?;
+            let removed = fields.next()?.parse::<u32>().ok()?;
+            Some((added, removed))
+        })
+        .fold((0, 0), |(a, r), (added, removed)| (a + added, r + removed))
+}
+
 /// Parse git status --porcelain --branch output for remote tracking info
 fn parse_branch_tracking_info(status_output: &str) -> Result<BranchTrackingInfo> {
     use regex::Regex;
@@ -309,13 +635,7 @@ pub fn get_remote_status_native(repo: &Repo) -> RemoteStatus {
 
     // Execute git status --porcelain --branch
     let output = match run_checked(
-        Command::new("git").args([
-            "-C",
-            &repo.path.to_string_lossy(),
-            "status",
-            "--porcelain",
-            "--branch",
-        ]),
+        read_only_git_command(&repo.path).args(["status", "--porcelain", "--branch"]),
         subprocess_timeout(),
     ) {
         Ok(output) => output,
@@ -342,17 +662,17 @@ pub fn get_remote_status_native(repo: &Repo) -> RemoteStatus {
         }
     };
 
+    // Checked first: the branch regex would read it as a branch named `HEAD`
+    // with no upstream.
+    if output_str.starts_with("## HEAD (no branch)") {
+        return RemoteStatus::DetachedHead;
+    }
+
     // Parse tracking info
     let tracking_info = match parse_branch_tracking_info(&output_str) {
         Ok(info) => info,
         Err(e) => {
             debug!("Failed to parse git status for {}: {}", repo.name, e);
-
-            // Handle special cases
-            if output_str.contains("## HEAD (no branch)") {
-                return RemoteStatus::DetachedHead;
-            }
-
             return RemoteStatus::Error("Parse failed".to_string());
         }
     };
@@ -362,13 +682,179 @@ pub fn get_remote_status_native(repo: &Repo) -> RemoteStatus {
         return RemoteStatus::NoUpstream;
     }
 
-    // Convert to RemoteStatus based on ahead/behind counts
-    match (tracking_info.ahead, tracking_info.behind) {
+    remote_status_from_counts(tracking_info.ahead, tracking_info.behind)
+}
+
+/// Convert ahead/behind counts to a [`RemoteStatus`].
+fn remote_status_from_counts(ahead: u32, behind: u32) -> RemoteStatus {
+    match (ahead, behind) {
         (0, 0) => RemoteStatus::UpToDate,
-        (a, 0) if a > 0 => RemoteStatus::Ahead(a),
-        (0, b) if b > 0 => RemoteStatus::Behind(b),
-        (a, b) if a > 0 && b > 0 => RemoteStatus::Diverged(a, b),
-        _ => RemoteStatus::UpToDate,
+        (a, 0) => RemoteStatus::Ahead(a),
+        (0, b) => RemoteStatus::Behind(b),
+        (a, b) => RemoteStatus::Diverged(a, b),
+    }
+}
+
+/// Remote status of the current branch against `<remote>/<branch>` rather
+/// than the configured upstream, for forks whose ahead/behind matters
+/// relative to `upstream`. Like [`get_remote_status_native`] it reads the
+/// LOCAL `refs/remotes/<remote>/*` ref and never fetches.
+pub fn get_remote_status_against(repo: &Repo, remote: &str) -> RemoteStatus {
+    debug!(
+        "get_remote_status_against: repo={} remote={remote}",
+        repo.name
+    );
+
+    let branch = match get_current_branch_name(&repo.path) {
+        Ok(branch) if !branch.is_empty() => branch,
+        Ok(_) => return RemoteStatus::DetachedHead,
+        Err(e) => {
+            debug!("Failed to get current branch for {}: {}", repo.name, e);
+            return RemoteStatus::Error("Git command failed".to_string());
+        }
+    };
+
+    let remote_ref = format!("refs/remotes/{remote}/{branch}");
+    // No such remote, or the remote has no branch of this name.
+    count_ahead_behind(repo, "HEAD", &remote_ref).unwrap_or(RemoteStatus::NoRemote)
+}
+
+/// Remote status of the local branch `branch` -- checked out or not --
+/// against its upstream, or against `<remote>/<branch>` when `remote` is
+/// given. The working tree is never touched. Like
+/// [`get_remote_status_native`] it reads LOCAL refs only and never fetches.
+/// A repo without a local `branch` is [`RemoteStatus::NoBranch`].
+pub fn get_branch_remote_status(repo: &Repo, branch: &str, remote: Option<&str>) -> RemoteStatus {
+    debug!(
+        "get_branch_remote_status: repo={} branch={branch} remote={remote:?}",
+        repo.name
+    );
+
+    match branch_exists_locally(&repo.path, branch) {
+        Ok(true) => {}
+        Ok(false) => return RemoteStatus::NoBranch,
+        Err(e) => {
+            debug!("Failed to look up {branch} in {}: {}", repo.name, e);
+            return RemoteStatus::Error("Git command failed".to_string());
+        }
+    }
+
+    let local_ref = format!("refs/heads/{branch}");
+    match remote {
+        Some(remote) => {
+            count_ahead_behind(repo, &local_ref, &format!("refs/remotes/{remote}/{branch}"))
+                .unwrap_or(RemoteStatus::NoRemote)
+        }
+        None => count_ahead_behind(repo, &local_ref, &format!("{local_ref}@{{upstream}}"))
+            .unwrap_or(RemoteStatus::NoUpstream),
+    }
+}
+
+/// Ahead/behind of every local branch against its own upstream, in ref
+/// order. One `for-each-ref` plus one `rev-list` per tracked branch, so it is
+/// only run for `gx status --ahead-behind-all`.
+pub fn all_branch_sync(repo: &Repo) -> Result<Vec<BranchSync>> {
+    debug!("all_branch_sync: repo={}", repo.name);
+
+    let output = run_checked(
+        git_command(&repo.path).args([
+            "for-each-ref",
+            "--format=%(refname:short)%09%(upstream:short)",
+            "refs/heads",
+        ]),
+        subprocess_timeout(),
+    )
+    .context("Failed to run git for-each-ref")?;
+    if !output.status.success() {
+        return Err(eyre::eyre!(
+            "git for-each-ref failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    let listing = String::from_utf8(output.stdout).context("Invalid UTF-8 in git for-each-ref")?;
+    Ok(listing
+        .lines()
+        .filter_map(|line| line.split_once('\t'))
+        .map(|(branch, upstream)| {
+            if upstream.is_empty() {
+                return BranchSync {
+                    branch: branch.to_string(),
+                    upstream: None,
+                    remote_status: RemoteStatus::NoUpstream,
+                };
+            }
+            let local_ref = format!("refs/heads/{branch}");
+            BranchSync {
+                branch: branch.to_string(),
+                upstream: Some(upstream.to_string()),
+                remote_status: count_ahead_behind(
+                    repo,
+                    &local_ref,
+                    &format!("{local_ref}@{{upstream}}"),
+                )
+                .unwrap_or(RemoteStatus::NoRemote),
+            }
+        })
+        .collect())
+}
+
+/// Ahead/behind of `local` relative to `remote` via
+/// `git rev-list --left-right --count local...remote`. `None` when git
+/// cannot resolve one of the refs (the caller picks the "missing" state).
+fn count_ahead_behind(repo: &Repo, local: &str, remote: &str) -> Option<RemoteStatus> {
+    let output = match run_checked(
+        git_command(&repo.path).args([
+            "rev-list",
+            "--left-right",
+            "--count",
+            &format!("{local}...{remote}"),
+        ]),
+        subprocess_timeout(),
+    ) {
+        Ok(output) => output,
+        Err(e) => {
+            debug!("git rev-list failed for {}: {}", repo.name, e);
+            return Some(RemoteStatus::Error("Git command failed".to_string()));
+        }
+    };
+
+    if !output.status.success() {
+        debug!(
+            "{} cannot compare {local} with {remote}: {}",
+            repo.name,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+        return None;
+    }
+
+    let counts = String::from_utf8_lossy(&output.stdout);
+    let mut fields = counts.split_whitespace().map(str::parse::<u32>);
+    Some(match (fields.next(), fields.next()) {
+        (Some(Ok(ahead)), Some(Ok(behind))) => remote_status_from_counts(ahead, behind),
+        _ => RemoteStatus::Error("Parse failed".to_string()),
+    })
+}
+
+/// Add a remote named `name` pointing at `url`.
+pub fn add_remote(repo_path: &std::path::Path, name: &str, url: &str) -> Result<()> {
+    let output = run_checked(
+        git_command(repo_path).args(["remote", "add", name, url]),
+        subprocess_timeout(),
+    )
+    .context("Failed to execute git remote add")?;
+
+    if output.status.success() {
+        debug!(
+            "Added remote '{}' -> {} in '{}'",
+            name,
+            url,
+            repo_path.display()
+        );
+        Ok(())
+    } else {
+        let error = String::from_utf8_lossy(&output.stderr);
+        Err(eyre::eyre!("Failed to add remote '{}': {}", name, error))
     }
 }
 
@@ -381,39 +867,38 @@ pub fn resolve_branch_name(repo: &Repo, branch_name: &str) -> Result<String> {
     }
 }
 
+/// The branch `refs/remotes/origin/HEAD` points at, if the clone recorded
+/// one. This is the authoritative local answer for the default branch; unlike
+/// [`get_default_branch_local`] it never guesses.
+pub fn get_origin_head_branch(repo_path: &std::path::Path) -> Option<String> {
+    let output = run_checked(
+        git_command(repo_path).args(["symbolic-ref", "--quiet", "refs/remotes/origin/HEAD"]),
+        subprocess_timeout(),
+    )
+    .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8(output.stdout)
+        .ok()?
+        .trim()
+        .strip_prefix("refs/remotes/origin/")
+        .map(str::to_string)
+}
+
 /// Get default branch using local git commands (fast, no GitHub API)
 pub fn get_default_branch_local(repo: &Repo) -> Result<String> {
     debug!("Getting default branch for repo: {}", repo.name);
 
     // Try to get the default branch from remote HEAD
-    let output = run_checked(
-        Command::new("git").args([
-            "-C",
-            &repo.path.to_string_lossy(),
-            "symbolic-ref",
-            "refs/remotes/origin/HEAD",
-        ]),
-        subprocess_timeout(),
-    );
-
-    if let Ok(output) = output {
-        if output.status.success() {
-            let head_ref = String::from_utf8(output.stdout)
-                .context("Invalid UTF-8 in git symbolic-ref output")?;
-
-            // Extract branch name from refs/remotes/origin/branch-name
-            if let Some(branch) = head_ref.trim().strip_prefix("refs/remotes/origin/") {
-                return Ok(branch.to_string());
-            }
-        }
+    if let Some(branch) = get_origin_head_branch(&repo.path) {
+        return Ok(branch);
     }
 
     // Fallback: try common default branch names (check if they exist locally)
     for branch in &["main", "master"] {
         let output = run_checked(
-            Command::new("git").args([
-                "-C",
-                &repo.path.to_string_lossy(),
+            git_command(&repo.path).args([
                 "rev-parse",
                 "--verify",
                 &format!("refs/heads/{branch}"),
@@ -430,7 +915,7 @@ pub fn get_default_branch_local(repo: &Repo) -> Result<String> {
 
     // Try to find the initial branch (the one created by git init)
     let output = run_checked(
-        Command::new("git").args(["-C", &repo.path.to_string_lossy(), "branch", "--list"]),
+        git_command(&repo.path).args(["branch", "--list"]),
         subprocess_timeout(),
     );
 
@@ -479,36 +964,30 @@ pub fn resolve_update_work_tree(target_dir: &std::path::Path) -> Result<std::pat
 
 /// Get remote origin URL for a repository
 pub fn get_remote_origin(repo_path: &std::path::Path) -> Result<String> {
-    let output = run_checked(
-        Command::new("git").args([
-            "-C",
-            &repo_path.to_string_lossy(),
-            "remote",
-            "get-url",
-            "origin",
-        ]),
-        subprocess_timeout(),
-    )
-    .context("Failed to get remote origin")?;
-
-    if !output.status.success() {
-        let error = String::from_utf8_lossy(&output.stderr);
-        return Err(eyre::eyre!("Failed to get remote origin: {}", error));
-    }
-
-    let url = String::from_utf8(output.stdout)?.trim().to_string();
-    Ok(url)
+    GitCmd::new(repo_path)
+        .args(["remote", "get-url", "origin"])
+        .stdout("get remote origin")
 }
 
-/// Check if remote URL matches the expected repository slug
+/// Check if remote URL matches the expected repository slug, whichever
+/// scheme the clone used (SSH, or HTTPS with or without `user@` credentials).
 pub fn is_same_repo(remote_url: &str, expected_slug: &str) -> bool {
     // Handle different URL formats
     let normalized_remote = if let Some(ssh_part) = remote_url.strip_prefix("git@github.com:") {
         ssh_part.trim_end_matches(".git").to_string()
     } else if let Some(ssh_part) = remote_url.strip_prefix("ssh://git@github.com/") {
         ssh_part.trim_end_matches(".git").to_string()
-    } else if let Some(https_part) = remote_url.strip_prefix("https://github.com/") {
-        https_part.trim_end_matches(".git").to_string()
+    } else if let Some(https_part) = remote_url.strip_prefix("https://") {
+        let host_path = https_part
+            .rsplit_once('@')
+            .map_or(https_part, |(_, rest)| rest);
+        match host_path.strip_prefix("github.com/") {
+            Some(path) => path
+                .trim_end_matches('/')
+                .trim_end_matches(".git")
+                .to_string(),
+            None => remote_url.to_string(),
+        }
     } else {
         remote_url.to_string()
     };
@@ -518,7 +997,10 @@ pub fn is_same_repo(remote_url: &str, expected_slug: &str) -> bool {
 
 /// Get status changes for a repository path (helper function for clone)
 pub fn get_status_changes_for_path(repo_path: &std::path::Path) -> Result<StatusChanges> {
-    Ok(parse_porcelain_status(&run_status_porcelain(repo_path)?))
+    Ok(parse_porcelain_status(&run_status_porcelain(
+        repo_path,
+        UntrackedFiles::default(),
+    )?))
 }
 
 // Enhanced git operations for create/review functionality
@@ -538,58 +1020,28 @@ pub fn create_branch(repo_path: &std::path::Path, branch_name: &str) -> Result<(
     }
 
     // Create new branch from current HEAD
-    let output = run_checked(
-        Command::new("git").args([
-            "-C",
-            &repo_path.to_string_lossy(),
-            "checkout",
-            "-b",
-            branch_name,
-        ]),
-        subprocess_timeout(),
-    )
-    .context("Failed to execute git checkout -b")?;
-
-    if output.status.success() {
-        debug!(
-            "Created new branch '{}' in '{}'",
-            branch_name,
-            repo_path.display()
-        );
-        Ok(())
-    } else {
-        let error = String::from_utf8_lossy(&output.stderr);
-        Err(eyre::eyre!(
-            "Failed to create branch '{}': {}",
-            branch_name,
-            error
-        ))
-    }
+    GitCmd::new(repo_path)
+        .args(["checkout", "-b", branch_name])
+        .run(&format!("create branch '{branch_name}'"))?;
+    debug!(
+        "Created new branch '{}' in '{}'",
+        branch_name,
+        repo_path.display()
+    );
+    Ok(())
 }
 
 /// Switch to an existing branch
 pub fn switch_branch(repo_path: &std::path::Path, branch_name: &str) -> Result<()> {
-    let output = run_checked(
-        Command::new("git").args(["-C", &repo_path.to_string_lossy(), "checkout", branch_name]),
-        subprocess_timeout(),
-    )
-    .context("Failed to execute git checkout")?;
-
-    if output.status.success() {
-        debug!(
-            "Switched to branch '{}' in '{}'",
-            branch_name,
-            repo_path.display()
-        );
-        Ok(())
-    } else {
-        let error = String::from_utf8_lossy(&output.stderr);
-        Err(eyre::eyre!(
-            "Failed to switch to branch '{}': {}",
-            branch_name,
-            error
-        ))
-    }
+    GitCmd::new(repo_path)
+        .args(["checkout", branch_name])
+        .run(&format!("switch to branch '{branch_name}'"))?;
+    debug!(
+        "Switched to branch '{}' in '{}'",
+        branch_name,
+        repo_path.display()
+    );
+    Ok(())
 }
 
 /// Prove -- by PATCH identity, not commit identity -- that every commit on
@@ -630,13 +1082,7 @@ pub fn branch_changes_in_base(
         repo_path.display()
     );
     let output = run_checked(
-        Command::new("git").args([
-            "-C",
-            &repo_path.to_string_lossy(),
-            "cherry",
-            base_ref,
-            branch_name,
-        ]),
+        git_command(repo_path).args(["cherry", base_ref, branch_name]),
         subprocess_timeout(),
     )
     .context("Failed to execute git cherry")?;
@@ -660,13 +1106,7 @@ pub fn branch_changes_in_base(
 /// Delete a local branch
 pub fn delete_local_branch(repo_path: &std::path::Path, branch_name: &str) -> Result<()> {
     let output = run_checked(
-        Command::new("git").args([
-            "-C",
-            &repo_path.to_string_lossy(),
-            "branch",
-            "-D",
-            branch_name,
-        ]),
+        git_command(repo_path).args(["branch", "-D", branch_name]),
         subprocess_timeout(),
     )
     .context("Failed to execute git branch -D")?;
@@ -688,6 +1128,124 @@ pub fn delete_local_branch(repo_path: &std::path::Path, branch_name: &str) -> Re
     }
 }
 
+/// Whether `refs/tags/<tag_name>` exists locally.
+pub fn tag_exists_locally(repo_path: &std::path::Path, tag_name: &str) -> Result<bool> {
+    let output = run_checked(
+        git_command(repo_path).args([
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("refs/tags/{tag_name}"),
+        ]),
+        subprocess_timeout(),
+    )
+    .context("Failed to execute git rev-parse")?;
+
+    Ok(output.status.success())
+}
+
+/// Create an annotated tag on HEAD (`git tag -a <name> -m <message>`). Fails
+/// if the tag already exists.
+pub fn create_tag(repo_path: &std::path::Path, tag_name: &str, message: &str) -> Result<()> {
+    let output = run_checked(
+        git_command(repo_path).args(["tag", "-a", tag_name, "-m", message]),
+        subprocess_timeout(),
+    )
+    .context("Failed to execute git tag")?;
+
+    if output.status.success() {
+        debug!("Created tag '{}' in '{}'", tag_name, repo_path.display());
+        Ok(())
+    } else {
+        let error = String::from_utf8_lossy(&output.stderr);
+        Err(eyre::eyre!(
+            "Failed to create tag '{}': {}",
+            tag_name,
+            error.trim()
+        ))
+    }
+}
+
+/// Delete a local tag (`git tag -d`).
+pub fn delete_local_tag(repo_path: &std::path::Path, tag_name: &str) -> Result<()> {
+    let output = run_checked(
+        git_command(repo_path).args(["tag", "-d", tag_name]),
+        subprocess_timeout(),
+    )
+    .context("Failed to execute git tag -d")?;
+
+    if output.status.success() {
+        debug!("Deleted tag '{}' in '{}'", tag_name, repo_path.display());
+        Ok(())
+    } else {
+        let error = String::from_utf8_lossy(&output.stderr);
+        Err(eyre::eyre!(
+            "Failed to delete tag '{}': {}",
+            tag_name,
+            error.trim()
+        ))
+    }
+}
+
+/// Every local branch as `(name, upstream_gone)`. `upstream_gone` is true when
+/// the branch tracks a remote branch that no longer exists (`[gone]` once a
+/// fetch has pruned it); a branch with no upstream at all is not gone.
+pub fn list_local_branches(repo_path: &std::path::Path) -> Result<Vec<(String, bool)>> {
+    let output = run_checked(
+        git_command(repo_path).args([
+            "for-each-ref",
+            "--format=%(refname:short)%09%(upstream:track)",
+            "refs/heads",
+        ]),
+        subprocess_timeout(),
+    )
+    .context("Failed to execute git for-each-ref")?;
+
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        return Err(eyre::eyre!("Failed to list local branches: {}", error));
+    }
+    Ok(parse_branch_tracks(&String::from_utf8_lossy(
+        &output.stdout,
+    )))
+}
+
+/// Parse `name<TAB>track` lines from [`list_local_branches`].
+fn parse_branch_tracks(output: &str) -> Vec<(String, bool)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (name, track) = line.split_once('\t').unwrap_or((line, ""));
+            let name = name.trim();
+            (!name.is_empty()).then(|| (name.to_string(), track.trim() == "[gone]"))
+        })
+        .collect()
+}
+
+/// Local branches whose tip is reachable from `base` (`git branch --merged`).
+pub fn list_merged_branches(repo_path: &std::path::Path, base: &str) -> Result<Vec<String>> {
+    let output = run_checked(
+        git_command(repo_path).args(["branch", "--merged", base, "--format=%(refname:short)"]),
+        subprocess_timeout(),
+    )
+    .context("Failed to execute git branch --merged")?;
+
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        return Err(eyre::eyre!(
+            "Failed to list branches merged into '{}': {}",
+            base,
+            error
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
 /// Stage specific files (handles add, modify, and delete)
 /// Uses "git add -A --" which stages all changes for the specified files:
 /// - New files are added
@@ -701,15 +1259,15 @@ pub fn add_files(repo_path: &std::path::Path, files: &[String]) -> Result<()> {
 
     // Use literal pathspecs (`:(literal)<path>`) so a tracked filename
     // containing glob metacharacters cannot be re-expanded by git ([A26]).
-    let repo_path_str = repo_path.to_string_lossy().to_string();
     let literal_specs: Vec<String> = files.iter().map(|f| format!(":(literal){f}")).collect();
-    let mut args: Vec<&str> = vec!["-C", &repo_path_str, "add", "-A", "--"];
-    for spec in &literal_specs {
-        args.push(spec.as_str());
-    }
 
-    let output = run_checked(Command::new("git").args(&args), subprocess_timeout())
-        .context("Failed to execute git add")?;
+    let output = run_checked(
+        git_command(repo_path)
+            .args(["add", "-A", "--"])
+            .args(&literal_specs),
+        subprocess_timeout(),
+    )
+    .context("Failed to execute git add")?;
 
     if output.status.success() {
         debug!(
@@ -725,31 +1283,102 @@ pub fn add_files(repo_path: &std::path::Path, files: &[String]) -> Result<()> {
     }
 }
 
-/// Commit staged changes with a message
-pub fn commit_changes(repo_path: &std::path::Path, message: &str) -> Result<()> {
+/// Per-commit options for [`commit_changes`]. `Default` is a plain
+/// `git commit -m <message>`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommitOptions {
+    /// Sign the commit (`git commit -S`), using git's configured
+    /// `gpg.format` (GPG or SSH).
+    pub sign: bool,
+    /// Signing key for this one commit, passed as `-c user.signingkey=<key>`
+    /// so the repo's own config is never touched. Only used with `sign`.
+    pub sign_key: Option<String>,
+    /// `Key: Value` trailers (`Co-authored-by: ...`) appended to the message,
+    /// in order.
+    pub trailers: Vec<String>,
+    /// Skip the pre-commit and commit-msg hooks (`git commit --no-verify`).
+    /// `gx create` skips the pre-push hook on its pushes too.
+    pub no_verify: bool,
+}
+
+/// `message` with `trailers` appended, one per line and in order, as a final
+/// paragraph of their own so git parses them as trailers (`%(trailers)`,
+/// `git interpret-trailers`). Built here rather than with `git commit
+/// --trailer`, which needs git 2.32.
+pub fn message_with_trailers(message: &str, trailers: &[String]) -> String {
+    if trailers.is_empty() {
+        return message.to_string();
+    }
+    format!("{}\n\n{}", message.trim_end(), trailers.join("\n"))
+}
+
+/// Build the `git` argument vector for [`commit_changes`]. Pure so the exact
+/// signing flags can be asserted without running git. `-c` is a global git
+/// option and must precede the `commit` subcommand. `OsString` so the repo
+/// path reaches git byte-for-byte, even when it isn't UTF-8.
+pub fn commit_args(
+    repo_path: &std::path::Path,
+    message: &str,
+    options: &CommitOptions,
+) -> Vec<OsString> {
+    let mut args: Vec<OsString> = vec!["-C".into(), repo_path.into()];
+    if options.sign {
+        if let Some(key) = &options.sign_key {
+            args.push("-c".into());
+            args.push(format!("user.signingkey={key}").into());
+        }
+    }
+    args.push("commit".into());
+    if options.sign {
+        args.push("-S".into());
+    }
+    if options.no_verify {
+        args.push("--no-verify".into());
+    }
+    args.push("-m".into());
+    args.push(message_with_trailers(message, &options.trailers).into());
+    args
+}
+
+/// Commit staged changes with a message. A signing failure (no key, agent
+/// unavailable) is an error like any other commit failure: git creates no
+/// commit at all, so an unsigned commit can never slip through.
+pub fn commit_changes(
+    repo_path: &std::path::Path,
+    message: &str,
+    options: &CommitOptions,
+) -> Result<()> {
     let output = run_checked(
-        Command::new("git").args(["-C", &repo_path.to_string_lossy(), "commit", "-m", message]),
+        noninteractive_git().args(commit_args(repo_path, message, options)),
         subprocess_timeout(),
     )
     .context("Failed to execute git commit")?;
 
     if output.status.success() {
         debug!(
-            "Committed changes in '{}' with message: {}",
+            "Committed changes in '{}' with message: {} (signed: {})",
             repo_path.display(),
-            message
+            message,
+            options.sign
         );
         Ok(())
     } else {
         let error = String::from_utf8_lossy(&output.stderr);
-        Err(eyre::eyre!("Failed to commit changes: {}", error))
+        if options.sign {
+            Err(eyre::eyre!(
+                "Failed to create signed commit (is a signing key configured?): {}",
+                error
+            ))
+        } else {
+            Err(eyre::eyre!("Failed to commit changes: {}", error))
+        }
     }
 }
 
 /// Check if repository has uncommitted changes
 pub fn has_uncommitted_changes(repo_path: &std::path::Path) -> Result<bool> {
     let output = run_checked(
-        Command::new("git").args(["-C", &repo_path.to_string_lossy(), "status", "--porcelain"]),
+        git_command(repo_path).args(["status", "--porcelain"]),
         subprocess_timeout(),
     )
     .context("Failed to execute git status")?;
@@ -767,12 +1396,7 @@ pub fn has_uncommitted_changes(repo_path: &std::path::Path) -> Result<bool> {
 /// Get the current branch name
 pub fn get_current_branch_name(repo_path: &std::path::Path) -> Result<String> {
     let output = run_checked(
-        Command::new("git").args([
-            "-C",
-            &repo_path.to_string_lossy(),
-            "branch",
-            "--show-current",
-        ]),
+        git_command(repo_path).args(["branch", "--show-current"]),
         subprocess_timeout(),
     )
     .context("Failed to execute git branch --show-current")?;
@@ -792,9 +1416,7 @@ pub fn get_current_branch_name(repo_path: &std::path::Path) -> Result<String> {
 /// Check if a branch exists locally
 pub fn branch_exists_locally(repo_path: &std::path::Path, branch_name: &str) -> Result<bool> {
     let output = run_checked(
-        Command::new("git").args([
-            "-C",
-            &repo_path.to_string_lossy(),
+        git_command(repo_path).args([
             "rev-parse",
             "--verify",
             &format!("refs/heads/{branch_name}"),
@@ -813,9 +1435,7 @@ pub fn branch_exists_locally(repo_path: &std::path::Path, branch_name: &str) ->
 /// `branch_exists_remotely`, which really runs `git ls-remote`.
 pub fn branch_exists_on_remote(repo_path: &std::path::Path, branch_name: &str) -> Result<bool> {
     let output = run_checked(
-        Command::new("git").args([
-            "-C",
-            &repo_path.to_string_lossy(),
+        git_command(repo_path).args([
             "rev-parse",
             "--verify",
             &format!("refs/remotes/origin/{branch_name}"),
@@ -831,9 +1451,7 @@ pub fn branch_exists_on_remote(repo_path: &std::path::Path, branch_name: &str) -
 /// tracking branch from `origin/<branch>` already on disk; no network).
 pub fn checkout_remote_branch(repo_path: &std::path::Path, branch_name: &str) -> Result<()> {
     let output = run_checked(
-        Command::new("git").args([
-            "-C",
-            &repo_path.to_string_lossy(),
+        git_command(repo_path).args([
             "checkout",
             "-b",
             branch_name,
@@ -872,9 +1490,7 @@ pub fn commit_parent_count(repo_path: &std::path::Path, oid: &str) -> Result<usi
         repo_path.display()
     );
     let output = run_checked(
-        Command::new("git")
-            .current_dir(repo_path)
-            .args(["rev-list", "--parents", "-n", "1", oid]),
+        git_command(repo_path).args(["rev-list", "--parents", "-n", "1", oid]),
         subprocess_timeout(),
     )
     .context("Failed to execute git rev-list --parents")?;
@@ -909,12 +1525,7 @@ pub fn create_branch_at(
         repo_path.display()
     );
     let output = run_checked(
-        Command::new("git").current_dir(repo_path).args([
-            "checkout",
-            "-b",
-            branch_name,
-            start_point,
-        ]),
+        git_command(repo_path).args(["checkout", "-b", branch_name, start_point]),
         subprocess_timeout(),
     )
     .context("Failed to execute git checkout -b")?;
@@ -953,11 +1564,8 @@ pub fn revert_commit(repo_path: &std::path::Path, oid: &str, mainline: Option<u3
     args.push(oid.to_string());
     let arg_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
 
-    let output = run_checked(
-        Command::new("git").current_dir(repo_path).args(&arg_refs),
-        subprocess_timeout(),
-    )
-    .context("Failed to execute git revert")?;
+    let output = run_checked(git_command(repo_path).args(&arg_refs), subprocess_timeout())
+        .context("Failed to execute git revert")?;
 
     if output.status.success() {
         debug!("Reverted {oid} in '{}'", repo_path.display());
@@ -973,13 +1581,31 @@ pub fn revert_commit(repo_path: &std::path::Path, oid: &str, mainline: Option<u3
     }
 }
 
+/// Abandon an in-progress revert (`git revert --abort`), restoring the branch
+/// and worktree to where they were before it started.
+pub fn revert_abort(repo_path: &std::path::Path) -> Result<()> {
+    debug!("revert_abort: repo_path={}", repo_path.display());
+    let output = run_checked(
+        git_command(repo_path).args(["revert", "--abort"]),
+        subprocess_timeout(),
+    )
+    .context("Failed to execute git revert --abort")?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(eyre::eyre!(
+            "git revert --abort failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+    }
+}
+
 /// Get the full SHA of HEAD.
 pub fn get_head_sha(repo_path: &std::path::Path) -> Result<String> {
     debug!("get_head_sha: repo_path={}", repo_path.display());
     let output = run_checked(
-        Command::new("git")
-            .current_dir(repo_path)
-            .args(["rev-parse", "HEAD"]),
+        git_command(repo_path).args(["rev-parse", "HEAD"]),
         subprocess_timeout(),
     )
     .context("Failed to execute git rev-parse HEAD")?;
@@ -1003,9 +1629,7 @@ pub fn stash_save_with_untracked(repo_path: &std::path::Path, message: &str) ->
         repo_path.display()
     );
     let output = run_checked(
-        Command::new("git")
-            .current_dir(repo_path)
-            .args(["stash", "push", "-u", "-m", message]),
+        git_command(repo_path).args(["stash", "push", "-u", "-m", message]),
         subprocess_timeout(),
     )
     .map_err(|e| eyre::eyre!("Failed to run git stash push -u: {}", e))?;
@@ -1019,9 +1643,7 @@ pub fn stash_save_with_untracked(repo_path: &std::path::Path, message: &str) ->
 
     // Resolve the SHA of the stash we just created.
     let sha_output = run_checked(
-        Command::new("git")
-            .current_dir(repo_path)
-            .args(["rev-parse", "stash@{0}"]),
+        git_command(repo_path).args(["rev-parse", "stash@{0}"]),
         subprocess_timeout(),
     )
     .map_err(|e| eyre::eyre!("Failed to resolve stash SHA: {}", e))?;
@@ -1050,9 +1672,7 @@ pub fn stash_sha_by_message(repo_path: &std::path::Path, message: &str) -> Resul
         repo_path.display()
     );
     let output = run_checked(
-        Command::new("git")
-            .current_dir(repo_path)
-            .args(["stash", "list", "--format=%H %gs"]),
+        git_command(repo_path).args(["stash", "list", "--format=%H %gs"]),
         subprocess_timeout(),
     )
     .map_err(|e| eyre::eyre!("Failed to list stashes: {}", e))?;
@@ -1085,9 +1705,7 @@ pub fn stash_apply_sha(repo_path: &std::path::Path, stash_sha: &str) -> Result<(
         repo_path.display()
     );
     let output = run_checked(
-        Command::new("git")
-            .current_dir(repo_path)
-            .args(["stash", "apply", stash_sha]),
+        git_command(repo_path).args(["stash", "apply", stash_sha]),
         subprocess_timeout(),
     )
     .map_err(|e| eyre::eyre!("Failed to run git stash apply: {}", e))?;
@@ -1105,19 +1723,42 @@ pub fn stash_apply_sha(repo_path: &std::path::Path, stash_sha: &str) -> Result<(
 
 /// Drop the stash entry whose commit SHA equals `stash_sha`.
 ///
-/// `git stash drop` only accepts a positional `stash@{n}`, so we resolve `n`
-/// from `git reflog show stash` by matching the SHA immediately before dropping
-/// and re-verify the SHA at that index, so a concurrent stash mutation cannot
-/// shift the entry and drop the wrong stash ([A15]).
+/// `git stash drop` only accepts a positional `stash@{n}`, so the entry is
+/// resolved with [`stash_ref_for_sha`] immediately before dropping, so a
+/// concurrent stash mutation cannot shift the entry and drop the wrong stash
+/// ([A15]).
 pub fn stash_drop_by_sha(repo_path: &std::path::Path, stash_sha: &str) -> Result<()> {
     debug!(
         "stash_drop_by_sha: repo_path={} stash_sha={stash_sha}",
         repo_path.display()
     );
+    let stash_ref = stash_ref_for_sha(repo_path, stash_sha)?;
+
+    let drop = run_checked(
+        git_command(repo_path).args(["stash", "drop", &stash_ref]),
+        subprocess_timeout(),
+    )
+    .map_err(|e| eyre::eyre!("Failed to drop stash: {}", e))?;
+
+    if drop.status.success() {
+        debug!("stash_drop_by_sha: dropped {stash_ref} ({stash_sha})");
+        Ok(())
+    } else {
+        Err(eyre::eyre!(
+            "Failed to drop stash {}: {}",
+            stash_ref,
+            String::from_utf8_lossy(&drop.stderr)
+        ))
+    }
+}
+
+/// The positional `stash@{n}` currently holding the stash whose commit SHA is
+/// `stash_sha`, found in `git reflog show stash` and re-verified at that
+/// index. Resolve it right before the `pop`/`drop` that needs it: positions
+/// shift whenever a stash is pushed or dropped ([A15]).
+pub fn stash_ref_for_sha(repo_path: &std::path::Path, stash_sha: &str) -> Result<String> {
     let reflog = run_checked(
-        Command::new("git")
-            .current_dir(repo_path)
-            .args(["reflog", "show", "stash", "--format=%H"]),
+        git_command(repo_path).args(["reflog", "show", "stash", "--format=%H"]),
         subprocess_timeout(),
     )
     .map_err(|e| eyre::eyre!("Failed to read stash reflog: {}", e))?;
@@ -1137,11 +1778,9 @@ pub fn stash_drop_by_sha(repo_path: &std::path::Path, stash_sha: &str) -> Result
 
     let stash_ref = format!("stash@{{{index}}}");
 
-    // Re-verify the SHA at that index before dropping.
+    // Re-verify the SHA at that index before handing it out.
     let verify = run_checked(
-        Command::new("git")
-            .current_dir(repo_path)
-            .args(["rev-parse", &stash_ref]),
+        git_command(repo_path).args(["rev-parse", &stash_ref]),
         subprocess_timeout(),
     )
     .map_err(|e| eyre::eyre!("Failed to verify stash ref {}: {}", stash_ref, e))?;
@@ -1154,23 +1793,32 @@ pub fn stash_drop_by_sha(repo_path: &std::path::Path, stash_sha: &str) -> Result
             stash_sha
         ));
     }
+    Ok(stash_ref)
+}
 
-    let drop = run_checked(
-        Command::new("git")
-            .current_dir(repo_path)
-            .args(["stash", "drop", &stash_ref]),
+/// Pop `stash_ref` (a positional `stash@{n}`) onto the working tree. When the
+/// pop conflicts git keeps the entry, so nothing is lost; the conflict is
+/// returned as an error for the caller to report.
+pub fn stash_pop(repo_path: &std::path::Path, stash_ref: &str) -> Result<()> {
+    debug!(
+        "stash_pop: repo_path={} stash_ref={stash_ref}",
+        repo_path.display()
+    );
+    let output = run_checked(
+        git_command(repo_path).args(["stash", "pop", stash_ref]),
         subprocess_timeout(),
     )
-    .map_err(|e| eyre::eyre!("Failed to drop stash: {}", e))?;
+    .map_err(|e| eyre::eyre!("Failed to run git stash pop: {}", e))?;
 
-    if drop.status.success() {
-        debug!("stash_drop_by_sha: dropped {stash_ref} ({stash_sha})");
+    if output.status.success() {
         Ok(())
     } else {
+        // A conflicting pop reports on stdout, other failures on stderr.
         Err(eyre::eyre!(
-            "Failed to drop stash {}: {}",
+            "git stash pop {} failed (the stash is kept): {}{}",
             stash_ref,
-            String::from_utf8_lossy(&drop.stderr)
+            String::from_utf8_lossy(&output.stdout).trim(),
+            String::from_utf8_lossy(&output.stderr).trim()
         ))
     }
 }
@@ -1183,9 +1831,7 @@ pub fn reset_hard_to_sha(repo_path: &std::path::Path, sha: &str) -> Result<()> {
         repo_path.display()
     );
     let output = run_checked(
-        Command::new("git")
-            .current_dir(repo_path)
-            .args(["reset", "--hard", sha]),
+        git_command(repo_path).args(["reset", "--hard", sha]),
         subprocess_timeout(),
     )
     .map_err(|e| eyre::eyre!("Failed to run git reset --hard {}: {}", sha, e))?;
@@ -1209,9 +1855,7 @@ pub fn force_switch_branch(repo_path: &std::path::Path, branch_name: &str) -> Re
         repo_path.display()
     );
     let output = run_checked(
-        Command::new("git")
-            .current_dir(repo_path)
-            .args(["checkout", "-f", branch_name]),
+        git_command(repo_path).args(["checkout", "-f", branch_name]),
         subprocess_timeout(),
     )
     .map_err(|e| eyre::eyre!("Failed to run git checkout -f {}: {}", branch_name, e))?;
@@ -1253,9 +1897,7 @@ fn bytes_to_path(bytes: &[u8]) -> std::path::PathBuf {
 pub fn list_index_files(repo_path: &std::path::Path) -> Result<Vec<(String, std::path::PathBuf)>> {
     debug!("list_index_files: repo_path={}", repo_path.display());
     let output = run_checked(
-        Command::new("git")
-            .current_dir(repo_path)
-            .args(["ls-files", "--stage", "-z"]),
+        git_command(repo_path).args(["ls-files", "--stage", "-z"]),
         subprocess_timeout(),
     )
     .context("Failed to execute git ls-files --stage -z")?;
@@ -1285,6 +1927,65 @@ pub fn list_index_files(repo_path: &std::path::Path) -> Result<Vec<(String, std:
     Ok(entries)
 }
 
+/// Paths per `git check-ignore` invocation, keeping argv well under ARG_MAX.
+const CHECK_IGNORE_BATCH: usize = 500;
+
+/// The subset of `paths` (relative to `repo_path`) that the repo's ignore rules
+/// match, whether or not they are tracked.
+///
+/// Uses `git check-ignore --no-index -v -n` in batches: `--no-index` is what
+/// makes tracked-but-ignored files (vendored or force-added) report as
+/// ignored, and `-v -n` prints exactly one `<source>:<line>:<pattern>\t<path>`
+/// record per input path, in input order, so results map back onto `paths`
+/// without parsing git's quoted path output. A record with no source (`::`)
+/// or a negated (`!`) pattern is not ignored. Exit status 1 only means
+/// "nothing in this batch is ignored".
+pub fn ignored_paths(
+    repo_path: &std::path::Path,
+    paths: &[std::path::PathBuf],
+) -> Result<std::collections::HashSet<std::path::PathBuf>> {
+    debug!(
+        "ignored_paths: repo_path={} paths={}",
+        repo_path.display(),
+        paths.len()
+    );
+    let mut ignored = std::collections::HashSet::new();
+    for batch in paths.chunks(CHECK_IGNORE_BATCH) {
+        let output = run_checked(
+            git_command(repo_path)
+                .args(["check-ignore", "--no-index", "-v", "-n", "--"])
+                .args(batch),
+            subprocess_timeout(),
+        )
+        .context("Failed to execute git check-ignore")?;
+
+        if !matches!(output.status.code(), Some(0) | Some(1)) {
+            let error = String::from_utf8_lossy(&output.stderr);
+            return Err(eyre::eyre!("Failed to check ignored paths: {}", error));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let records: Vec<&str> = stdout.lines().collect();
+        if records.len() != batch.len() {
+            return Err(eyre::eyre!(
+                "git check-ignore returned {} records for {} paths",
+                records.len(),
+                batch.len()
+            ));
+        }
+        for (record, path) in records.iter().zip(batch) {
+            let meta = record.split('\t').next().unwrap_or("");
+            let pattern = meta.splitn(3, ':').nth(2).unwrap_or("");
+            if meta != "::" && !pattern.starts_with('!') {
+                ignored.insert(path.clone());
+            }
+        }
+    }
+
+    debug!("ignored_paths: {} ignored", ignored.len());
+    Ok(ignored)
+}
+
 /// Add a DETACHED worktree of `base_sha` at `worktree_path`, checked out from
 /// the repo at `repo_path`. Used by the `llm` propose pass to give the agent a
 /// throwaway checkout that shares the object store but is OUTSIDE the real
@@ -1301,8 +2002,7 @@ pub fn worktree_add_detached(
         worktree_path.display()
     );
     let output = run_checked(
-        Command::new("git")
-            .current_dir(repo_path)
+        git_command(repo_path)
             .args(["worktree", "add", "--detach"])
             .arg(worktree_path)
             .arg(base_sha),
@@ -1331,8 +2031,7 @@ pub fn worktree_remove(repo_path: &std::path::Path, worktree_path: &std::path::P
         worktree_path.display()
     );
     let output = run_checked(
-        Command::new("git")
-            .current_dir(repo_path)
+        git_command(repo_path)
             .args(["worktree", "remove", "--force"])
             .arg(worktree_path),
         subprocess_timeout(),
@@ -1357,9 +2056,7 @@ pub fn worktree_remove(repo_path: &std::path::Path, worktree_path: &std::path::P
 pub fn stage_all(worktree_path: &std::path::Path) -> Result<()> {
     debug!("stage_all: worktree_path={}", worktree_path.display());
     let output = run_checked(
-        Command::new("git")
-            .current_dir(worktree_path)
-            .args(["add", "-A"]),
+        git_command(worktree_path).args(["add", "-A"]),
         subprocess_timeout(),
     )
     .context("Failed to execute git add -A")?;
@@ -1393,9 +2090,7 @@ pub fn resolve_worktree_repo(
         return Ok(None);
     }
     let output = run_checked(
-        Command::new("git")
-            .current_dir(worktree_path)
-            .args(["rev-parse", "--git-common-dir"]),
+        git_command(worktree_path).args(["rev-parse", "--git-common-dir"]),
         subprocess_timeout(),
     )
     .context("Failed to execute git rev-parse --git-common-dir")?;
@@ -1427,9 +2122,7 @@ pub fn diff_cached_patch(worktree_path: &std::path::Path, base_sha: &str) -> Res
         worktree_path.display()
     );
     let output = run_checked(
-        Command::new("git")
-            .current_dir(worktree_path)
-            .args(["diff", "--cached", base_sha]),
+        git_command(worktree_path).args(["diff", "--cached", base_sha]),
         subprocess_timeout(),
     )
     .context("Failed to execute git diff --cached")?;
@@ -1455,9 +2148,7 @@ pub fn diff_cached_raw_z(worktree_path: &std::path::Path, base_sha: &str) -> Res
         worktree_path.display()
     );
     let output = run_checked(
-        Command::new("git")
-            .current_dir(worktree_path)
-            .args(["diff", "--cached", "--raw", "-z", base_sha]),
+        git_command(worktree_path).args(["diff", "--cached", "--raw", "-z", base_sha]),
         subprocess_timeout(),
     )
     .context("Failed to execute git diff --cached --raw -z")?;
@@ -1475,6 +2166,172 @@ pub fn diff_cached_raw_z(worktree_path: &std::path::Path, base_sha: &str) -> Res
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_git_command_is_noninteractive() {
+        let cmd = git_command(Path::new("/some/repo"));
+        let args: Vec<_> = cmd.get_args().collect();
+        assert_eq!(args, ["-C", "/some/repo"]);
+
+        let env = |key: &str| {
+            cmd.get_envs()
+                .find(|(k, _)| *k == key)
+                .and_then(|(_, v)| v)
+                .map(|v| v.to_string_lossy().into_owned())
+        };
+        assert_eq!(env("GIT_TERMINAL_PROMPT").as_deref(), Some("0"));
+        let ssh = env("GIT_SSH_COMMAND").expect("GIT_SSH_COMMAND is set");
+        assert!(
+            ssh.starts_with(&configured_ssh_command(Some(Path::new("/some/repo")))),
+            "{ssh}"
+        );
+        assert!(ssh.to_lowercase().contains("batchmode"), "{ssh}");
+    }
+
+    #[test]
+    fn test_status_commands_take_no_optional_locks() {
+        let cmd = status_porcelain_command(Path::new("/some/repo"), UntrackedFiles::No);
+        let args: Vec<_> = cmd.cmd.get_args().collect();
+        assert_eq!(
+            args,
+            [
+                "-C",
+                "/some/repo",
+                "--no-optional-locks",
+                "status",
+                "--porcelain=v1",
+                "-uno"
+            ]
+        );
+
+        let read_only = read_only_git_command(Path::new("/some/repo"));
+        let args: Vec<_> = read_only.get_args().collect();
+        assert_eq!(args, ["-C", "/some/repo", "--no-optional-locks"]);
+        // Same non-interactive environment as every other git call.
+        assert!(read_only
+            .get_envs()
+            .any(|(k, v)| k == "GIT_TERMINAL_PROMPT" && v.is_some_and(|v| v == "0")));
+    }
+
+    #[test]
+    fn test_git_cmd_surfaces_success_and_failure() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let repo = crate::test_utils::create_minimal_test_repo(temp.path(), "gitcmd");
+
+        let head = GitCmd::new(&repo)
+            .args(["rev-parse", "--abbrev-ref", "HEAD"])
+            .stdout("read HEAD")
+            .unwrap();
+        assert!(!head.is_empty());
+
+        // A non-zero exit is `Ok` from `output` but an `Err` naming the
+        // operation and git's stderr from `run`.
+        let output = GitCmd::new(&repo)
+            .args(["checkout", "no-such-branch"])
+            .output()
+            .unwrap();
+        assert!(!output.status.success());
+        let err = GitCmd::new(&repo)
+            .args(["checkout", "no-such-branch"])
+            .run("check out 'no-such-branch'")
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.starts_with("Failed to check out 'no-such-branch': "),
+            "{err}"
+        );
+        assert!(err.contains("no-such-branch"), "{err}");
+    }
+
+    #[test]
+    fn test_migrated_helpers_report_through_git_cmd() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = crate::test_utils::create_minimal_test_repo(temp.path(), "helpers");
+
+        assert_eq!(
+            get_remote_origin(&path).unwrap(),
+            "git@github.com:testorg/helpers.git"
+        );
+        let err = switch_branch(&path, "missing").unwrap_err().to_string();
+        assert!(
+            err.starts_with("Failed to switch to branch 'missing': "),
+            "{err}"
+        );
+
+        let repo = Repo::new(path.clone()).unwrap();
+        assert_eq!(get_current_commit_sha(&repo).map(|sha| sha.len()), Some(7));
+        assert!(get_status_changes(&repo).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_ssh_command_is_resolved_per_repo() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let keyed = crate::test_utils::create_minimal_test_repo(temp.path(), "keyed");
+        let plain = crate::test_utils::create_minimal_test_repo(temp.path(), "plain");
+        crate::test_utils::run_git_command(
+            &[
+                "config",
+                "core.sshCommand",
+                "ssh -i ~/.ssh/work -o BatchMode=no",
+            ],
+            &keyed,
+        );
+
+        let ssh_for = |repo: &Path| {
+            git_command(repo)
+                .get_envs()
+                .find(|(k, _)| *k == "GIT_SSH_COMMAND")
+                .and_then(|(_, v)| v)
+                .map(|v| v.to_string_lossy().into_owned())
+                .unwrap()
+        };
+        if std::env::var("GIT_SSH_COMMAND").is_ok() {
+            // `$GIT_SSH_COMMAND` overrides every repo's config.
+            return;
+        }
+        // The repo's own command, with its explicit BatchMode kept as is.
+        assert_eq!(ssh_for(&keyed), "ssh -i ~/.ssh/work -o BatchMode=no");
+        let plain_ssh = ssh_for(&plain);
+        assert!(!plain_ssh.contains("~/.ssh/work"), "{plain_ssh}");
+        assert!(
+            plain_ssh.to_lowercase().contains("batchmode"),
+            "{plain_ssh}"
+        );
+    }
+
+    #[test]
+    fn test_with_batch_mode_keeps_an_explicit_setting() {
+        assert_eq!(with_batch_mode("ssh"), "ssh -o BatchMode=yes");
+        assert_eq!(
+            with_batch_mode("ssh -i ~/.ssh/work"),
+            "ssh -i ~/.ssh/work -o BatchMode=yes"
+        );
+        assert_eq!(
+            with_batch_mode("ssh -o BatchMode=no"),
+            "ssh -o BatchMode=no"
+        );
+    }
+
+    #[test]
+    fn test_is_same_repo_normalizes_ssh_and_https() {
+        for url in [
+            "git@github.com:scottidler/gx.git",
+            "ssh://git@github.com/scottidler/gx.git",
+            "https://github.com/scottidler/gx.git",
+            "https://github.com/scottidler/gx",
+            "https://x-access-token@github.com/scottidler/gx.git",
+        ] {
+            assert!(is_same_repo(url, "scottidler/gx"), "{url}");
+        }
+        assert!(!is_same_repo(
+            "https://github.com/scottidler/other.git",
+            "scottidler/gx"
+        ));
+        assert!(!is_same_repo(
+            "https://gitlab.com/scottidler/gx.git",
+            "scottidler/gx"
+        ));
+    }
+
     #[test]
     fn test_resolve_update_work_tree_routes_bare_container_to_worktree() {
         let temp = tempfile::TempDir::new().unwrap();
@@ -1546,21 +2403,42 @@ mod tests {
             ..Default::default()
         };
         assert!(!not_empty.is_empty());
+
+        let only_a_dir = StatusChanges {
+            untracked_dirs: 1,
+            ..Default::default()
+        };
+        assert!(only_a_dir.has_untracked());
+        assert!(!only_a_dir.is_empty());
     }
 
     #[test]
     fn test_parse_porcelain_status_table() {
-        // (input, [modified, added, deleted, renamed, untracked, staged])
-        let cases: &[(&str, [u32; 6])] = &[
-            ("", [0, 0, 0, 0, 0, 0]),
-            ("?? new.txt", [0, 0, 0, 0, 1, 0]),
-            (" M mod.txt", [1, 0, 0, 0, 0, 0]),
-            ("M  staged.txt", [0, 0, 0, 0, 0, 1]),
-            ("A  added.txt", [0, 1, 0, 0, 0, 0]),
-            (" D del.txt", [0, 0, 1, 0, 0, 0]),
-            ("R  old.txt -> new.txt", [0, 0, 0, 1, 0, 0]),
-            ("MM both.txt", [1, 0, 0, 0, 0, 1]),
-            ("?? a\n M b\nA  c\n D d", [1, 1, 1, 0, 1, 0]),
+        // (input, [modified, added, deleted, renamed, untracked, untracked_dirs, staged])
+        let cases: &[(&str, [u32; 7])] = &[
+            ("", [0, 0, 0, 0, 0, 0, 0]),
+            ("?? new.txt", [0, 0, 0, 0, 1, 0, 0]),
+            ("?? node_modules/", [0, 0, 0, 0, 0, 1, 0]),
+            ("?? \"with space/\"", [0, 0, 0, 0, 0, 1, 0]),
+            (" M mod.txt", [1, 0, 0, 0, 0, 0, 0]),
+            ("M  staged.txt", [0, 0, 0, 0, 0, 0, 1]),
+            ("A  added.txt", [0, 1, 0, 0, 0, 0, 0]),
+            (" D del.txt", [0, 0, 1, 0, 0, 0, 0]),
+            ("D  gone.txt", [0, 0, 0, 0, 0, 0, 1]),
+            ("R  old.txt -> new.txt", [0, 0, 0, 1, 0, 0, 0]),
+            ("RM old.txt -> new.txt", [1, 0, 0, 1, 0, 0, 0]),
+            ("MM both.txt", [1, 0, 0, 0, 0, 0, 1]),
+            ("AM new.txt", [1, 1, 0, 0, 0, 0, 0]),
+            ("AD new.txt", [0, 1, 1, 0, 0, 0, 0]),
+            ("T  link", [0, 0, 0, 0, 0, 0, 1]),
+            (" T link", [1, 0, 0, 0, 0, 0, 0]),
+            // Unmerged pairs count once, as modified, never as added/staged.
+            ("UU conflict.txt", [1, 0, 0, 0, 0, 0, 0]),
+            ("AA both-added.txt", [1, 0, 0, 0, 0, 0, 0]),
+            ("DD both-deleted.txt", [1, 0, 0, 0, 0, 0, 0]),
+            ("UD theirs-deleted.txt", [1, 0, 0, 0, 0, 0, 0]),
+            ("?? a\n M b\nA  c\n D d", [1, 1, 1, 0, 1, 0, 0]),
+            ("?? a\n?? b\n?? build/\n?? c", [0, 0, 0, 0, 3, 1, 0]),
         ];
         for (input, expected) in cases {
             let c = parse_porcelain_status(input);
@@ -1571,6 +2449,7 @@ mod tests {
                     c.deleted,
                     c.renamed,
                     c.untracked,
+                    c.untracked_dirs,
                     c.staged
                 ],
                 *expected,
@@ -1579,6 +2458,290 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_porcelain_status_mixed_output() {
+        // A realistic `git status --porcelain=v1` run: staged, unstaged,
+        // both, a rename, a copy, a quoted path and untracked entries.
+        let output = "\
+M  src/lib.rs
+ M README.md
+MM Cargo.toml
+A  src/new.rs
+D  old.rs
+ D docs/gone.md
+R  a.rs -> b.rs
+C  tmpl.yml -> copy.yml
+?? \"notes with space.txt\"
+?? target/
+";
+        let c = parse_porcelain_status(output);
+        assert_eq!(c.staged, 4, "M , MM, D  and C  are staged");
+        assert_eq!(c.modified, 2, " M and MM modify the worktree");
+        assert_eq!(c.added, 1);
+        assert_eq!(c.deleted, 1);
+        assert_eq!(c.renamed, 1);
+        assert_eq!((c.untracked, c.untracked_dirs), (1, 1));
+
+        // Blank and truncated lines are skipped, not miscounted.
+        assert!(parse_porcelain_status("\n?\n\n").is_empty());
+    }
+
+    #[test]
+    fn test_untracked_files_mode_changes_untracked_count() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = crate::test_utils::create_minimal_test_repo(temp.path(), "gx");
+        let build = path.join("build");
+        std::fs::create_dir_all(&build).unwrap();
+        for name in ["a.o", "b.o", "c.o"] {
+            std::fs::write(build.join(name), "x").unwrap();
+        }
+        let repo = Repo::new(path).unwrap();
+
+        let all = get_status_changes_with_untracked(&repo, UntrackedFiles::All).unwrap();
+        assert_eq!((all.untracked, all.untracked_dirs), (3, 0));
+
+        let normal = get_status_changes_with_untracked(&repo, UntrackedFiles::Normal).unwrap();
+        assert_eq!((normal.untracked, normal.untracked_dirs), (0, 1));
+
+        let no = get_status_changes_with_untracked(&repo, UntrackedFiles::No).unwrap();
+        assert!(!no.has_untracked());
+        assert!(
+            no.is_empty(),
+            "untracked files alone don't dirty a -uno scan"
+        );
+
+        assert_eq!("ALL".parse::<UntrackedFiles>(), Ok(UntrackedFiles::All));
+        assert!("some".parse::<UntrackedFiles>().is_err());
+    }
+
+    #[test]
+    fn test_parse_numstat_skips_binary_files() {
+        assert_eq!(parse_numstat(""), (0, 0));
+        assert_eq!(parse_numstat("3\t1\ta.txt\n0\t2\tb.txt"), (3, 3));
+        // Binary files report `-`/`-` and don't count toward the totals.
+        assert_eq!(parse_numstat("-\t-\tlogo.png\n4\t0\tc.txt"), (4, 0));
+    }
+
+    #[test]
+    fn test_repo_operation_in_progress_detects_each_marker() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = crate::test_utils::create_minimal_test_repo(temp.path(), "gx");
+        assert_eq!(repo_operation_in_progress(&path), None);
+
+        let git_dir = path.join(".git");
+        for (marker, operation) in [
+            ("rebase-merge", "rebase"),
+            ("rebase-apply", "rebase"),
+            ("MERGE_HEAD", "merge"),
+            ("CHERRY_PICK_HEAD", "cherry-pick"),
+            ("REVERT_HEAD", "revert"),
+            ("BISECT_LOG", "bisect"),
+        ] {
+            let marker_path = git_dir.join(marker);
+            if marker.starts_with("rebase-") {
+                std::fs::create_dir(&marker_path).unwrap();
+            } else {
+                std::fs::write(&marker_path, "0000000000000000000000000000000000000000\n").unwrap();
+            }
+            assert_eq!(
+                repo_operation_in_progress(&path).as_deref(),
+                Some(operation),
+                "{marker}"
+            );
+            // Also seen from a subdirectory, as git itself would.
+            std::fs::create_dir_all(path.join("sub")).unwrap();
+            assert_eq!(
+                repo_operation_in_progress(&path.join("sub")).as_deref(),
+                Some(operation),
+                "{marker} from a subdirectory"
+            );
+            if marker_path.is_dir() {
+                std::fs::remove_dir(&marker_path).unwrap();
+            } else {
+                std::fs::remove_file(&marker_path).unwrap();
+            }
+        }
+        assert_eq!(repo_operation_in_progress(&path), None);
+        assert_eq!(repo_operation_in_progress(temp.path()), None);
+    }
+
+    #[test]
+    fn test_get_commit_subject_reads_head_subject_only() {
+        use crate::test_utils::run_git_command;
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = crate::test_utils::create_minimal_test_repo(temp.path(), "gx");
+        let repo = Repo::new(path.clone()).unwrap();
+        std::fs::write(path.join("README.md"), "# moved\n").unwrap();
+        run_git_command(
+            &[
+                "commit",
+                "--quiet",
+                "-am",
+                "🚀 Ship it\n\nThe body is not the subject.",
+            ],
+            &path,
+        );
+        assert_eq!(get_commit_subject(&repo).as_deref(), Some("🚀 Ship it"));
+
+        let empty = temp.path().join("empty");
+        std::fs::create_dir(&empty).unwrap();
+        run_git_command(&["init", "--quiet"], &empty);
+        assert_eq!(get_commit_subject(&Repo::new(empty).unwrap()), None);
+    }
+
+    #[test]
+    fn test_parse_submodule_status_counts_moved_pointers() {
+        assert_eq!(parse_submodule_status(""), 0);
+        let output = [
+            "+1f2e3d4c5b6a79881f2e3d4c5b6a79881f2e3d4c lib (v1.2-3-g1f2e3d4)",
+            " a1b2c3d4e5f6a7b8c9d0a1b2c3d4e5f6a7b8c9d0 vendor/x (heads/main)",
+            "-0123456789abcdef0123456789abcdef01234567 docs",
+            "U0000000000000000000000000000000000000000 conflicted",
+        ]
+        .join("\n");
+        assert_eq!(parse_submodule_status(&output), 1);
+    }
+
+    #[test]
+    fn test_get_diff_stat_counts_unstaged_and_staged_lines() {
+        use crate::test_utils::run_git_command;
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = crate::test_utils::create_minimal_test_repo(temp.path(), "gx");
+        let repo = Repo::new(path.clone()).unwrap();
+        assert_eq!(get_diff_stat(&repo).unwrap(), (0, 0));
+
+        // README.md is the single line "# gx": replace it (+1/-1) and add
+        // two lines (+2), then stage a new three-line file (+3).
+        std::fs::write(path.join("README.md"), "# renamed\nline two\nline three\n").unwrap();
+        std::fs::write(path.join("staged.txt"), "a\nb\nc\n").unwrap();
+        run_git_command(&["add", "staged.txt"], &path);
+
+        assert_eq!(get_diff_stat(&repo).unwrap(), (6, 1));
+    }
+
+    #[test]
+    fn test_all_branch_sync_reports_each_local_branch() {
+        use crate::test_utils::run_git_command;
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = crate::test_utils::create_minimal_test_repo(temp.path(), "gx");
+        let repo = Repo::new(path.clone()).unwrap();
+        let bare = temp.path().join("gx.git");
+        run_git_command(
+            &["init", "--quiet", "--bare", bare.to_str().unwrap()],
+            temp.path(),
+        );
+        run_git_command(
+            &["remote", "set-url", "origin", bare.to_str().unwrap()],
+            &path,
+        );
+        let default = get_current_branch_name(&path).unwrap();
+        run_git_command(&["push", "--quiet", "-u", "origin", &default], &path);
+
+        // `stale` tracks a remote branch that moved one commit past it; the
+        // default branch has one unpushed commit; `scratch` tracks nothing.
+        run_git_command(&["branch", "stale"], &path);
+        run_git_command(&["push", "--quiet", "-u", "origin", "stale"], &path);
+        run_git_command(&["branch", "scratch"], &path);
+        std::fs::write(path.join("README.md"), "# moved\n").unwrap();
+        run_git_command(&["commit", "--quiet", "-am", "move"], &path);
+        run_git_command(&["push", "--quiet", "origin", "HEAD:stale"], &path);
+
+        let syncs = all_branch_sync(&repo).unwrap();
+        let by_branch = |name: &str| syncs.iter().find(|s| s.branch == name).unwrap();
+
+        assert_eq!(syncs.len(), 3);
+        assert!(matches!(
+            by_branch(&default).remote_status,
+            RemoteStatus::Ahead(1)
+        ));
+        let stale = by_branch("stale");
+        assert!(matches!(stale.remote_status, RemoteStatus::Behind(1)));
+        assert_eq!(stale.upstream.as_deref(), Some("origin/stale"));
+        let scratch = by_branch("scratch");
+        assert!(matches!(scratch.remote_status, RemoteStatus::NoUpstream));
+        assert!(scratch.upstream.is_none());
+    }
+
+    #[test]
+    fn test_get_status_changes_counts_untracked_dirs_separately() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = crate::test_utils::create_minimal_test_repo(temp.path(), "gx");
+        let node_modules = path.join("node_modules");
+        std::fs::create_dir_all(node_modules.join("left-pad")).unwrap();
+        for name in ["a.js", "b.js", "left-pad/index.js"] {
+            std::fs::write(node_modules.join(name), "x").unwrap();
+        }
+        for name in ["notes.txt", "todo.txt", "scratch.rs"] {
+            std::fs::write(path.join(name), "x").unwrap();
+        }
+
+        let repo = Repo::new(path).unwrap();
+        let changes = get_status_changes(&repo).unwrap();
+        assert_eq!(changes.untracked, 3);
+        assert_eq!(changes.untracked_dirs, 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_git_calls_preserve_non_utf8_repo_path() {
+        use std::os::unix::ffi::OsStrExt;
+        let temp = tempfile::TempDir::new().unwrap();
+        let utf8 = crate::test_utils::create_minimal_test_repo(temp.path(), "gx");
+        // "caf\xe9" is Latin-1, not UTF-8: a lossy conversion would turn it
+        // into "caf\u{fffd}" and point git at a directory that doesn't exist.
+        let path = temp.path().join(std::ffi::OsStr::from_bytes(b"caf\xe9"));
+        std::fs::rename(&utf8, &path).unwrap();
+
+        assert!(!has_uncommitted_changes(&path).unwrap());
+        assert_eq!(
+            get_remote_origin(&path).unwrap(),
+            "git@github.com:testorg/gx.git"
+        );
+        let args = commit_args(&path, "msg", &CommitOptions::default());
+        assert_eq!(args[1], path.as_os_str());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_non_utf8_repo_is_discovered_and_named_readably() {
+        use std::os::unix::ffi::OsStrExt;
+        let temp = tempfile::TempDir::new().unwrap();
+        let utf8 = crate::test_utils::create_minimal_test_repo(temp.path(), "gx");
+        let path = temp.path().join(std::ffi::OsStr::from_bytes(b"r\xe9po"));
+        std::fs::rename(&utf8, &path).unwrap();
+        std::fs::write(path.join("new.txt"), "x").unwrap();
+
+        let repos = crate::repo::discover_repos_with_settings(
+            temp.path(),
+            2,
+            &[],
+            &crate::repo::DiscoverySettings::default(),
+        )
+        .unwrap();
+        assert_eq!(repos.len(), 1);
+        let repo = &repos[0];
+        // The path reaches git byte-for-byte; only the display name is lossy.
+        assert_eq!(repo.path, path);
+        assert_eq!(repo.name, "r\u{FFFD}po");
+        assert_eq!(repo.slug, "testorg/r\u{FFFD}po");
+        assert!(get_status_changes(repo).unwrap().has_untracked());
+        assert_eq!(get_current_commit_sha(repo).map(|sha| sha.len()), Some(7));
+    }
+
+    #[test]
+    fn test_parse_branch_tracks_marks_only_gone_upstreams() {
+        let parsed = parse_branch_tracks("main\t\nGX-a\t[gone]\nGX-b\t[ahead 1]\nGX-c\n");
+        assert_eq!(
+            parsed,
+            [
+                ("main".to_string(), false),
+                ("GX-a".to_string(), true),
+                ("GX-b".to_string(), false),
+                ("GX-c".to_string(), false),
+            ]
+        );
+    }
+
     #[test]
     fn test_get_current_branch_name_empty_on_detached_head() {
         // The detached-HEAD guard ([A30]) keys off an empty branch name.
@@ -1683,6 +2846,58 @@ mod tests {
             fs::write(repo_path.join("test.txt"), "test content").unwrap();
             assert!(has_uncommitted_changes(&repo_path).unwrap());
         }
+
+        /// A repo with `f.txt` = "base\n" committed and a stash holding
+        /// `f.txt` = "wip\n"; returns the stash's SHA.
+        fn repo_with_stash(repo_path: &std::path::Path) -> String {
+            use crate::test_utils::run_git_command;
+            run_git_command(&["config", "commit.gpgsign", "false"], repo_path);
+            fs::write(repo_path.join("f.txt"), "base\n").unwrap();
+            run_git_command(&["add", "-A"], repo_path);
+            run_git_command(&["commit", "--quiet", "-m", "base"], repo_path);
+            fs::write(repo_path.join("f.txt"), "wip\n").unwrap();
+            run_git_command(&["stash", "push", "--quiet", "-m", "wip"], repo_path);
+            let sha = run_git_command(&["rev-parse", "stash@{0}"], repo_path);
+            String::from_utf8(sha.stdout).unwrap().trim().to_string()
+        }
+
+        #[test]
+        fn test_stash_pop_restores_and_drops() {
+            let (_temp_dir, repo_path) = setup_test_repo();
+            let sha = repo_with_stash(&repo_path);
+            assert_eq!(
+                fs::read_to_string(repo_path.join("f.txt")).unwrap(),
+                "base\n"
+            );
+
+            let stash_ref = stash_ref_for_sha(&repo_path, &sha).unwrap();
+            assert_eq!(stash_ref, "stash@{0}");
+            stash_pop(&repo_path, &stash_ref).unwrap();
+
+            assert_eq!(
+                fs::read_to_string(repo_path.join("f.txt")).unwrap(),
+                "wip\n"
+            );
+            assert!(stash_sha_by_message(&repo_path, "wip").unwrap().is_none());
+        }
+
+        #[test]
+        fn test_stash_pop_conflict_keeps_stash() {
+            use crate::test_utils::run_git_command;
+            let (_temp_dir, repo_path) = setup_test_repo();
+            let sha = repo_with_stash(&repo_path);
+            // A committed edit to the same line makes the pop conflict.
+            fs::write(repo_path.join("f.txt"), "other\n").unwrap();
+            run_git_command(&["commit", "--quiet", "-am", "other"], &repo_path);
+
+            let err = stash_pop(&repo_path, "stash@{0}").unwrap_err();
+
+            assert!(err.to_string().contains("the stash is kept"), "{err}");
+            assert_eq!(
+                stash_sha_by_message(&repo_path, "wip").unwrap().as_deref(),
+                Some(sha.as_str())
+            );
+        }
     }
 
     // Tests for new git status --porcelain --branch parser
@@ -1739,4 +2954,67 @@ mod tests {
         assert_eq!(info.ahead, 0);
         assert_eq!(info.behind, 1);
     }
+
+    #[test]
+    fn test_message_with_trailers_appends_a_trailer_paragraph() {
+        assert_eq!(message_with_trailers("Fix it\n", &[]), "Fix it\n");
+        let trailers = [
+            "Co-authored-by: A <a@example.com>".to_string(),
+            "Change-Id: GX-1".to_string(),
+        ];
+        assert_eq!(
+            message_with_trailers("Fix it\n", &trailers),
+            "Fix it\n\nCo-authored-by: A <a@example.com>\nChange-Id: GX-1"
+        );
+    }
+
+    #[test]
+    fn test_commit_args_sign_adds_dash_s_and_key() {
+        let path = std::path::Path::new("/tmp/repo");
+
+        let plain = commit_args(path, "msg", &CommitOptions::default());
+        assert_eq!(plain, ["-C", "/tmp/repo", "commit", "-m", "msg"]);
+
+        let signed = CommitOptions {
+            sign: true,
+            ..Default::default()
+        };
+        let args = commit_args(path, "msg", &signed);
+        assert_eq!(args, ["-C", "/tmp/repo", "commit", "-S", "-m", "msg"]);
+
+        // The key rides a one-shot `-c`, placed before the subcommand.
+        let keyed = CommitOptions {
+            sign: true,
+            sign_key: Some("ABCD1234".to_string()),
+            ..Default::default()
+        };
+        let args = commit_args(path, "msg", &keyed);
+        assert_eq!(
+            args,
+            [
+                "-C",
+                "/tmp/repo",
+                "-c",
+                "user.signingkey=ABCD1234",
+                "commit",
+                "-S",
+                "-m",
+                "msg"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_commit_args_no_verify_skips_hooks() {
+        let path = std::path::Path::new("/tmp/repo");
+        let options = CommitOptions {
+            no_verify: true,
+            ..Default::default()
+        };
+        let args = commit_args(path, "msg", &options);
+        assert_eq!(
+            args,
+            ["-C", "/tmp/repo", "commit", "--no-verify", "-m", "msg"]
+        );
+    }
 }
@@ -1,5 +1,5 @@
 //! `local`: credential-free gx logic (repo/config/subprocess/hash/utils/bare/
-//! diff/user_org/file and the LOCAL half of git).
+//! diff/user_org/file/manifest and the LOCAL half of git).
 //!
 //! This crate MUST NOT depend on `ssh`/`persona`/`github` or any remote-git
 //! function. That boundary is what makes the intel-catalog cross-org non-goal
@@ -12,6 +12,7 @@ pub mod diff;
 pub mod file;
 pub mod git;
 pub mod hash;
+pub mod manifest;
 pub mod repo;
 pub mod subprocess;
 pub mod user_org;
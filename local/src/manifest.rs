@@ -0,0 +1,85 @@
+//! Workspace manifest: a YAML record of which repos a workspace holds, where
+//! each is checked out, and its default branch. `gx clone --write-manifest`
+//! writes one so a teammate can recreate the same layout.
+//!
+//! Entries are keyed by slug and kept sorted, so re-writing a manifest after
+//! a re-clone is idempotent: unchanged repos produce byte-identical output.
+
+use crate::file;
+use eyre::{Context, Result};
+use log::debug;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// A workspace manifest file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Manifest {
+    #[serde(default)]
+    pub repos: Vec<ManifestEntry>,
+}
+
+/// One repo in a manifest.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ManifestEntry {
+    /// `owner/repo`.
+    pub slug: String,
+    /// Checkout location, relative to the workspace root.
+    pub path: String,
+    pub default_branch: String,
+}
+
+impl Manifest {
+    /// Add `entry`, replacing any existing entry for the same slug, and keep
+    /// the entries sorted by slug.
+    pub fn upsert(&mut self, entry: ManifestEntry) {
+        match self.repos.binary_search_by(|e| e.slug.cmp(&entry.slug)) {
+            Ok(i) => self.repos[i] = entry,
+            Err(i) => self.repos.insert(i, entry),
+        }
+    }
+}
+
+/// Read the manifest at `path`; a missing file is an empty manifest.
+pub fn read_manifest(path: &Path) -> Result<Manifest> {
+    debug!("read_manifest: path={}", path.display());
+    if !path.exists() {
+        return Ok(Manifest::default());
+    }
+    let yaml = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read manifest {}", path.display()))?;
+    let mut manifest: Manifest = serde_yaml::from_str(&yaml)
+        .with_context(|| format!("Failed to parse manifest {}", path.display()))?;
+    manifest.repos.sort_by(|a, b| a.slug.cmp(&b.slug));
+    Ok(manifest)
+}
+
+/// Write `manifest` to `path` as YAML (atomic: a reader never sees a
+/// half-written manifest).
+pub fn write_manifest(path: &Path, manifest: &Manifest) -> Result<()> {
+    debug!(
+        "write_manifest: path={} repos={}",
+        path.display(),
+        manifest.repos.len()
+    );
+    let yaml = serde_yaml::to_string(manifest).context("Failed to serialize manifest")?;
+    file::atomic_write(path, yaml.as_bytes())
+        .with_context(|| format!("Failed to write manifest {}", path.display()))
+}
+
+/// Merge `entries` into the manifest at `path` (creating it if needed) and
+/// write it back.
+pub fn update_manifest(
+    path: &Path,
+    entries: impl IntoIterator<Item = ManifestEntry>,
+) -> Result<()> {
+    let mut manifest = read_manifest(path)?;
+    for entry in entries {
+        manifest.upsert(entry);
+    }
+    write_manifest(path, &manifest)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,62 @@
+use super::*;
+use tempfile::TempDir;
+
+fn entry(slug: &str, branch: &str) -> ManifestEntry {
+    ManifestEntry {
+        slug: slug.to_string(),
+        path: slug.to_string(),
+        default_branch: branch.to_string(),
+    }
+}
+
+#[test]
+fn test_read_missing_manifest_is_empty() {
+    let temp = TempDir::new().unwrap();
+    let manifest = read_manifest(&temp.path().join("gx-manifest.yml")).unwrap();
+    assert!(manifest.repos.is_empty());
+}
+
+#[test]
+fn test_upsert_replaces_by_slug_and_keeps_sorted() {
+    let mut manifest = Manifest::default();
+    manifest.upsert(entry("org/web", "main"));
+    manifest.upsert(entry("org/api", "master"));
+    manifest.upsert(entry("org/web", "develop"));
+
+    assert_eq!(
+        manifest.repos,
+        [entry("org/api", "master"), entry("org/web", "develop")]
+    );
+}
+
+#[test]
+fn test_update_manifest_is_idempotent() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("gx-manifest.yml");
+
+    update_manifest(&path, [entry("org/web", "main"), entry("org/api", "main")]).unwrap();
+    let first = std::fs::read_to_string(&path).unwrap();
+    update_manifest(&path, [entry("org/api", "main"), entry("org/web", "main")]).unwrap();
+    let second = std::fs::read_to_string(&path).unwrap();
+
+    assert_eq!(first, second);
+    assert!(first.contains("default-branch: main"), "got {first}");
+    assert_eq!(read_manifest(&path).unwrap().repos.len(), 2);
+}
+
+#[test]
+fn test_update_manifest_keeps_repos_not_in_this_run() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("gx-manifest.yml");
+
+    update_manifest(&path, [entry("org/web", "main")]).unwrap();
+    update_manifest(&path, [entry("other/lib", "main")]).unwrap();
+
+    let slugs: Vec<String> = read_manifest(&path)
+        .unwrap()
+        .repos
+        .into_iter()
+        .map(|e| e.slug)
+        .collect();
+    assert_eq!(slugs, ["org/web", "other/lib"]);
+}
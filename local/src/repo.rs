@@ -1,6 +1,8 @@
-use eyre::Result;
-use log::debug;
+use eyre::{Context, Result};
+use log::{debug, warn};
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, OnceLock};
 use walkdir::WalkDir;
 
 /// Structural layout of a discovered repo - known at discovery time from which
@@ -12,6 +14,9 @@ pub enum Layout {
     /// The repo IS the default worktree of a bare container (`path` is that
     /// worktree, not the `.bare` root).
     Bare,
+    /// A bare repository with no work tree at all (`gx clone --mirror`,
+    /// `git init --bare`): `path` is the `<name>.git` git dir itself.
+    BareRepo,
     /// Synthetic repo (`from_slug`); no filesystem to classify.
     Unknown,
 }
@@ -26,15 +31,15 @@ pub struct Repo {
 
 impl Repo {
     pub fn new(path: PathBuf) -> Result<Self> {
-        let name = path
-            .file_name()
-            .and_then(|n| n.to_str())
-            .unwrap_or("unknown")
-            .to_string();
+        Self::new_with_slug_skip(path, &discovery_settings().slug_fallback_skip)
+    }
+
+    fn new_with_slug_skip(path: PathBuf, slug_fallback_skip: &[String]) -> Result<Self> {
+        let name = display_name(&path);
 
         // A flat repo probes origin at its own root and infers a fallback slug
         // from its own parent directory.
-        let slug = resolve_slug(&name, &path, path.parent());
+        let slug = resolve_slug(&name, &path, path.parent(), slug_fallback_skip);
         Ok(Self {
             path,
             name,
@@ -48,16 +53,24 @@ impl Repo {
     /// default `worktree` (which becomes `self.path`), because the container
     /// root is not a work tree. A bare container is ONE logical repo.
     pub fn from_container(container: &Path, worktree: PathBuf) -> Result<Self> {
-        let name = container
-            .file_name()
-            .and_then(|n| n.to_str())
-            .unwrap_or("unknown")
-            .to_string();
+        Self::from_container_with_slug_skip(
+            container,
+            worktree,
+            &discovery_settings().slug_fallback_skip,
+        )
+    }
+
+    fn from_container_with_slug_skip(
+        container: &Path,
+        worktree: PathBuf,
+        slug_fallback_skip: &[String],
+    ) -> Result<Self> {
+        let name = display_name(container);
 
         // Probe origin inside the worktree (the container root has no work
         // tree); infer the fallback slug from the *container's* parent, so a
         // container behaves like a flat repo of the same name.
-        let slug = resolve_slug(&name, &worktree, container.parent());
+        let slug = resolve_slug(&name, &worktree, container.parent(), slug_fallback_skip);
         Ok(Self {
             path: worktree,
             name,
@@ -66,6 +79,36 @@ impl Repo {
         })
     }
 
+    /// Construct a `Repo` for a bare repository (`<name>.git` with no work
+    /// tree). The name drops the `.git` suffix, so a mirror filters and slugs
+    /// like the checkout it mirrors.
+    pub fn from_bare_repo(path: PathBuf) -> Result<Self> {
+        Self::from_bare_repo_with_slug_skip(path, &discovery_settings().slug_fallback_skip)
+    }
+
+    fn from_bare_repo_with_slug_skip(path: PathBuf, slug_fallback_skip: &[String]) -> Result<Self> {
+        let dir_name = display_name(&path);
+        let name = dir_name
+            .strip_suffix(".git")
+            .unwrap_or(&dir_name)
+            .to_string();
+        let slug = resolve_slug(&name, &path, path.parent(), slug_fallback_skip);
+        Ok(Self {
+            path,
+            name,
+            slug,
+            layout: Layout::BareRepo,
+        })
+    }
+
+    /// Whether this is a bare repository (discovered as [`Layout::BareRepo`]).
+    /// Not the same as [`Layout::Bare`]: a bare *container*'s `path` is its
+    /// default worktree, which is not bare. A bare repo has no work tree, so
+    /// status, checkout and create skip it.
+    pub fn is_bare(&self) -> bool {
+        self.layout == Layout::BareRepo
+    }
+
     /// Create a fake repo from slug for filtering purposes (used in clone command)
     pub fn from_slug(slug: String) -> Self {
         let parts: Vec<&str> = slug.split('/').collect();
@@ -84,29 +127,157 @@ impl Repo {
     }
 }
 
+/// Discovery settings that come from config but are not per-command: the
+/// path-fragment ignore list, the slug-fallback skip list and the repo limit
+/// (`repo-discovery.ignore-dirs` / `slug-fallback-skip` / `max-repos`).
+#[derive(Debug, Clone)]
+pub struct DiscoverySettings {
+    pub ignore_dirs: Vec<String>,
+    pub slug_fallback_skip: Vec<String>,
+    /// Stop walking once this many repos are found (`--max-repos`).
+    pub max_repos: Option<usize>,
+    /// Keep only repos with these slugs (`--repos-from-file`).
+    pub only_slugs: Option<Vec<String>>,
+    /// Whether starting inside a repo scans from its parent; `false` scans
+    /// strictly downward from the starting directory (`--no-walk-up`).
+    pub walk_up: bool,
+    /// Scan from exactly this directory, skipping the workspace-root search
+    /// (`--root`).
+    pub root: Option<PathBuf>,
+    /// List bare repos (mirrors) too. Off by default: only the commands that
+    /// report skipping them opt in ([`discover_repos_including_bare`]).
+    pub include_bare: bool,
+}
+
+impl Default for DiscoverySettings {
+    fn default() -> Self {
+        let config = crate::config::Config::default();
+        Self {
+            ignore_dirs: config.ignore_dirs(),
+            slug_fallback_skip: config.slug_fallback_skip(),
+            max_repos: config.max_repos(),
+            only_slugs: None,
+            walk_up: true,
+            root: None,
+            include_bare: false,
+        }
+    }
+}
+
+impl DiscoverySettings {
+    /// The search root forced by the flags: `--root` if given, else `start_dir`
+    /// itself under `--no-walk-up`. `None` means the usual workspace-root
+    /// search (`find_workspace_root`).
+    pub fn search_root_override(&self, start_dir: &Path) -> Option<PathBuf> {
+        match &self.root {
+            Some(root) => Some(root.clone()),
+            None if !self.walk_up => Some(start_dir.to_path_buf()),
+            None => None,
+        }
+    }
+
+    /// The directory discovery walks up to from `start_dir`, honoring
+    /// `--root` and `--no-walk-up` (neither ever walks up).
+    pub fn walked_up_root(&self, start_dir: &Path) -> Option<PathBuf> {
+        if self.search_root_override(start_dir).is_some() {
+            None
+        } else {
+            walked_up_root(start_dir)
+        }
+    }
+
+    /// The directory discovery searches from for `start_dir`: the forced
+    /// root, else the walked-up root, else `start_dir` itself.
+    pub fn search_root(&self, start_dir: &Path) -> PathBuf {
+        self.search_root_override(start_dir)
+            .or_else(|| walked_up_root(start_dir))
+            .unwrap_or_else(|| start_dir.to_path_buf())
+    }
+}
+
+/// Process-global discovery settings, installed once from `Config` in `main`.
+/// Same write-once shape as `subprocess::init_subprocess_timeout`: `Repo::new`
+/// and `discover_repos` have too many callers to thread `Config` through.
+static DISCOVERY_SETTINGS: OnceLock<DiscoverySettings> = OnceLock::new();
+
+/// The compiled-in defaults, used when nothing initialized the global.
+static DEFAULT_DISCOVERY_SETTINGS: LazyLock<DiscoverySettings> =
+    LazyLock::new(DiscoverySettings::default);
+
+/// Install the configured discovery settings (called once from `main` after
+/// the config loads). A second call is a no-op -- the first value wins.
+pub fn init_discovery_settings(settings: DiscoverySettings) {
+    debug!("init_discovery_settings: {settings:?}");
+    if DISCOVERY_SETTINGS.set(settings).is_err() {
+        warn!("init_discovery_settings: already initialized; ignoring second value");
+    }
+}
+
+/// The effective discovery settings: the ones installed from config, or the
+/// documented defaults (tests / library callers).
+pub fn discovery_settings() -> &'static DiscoverySettings {
+    DISCOVERY_SETTINGS
+        .get()
+        .unwrap_or(&DEFAULT_DISCOVERY_SETTINGS)
+}
+
 /// Discover git repositories starting from the given directory with workspace awareness
 pub fn discover_repos(
     start_dir: &Path,
     max_depth: usize,
     ignore_patterns: &[String],
+) -> Result<Vec<Repo>> {
+    discover_repos_with_settings(start_dir, max_depth, ignore_patterns, discovery_settings())
+}
+
+/// [`discover_repos`], also listing bare repos (mirrors) as
+/// [`Layout::BareRepo`], for commands that report skipping them
+/// ([`partition_bare`]) rather than have them silently missing.
+pub fn discover_repos_including_bare(
+    start_dir: &Path,
+    max_depth: usize,
+    ignore_patterns: &[String],
+) -> Result<Vec<Repo>> {
+    let settings = DiscoverySettings {
+        include_bare: true,
+        ..discovery_settings().clone()
+    };
+    discover_repos_with_settings(start_dir, max_depth, ignore_patterns, &settings)
+}
+
+/// [`discover_repos`] with explicit [`DiscoverySettings`] instead of the
+/// process-global ones.
+pub fn discover_repos_with_settings(
+    start_dir: &Path,
+    max_depth: usize,
+    ignore_patterns: &[String],
+    settings: &DiscoverySettings,
 ) -> Result<Vec<Repo>> {
     debug!(
-        "discover_repos: start_dir={} max_depth={} ignore_patterns={:?}",
+        "discover_repos: start_dir={} max_depth={} ignore_patterns={:?} ignore_dirs={:?}",
         start_dir.display(),
         max_depth,
-        ignore_patterns
+        ignore_patterns,
+        settings.ignore_dirs
     );
+    let ignore_dirs = &settings.ignore_dirs;
 
-    let search_root = find_workspace_root(start_dir, max_depth, ignore_patterns)?;
+    let search_root = match settings.search_root_override(start_dir) {
+        Some(root) => root,
+        None => find_workspace_root(start_dir, max_depth, ignore_patterns, ignore_dirs)?,
+    };
     debug!("Using search root: {}", search_root.display());
 
     let mut repos = Vec::new();
+    let mut truncated = false;
 
     for entry in WalkDir::new(&search_root)
         .max_depth(max_depth)
         .into_iter()
         .filter_entry(|e| {
-            !is_ignored_directory(e.path(), ignore_patterns) && !is_inside_bare_container(e.path())
+            !is_ignored_directory(e.path(), ignore_patterns, ignore_dirs)
+                && !is_inside_bare_container(e.path())
+                && !is_inside_bare_repo(e.path())
         })
         .filter_map(|e| match e {
             Ok(entry) => Some(entry),
@@ -123,13 +294,13 @@ pub fn discover_repos(
         // do NOT descend into its internals (its worktrees/.bare are pruned by
         // `is_inside_bare_container` above, so they are never separate repos).
         if entry.file_type().is_dir() && crate::bare::is_bare_container(path) {
-            if is_ignored_directory(path, ignore_patterns) {
+            if is_ignored_directory(path, ignore_patterns, ignore_dirs) {
                 debug!("Skipping ignored bare container: {}", path.display());
                 continue;
             }
-            match crate::bare::default_worktree(path)
-                .and_then(|worktree| Repo::from_container(path, worktree))
-            {
+            match crate::bare::default_worktree(path).and_then(|worktree| {
+                Repo::from_container_with_slug_skip(path, worktree, &settings.slug_fallback_skip)
+            }) {
                 Ok(repo) => {
                     debug!(
                         "Found bare container: {} (default worktree {}, layout={:?})",
@@ -137,7 +308,10 @@ pub fn discover_repos(
                         repo.path.display(),
                         repo.layout
                     );
-                    repos.push(repo);
+                    if !push_wanted(&mut repos, repo, settings) {
+                        truncated = true;
+                        break;
+                    }
                 }
                 Err(e) => {
                     debug!("Skipping bare container at {}: {}", path.display(), e);
@@ -146,16 +320,42 @@ pub fn discover_repos(
             continue;
         }
 
+        // A bare repo (a mirror) is listed only when asked for, so a command
+        // that reports skipping it need not have it silently vanish.
+        if entry.file_type().is_dir() && is_bare_repo_dir(path) {
+            if !settings.include_bare {
+                debug!("Skipping bare repo at {}", path.display());
+                continue;
+            }
+            match Repo::from_bare_repo_with_slug_skip(
+                path.to_path_buf(),
+                &settings.slug_fallback_skip,
+            ) {
+                Ok(repo) => {
+                    debug!("Found bare repo: {} at {}", repo.slug, repo.path.display());
+                    if !push_wanted(&mut repos, repo, settings) {
+                        truncated = true;
+                        break;
+                    }
+                }
+                Err(e) => debug!("Skipping bare repo at {}: {}", path.display(), e),
+            }
+            continue;
+        }
+
         if path.file_name() == Some(std::ffi::OsStr::new(".git")) && path.is_dir() {
             if let Some(repo_root) = path.parent() {
                 // Skip if this is an ignored directory
-                if is_ignored_directory(repo_root, ignore_patterns) {
+                if is_ignored_directory(repo_root, ignore_patterns, ignore_dirs) {
                     debug!("Skipping ignored directory: {}", repo_root.display());
                     continue;
                 }
 
                 // Try to create repo, skip if it fails (e.g., invalid git config)
-                match Repo::new(repo_root.to_path_buf()) {
+                match Repo::new_with_slug_skip(
+                    repo_root.to_path_buf(),
+                    &settings.slug_fallback_skip,
+                ) {
                     Ok(repo) => {
                         debug!(
                             "Found repo: {} at {} (layout={:?})",
@@ -163,7 +363,10 @@ pub fn discover_repos(
                             repo.path.display(),
                             repo.layout
                         );
-                        repos.push(repo);
+                        if !push_wanted(&mut repos, repo, settings) {
+                            truncated = true;
+                            break;
+                        }
                     }
                     Err(e) => {
                         debug!(
@@ -177,6 +380,20 @@ pub fn discover_repos(
         }
     }
 
+    // Safety limit: a `--cwd` pointed at `$HOME` can otherwise walk
+    // thousands of repos. Discovery stopped at the first repo past the limit;
+    // say the list is partial.
+    if truncated {
+        let message = format!(
+            "discovery stopped at --max-repos {} under {}; results may be truncated \
+             (narrow --root, --cwd or --depth, or list repos with --repos-from-file)",
+            repos.len(),
+            search_root.display()
+        );
+        warn!("{message}");
+        eprintln!("⚠️  {message}");
+    }
+
     // Sort by path for consistent ordering
     repos.sort_by(|a, b| a.path.cmp(&b.path));
 
@@ -184,6 +401,52 @@ pub fn discover_repos(
     Ok(repos)
 }
 
+/// Discovered repos that share a slug, as `(slug, paths)` in slug order. Two
+/// clones of one remote, or same-named repos that fall back to the same
+/// `unknown/<name>` slug, collapse together, and everything keyed by slug
+/// (state, review/create results, `--repos-from-file`) then mixes them up.
+/// Bare repos are left out: a mirror beside a working clone of the same
+/// remote always shares its slug, and commands never act on the mirror.
+/// Commands report these; discovery itself stays silent.
+pub fn detect_slug_collisions(repos: &[Repo]) -> Vec<(String, Vec<PathBuf>)> {
+    let mut by_slug: BTreeMap<&str, Vec<PathBuf>> = BTreeMap::new();
+    for repo in repos.iter().filter(|repo| !repo.is_bare()) {
+        by_slug
+            .entry(repo.slug.as_str())
+            .or_default()
+            .push(repo.path.clone());
+    }
+    by_slug
+        .into_iter()
+        .filter(|(_, paths)| paths.len() > 1)
+        .map(|(slug, paths)| (slug.to_string(), paths))
+        .collect()
+}
+
+/// The warning for repos at `paths` sharing `slug`.
+pub fn slug_collision_message(slug: &str, paths: &[PathBuf]) -> String {
+    let paths: Vec<String> = paths.iter().map(|p| p.display().to_string()).collect();
+    format!(
+        "{} repos share the slug {slug}; results keyed by slug may mix them up: {}",
+        paths.len(),
+        paths.join(", ")
+    )
+}
+
+/// Read a `--repos-from-file` list: one repo slug per line, ignoring blank
+/// lines and `#` comments.
+pub fn read_repo_list(path: &Path) -> Result<Vec<String>> {
+    debug!("read_repo_list: path={}", path.display());
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read repo list {}", path.display()))?;
+    Ok(text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+        .collect())
+}
+
 /// Find the appropriate search root based on simple rules:
 /// 1. If we're inside a repo (`.git` exists here): search from the parent so the
 ///    current repo and its siblings are included.
@@ -197,23 +460,23 @@ fn find_workspace_root(
     start_dir: &Path,
     max_depth: usize,
     ignore_patterns: &[String],
+    ignore_dirs: &[String],
 ) -> Result<PathBuf> {
     let current = start_dir.to_path_buf();
 
     // Case 1: If we're inside a git repository, search from its parent.
-    if current.join(".git").exists() {
-        if let Some(parent) = current.parent() {
-            debug!(
-                "Inside git repo at {}, searching from parent: {}",
-                current.display(),
-                parent.display()
-            );
-            return Ok(parent.to_path_buf());
-        }
+    if let Some(parent) = walked_up_root(start_dir) {
+        debug!(
+            "Inside git repo at {}, searching from parent: {}",
+            current.display(),
+            parent.display()
+        );
+        return Ok(parent);
     }
 
     // Case 2: Search downward from the current directory.
-    let repos_found_down = count_repos_in_subtree(&current, max_depth, ignore_patterns)?;
+    let repos_found_down =
+        count_repos_in_subtree(&current, max_depth, ignore_patterns, ignore_dirs)?;
     if repos_found_down > 0 {
         debug!(
             "Found {} repos searching down from {}, using as search root",
@@ -231,11 +494,23 @@ fn find_workspace_root(
     Ok(start_dir.to_path_buf())
 }
 
+/// The directory discovery walks up to from `start_dir`, if it does: the
+/// parent of a repo checkout, so the repo's siblings are included. `None` means
+/// discovery scans from `start_dir` itself.
+pub fn walked_up_root(start_dir: &Path) -> Option<PathBuf> {
+    if start_dir.join(".git").exists() {
+        start_dir.parent().map(Path::to_path_buf)
+    } else {
+        None
+    }
+}
+
 /// Count git repositories in subtree with given max depth
 fn count_repos_in_subtree(
     dir: &Path,
     max_depth: usize,
     ignore_patterns: &[String],
+    ignore_dirs: &[String],
 ) -> Result<usize> {
     let mut count = 0;
 
@@ -243,21 +518,27 @@ fn count_repos_in_subtree(
         .max_depth(max_depth)
         .into_iter()
         .filter_entry(|e| {
-            !is_ignored_directory(e.path(), ignore_patterns) && !is_inside_bare_container(e.path())
+            !is_ignored_directory(e.path(), ignore_patterns, ignore_dirs)
+                && !is_inside_bare_container(e.path())
+                && !is_inside_bare_repo(e.path())
         })
         .filter_map(|e| e.ok())
     {
         let path = entry.path();
         // A bare container counts as exactly one repo, same as a flat repo.
         if entry.file_type().is_dir() && crate::bare::is_bare_container(path) {
-            if !is_ignored_directory(path, ignore_patterns) {
+            if !is_ignored_directory(path, ignore_patterns, ignore_dirs) {
                 count += 1;
             }
             continue;
         }
+        if entry.file_type().is_dir() && is_bare_repo_dir(path) {
+            count += 1;
+            continue;
+        }
         if path.file_name() == Some(std::ffi::OsStr::new(".git")) && path.is_dir() {
             if let Some(repo_root) = path.parent() {
-                if !is_ignored_directory(repo_root, ignore_patterns) {
+                if !is_ignored_directory(repo_root, ignore_patterns, ignore_dirs) {
                     count += 1;
                 }
             }
@@ -277,33 +558,79 @@ fn is_inside_bare_container(path: &Path) -> bool {
         .unwrap_or(false)
 }
 
+/// True if `path` is a bare repository directory: named `<name>.git` (but
+/// not a work tree's own `.git`) and holding `HEAD`, `objects/` and `refs/`.
+fn is_bare_repo_dir(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+        return false;
+    };
+    name.ends_with(".git")
+        && name != ".git"
+        && path.join("HEAD").is_file()
+        && path.join("objects").is_dir()
+        && path.join("refs").is_dir()
+}
+
+/// True if `path` is an entry inside a bare repo (its `objects/`, `refs/`,
+/// ...), which discovery never descends into.
+fn is_inside_bare_repo(path: &Path) -> bool {
+    path.parent().map(is_bare_repo_dir).unwrap_or(false)
+}
+
+/// Keep a found repo unless `--repos-from-file` leaves it out. Filtering as
+/// repos are found means only the wanted ones count toward `--max-repos`.
+/// `false` when a wanted repo arrives with `--max-repos` already reached: it
+/// is dropped, and discovery stops there.
+fn push_wanted(repos: &mut Vec<Repo>, repo: Repo, settings: &DiscoverySettings) -> bool {
+    match &settings.only_slugs {
+        Some(only) if !only.contains(&repo.slug) => {
+            debug!("Skipping {} (not in --repos-from-file)", repo.slug)
+        }
+        _ if settings.max_repos.is_some_and(|max| repos.len() >= max) => return false,
+        _ => repos.push(repo),
+    }
+    true
+}
+
+/// Split `repos` into (work-tree repos, bare repos). Commands that need a
+/// work tree run on the first and report the second as skipped.
+pub fn partition_bare(repos: Vec<Repo>) -> (Vec<Repo>, Vec<Repo>) {
+    let (bare, work_trees): (Vec<Repo>, Vec<Repo>) = repos.into_iter().partition(Repo::is_bare);
+    (work_trees, bare)
+}
+
 /// Derive a repo slug (`user/name`) from origin, falling back to parent-dir
 /// inference. `origin_probe` is the path git runs in to read origin;
 /// `fallback_parent` is the directory whose name seeds the fallback slug.
-fn resolve_slug(name: &str, origin_probe: &Path, fallback_parent: Option<&Path>) -> String {
+fn resolve_slug(
+    name: &str,
+    origin_probe: &Path,
+    fallback_parent: Option<&Path>,
+    slug_fallback_skip: &[String],
+) -> String {
     match extract_origin_url(origin_probe).and_then(|url| extract_user_from_remote(&url)) {
         Ok(user) => format!("{user}/{name}"),
         Err(_) => {
-            // Fallback: infer from the parent directory structure. If the repo
-            // is at /path/to/user/repo, use user/repo; otherwise unknown/repo.
-            if let Some(parent_name) = fallback_parent
+            let parent_name = fallback_parent
                 .and_then(|p| p.file_name())
-                .and_then(|n| n.to_str())
-            {
-                // Skip common directory names that aren't user/org names.
-                if !["repos", "src", "code", "projects", "workspace", "git"].contains(&parent_name)
-                {
-                    format!("{parent_name}/{name}")
-                } else {
-                    format!("unknown/{name}")
-                }
-            } else {
-                format!("unknown/{name}")
-            }
+                .and_then(|n| n.to_str());
+            fallback_slug(name, parent_name, slug_fallback_skip)
         }
     }
 }
 
+/// Infer a slug from the parent directory structure: a repo at
+/// `/path/to/user/repo` becomes `user/repo`, unless the parent is a common
+/// non-owner directory name (`slug_fallback_skip`), which gives `unknown/repo`.
+fn fallback_slug(name: &str, parent_name: Option<&str>, slug_fallback_skip: &[String]) -> String {
+    match parent_name {
+        Some(parent) if !slug_fallback_skip.iter().any(|skip| skip == parent) => {
+            format!("{parent}/{name}")
+        }
+        _ => format!("unknown/{name}"),
+    }
+}
+
 /// Extract origin URL for a repo path, layout-aware.
 ///
 /// A flat repo has a `.git` *directory* with a `config` file we read directly
@@ -396,26 +723,31 @@ fn parse_user_from_url(url: &str) -> Result<String> {
     Err(eyre::eyre!("Unsupported remote URL format: {url}"))
 }
 
+/// A repo's display name: its directory name, with any non-UTF-8 bytes
+/// replaced. Only for display and slugs; git is always handed `path` itself.
+fn display_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
 /// Check if a directory should be ignored during discovery.
 ///
-/// Cache directories are excluded by path substring (this already catches
-/// pre-commit caches under `~/.cache/`). Directory *names* are matched against
+/// Configured `ignore_dirs` fragments are excluded by path substring (the
+/// defaults catch pre-commit caches under `~/.cache/`). Directory *names* are matched against
 /// the configured `ignore_patterns` ([A27]); the previous `name.starts_with("repo")`
 /// heuristic is gone - it silently hid real repos like `reporting` ([A6]).
-fn is_ignored_directory(path: &Path, ignore_patterns: &[String]) -> bool {
-    if let Some(path_str) = path.to_str() {
-        // Ignore cache directories by path - this catches the pre-commit cache.
-        if path_str.contains("/.cache/")
-            || path_str.contains("/.local/")
-            || path_str.contains("/.nvm/")
-        {
-            return true;
-        }
-
-        // Ignore Go module cache
-        if path_str.contains("/go/pkg/mod/") {
-            return true;
-        }
+fn is_ignored_directory(path: &Path, ignore_patterns: &[String], ignore_dirs: &[String]) -> bool {
+    // Ignore configured path fragments anywhere in the path (by default
+    // cache dirs such as the pre-commit cache, and the Go module cache). Lossy
+    // is fine here: the fragments are UTF-8, so a non-UTF-8 component can
+    // never be part of a match.
+    let path_str = format!("{}/", path.to_string_lossy());
+    if ignore_dirs.iter().any(|dir| {
+        let dir = dir.trim_matches('/');
+        !dir.is_empty() && path_str.contains(&format!("/{dir}/"))
+    }) {
+        return true;
     }
 
     if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
@@ -514,6 +846,44 @@ mod tests {
         assert!(names.contains(&"normal".to_string()));
     }
 
+    #[test]
+    fn test_bare_repo_is_discovered_and_partitioned_out() {
+        let temp = TempDir::new().unwrap();
+        let source = create_minimal_test_repo(temp.path(), "api");
+        let mirror = temp.path().join("api-mirror.git");
+        let cloned = crate::test_utils::run_git_command(
+            &[
+                "clone",
+                "--mirror",
+                source.to_str().unwrap(),
+                mirror.to_str().unwrap(),
+            ],
+            temp.path(),
+        );
+        assert!(cloned.status.success());
+
+        // Plain discovery never lists it; only commands that opt in see it.
+        let repos = discover_repos(temp.path(), 3, &[]).unwrap();
+        assert_eq!(repos.len(), 1, "the mirror is not listed by default");
+        assert!(repos.iter().all(|r| r.path != mirror));
+
+        let repos = discover_repos_including_bare(temp.path(), 3, &[]).unwrap();
+        let bare = repos
+            .iter()
+            .find(|r| r.path == mirror)
+            .expect("bare repo should be discovered");
+        assert_eq!(bare.layout, Layout::BareRepo);
+        assert_eq!(bare.name, "api-mirror");
+        assert_eq!(repos.len(), 2, "the mirror's internals are not repos");
+
+        let (work_trees, skipped) = partition_bare(repos);
+        assert_eq!(work_trees.len(), 1);
+        assert_eq!(work_trees[0].name, "api");
+        assert!(!work_trees[0].is_bare());
+        assert_eq!(skipped.len(), 1);
+        assert!(skipped[0].is_bare());
+    }
+
     #[test]
     fn test_ignore_patterns_respected() {
         // A repo directory whose name matches a configured ignore pattern is
@@ -533,10 +903,236 @@ mod tests {
         let patterns = vec!["node_modules".to_string()];
         assert!(is_ignored_directory(
             Path::new("/x/node_modules"),
-            &patterns
+            &patterns,
+            &[]
         ));
         // No name heuristic any more: `reporting` is not ignored.
-        assert!(!is_ignored_directory(Path::new("/x/reporting"), &patterns));
+        assert!(!is_ignored_directory(
+            Path::new("/x/reporting"),
+            &patterns,
+            &[]
+        ));
+    }
+
+    #[test]
+    fn test_is_ignored_directory_uses_dir_fragments() {
+        let dirs = vec![".cache".to_string(), "go/pkg/mod".to_string()];
+        assert!(is_ignored_directory(
+            Path::new("/home/u/.cache/pre-commit/repo"),
+            &[],
+            &dirs
+        ));
+        assert!(is_ignored_directory(
+            Path::new("/home/u/go/pkg/mod/x"),
+            &[],
+            &dirs
+        ));
+        // A fragment must match whole components, not a name prefix.
+        assert!(!is_ignored_directory(
+            Path::new("/home/u/.cached/repo"),
+            &[],
+            &dirs
+        ));
+    }
+
+    #[test]
+    fn test_configured_ignore_dir_is_excluded_from_discovery() {
+        let temp = TempDir::new().unwrap();
+        let third_party = temp.path().join("third_party");
+        std::fs::create_dir_all(&third_party).unwrap();
+        create_minimal_test_repo(&third_party, "vendored");
+        create_minimal_test_repo(temp.path(), "ours");
+
+        let settings = DiscoverySettings {
+            ignore_dirs: vec!["third_party".to_string()],
+            ..DiscoverySettings::default()
+        };
+        let repos = discover_repos_with_settings(temp.path(), 3, &[], &settings).unwrap();
+        let names: Vec<String> = repos.iter().map(|r| r.name.clone()).collect();
+        assert!(names.contains(&"ours".to_string()));
+        assert!(!names.contains(&"vendored".to_string()));
+
+        // Same tree with the default settings: the vendored repo is found.
+        let repos =
+            discover_repos_with_settings(temp.path(), 3, &[], &DiscoverySettings::default())
+                .unwrap();
+        assert!(repos.iter().any(|r| r.name == "vendored"));
+    }
+
+    #[test]
+    fn test_max_repos_stops_discovery_at_limit() {
+        let temp = TempDir::new().unwrap();
+        for i in 1..=5 {
+            create_minimal_test_repo(temp.path(), &format!("repo{i}"));
+        }
+
+        let settings = DiscoverySettings {
+            max_repos: Some(2),
+            ..DiscoverySettings::default()
+        };
+        let repos = discover_repos_with_settings(temp.path(), 3, &[], &settings).unwrap();
+        assert_eq!(repos.len(), 2);
+
+        let repos =
+            discover_repos_with_settings(temp.path(), 3, &[], &DiscoverySettings::default())
+                .unwrap();
+        assert_eq!(repos.len(), 5);
+    }
+
+    #[test]
+    fn test_max_repos_is_reached_only_by_a_further_repo() {
+        let temp = TempDir::new().unwrap();
+        let settings = DiscoverySettings {
+            max_repos: Some(1),
+            ..DiscoverySettings::default()
+        };
+        let first = Repo::new(create_minimal_test_repo(temp.path(), "repo1")).unwrap();
+        let second = Repo::new(create_minimal_test_repo(temp.path(), "repo2")).unwrap();
+        let mut repos = Vec::new();
+
+        // Filling the limit exactly is not a truncation...
+        assert!(push_wanted(&mut repos, first, &settings));
+        // ...finding one more is, and that repo is dropped.
+        assert!(!push_wanted(&mut repos, second, &settings));
+        assert_eq!(repos.len(), 1);
+    }
+
+    #[test]
+    fn test_only_slugs_keeps_just_the_listed_repos() {
+        let temp = TempDir::new().unwrap();
+        for i in 1..=4 {
+            create_minimal_test_repo(temp.path(), &format!("repo{i}"));
+        }
+        let all = discover_repos_with_settings(temp.path(), 3, &[], &DiscoverySettings::default())
+            .unwrap();
+        let wanted = vec![all[1].slug.clone(), all[3].slug.clone()];
+
+        let list = temp.path().join("repos.txt");
+        std::fs::write(
+            &list,
+            format!("# failed last run\n{}\n\n{}\n", wanted[0], wanted[1]),
+        )
+        .unwrap();
+        let settings = DiscoverySettings {
+            only_slugs: Some(read_repo_list(&list).unwrap()),
+            ..DiscoverySettings::default()
+        };
+        let repos = discover_repos_with_settings(temp.path(), 3, &[], &settings).unwrap();
+        let slugs: Vec<String> = repos.iter().map(|r| r.slug.clone()).collect();
+        assert_eq!(slugs, wanted);
+
+        // The list is applied before the limit: unlisted repos found first
+        // do not use up `--max-repos`.
+        let limited = DiscoverySettings {
+            max_repos: Some(2),
+            ..settings
+        };
+        let repos = discover_repos_with_settings(temp.path(), 3, &[], &limited).unwrap();
+        let slugs: Vec<String> = repos.iter().map(|r| r.slug.clone()).collect();
+        assert_eq!(slugs, wanted);
+    }
+
+    #[test]
+    fn test_repos_sharing_a_slug_are_reported() {
+        // Two clones of one remote under different parents both resolve to
+        // `testorg/app`.
+        let temp = TempDir::new().unwrap();
+        let first = create_minimal_test_repo(&temp.path().join("one"), "app");
+        let second = create_minimal_test_repo(&temp.path().join("two"), "app");
+        create_minimal_test_repo(temp.path(), "other");
+
+        let repos = discover_repos(temp.path(), 3, &[]).unwrap();
+        assert_eq!(repos.len(), 3);
+        let collisions = detect_slug_collisions(&repos);
+        assert_eq!(
+            collisions,
+            vec![(
+                "testorg/app".to_string(),
+                vec![first.clone(), second.clone()]
+            )]
+        );
+
+        // A bare mirror of the same remote is not a collision.
+        let mut with_mirror = repos.clone();
+        with_mirror.push(Repo {
+            path: temp.path().join("mirrors/app.git"),
+            name: "app".to_string(),
+            slug: "testorg/app".to_string(),
+            layout: Layout::BareRepo,
+        });
+        assert_eq!(detect_slug_collisions(&with_mirror), collisions);
+
+        let message = slug_collision_message(&collisions[0].0, &collisions[0].1);
+        assert!(message.starts_with("2 repos share the slug testorg/app"));
+        assert!(message.contains(&first.display().to_string()));
+        assert!(message.contains(&second.display().to_string()));
+    }
+
+    #[test]
+    fn test_fallback_slug_honors_skip_list() {
+        let defaults = DiscoverySettings::default().slug_fallback_skip;
+        assert_eq!(fallback_slug("app", Some("acme"), &defaults), "acme/app");
+        assert_eq!(fallback_slug("app", Some("src"), &defaults), "unknown/app");
+        assert_eq!(fallback_slug("app", None, &defaults), "unknown/app");
+
+        let custom = vec!["checkouts".to_string()];
+        assert_eq!(
+            fallback_slug("app", Some("checkouts"), &custom),
+            "unknown/app"
+        );
+        assert_eq!(fallback_slug("app", Some("src"), &custom), "src/app");
+    }
+
+    #[test]
+    fn test_walked_up_root_only_from_inside_a_repo() {
+        let temp = TempDir::new().unwrap();
+        let repo = create_minimal_test_repo(temp.path(), "inside");
+        assert_eq!(walked_up_root(&repo), Some(temp.path().to_path_buf()));
+        assert_eq!(walked_up_root(temp.path()), None);
+    }
+
+    #[test]
+    fn test_no_walk_up_and_root_narrow_discovery_from_inside_a_repo() {
+        let temp = TempDir::new().unwrap();
+        let inside = create_minimal_test_repo(temp.path(), "inside");
+        create_minimal_test_repo(temp.path(), "sibling");
+        let other = temp.path().join("other");
+        std::fs::create_dir_all(&other).unwrap();
+        create_minimal_test_repo(&other, "elsewhere");
+
+        let names = |settings: &DiscoverySettings| {
+            let mut names: Vec<String> = discover_repos_with_settings(&inside, 3, &[], settings)
+                .unwrap()
+                .into_iter()
+                .map(|r| r.name)
+                .collect();
+            names.sort();
+            names
+        };
+
+        // Heuristics on: walks up to the parent and finds every sibling.
+        let defaults = DiscoverySettings::default();
+        assert_eq!(names(&defaults), vec!["elsewhere", "inside", "sibling"]);
+        assert_eq!(
+            defaults.walked_up_root(&inside),
+            Some(temp.path().to_path_buf())
+        );
+
+        // --no-walk-up: only what lies under the starting directory.
+        let no_walk_up = DiscoverySettings {
+            walk_up: false,
+            ..DiscoverySettings::default()
+        };
+        assert_eq!(names(&no_walk_up), vec!["inside"]);
+        assert_eq!(no_walk_up.walked_up_root(&inside), None);
+
+        // --root: the given directory, wherever discovery started.
+        let root = DiscoverySettings {
+            root: Some(other.clone()),
+            ..DiscoverySettings::default()
+        };
+        assert_eq!(names(&root), vec!["elsewhere"]);
+        assert_eq!(root.walked_up_root(&inside), None);
     }
 
     #[test]
@@ -28,9 +28,22 @@ pub fn get_gx_binary_path() -> PathBuf {
     path
 }
 
+/// A throwaway `XDG_DATA_HOME` for every gx a test spawns through
+/// [`gx_command`], so no test run writes to the real data dir (the
+/// `gx retry` last-run record, change state, recovery files).
+static TEST_DATA_HOME: std::sync::LazyLock<TempDir> =
+    std::sync::LazyLock::new(|| TempDir::new().expect("Failed to create test XDG_DATA_HOME"));
+
+/// `gx` with its data dir isolated in [`TEST_DATA_HOME`].
+pub fn gx_command() -> Command {
+    let mut cmd = Command::new(get_gx_binary_path());
+    cmd.env("XDG_DATA_HOME", TEST_DATA_HOME.path());
+    cmd
+}
+
 /// Run a gx command and return the output
 pub fn run_gx_command(args: &[&str], working_dir: &Path) -> std::process::Output {
-    Command::new(get_gx_binary_path())
+    gx_command()
         .args(args)
         .current_dir(working_dir)
         .output()
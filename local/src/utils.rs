@@ -1,15 +1,69 @@
 //! Common utility functions for gx subcommands
 
 use crate::config::Config;
+use eyre::Result;
+use log::{debug, warn};
+use std::sync::OnceLock;
+use std::time::Duration;
 
 /// Get jobs from config, handling "nproc" string
 pub fn get_jobs_from_config(config: &Config) -> Option<usize> {
-    match config.jobs.as_deref()? {
+    parse_jobs(config.jobs.as_deref()?)
+}
+
+/// Parse a `jobs` value: a number, or `nproc`
+pub fn parse_jobs(jobs: &str) -> Option<usize> {
+    match jobs {
         "nproc" => get_nproc(),
         jobs_str => jobs_str.parse().ok(),
     }
 }
 
+/// Parse a human duration such as `30s`, `15m`, `24h`, `7d` or `2w`: one
+/// non-negative whole number and one unit (long forms like `days` work too).
+/// Compound values (`1d12h`) are rejected rather than half-read.
+pub fn parse_duration(duration: &str) -> Result<chrono::Duration> {
+    let duration = duration.trim();
+    if duration.is_empty() {
+        return Err(eyre::eyre!("Duration cannot be empty (e.g. 7d, 24h)"));
+    }
+    let split = duration
+        .find(|c: char| !c.is_ascii_digit())
+        .ok_or_else(|| eyre::eyre!("Duration {duration:?} needs a unit: s, m, h, d or w"))?;
+    let (number, unit) = duration.split_at(split);
+    let number: i64 = number
+        .parse()
+        .map_err(|_| eyre::eyre!("Duration {duration:?} must start with a whole number"))?;
+    if unit.chars().any(|c| c.is_ascii_digit()) {
+        return Err(eyre::eyre!(
+            "Duration {duration:?} must be one whole number and one unit (e.g. 36h, not 1d12h)"
+        ));
+    }
+
+    match unit.to_lowercase().as_str() {
+        "s" | "sec" | "second" | "seconds" => Ok(chrono::Duration::seconds(number)),
+        "m" | "min" | "minute" | "minutes" => Ok(chrono::Duration::minutes(number)),
+        "h" | "hr" | "hour" | "hours" => Ok(chrono::Duration::hours(number)),
+        "d" | "day" | "days" => Ok(chrono::Duration::days(number)),
+        "w" | "week" | "weeks" => Ok(chrono::Duration::weeks(number)),
+        _ => Err(eyre::eyre!(
+            "Invalid duration unit {unit:?} in {duration:?}; use s, m, h, d or w"
+        )),
+    }
+}
+
+/// Parse a `--timeout` value: a [`parse_duration`] string that must be
+/// non-zero, since a zero deadline would kill every git/gh call on spawn.
+pub fn parse_timeout(timeout: &str) -> Result<std::time::Duration> {
+    let duration = parse_duration(timeout)?
+        .to_std()
+        .map_err(|e| eyre::eyre!("Invalid timeout {timeout:?}: {e}"))?;
+    if duration.is_zero() {
+        return Err(eyre::eyre!("Timeout {timeout:?} must be greater than zero"));
+    }
+    Ok(duration)
+}
+
 /// Get max depth from config
 pub fn get_max_depth_from_config(config: &Config) -> Option<usize> {
     config.repo_discovery.as_ref()?.max_depth
@@ -28,3 +82,239 @@ pub fn indent(text: &str, spaces: usize) -> String {
         .collect::<Vec<_>>()
         .join("\n")
 }
+
+/// How hard to retry a transient network failure (`--retries`, `retries` /
+/// `retry-backoff-ms` in config).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt; `0` disables retrying.
+    pub retries: u32,
+    /// Delay before the first retry; doubled for each retry after that.
+    pub base_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Total attempts, counting the first.
+    pub fn attempts(&self) -> u32 {
+        self.retries.saturating_add(1)
+    }
+}
+
+/// Process-global retry policy, installed once from `Config` + `--retries` in
+/// `main`. Same write-once shape as `subprocess::init_subprocess_timeout`: the
+/// clone and gh call sites are too deep to thread `Config` through.
+static RETRY_POLICY: OnceLock<RetryPolicy> = OnceLock::new();
+
+/// Install the effective retry policy. A second call is a no-op.
+pub fn init_retry_policy(policy: RetryPolicy) {
+    debug!("init_retry_policy: {policy:?}");
+    if RETRY_POLICY.set(policy).is_err() {
+        warn!("init_retry_policy: already initialized; ignoring second value");
+    }
+}
+
+/// The effective retry policy: the installed one, or the config default.
+pub fn retry_policy() -> RetryPolicy {
+    RETRY_POLICY
+        .get()
+        .copied()
+        .unwrap_or_else(|| Config::default().retry_policy())
+}
+
+/// Whether an error message describes a transient condition worth retrying
+/// (rate limit, timeout, dropped connection). Auth and not-found failures are
+/// never retried, even when the message also mentions something transient.
+pub fn is_retryable_error(error: &str) -> bool {
+    let fatal_patterns = [
+        "authentication",
+        "bad credentials",
+        "permission denied",
+        "could not read username",
+        "repository not found",
+        "not found",
+        "401",
+        "404",
+    ];
+    let retryable_patterns = [
+        "timeout",
+        "timed out",
+        "connection refused",
+        "connection reset",
+        "connection closed",
+        "network",
+        "rate limit",
+        "too many requests",
+        "503",
+        "502",
+        "504",
+        "etimedout",
+        "econnreset",
+        "enotfound",
+    ];
+
+    let error_lower = error.to_lowercase();
+    if fatal_patterns.iter().any(|p| error_lower.contains(p)) {
+        return false;
+    }
+    retryable_patterns.iter().any(|p| error_lower.contains(p))
+}
+
+/// Run `f` up to `attempts` times, sleeping `base_delay * 2^n` between tries,
+/// as long as each failure [`is_retryable_error`]. A non-retryable failure is
+/// returned at once; exhausting the attempts returns the last error, noting
+/// how many attempts were made.
+pub fn retry_with_backoff<T, F>(attempts: u32, base_delay: Duration, mut f: F) -> Result<T>
+where
+    F: FnMut() -> Result<T>,
+{
+    let attempts = attempts.max(1);
+    let mut attempt = 1;
+    loop {
+        match f() {
+            Ok(value) => return Ok(value),
+            Err(e) => {
+                let message = format!("{e:#}");
+                if !is_retryable_error(&message) {
+                    return Err(e);
+                }
+                if attempt >= attempts {
+                    return Err(if attempts > 1 {
+                        e.wrap_err(format!("Failed after {attempts} attempts"))
+                    } else {
+                        e
+                    });
+                }
+                let delay = base_delay.saturating_mul(1u32 << (attempt - 1).min(16));
+                warn!(
+                    "Attempt {attempt}/{attempts} failed, retrying in {}ms: {}",
+                    delay.as_millis(),
+                    message.trim()
+                );
+                std::thread::sleep(delay);
+                attempt += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_parse_duration_units() {
+        use chrono::Duration as Span;
+        assert_eq!(parse_duration("30s").unwrap(), Span::seconds(30));
+        assert_eq!(parse_duration("5m").unwrap(), Span::minutes(5));
+        assert_eq!(parse_duration("24h").unwrap(), Span::hours(24));
+        assert_eq!(parse_duration("7d").unwrap(), Span::days(7));
+        assert_eq!(parse_duration("2w").unwrap(), Span::weeks(2));
+        assert_eq!(parse_duration("3days").unwrap(), Span::days(3));
+        assert_eq!(parse_duration(" 1W ").unwrap(), Span::weeks(1));
+    }
+
+    #[test]
+    fn test_parse_timeout() {
+        use std::time::Duration;
+        assert_eq!(parse_timeout("90s").unwrap(), Duration::from_secs(90));
+        assert_eq!(parse_timeout("2m").unwrap(), Duration::from_secs(120));
+        assert!(parse_timeout("0s")
+            .unwrap_err()
+            .to_string()
+            .contains("greater than zero"));
+        assert!(parse_timeout("30").is_err());
+    }
+
+    #[test]
+    fn test_parse_duration_rejects_bad_input() {
+        for (input, expected) in [
+            ("", "cannot be empty"),
+            ("30", "needs a unit"),
+            ("7x", "Invalid duration unit"),
+            ("d", "whole number"),
+            ("-1d", "whole number"),
+            ("1d12h", "one whole number and one unit"),
+            ("1.5h", "one whole number and one unit"),
+        ] {
+            let err = parse_duration(input).unwrap_err().to_string();
+            assert!(err.contains(expected), "{input:?}: {err}");
+        }
+    }
+
+    /// A closure that fails `failures` times with `error`, then succeeds.
+    fn flaky(
+        failures: u32,
+        error: &'static str,
+        calls: &Cell<u32>,
+    ) -> impl FnMut() -> Result<u32> + '_ {
+        move || {
+            calls.set(calls.get() + 1);
+            if calls.get() <= failures {
+                Err(eyre::eyre!(error))
+            } else {
+                Ok(calls.get())
+            }
+        }
+    }
+
+    #[test]
+    fn test_retry_succeeds_after_transient_failures() {
+        let calls = Cell::new(0);
+        let result = retry_with_backoff(
+            3,
+            Duration::ZERO,
+            flaky(2, "connection reset by peer", &calls),
+        );
+        assert_eq!(result.unwrap(), 3);
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn test_retry_gives_up_after_attempts() {
+        let calls = Cell::new(0);
+        let err = retry_with_backoff(
+            3,
+            Duration::ZERO,
+            flaky(5, "API rate limit exceeded", &calls),
+        )
+        .unwrap_err();
+        assert_eq!(calls.get(), 3);
+        let message = format!("{err:#}");
+        assert!(message.contains("after 3 attempts"), "{message}");
+        assert!(message.contains("rate limit"), "{message}");
+    }
+
+    #[test]
+    fn test_retry_fails_fast_on_non_retryable_error() {
+        let calls = Cell::new(0);
+        let err = retry_with_backoff(5, Duration::ZERO, flaky(5, "HTTP 404: Not Found", &calls))
+            .unwrap_err();
+        assert_eq!(calls.get(), 1);
+        assert!(err.to_string().contains("404"));
+
+        let calls = Cell::new(0);
+        retry_with_backoff(5, Duration::ZERO, flaky(5, "Bad credentials (401)", &calls))
+            .unwrap_err();
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn test_retry_single_attempt_never_retries() {
+        let calls = Cell::new(0);
+        retry_with_backoff(1, Duration::ZERO, flaky(1, "timed out", &calls)).unwrap_err();
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn test_is_retryable_error_classification() {
+        assert!(is_retryable_error(
+            "ssh: connect to host github.com: Connection timed out"
+        ));
+        assert!(is_retryable_error("HTTP 502 Bad Gateway"));
+        assert!(is_retryable_error("getaddrinfo ENOTFOUND api.github.com"));
+        assert!(!is_retryable_error("ERROR: Repository not found."));
+        assert!(!is_retryable_error("Permission denied (publickey)."));
+        assert!(!is_retryable_error("fatal: not a git repository"));
+    }
+}
@@ -0,0 +1,114 @@
+//! Config-driven command aliases (`aliases:` in `gx.yml`).
+//!
+//! `gx fe` with `aliases: { fe: [status, -p, frontend, --detailed] }` runs as
+//! `gx status -p frontend --detailed`. Expansion happens on the raw argument
+//! vector before the real parse, and only when clap rejects the token as an
+//! unknown subcommand, so an alias can never shadow a real subcommand. Global
+//! flags before the alias and arguments after it are kept in place.
+
+use crate::cli::Cli;
+use clap::error::{ContextKind, ContextValue, ErrorKind};
+use clap::{CommandFactory, Parser};
+use eyre::Result;
+use log::debug;
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+/// Expand the alias in `args` (program name first), repeatedly, so an alias
+/// may name another alias. An alias that expands back to itself is refused.
+pub fn expand_aliases(
+    mut args: Vec<OsString>,
+    aliases: &BTreeMap<String, Vec<String>>,
+) -> Result<Vec<OsString>> {
+    let mut expanded: Vec<String> = Vec::new();
+    while let Some(name) = unknown_subcommand(&args) {
+        let Some(replacement) = aliases.get(&name) else {
+            break;
+        };
+        if expanded.contains(&name) {
+            expanded.push(name);
+            return Err(eyre::eyre!("alias loop: {}", expanded.join(" -> ")));
+        }
+        debug!("expand_aliases: {name} -> {replacement:?}");
+        let Some(position) =
+            subcommand_position(&args).filter(|&i| args[i].as_os_str() == name.as_str())
+        else {
+            break;
+        };
+        args.splice(position..=position, replacement.iter().map(OsString::from));
+        expanded.push(name);
+    }
+    Ok(args)
+}
+
+/// The index in `args` of the subcommand: the first positional token after
+/// the global options, skipping each option's value (which may itself look
+/// like the alias, as in `gx --cwd fe fe`).
+fn subcommand_position(args: &[OsString]) -> Option<usize> {
+    let command = Cli::command();
+    let takes_value = |matches: &dyn Fn(&clap::Arg) -> bool| {
+        command
+            .get_arguments()
+            .any(|arg| matches(arg) && arg.get_action().takes_values())
+    };
+    let mut i = 1;
+    while let Some(token) = args.get(i) {
+        let token = token.to_string_lossy();
+        if token == "--" {
+            return None;
+        }
+        if let Some(long) = token.strip_prefix("--") {
+            if !long.contains('=') && takes_value(&|arg| arg.get_long() == Some(long)) {
+                i += 1;
+            }
+        } else if let Some(shorts) = token.strip_prefix('-').filter(|s| !s.is_empty()) {
+            // A short cluster (`-vj4`): the first value-taking flag consumes
+            // the rest of the cluster, or the next token when it ends it.
+            if let Some((at, short)) = shorts
+                .char_indices()
+                .find(|&(_, short)| takes_value(&|arg| arg.get_short() == Some(short)))
+            {
+                if at + short.len_utf8() == shorts.len() {
+                    i += 1;
+                }
+            }
+        } else {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// The token clap rejected as an unknown subcommand, if that is why `args`
+/// fails to parse.
+fn unknown_subcommand(args: &[OsString]) -> Option<String> {
+    let err = Cli::try_parse_from(args).err()?;
+    if err.kind() != ErrorKind::InvalidSubcommand {
+        return None;
+    }
+    match err.get(ContextKind::InvalidSubcommand) {
+        Some(ContextValue::String(name)) => Some(name.clone()),
+        _ => None,
+    }
+}
+
+/// The `-c`/`--config` path in raw `args`, so the aliases can be read from
+/// the same config file the parsed command will load.
+pub fn config_path_from_args(args: &[OsString]) -> Option<PathBuf> {
+    let mut iter = args.iter().skip(1);
+    while let Some(arg) = iter.next() {
+        let arg = arg.to_string_lossy();
+        if arg == "-c" || arg == "--config" {
+            return iter.next().map(PathBuf::from);
+        }
+        if let Some(path) = arg.strip_prefix("--config=") {
+            return Some(PathBuf::from(path));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,95 @@
+use super::*;
+
+fn args(args: &[&str]) -> Vec<OsString> {
+    args.iter().map(OsString::from).collect()
+}
+
+fn aliases(entries: &[(&str, &[&str])]) -> BTreeMap<String, Vec<String>> {
+    entries
+        .iter()
+        .map(|(name, argv)| {
+            (
+                name.to_string(),
+                argv.iter().map(|a| a.to_string()).collect(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_alias_expands_in_place_keeping_surrounding_args() {
+    let aliases = aliases(&[("fe", &["status", "-p", "frontend", "--detailed"])]);
+    let expanded = expand_aliases(args(&["gx", "-v", "fe", "--no-emoji"]), &aliases).unwrap();
+    assert_eq!(
+        expanded,
+        args(&[
+            "gx",
+            "-v",
+            "status",
+            "-p",
+            "frontend",
+            "--detailed",
+            "--no-emoji"
+        ])
+    );
+}
+
+#[test]
+fn test_option_value_equal_to_the_alias_is_left_alone() {
+    let aliases = aliases(&[("fe", &["status", "-p", "frontend"])]);
+    for prefix in [
+        &["--cwd", "fe"][..],
+        &["-c", "fe"],
+        &["--cwd=fe"],
+        &["-vc", "fe"],
+    ] {
+        let mut argv = vec!["gx"];
+        argv.extend_from_slice(prefix);
+        argv.push("fe");
+        let expanded = expand_aliases(args(&argv), &aliases).unwrap();
+        let mut want = vec!["gx"];
+        want.extend_from_slice(prefix);
+        want.extend_from_slice(&["status", "-p", "frontend"]);
+        assert_eq!(expanded, args(&want), "for {prefix:?}");
+    }
+}
+
+#[test]
+fn test_alias_never_shadows_a_real_subcommand() {
+    let aliases = aliases(&[("status", &["checkout", "main"])]);
+    let expanded = expand_aliases(args(&["gx", "status"]), &aliases).unwrap();
+    assert_eq!(expanded, args(&["gx", "status"]));
+}
+
+#[test]
+fn test_alias_may_name_another_alias() {
+    let aliases = aliases(&[("fe", &["st", "-p", "frontend"]), ("st", &["status"])]);
+    let expanded = expand_aliases(args(&["gx", "fe"]), &aliases).unwrap();
+    assert_eq!(expanded, args(&["gx", "status", "-p", "frontend"]));
+}
+
+#[test]
+fn test_alias_loop_is_refused() {
+    let aliases = aliases(&[("a", &["b"]), ("b", &["a"])]);
+    let err = expand_aliases(args(&["gx", "a"]), &aliases).unwrap_err();
+    assert!(err.to_string().contains("a -> b -> a"), "got: {err}");
+}
+
+#[test]
+fn test_unknown_name_without_alias_is_left_for_clap() {
+    let expanded = expand_aliases(args(&["gx", "nope"]), &BTreeMap::new()).unwrap();
+    assert_eq!(expanded, args(&["gx", "nope"]));
+}
+
+#[test]
+fn test_config_path_from_args() {
+    assert_eq!(config_path_from_args(&args(&["gx", "status"])), None);
+    assert_eq!(
+        config_path_from_args(&args(&["gx", "-c", "gx.yml", "fe"])),
+        Some(PathBuf::from("gx.yml"))
+    );
+    assert_eq!(
+        config_path_from_args(&args(&["gx", "--config=alt.yml", "fe"])),
+        Some(PathBuf::from("alt.yml"))
+    );
+}
@@ -3,8 +3,11 @@
 //! intercept `mcp`, then hand off here.
 
 use crate::cli::{Cli, Commands};
-use crate::{catalog, checkout, cleanup, clone, create, doctor, review, rollback, status, undo};
-use eyre::Result;
+use crate::{
+    catalog, checkout, cleanup, clone, create, doctor, exec, github, output, prune, retry, review,
+    rollback, status, undo,
+};
+use eyre::{Context, Result};
 use local::config::Config;
 use log::info;
 
@@ -13,6 +16,8 @@ use log::info;
 pub fn run_application(cli: &Cli, config: &Config) -> Result<()> {
     info!("Starting gx with command: {:?}", cli.command);
 
+    let use_colors = output::apply_color_choice(cli.color);
+
     match &cli.command {
         Commands::Status {
             detailed,
@@ -20,15 +25,54 @@ pub fn run_application(cli: &Cli, config: &Config) -> Result<()> {
             no_color,
             patterns,
             fetch_first,
+            fetch_jobs,
             no_remote,
+            search,
+            no_root_notice,
+            diff_stat,
+            only_errors,
+            remote,
+            branch,
+            group,
+            ahead_behind_all,
+            porcelain,
+            untracked_files,
+            include_submodules_in_status,
+            count_only,
+            json,
+            relative_paths,
+            absolute_paths,
         } => {
+            // `--no-color` is the deprecated spelling of `--color never`.
+            let use_colors = if *no_color {
+                eprintln!("⚠️  --no-color is deprecated; use --color never");
+                output::apply_color_choice(cli.color.with_no_color(true))
+            } else {
+                use_colors
+            };
             let options = status::StatusCommandOptions {
                 detailed: *detailed,
                 use_emoji: !no_emoji,
-                use_colors: !no_color,
+                use_colors,
                 patterns,
                 fetch_first: *fetch_first,
+                fetch_jobs: *fetch_jobs,
                 no_remote: *no_remote,
+                search: search.as_deref(),
+                root_notice: !no_root_notice,
+                diff_stat: *diff_stat,
+                only_errors: *only_errors,
+                remote: remote.as_deref(),
+                branch: branch.as_deref(),
+                group: *group,
+                ahead_behind_all: *ahead_behind_all,
+                porcelain: *porcelain,
+                untracked_files: *untracked_files,
+                include_submodules: *include_submodules_in_status,
+                count_only: *count_only,
+                json: *json,
+                relative_paths: *relative_paths,
+                absolute_paths: *absolute_paths,
             };
             status::process_status_command(cli, config, options)
         }
@@ -37,6 +81,11 @@ pub fn run_application(cli: &Cli, config: &Config) -> Result<()> {
             from_branch,
             branch_name,
             stash,
+            pull,
+            worktree,
+            submodules,
+            detach,
+            sync_only,
             patterns,
         } => checkout::process_checkout_command(
             cli,
@@ -45,13 +94,45 @@ pub fn run_application(cli: &Cli, config: &Config) -> Result<()> {
             from_branch.as_deref(),
             branch_name,
             *stash,
+            *pull,
+            worktree.as_deref(),
+            *submodules,
+            *detach,
+            *sync_only,
             patterns,
         ),
         Commands::Clone {
             user_or_org,
+            search,
             include_archived,
+            archived_only,
+            add_upstream,
+            submodules,
+            mirror,
+            write_manifest,
+            limit,
+            since,
+            layout,
+            protocol,
+            skip_existing,
+            patterns,
+        } => clone::process_clone_command(
+            cli,
+            config,
+            user_or_org.as_deref(),
+            search.as_deref(),
+            github::ArchivedFilter::from_flags(*include_archived, *archived_only),
+            add_upstream.as_deref(),
+            *submodules,
+            *mirror,
+            write_manifest.as_deref(),
+            *limit,
+            since.as_deref(),
+            layout,
+            *protocol,
+            *skip_existing,
             patterns,
-        } => clone::process_clone_command(cli, config, user_or_org, *include_archived, patterns),
+        ),
         Commands::Create {
             files,
             change_id,
@@ -59,30 +140,95 @@ pub fn run_application(cli: &Cli, config: &Config) -> Result<()> {
             commit,
             pr,
             draft,
+            pr_body,
+            pr_body_file,
+            pr_use_template,
+            base,
+            reviewers,
+            assignees,
+            labels,
+            create_labels,
+            sign,
+            sign_key,
+            no_verify,
+            trailers,
+            change_id_trailer,
+            ensure_final_newline,
+            format_cmd,
+            tag,
+            tag_message,
+            no_empty_files,
+            no_gitignore,
+            limit_files,
+            parallel_files,
+            target_existing_branch,
+            interpolate,
+            detailed,
+            json,
             yes,
             report,
+            plan_out,
+            plan_in,
             action,
-        } => match action {
-            None => create::show_matches(cli, config, files, patterns),
-            Some(action) => {
+        } => match (plan_in, action) {
+            (Some(_), Some(_)) => Err(eyre::eyre!(
+                "--plan-in takes the change from the plan; drop the change subcommand"
+            )),
+            (Some(plan_path), None) => {
+                create::process_plan_command(cli, config, plan_path, *yes, *json, report.as_deref())
+            }
+            (None, None) if plan_out.is_some() => Err(eyre::eyre!(
+                "--plan-out needs a change to plan (add, append, delete, sub, regex or set-key)"
+            )),
+            (None, None) => {
+                create::show_matches(cli, config, files, patterns, *no_gitignore, *json)
+            }
+            (None, Some(crate::cli::CreateAction::Revert { change_id, force })) => {
+                create::process_revert_command(cli, change_id, *force)
+            }
+            (None, Some(action)) => {
                 let propose_only =
                     matches!(action, crate::cli::CreateAction::Llm { propose, .. } if *propose);
                 let change = match action {
                     crate::cli::CreateAction::Add { path, content } => {
-                        create::Change::Add(path.clone(), content.clone())
+                        create::Change::Add(path.clone(), create::resolve_add_content(content)?)
                     }
+                    crate::cli::CreateAction::Append {
+                        path,
+                        content,
+                        if_missing,
+                    } => create::Change::Append(
+                        path.clone(),
+                        create::resolve_add_content(content)?,
+                        *if_missing,
+                    ),
                     crate::cli::CreateAction::Delete => create::Change::Delete,
                     crate::cli::CreateAction::Sub {
                         pattern,
                         replacement,
-                    } => create::Change::Sub(pattern.clone(), replacement.clone()),
+                        ignore_case,
+                        whole_word,
+                    } => create::Change::Sub(
+                        pattern.clone(),
+                        replacement.clone(),
+                        local::diff::SubMatch {
+                            ignore_case: *ignore_case,
+                            whole_word: *whole_word,
+                        },
+                    ),
                     crate::cli::CreateAction::Regex {
                         pattern,
                         replacement,
                     } => create::Change::Regex(pattern.clone(), replacement.clone()),
+                    crate::cli::CreateAction::SetKey {
+                        key_path,
+                        value,
+                        create,
+                    } => create::Change::SetKey(key_path.clone(), value.clone(), *create),
                     crate::cli::CreateAction::Llm { prompt, .. } => {
                         create::Change::Llm(prompt.clone())
                     }
+                    crate::cli::CreateAction::Revert { .. } => unreachable!("routed above"),
                 };
                 create::process_create_command(
                     cli,
@@ -97,6 +243,46 @@ pub fn run_application(cli: &Cli, config: &Config) -> Result<()> {
                     change,
                     propose_only,
                     report.as_deref(),
+                    &create::CreateOptions {
+                        commit: local::git::CommitOptions {
+                            sign: *sign,
+                            sign_key: sign_key.clone(),
+                            trailers: trailers.clone(),
+                            no_verify: *no_verify,
+                        },
+                        change_id_trailer: *change_id_trailer,
+                        ensure_final_newline: *ensure_final_newline,
+                        format_cmd: format_cmd.clone(),
+                        tag: tag.clone(),
+                        tag_message: tag_message.clone(),
+                        no_empty_files: *no_empty_files,
+                        no_gitignore: *no_gitignore,
+                        limit_files: *limit_files,
+                        file_pool: create::file_pool(*parallel_files)?,
+                        target_existing_branch: *target_existing_branch,
+                        interpolate: *interpolate,
+                        pr_body: match pr_body_file {
+                            Some(path) => {
+                                Some(std::fs::read_to_string(path).with_context(|| {
+                                    format!("Failed to read --pr-body-file {}", path.display())
+                                })?)
+                            }
+                            None => pr_body.clone(),
+                        },
+                        pr_use_template: *pr_use_template,
+                        pr_base: base.clone(),
+                        pr_metadata: crate::github::PrMetadata {
+                            reviewers: reviewers.clone(),
+                            assignees: assignees.clone(),
+                            labels: labels.clone(),
+                            create_labels: *create_labels,
+                        },
+                        progress: crate::output::progress_wanted(cli.progress, cli.quiet, false),
+                        detailed: *detailed,
+                        json: *json,
+                        stats: Default::default(),
+                    },
+                    plan_out.as_deref(),
                 )
             }
         },
@@ -111,9 +297,25 @@ pub fn run_application(cli: &Cli, config: &Config) -> Result<()> {
             patterns,
             action,
         } => match action {
-            crate::cli::ReviewAction::Ls { change_ids } => {
-                review::process_review_ls_command(cli, config, org.as_deref(), patterns, change_ids)
-            }
+            crate::cli::ReviewAction::Ls {
+                change_ids,
+                json,
+                limit,
+                state,
+                author,
+            } => review::process_review_ls_command(
+                cli,
+                config,
+                org.as_deref(),
+                patterns,
+                change_ids,
+                *json,
+                review::PrListFilter {
+                    limit: *limit,
+                    state: *state,
+                    author: author.as_deref(),
+                },
+            ),
             crate::cli::ReviewAction::Clone { change_id, all } => {
                 review::process_review_clone_command(
                     cli,
@@ -149,6 +351,12 @@ pub fn run_application(cli: &Cli, config: &Config) -> Result<()> {
                     *yes,
                 )
             }
+            crate::cli::ReviewAction::Reopen { change_id } => {
+                review::process_review_reopen_command(cli, config, org.as_deref(), change_id)
+            }
+            crate::cli::ReviewAction::Status { change_id } => {
+                review::process_review_status_command(config, change_id)
+            }
             crate::cli::ReviewAction::Sync { change_id } => review::process_review_sync_command(
                 cli,
                 config,
@@ -156,9 +364,19 @@ pub fn run_application(cli: &Cli, config: &Config) -> Result<()> {
                 patterns,
                 change_id,
             ),
-            crate::cli::ReviewAction::Purge { yes } => {
-                review::process_review_purge_command(cli, config, org.as_deref(), patterns, *yes)
-            }
+            crate::cli::ReviewAction::Purge {
+                yes,
+                merged_only,
+                older_than,
+            } => review::process_review_purge_command(
+                cli,
+                config,
+                org.as_deref(),
+                patterns,
+                *yes,
+                *merged_only,
+                older_than.as_deref(),
+            ),
         },
         Commands::Rollback { action } => rollback::handle_rollback(action.clone()),
         Commands::Undo {
@@ -183,10 +401,33 @@ pub fn run_application(cli: &Cli, config: &Config) -> Result<()> {
             *force,
             *yes,
         ),
-        Commands::Doctor { purge } => doctor::run_doctor(*purge),
+        Commands::Prune {
+            prefix,
+            patterns,
+            merged_only,
+            dry_run,
+            yes,
+        } => prune::process_prune_command(
+            cli,
+            config,
+            prefix,
+            patterns,
+            *merged_only,
+            *dry_run,
+            *yes,
+        ),
+        Commands::Exec {
+            fail_fast,
+            patterns,
+            command,
+        } => exec::process_exec_command(cli, config, command, *fail_fast, patterns),
+        Commands::Retry { dry_run } => retry::process_retry_command(*dry_run),
+        Commands::Doctor { purge } => doctor::run_doctor(config, cli.config.as_deref(), *purge),
         Commands::Catalog { fetch } => catalog::process_catalog_command(cli, config, *fetch),
         // Intercepted in the bin's `run()` before `run_application` is ever
         // called, so it never reaches this dispatch.
         Commands::Mcp(_) => unreachable!("mcp is handled before run_application"),
+        // Also intercepted by the bin, before the config it inspects is loaded.
+        Commands::Config { .. } => unreachable!("config is handled before run_application"),
     }
 }
@@ -4,17 +4,20 @@
 
 use crate::cli::Cli;
 use crate::output::StatusOptions;
-use crate::{git, output};
+use crate::{git, output, retry};
 use eyre::{Context, Result};
 use local::config::Config;
 use local::repo;
-use local::utils::{get_jobs_from_config, get_max_depth_from_config, get_nproc};
+use local::utils::get_nproc;
 use log::{debug, info};
 use rayon::prelude::*;
 use std::env;
+use std::path::Path;
 use std::sync::Mutex;
 
-/// Process the checkout subcommand
+/// Process the checkout subcommand. With `sync_only`, each repo's current
+/// branch is fast-forwarded in place and `branch_name` is ignored.
+#[allow(clippy::too_many_arguments)]
 pub fn process_checkout_command(
     cli: &Cli,
     config: &Config,
@@ -22,6 +25,11 @@ pub fn process_checkout_command(
     from_branch: Option<&str>,
     branch_name: &str,
     stash: bool,
+    pull: bool,
+    worktree: Option<&Path>,
+    submodules: bool,
+    detach: bool,
+    sync_only: bool,
     patterns: &[String],
 ) -> Result<()> {
     info!(
@@ -30,10 +38,12 @@ pub fn process_checkout_command(
         patterns.len()
     );
 
+    let org_config = config.for_user_org(cli.user_org.as_deref());
+
     // Determine jobs
     let jobs = cli
         .parallel
-        .or_else(|| get_jobs_from_config(config))
+        .or(org_config.jobs)
         .unwrap_or_else(|| get_nproc().unwrap_or(4));
 
     debug!("Using jobs: {jobs}");
@@ -45,17 +55,16 @@ pub fn process_checkout_command(
         .context("Failed to initialize thread pool")?;
 
     // Determine max depth
-    let max_depth = cli
-        .max_depth
-        .or_else(|| get_max_depth_from_config(config))
-        .unwrap_or(3);
+    let max_depth = cli.max_depth.or(org_config.max_depth).unwrap_or(3);
 
     debug!("Using max depth: {max_depth}");
 
     // 1. Discover repositories
     let start_dir = env::current_dir().context("Failed to get current directory")?;
-    let repos = repo::discover_repos(&start_dir, max_depth, &config.ignore_patterns())
-        .context("Failed to discover repositories")?;
+    let repos =
+        repo::discover_repos_including_bare(&start_dir, max_depth, &config.ignore_patterns())
+            .context("Failed to discover repositories")?;
+    output::warn_slug_collisions(&repos, cli.quiet);
 
     info!("Discovered {} repositories", repos.len());
 
@@ -67,22 +76,54 @@ pub fn process_checkout_command(
         println!("🔍 No repositories found matching the criteria");
         return Ok(());
     }
+    // Bare repos (mirrors) have no work tree to check a branch out in.
+    let (filtered_repos, bare_repos) = repo::partition_bare(filtered_repos);
+
+    // git runs with the repo as its cwd, so a relative --worktree dir must be
+    // anchored to where gx was invoked, not to each repo.
+    let worktree = worktree.map(|dir| {
+        if dir.is_absolute() {
+            dir.to_path_buf()
+        } else {
+            start_dir.join(dir)
+        }
+    });
 
     // 3. Process repositories in parallel with streaming output
+    let display_opts = StatusOptions {
+        quiet: cli.quiet,
+        ..Default::default()
+    };
+    output::display_bare_skips(&bare_repos, &display_opts);
     let results = Mutex::new(Vec::new());
 
     filtered_repos.par_iter().for_each(|repo| {
-        // Resolve branch name per repo (handle 'default' keyword)
-        let resolved_branch = match local::git::resolve_branch_name(repo, branch_name) {
-            Ok(branch) => branch,
-            Err(e) => {
-                // Handle resolution error
+        if sync_only {
+            let result = git::sync_current_branch(repo);
+            results
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .push(result.clone());
+            if let Err(e) = output::display_checkout_result_immediate(&result, &display_opts) {
+                log::error!("Failed to display checkout result: {e}");
+            }
+            return;
+        }
+
+        // Resolve the target (and base) branch once per repo, up front, so the
+        // checkout itself only ever sees concrete branch names.
+        let resolved = resolve_checkout_branches(branch_name, from_branch, || {
+            local::git::get_default_branch_local(repo)
+        });
+        let (resolved_branch, resolved_from_branch) = match resolved {
+            Ok(resolved) => resolved,
+            Err(error) => {
                 let result = git::CheckoutResult {
                     repo: repo.clone(),
                     branch_name: branch_name.to_string(),
                     commit_sha: None,
                     action: git::CheckoutAction::CheckedOutSynced,
-                    error: Some(format!("Failed to resolve branch name: {e}")),
+                    error: Some(error),
                 };
 
                 // Store result and display immediately. Poison-recovery
@@ -92,50 +133,23 @@ pub fn process_checkout_command(
                     .lock()
                     .unwrap_or_else(|e| e.into_inner())
                     .push(result.clone());
-                if let Err(e) = output::display_checkout_result_immediate(&result) {
+                if let Err(e) = output::display_checkout_result_immediate(&result, &display_opts) {
                     log::error!("Failed to display checkout result: {e}");
                 }
                 return;
             }
         };
 
-        // Resolve from_branch if provided and it's 'default'
-        let resolved_from_branch = if let Some(from) = from_branch {
-            match local::git::resolve_branch_name(repo, from) {
-                Ok(branch) => Some(branch),
-                Err(e) => {
-                    // Handle from_branch resolution error
-                    let result = git::CheckoutResult {
-                        repo: repo.clone(),
-                        branch_name: branch_name.to_string(),
-                        commit_sha: None,
-                        action: git::CheckoutAction::CheckedOutSynced,
-                        error: Some(format!("Failed to resolve from branch '{from}': {e}")),
-                    };
-
-                    // Store result and display immediately. Poison-recovery
-                    // belt-and-suspenders (the panic hook in `main` is the
-                    // primary fix).
-                    results
-                        .lock()
-                        .unwrap_or_else(|e| e.into_inner())
-                        .push(result.clone());
-                    if let Err(e) = output::display_checkout_result_immediate(&result) {
-                        log::error!("Failed to display checkout result: {e}");
-                    }
-                    return;
-                }
-            }
-        } else {
-            None
-        };
-
         let result = git::checkout_branch(
             repo,
             &resolved_branch,
             create_branch,
             resolved_from_branch.as_deref(),
             stash,
+            pull,
+            worktree.as_deref(),
+            submodules,
+            detach,
         );
 
         // Store result and display immediately. Poison-recovery
@@ -144,7 +158,7 @@ pub fn process_checkout_command(
             .lock()
             .unwrap_or_else(|e| e.into_inner())
             .push(result.clone());
-        if let Err(e) = output::display_checkout_result_immediate(&result) {
+        if let Err(e) = output::display_checkout_result_immediate(&result, &display_opts) {
             log::error!("Failed to display checkout result: {e}");
         }
     });
@@ -153,17 +167,55 @@ pub fn process_checkout_command(
     let results_vec = results.into_inner().unwrap_or_else(|e| e.into_inner());
     let (clean_count, dirty_count, error_count) = categorize_checkout_results(&results_vec);
 
-    let status_opts = StatusOptions::default();
-    output::display_unified_summary(clean_count, dirty_count, error_count, &status_opts);
+    output::display_unified_summary(clean_count, dirty_count, error_count, &display_opts);
+    let failed: Vec<&str> = results_vec
+        .iter()
+        .filter(|r| r.error.is_some() || matches!(r.action, git::CheckoutAction::Skipped { .. }))
+        .map(|r| r.repo.slug.as_str())
+        .collect();
+    retry::record_last_run(&failed);
 
     // 5. Exit with error count
-    if error_count > 0 {
-        std::process::exit(error_count.min(255) as i32);
-    }
+    output::exit_on_errors(error_count, !cli.no_fail_on_error);
 
     Ok(())
 }
 
+/// Resolve the checkout target and optional `--from` base for one repo. The
+/// `default` keyword may appear in either (or both); `default_branch` is asked
+/// at most once, since each call costs several git spawns. Errors carry the
+/// user-facing message for the repo's result line.
+fn resolve_checkout_branches<F>(
+    branch_name: &str,
+    from_branch: Option<&str>,
+    mut default_branch: F,
+) -> std::result::Result<(String, Option<String>), String>
+where
+    F: FnMut() -> Result<String>,
+{
+    let mut cached: Option<String> = None;
+    let mut resolve = |name: &str| -> Result<String> {
+        if name != "default" {
+            return Ok(name.to_string());
+        }
+        if let Some(branch) = &cached {
+            return Ok(branch.clone());
+        }
+        let branch = default_branch()?;
+        cached = Some(branch.clone());
+        Ok(branch)
+    };
+
+    let branch = resolve(branch_name).map_err(|e| format!("Failed to resolve branch name: {e}"))?;
+    let from = match from_branch {
+        Some(from) => Some(
+            resolve(from).map_err(|e| format!("Failed to resolve from branch '{from}': {e}"))?,
+        ),
+        None => None,
+    };
+    Ok((branch, from))
+}
+
 /// Categorize checkout results into clean/dirty/error counts
 fn categorize_checkout_results(results: &[git::CheckoutResult]) -> (usize, usize, usize) {
     let mut clean_count = 0;
@@ -176,12 +228,20 @@ fn categorize_checkout_results(results: &[git::CheckoutResult]) -> (usize, usize
         } else {
             match result.action {
                 git::CheckoutAction::CheckedOutSynced => clean_count += 1,
+                git::CheckoutAction::Switched => clean_count += 1,
                 git::CheckoutAction::CreatedFromRemote => clean_count += 1,
-                git::CheckoutAction::Stashed => dirty_count += 1, // Had uncommitted changes
-                git::CheckoutAction::HasUntracked => dirty_count += 1, // Has untracked files
+                git::CheckoutAction::Stashed { .. } => dirty_count += 1, // Had uncommitted changes
+                git::CheckoutAction::HasUntracked => dirty_count += 1,   // Has untracked files
+                git::CheckoutAction::AddedWorktree => clean_count += 1,
+                git::CheckoutAction::Synced { .. } => clean_count += 1,
+                git::CheckoutAction::Detached => clean_count += 1,
+                git::CheckoutAction::Skipped { .. } => dirty_count += 1, // Operation in progress
             }
         }
     }
 
     (clean_count, dirty_count, error_count)
 }
+
+#[cfg(test)]
+mod tests;
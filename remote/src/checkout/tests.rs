@@ -0,0 +1,41 @@
+use super::*;
+use std::cell::Cell;
+
+#[test]
+fn test_default_branch_resolved_once_for_target_and_base() {
+    let calls = Cell::new(0);
+    let resolved = resolve_checkout_branches("default", Some("default"), || {
+        calls.set(calls.get() + 1);
+        Ok("main".to_string())
+    })
+    .unwrap();
+
+    assert_eq!(resolved, ("main".to_string(), Some("main".to_string())));
+    assert_eq!(calls.get(), 1, "default branch must be resolved once");
+}
+
+#[test]
+fn test_concrete_branches_never_resolve_default() {
+    let calls = Cell::new(0);
+    let resolved = resolve_checkout_branches("feature", Some("develop"), || {
+        calls.set(calls.get() + 1);
+        Ok("main".to_string())
+    })
+    .unwrap();
+
+    assert_eq!(
+        resolved,
+        ("feature".to_string(), Some("develop".to_string()))
+    );
+    assert_eq!(calls.get(), 0);
+}
+
+#[test]
+fn test_resolution_error_names_the_from_branch() {
+    let err = resolve_checkout_branches("feature", Some("default"), || {
+        Err(eyre::eyre!("no origin/HEAD"))
+    })
+    .unwrap_err();
+    assert!(err.contains("from branch 'default'"), "{err}");
+    assert!(err.contains("no origin/HEAD"), "{err}");
+}
@@ -7,20 +7,26 @@ use crate::cli::Cli;
 use crate::confirm::{confirm_destructive, DestructiveOp};
 use crate::git;
 use crate::lock::{ChangeLock, RepoLock};
-use crate::state::{ChangeState, ChangeStatus, RepoChangeStatus, StateManager};
+use crate::state::{ChangeState, ChangeStatus, RepoChangeState, RepoChangeStatus, StateManager};
 use eyre::{Context, Result};
 use local::config::Config;
 use log::{debug, info, warn};
 
-/// Count the local branches a cleanup pass would actually `git branch -D` for
-/// one change: repos needing cleanup that are eligible under the `force` gate
-/// (merged-only unless `--force`). This is the true blast radius the confirm
-/// gate reports - not the raw repo count.
+/// Whether a repo's change landed: its PR merged, or it merged and was then
+/// reverted onto a revert branch (`gx create revert --force`).
+fn is_merged(repo: &RepoChangeState) -> bool {
+    repo.status == RepoChangeStatus::PrMerged || repo.revert_branch.is_some()
+}
+
+/// Count the repos whose branches a cleanup pass would actually `git branch -D`
+/// for one change: repos needing cleanup that are eligible under the `force`
+/// gate (merged-only unless `--force`). This is the true blast radius the
+/// confirm gate reports - not the raw repo count.
 fn eligible_cleanup_count(state: &ChangeState, force: bool) -> usize {
     state
         .get_repos_needing_cleanup()
         .iter()
-        .filter(|r| force || r.status == RepoChangeStatus::PrMerged)
+        .filter(|r| force || is_merged(r))
         .count()
 }
 
@@ -289,10 +295,14 @@ fn cleanup_change(
         .get_repos_needing_cleanup()
         .iter()
         .map(|r| {
+            // A reverted change's revert branch goes along with the change branch.
+            let branches: Vec<String> = std::iter::once(r.branch_name.clone())
+                .chain(r.revert_branch.clone())
+                .collect();
             (
                 r.repo_slug.clone(),
-                r.branch_name.clone(),
-                r.status.clone(),
+                branches,
+                is_merged(r),
                 r.local_path.clone(),
             )
         })
@@ -303,9 +313,9 @@ fn cleanup_change(
     let mut failed = 0;
     let mut errors = Vec::new();
 
-    for (repo_slug, branch_name, status, recorded_path) in repos_to_clean {
+    for (repo_slug, branches, merged, recorded_path) in repos_to_clean {
         // Check if we should clean this repo
-        if !force && status != RepoChangeStatus::PrMerged {
+        if !force && !merged {
             info!("Skipping {} - PR not merged", repo_slug);
             skipped += 1;
             continue;
@@ -366,66 +376,79 @@ fn cleanup_change(
         // unconditional delete); on Ok(false) or a verification error we fail
         // CLOSED and PRESERVE the branch.
         if !force {
-            match git::branch_merged_into_base(&local_path, &branch_name) {
-                Ok(true) => {}
-                Ok(false) => {
-                    warn!(
-                        "Skipping {repo_slug}: branch {branch_name} has commits not in the base branch; re-check the merge or use --force"
-                    );
-                    skipped += 1;
-                    continue;
-                }
-                Err(e) => {
-                    warn!(
-                        "Skipping {repo_slug}: could not verify {branch_name} is merged into the base branch: {e}"
-                    );
-                    errors.push(format!("{repo_slug}: ancestry check failed: {e}"));
-                    failed += 1;
-                    continue;
+            if let Some(outcome) = unmerged_branch(&local_path, &branches) {
+                match outcome {
+                    Ok(branch_name) => {
+                        warn!(
+                            "Skipping {repo_slug}: branch {branch_name} has commits not in the base branch; re-check the merge or use --force"
+                        );
+                        skipped += 1;
+                    }
+                    Err(e) => {
+                        warn!(
+                            "Skipping {repo_slug}: could not verify its branches are merged into the base branch: {e}"
+                        );
+                        errors.push(format!("{repo_slug}: ancestry check failed: {e}"));
+                        failed += 1;
+                    }
                 }
+                continue;
             }
         }
 
-        // Delete local branch. Existence is checked explicitly FIRST (F13) so
+        // Delete local branches. Existence is checked explicitly FIRST (F13) so
         // an already-deleted branch is a no-op rather than the caller sniffing
         // the delete error's text for "not found"/"does not exist".
-        match local::git::branch_exists_locally(&local_path, &branch_name) {
-            Ok(true) => match local::git::delete_local_branch(&local_path, &branch_name) {
-                Ok(()) => {
-                    info!("🧹 Deleted local branch {} in {}", branch_name, repo_slug);
-                    cleaned += 1;
-                    state.mark_cleaned_up(&repo_slug);
+        let mut deleted = false;
+        let mut repo_failed = false;
+        for branch_name in &branches {
+            match local::git::branch_exists_locally(&local_path, branch_name) {
+                Ok(true) => match local::git::delete_local_branch(&local_path, branch_name) {
+                    Ok(()) => {
+                        info!("🧹 Deleted local branch {} in {}", branch_name, repo_slug);
+                        deleted = true;
+                    }
+                    Err(e) => {
+                        warn!(
+                            "Failed to delete branch {} in {}: {}",
+                            branch_name, repo_slug, e
+                        );
+                        errors.push(format!("{}: {}", repo_slug, e));
+                        repo_failed = true;
+                    }
+                },
+                Ok(false) => {
+                    debug!("Branch {branch_name} already deleted in {repo_slug}");
                 }
                 Err(e) => {
-                    warn!(
-                        "Failed to delete branch {} in {}: {}",
-                        branch_name, repo_slug, e
-                    );
-                    errors.push(format!("{}: {}", repo_slug, e));
-                    failed += 1;
+                    warn!("Failed to check local branch {branch_name} in {repo_slug}: {e}");
+                    errors.push(format!("{repo_slug}: {e}"));
+                    repo_failed = true;
                 }
-            },
-            Ok(false) => {
-                debug!("Branch {branch_name} already deleted in {repo_slug}");
-                state.mark_cleaned_up(&repo_slug);
-                skipped += 1;
             }
-            Err(e) => {
-                warn!("Failed to check local branch {branch_name} in {repo_slug}: {e}");
-                errors.push(format!("{repo_slug}: {e}"));
-                failed += 1;
+        }
+        if repo_failed {
+            failed += 1;
+        } else {
+            state.mark_cleaned_up(&repo_slug);
+            if deleted {
+                cleaned += 1;
+            } else {
+                skipped += 1;
             }
         }
 
-        // Optionally delete remote branch. `git::delete_remote_branch` already
+        // Optionally delete remote branches. `git::delete_remote_branch` already
         // pre-probes existence (F13), so an already-absent branch is a silent
         // no-op; only a genuine failure is worth a warning here.
         if include_remote {
-            if let Err(e) = git::delete_remote_branch(&local_path, &branch_name) {
-                warn!(
-                    "Failed to delete remote branch {} in {}: {}",
-                    branch_name, repo_slug, e
-                );
+            for branch_name in &branches {
+                if let Err(e) = git::delete_remote_branch(&local_path, branch_name) {
+                    warn!(
+                        "Failed to delete remote branch {} in {}: {}",
+                        branch_name, repo_slug, e
+                    );
+                }
             }
         }
     }
@@ -439,6 +462,19 @@ fn cleanup_change(
     })
 }
 
+/// The first of `branches` with commits absent from the fetched base branch,
+/// `None` when all are merged; `Err` when the check itself failed.
+fn unmerged_branch(local_path: &std::path::Path, branches: &[String]) -> Option<Result<String>> {
+    for branch_name in branches {
+        match git::branch_merged_into_base(local_path, branch_name) {
+            Ok(true) => {}
+            Ok(false) => return Some(Ok(branch_name.clone())),
+            Err(e) => return Some(Err(e)),
+        }
+    }
+    None
+}
+
 /// Try to find a repository locally by slug
 fn find_repo_locally(repo_slug: &str) -> Option<std::path::PathBuf> {
     // Extract repo name from slug
@@ -27,6 +27,49 @@ impl LogLevel {
     }
 }
 
+/// When to emit ANSI colors (`--color`), following git/cargo: `auto` colors
+/// only when stdout is a terminal and `NO_COLOR` is unset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[clap(rename_all = "lowercase")]
+pub enum ColorChoice {
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    /// Whether colors are on, given whether stdout is a terminal and whether
+    /// `NO_COLOR` is set. Only `always` overrides either.
+    pub fn resolve(self, stdout_is_tty: bool, no_color_env: bool) -> bool {
+        match self {
+            ColorChoice::Auto => stdout_is_tty && !no_color_env,
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        }
+    }
+
+    /// The choice in effect for a command that still takes the deprecated
+    /// `--no-color`, which means `never` whatever `--color` says.
+    pub fn with_no_color(self, no_color: bool) -> Self {
+        if no_color {
+            ColorChoice::Never
+        } else {
+            self
+        }
+    }
+}
+
+/// Which PRs `review ls --state` keeps. `closed` means every PR that is no
+/// longer open, merged or not, as on GitHub.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+#[clap(rename_all = "lowercase")]
+pub enum PrStateFilter {
+    #[default]
+    Open,
+    Closed,
+    All,
+}
+
 /// Validate a `--change-id`: it must start with `GX-` so the review tooling can
 /// find its PRs by that prefix ([A11]). Rejected at parse time.
 fn validate_change_id(value: &str) -> Result<String, String> {
@@ -39,6 +82,48 @@ fn validate_change_id(value: &str) -> Result<String, String> {
     }
 }
 
+/// Validate a `--trailer`: a `Key: Value` line, with a key git accepts as a
+/// trailer token (letters, digits and `-`). Rejected at parse time.
+fn validate_trailer(value: &str) -> Result<String, String> {
+    let Some((key, val)) = value.split_once(':') else {
+        return Err(format!("trailer must be 'Key: Value' (got '{value}')"));
+    };
+    if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        return Err(format!(
+            "trailer key must be letters, digits and '-' (got '{key}')"
+        ));
+    }
+    if val.trim().is_empty() || value.contains('\n') {
+        return Err(format!(
+            "trailer must be a single 'Key: Value' line (got '{value}')"
+        ));
+    }
+    Ok(format!("{key}: {}", val.trim()))
+}
+
+/// Validate a `--reviewer`/`--assignee`: a GitHub login, or (for reviewers)
+/// an `org/team`. Rejected at parse time rather than by `gh` per repo.
+fn validate_pr_participant(value: &str) -> Result<String, String> {
+    let valid_name = |name: &str| {
+        !name.is_empty()
+            && !name.starts_with('-')
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+    };
+    let valid = match value.split_once('/') {
+        Some((org, team)) => valid_name(org) && valid_name(team),
+        None => valid_name(value),
+    };
+    if valid {
+        Ok(value.to_string())
+    } else {
+        Err(format!(
+            "expected a GitHub user or org/team (got '{value}')"
+        ))
+    }
+}
+
 static JOBS_HELP: LazyLock<String> = LazyLock::new(|| {
     format!(
         "Number of parallel operations [default: {}]",
@@ -88,14 +173,75 @@ pub struct Cli {
     )]
     pub log_level: LogLevel,
 
+    /// When to use colored output
+    #[arg(
+        long,
+        value_enum,
+        ignore_case = true,
+        default_value = "auto",
+        global = true,
+        help = "Colored output: auto (only on a terminal, and not when NO_COLOR is set)|always|never"
+    )]
+    pub color: ColorChoice,
+
     /// Path to config file
     #[arg(short, long, help = "Path to config file")]
     pub config: Option<PathBuf>,
 
+    /// Override config values for this run only
+    #[arg(
+        long = "set",
+        visible_alias = "config-override",
+        value_name = "KEY=VALUE",
+        global = true,
+        help = "Override a config value for this run (repeatable), e.g. --set repo-discovery.max-depth=5"
+    )]
+    pub config_overrides: Vec<String>,
+
     /// Enable verbose output
     #[arg(short, long, help = "Enable verbose output")]
     pub verbose: bool,
 
+    /// Suppress per-repo output; summaries and the exit code are unchanged
+    #[arg(
+        short,
+        long,
+        conflicts_with = "verbose",
+        help = "Only print summaries, not per-repo lines (for CI)"
+    )]
+    pub quiet: bool,
+
+    /// Live `[n/total]` counter on stderr for parallel commands
+    #[arg(
+        long,
+        global = true,
+        help = "Show a live [done/total] counter on stderr (clone, create, review; terminals only)"
+    )]
+    pub progress: bool,
+
+    /// Per-phase and per-repo timings on stderr once the run is done
+    #[arg(
+        long,
+        global = true,
+        help = "Print discovery/filtering times and the slowest repos on stderr (status, create, clone)"
+    )]
+    pub stats: bool,
+
+    /// Skip the startup git/gh minimum-version check
+    #[arg(
+        long,
+        global = true,
+        help = "Skip the startup check that git and gh meet their minimum versions"
+    )]
+    pub skip_tool_check: bool,
+
+    /// Exit 0 even when some repos fail; the summary still counts the errors
+    #[arg(
+        long,
+        help = "Exit 0 even when some repos fail (best-effort runs)"
+    )]
+    pub no_fail_on_error: bool,
+
     /// Override jobs
     #[arg(short = 'j', long = "jobs", value_name = "INT", help = JOBS_HELP.as_str())]
     pub parallel: Option<usize>,
@@ -104,6 +250,67 @@ pub struct Cli {
     #[arg(short = 'm', long = "depth", value_name = "INT", help = DEPTH_HELP.as_str())]
     pub max_depth: Option<usize>,
 
+    /// Retries for transient clone/GitHub failures
+    #[arg(
+        long = "retries",
+        value_name = "INT",
+        help = "Retry transient clone/GitHub failures INT times with backoff [default: 2]"
+    )]
+    pub retries: Option<u32>,
+
+    /// Wall-clock limit on each git/gh subprocess
+    #[arg(
+        long = "timeout",
+        value_name = "DURATION",
+        global = true,
+        help = "Kill any git/gh call still running after DURATION (e.g. 30s, 5m) and report that repo as failed [default: 300s]"
+    )]
+    pub timeout: Option<String>,
+
+    /// Cap on concurrent GitHub API (`gh`) calls
+    #[arg(
+        long = "api-concurrency",
+        value_name = "INT",
+        global = true,
+        help = "Run at most INT GitHub API calls at once, whatever --parallel is [default: 8]"
+    )]
+    pub api_concurrency: Option<usize>,
+
+    /// Stop repository discovery after N repos
+    #[arg(
+        long = "max-repos",
+        value_name = "INT",
+        help = "Stop discovery after INT repos (warns that results are truncated)"
+    )]
+    pub max_repos: Option<usize>,
+
+    /// Only operate on the repo slugs listed in a file
+    #[arg(
+        long = "repos-from-file",
+        value_name = "FILE",
+        global = true,
+        help = "Only operate on the repo slugs listed in FILE (one per line; # comments)"
+    )]
+    pub repos_from_file: Option<PathBuf>,
+
+    /// Don't scan from a repo's parent when started inside one
+    #[arg(
+        long = "no-walk-up",
+        global = true,
+        conflicts_with = "root",
+        help = "Scan strictly downward from the current dir (or --cwd), even inside a repo"
+    )]
+    pub no_walk_up: bool,
+
+    /// Scan for repos from exactly this directory
+    #[arg(
+        long = "root",
+        value_name = "PATH",
+        global = true,
+        help = "Discover repos under PATH, skipping the workspace-root search (relative to --cwd)"
+    )]
+    pub root: Option<PathBuf>,
+
     /// Override user/org for operations
     #[arg(
         long = "user-org",
@@ -111,6 +318,15 @@ pub struct Cli {
     )]
     pub user_org: Option<String>,
 
+    /// GitHub token for this run; beats every env var and `gh auth`
+    #[arg(
+        long = "token",
+        value_name = "TOKEN",
+        global = true,
+        help = "GitHub token for this run (prefer GX_GITHUB_TOKEN: flags are visible in ps)"
+    )]
+    pub token: Option<String>,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -122,17 +338,33 @@ pub enum Commands {
   📝  Modified files       ➕  Added files         ❌  Deleted files
   ❓  Untracked files      🎯  Staged files        🔄  Renamed files
   ✅  Clean repository     📁  Repository header   📊  Summary stats
+  🧩  Moved submodule pointer (--include-submodules-in-status)
 
 REMOTE STATUS:
   🟢  Up to date with remote    ↑N  Ahead by N commits
   ↓N  Behind by N commits       🔀  Diverged (ahead+behind)
   📍  No remote branch          🚨git Remote check error (git command failed)
+  🚫  No local branch named by --branch
 
 EXAMPLES:
   gx status                     # Show all repositories
   gx status --detailed          # Show file-by-file details
+  gx status --diff-stat         # Lines added/removed per repo (+N/-M)
+  gx status --only-errors       # Triage: just the repos that errored
+  gx status --group             # Summary lists which repos are behind, diverged, ...
+  gx status --ahead-behind-all  # Per-branch sync, to spot stale local branches
+  gx status --remote upstream   # Fork ahead/behind relative to upstream
+  gx status --branch release/1.0 --fetch-first  # How far behind is release/1.0?
+  gx status --fetch-first --fetch-jobs 32  # Wider fetch pool; status stays at -j
   gx status -p frontend -p api  # Filter by repo patterns
-  gx status --no-emoji          # Plain text for scripts")]
+  gx status --relative-paths    # Paths you can cd into, not org/repo slugs
+  gx status --no-emoji          # Plain text for scripts
+  gx status --untracked-files no  # Skip the untracked scan in repos with huge build dirs
+  gx status --include-submodules-in-status  # Tell moved submodule pointers from edits
+  gx status --porcelain | awk '$4 == \"dirty\" {print $1}'  # Stable columns for scripts
+  gx status --count-only --json   # {\"clean\":N,\"dirty\":M,\"errors\":K} for a dashboard
+  gx status --color always | less -R  # Keep colors through a pager
+  gx status --search 'org:tatari-tv language:rust'  # Local clones of search results")]
     Status {
         /// Show detailed file-by-file status
         #[arg(
@@ -146,8 +378,11 @@ EXAMPLES:
         #[arg(long, help = "Disable emoji in output")]
         no_emoji: bool,
 
-        /// Disable colored output
-        #[arg(long, help = "Disable colored output")]
+        /// Disable colored output (deprecated alias for `--color never`)
+        #[arg(
+            long,
+            help = "Disable colored output (deprecated: use --color never)"
+        )]
         no_color: bool,
 
         /// Repository name patterns to filter
@@ -162,16 +397,147 @@ EXAMPLES:
         #[arg(long, help = "Fetch latest remote refs before status check")]
         fetch_first: bool,
 
+        /// Size of the pre-status fetch pool
+        #[arg(
+            long,
+            value_name = "INT",
+            help = "Run the --fetch-first fetches INT at a time, apart from -j [default: min(jobs, 16)]"
+        )]
+        fetch_jobs: Option<usize>,
+
         /// Skip remote status checks entirely
         #[arg(long, help = "Skip remote status checks entirely")]
         no_remote: bool,
+
+        /// Restrict to local clones of the repos a GitHub search returns
+        #[arg(
+            long,
+            value_name = "QUERY",
+            help = "Only repos matching a GitHub search query (must include org:<owner> or user:<owner>)"
+        )]
+        search: Option<String>,
+
+        /// Don't say when discovery walked up from the working directory
+        #[arg(
+            long,
+            help = "Suppress the notice when scanning walked up from the working directory"
+        )]
+        no_root_notice: bool,
+
+        /// Show lines added/removed per repo
+        #[arg(
+            long,
+            help = "Show lines added/removed per repo (staged and unstaged; one extra git call per repo)"
+        )]
+        diff_stat: bool,
+
+        /// Only show repos whose status check failed
+        #[arg(
+            long,
+            help = "Only show repos that errored (the summary still counts every repo)"
+        )]
+        only_errors: bool,
+
+        /// Compare against this remote instead of the branch's upstream
+        #[arg(
+            long,
+            value_name = "NAME",
+            conflicts_with = "no_remote",
+            help = "Report ahead/behind against <NAME>/<branch> (e.g. upstream) instead of the configured upstream"
+        )]
+        remote: Option<String>,
+
+        /// Report ahead/behind for this branch instead of the checked-out one
+        #[arg(
+            long,
+            value_name = "NAME",
+            conflicts_with = "no_remote",
+            help = "Report ahead/behind for local branch <NAME> vs its remote, without switching (file changes still describe HEAD)"
+        )]
+        branch: Option<String>,
+
+        /// List repos under per-state headers before the summary
+        #[arg(
+            long,
+            help = "Also list repo slugs grouped by state (Errors, Diverged, Behind, Ahead, Dirty, ...) before the summary"
+        )]
+        group: bool,
+
+        /// Ahead/behind for every local branch, listed under each repo
+        #[arg(
+            long,
+            conflicts_with = "no_remote",
+            help = "List every local branch's ahead/behind vs its upstream under each repo (slower: one git call per branch)"
+        )]
+        ahead_behind_all: bool,
+
+        /// Stable tab-separated lines for scripts
+        #[arg(
+            long,
+            conflicts_with_all = ["detailed", "diff_stat", "group", "ahead_behind_all", "only_errors"],
+            help = "One stable tab-separated line per repo: slug, branch, sha, clean|dirty|error, modified, added, deleted, untracked, staged, ahead, behind"
+        )]
+        porcelain: bool,
+
+        /// How untracked files are scanned and counted
+        #[arg(
+            long,
+            value_name = "all|normal|no",
+            default_value = "normal",
+            help = "Untracked-file scan, as git's -u: all (every file), normal (an untracked dir counts once), no (skip the scan; faster, never shows ❓)"
+        )]
+        untracked_files: local::git::UntrackedFiles,
+
+        /// Count moved submodule pointers apart from modified files
+        #[arg(
+            long,
+            help = "Count submodules whose pointer moved separately from modified files (🧩; one extra git call per repo)"
+        )]
+        include_submodules_in_status: bool,
+
+        /// Print only the clean/dirty/error counts
+        #[arg(
+            long,
+            conflicts_with_all = ["detailed", "diff_stat", "group", "ahead_behind_all", "only_errors", "porcelain"],
+            help = "Print only the clean/dirty/error summary, no per-repo lines (for dashboards)"
+        )]
+        count_only: bool,
+
+        /// With --count-only, print the counts as JSON
+        #[arg(
+            long,
+            requires = "count_only",
+            help = "With --count-only, print just {\"clean\":N,\"dirty\":M,\"errors\":K}"
+        )]
+        json: bool,
+
+        /// Name repos by path relative to the discovery root instead of slug
+        #[arg(
+            long,
+            conflicts_with = "absolute_paths",
+            help = "Show each repo's path relative to the discovery root instead of its slug"
+        )]
+        relative_paths: bool,
+
+        /// Name repos by absolute path instead of slug
+        #[arg(
+            long,
+            help = "Show each repo's absolute path instead of its slug"
+        )]
+        absolute_paths: bool,
     },
 
     /// Checkout branches across multiple repositories
     #[command(after_help = "CHECKOUT LEGEND:
-  🔄  Checked out and synced with remote    ✨  Created new branch from remote
-  📦  Stashed uncommitted changes           ❌  Checkout failed (error)
-  🚨  Has untracked files                  📊  Summary stats
+  🔁  Switched branch (local only)          📥  Switched and pulled (--pull)
+  ✨  Created new branch from remote        ❌  Checkout failed (error)
+  📦  Stashed uncommitted changes           🚨  Has untracked files
+  🌳  Added worktree (--worktree)           ⏩  Fast-forwarded (--sync-only)
+  📌  Detached HEAD at a tag/SHA (--detach) 📊  Summary stats
+  ⏭️  Skipped (rebase/merge/... in progress)
+
+  Checkout no longer pulls by default: switching to an existing branch is a
+  local operation. Pass --pull to also fast-forward it from its upstream.
 
 EXAMPLES:
   gx checkout                       # Checkout default branch in all repos
@@ -182,7 +548,11 @@ EXAMPLES:
   gx checkout -b new-feature        # Create and checkout new branch in all repos
   gx checkout -b fix -f main        # Create branch from specific base branch
   gx checkout main -s               # Checkout main and stash uncommitted changes
-  gx checkout main -p frontend -p api  # Checkout main in repos matching 'frontend' or 'api'")]
+  gx checkout main -p frontend -p api  # Checkout main in repos matching 'frontend' or 'api'
+  gx checkout pr-branch --worktree ../review  # Check out into ../review/<repo>, HEAD untouched
+  gx checkout release --submodules  # Checkout release and update its submodules
+  gx checkout --sync-only           # Fast-forward whatever branch each repo is on
+  gx checkout --detach v1.4.0       # Detach HEAD at tag v1.4.0 in every repo, no branch")]
     Checkout {
         /// Create a new branch
         #[arg(
@@ -209,6 +579,46 @@ EXAMPLES:
         )]
         stash: bool,
 
+        /// Fast-forward pull an existing branch after switching to it
+        #[arg(
+            long,
+            conflicts_with_all = ["create_branch", "worktree"],
+            help = "After switching, fast-forward pull the branch from its upstream (network)"
+        )]
+        pull: bool,
+
+        /// Check out into a linked worktree at <DIR>/<repo> instead of switching HEAD
+        #[arg(
+            long = "worktree",
+            value_name = "DIR",
+            conflicts_with_all = ["create_branch", "stash"],
+            help = "Check out into a linked worktree at <DIR>/<repo>; the primary checkout is untouched"
+        )]
+        worktree: Option<PathBuf>,
+
+        /// Update submodules after a successful checkout
+        #[arg(
+            long,
+            help = "Run `git submodule update --init --recursive` after a successful checkout"
+        )]
+        submodules: bool,
+
+        /// Detach HEAD at a tag or commit instead of switching branches
+        #[arg(
+            long,
+            conflicts_with_all = ["create_branch", "from_branch", "pull", "worktree"],
+            help = "Treat the positional as a tag or SHA and detach HEAD at it (git checkout --detach); no branch, no pull"
+        )]
+        detach: bool,
+
+        /// Fast-forward each repo's current branch instead of switching
+        #[arg(
+            long,
+            conflicts_with_all = ["create_branch", "from_branch", "stash", "pull", "worktree", "submodules", "detach", "branch_name"],
+            help = "Fast-forward pull the currently checked-out branch in each repo; no branch switch (takes no BRANCH)"
+        )]
+        sync_only: bool,
+
         /// Repository name patterns to filter
         #[arg(
             short = 'p',
@@ -229,6 +639,7 @@ EXAMPLES:
   📍  Checked out default branch          🚨  Clone/update failed
   🏠  Directory exists but not git repo   🔗  Different remote URL detected
   📦  Stashed uncommitted changes         📊  Summary stats
+  🪞  Mirrored / mirror updated (--mirror) ⏭️  Already cloned (--skip-existing)
 
 WORKING DIRECTORY:
   By default, repositories are cloned to the current working directory under <user|org>/<repo-name>/
@@ -237,16 +648,115 @@ WORKING DIRECTORY:
 EXAMPLES:
   gx clone scottidler                     # Clone to ./scottidler/<repo-name>/
   gx clone tatari-tv -p frontend -p api   # Clone filtered repos to ./tatari-tv/<repo-name>/
-  gx --cwd /workspace clone tatari-tv     # Clone to /workspace/tatari-tv/<repo-name>/")]
+  gx --cwd /workspace clone tatari-tv     # Clone to /workspace/tatari-tv/<repo-name>/
+  gx clone --search 'org:tatari-tv topic:service'  # Clone every repo matching a GitHub search
+  gx clone tatari-tv --archived-only      # Back up only the archived repos
+  gx clone my-user --add-upstream tatari-tv  # Clone forks, add `upstream` at the parent
+  gx clone tatari-tv --submodules         # Clone with submodules initialized
+  gx clone tatari-tv --write-manifest gx-manifest.yml  # Record slug/path/default branch per repo
+  gx clone bigorg --limit 50              # Only the first 50 repos GitHub lists
+  gx clone tatari-tv --since 30d          # Only repos pushed to in the last 30 days
+  gx clone tatari-tv --mirror             # Bare mirror backups in ./tatari-tv/<repo>.git
+  gx clone tatari-tv --layout '{repo}'    # Flat: ./<repo> instead of ./tatari-tv/<repo>
+  gx clone tatari-tv --protocol https     # HTTPS URLs, no SSH key needed
+  gx clone tatari-tv --skip-existing      # Resume: only clone what is missing")]
     Clone {
         /// GitHub user or organization name
-        #[arg(value_name = "USER|ORG")]
-        user_or_org: String,
+        #[arg(value_name = "USER|ORG", required_unless_present = "search")]
+        user_or_org: Option<String>,
+
+        /// Use a GitHub repo search to define the repo set
+        #[arg(
+            long,
+            value_name = "QUERY",
+            conflicts_with = "user_or_org",
+            help = "GitHub search query defining the repos (must include org:<owner> or user:<owner>)"
+        )]
+        search: Option<String>,
 
         /// Include archived repositories
         #[arg(long, help = "Include archived repositories")]
         include_archived: bool,
 
+        /// Clone only archived repositories
+        #[arg(
+            long,
+            conflicts_with = "include_archived",
+            help = "Clone only archived repositories (for archival/backup)"
+        )]
+        archived_only: bool,
+
+        /// Add an `upstream` remote at <OWNER>/<repo> to each fresh clone
+        #[arg(
+            long,
+            value_name = "OWNER",
+            help = "After cloning (e.g. your forks), add an `upstream` remote pointing at OWNER/<repo>"
+        )]
+        add_upstream: Option<String>,
+
+        /// Initialize submodules after cloning or updating
+        #[arg(
+            long,
+            help = "Initialize and update submodules (recursively) after each clone or update"
+        )]
+        submodules: bool,
+
+        /// Bare mirror clones (backups): no working tree
+        #[arg(
+            long,
+            conflicts_with_all = ["add_upstream", "submodules", "write_manifest"],
+            help = "Make bare mirror clones into <owner>/<repo>.git (git clone --mirror); existing mirrors get git remote update"
+        )]
+        mirror: bool,
+
+        /// Record the cloned repos in a workspace manifest
+        #[arg(
+            long,
+            value_name = "FILE",
+            help = "Record each cloned repo (slug, path, default branch) in a YAML manifest, updating it in place"
+        )]
+        write_manifest: Option<PathBuf>,
+
+        /// Cap the GitHub listing at the first N repos
+        #[arg(
+            long,
+            value_name = "N",
+            help = "Only consider the first N repos GitHub lists (default: all of them)"
+        )]
+        limit: Option<usize>,
+
+        /// Only clone repos pushed to within this duration
+        #[arg(
+            long,
+            value_name = "DURATION",
+            help = "Only clone repos pushed to within DURATION (e.g. 30d, 2w); never-pushed repos are skipped"
+        )]
+        since: Option<String>,
+
+        /// Target directory template for each clone
+        #[arg(
+            long,
+            value_name = "TEMPLATE",
+            default_value = crate::clone::DEFAULT_LAYOUT,
+            help = "Where each repo clones to: {user}, {org} and {repo} placeholders, e.g. '{repo}' (flat) or 'repos/{org}/{repo}'"
+        )]
+        layout: String,
+
+        /// URL scheme for new clones
+        #[arg(
+            long,
+            value_name = "ssh|https",
+            help = "Clone over ssh (git@github.com:) or https (https://github.com/, via the git credential helper); default: clone.protocol in config, else ssh"
+        )]
+        protocol: Option<local::config::CloneProtocol>,
+
+        /// Leave repos that are already cloned untouched
+        #[arg(
+            long,
+            help = "Skip repos already cloned with the right remote (no fetch or pull), so re-running an interrupted clone only does the rest"
+        )]
+        skip_existing: bool,
+
         /// Repository name patterns to filter
         #[arg(
             short = 'p',
@@ -262,57 +772,297 @@ EXAMPLES:
   🔄  Branch created        📥  PR created          📊  Summary stats
   👀  Dry run (would change)  ➖  Dry run (no change)
   💾  Changes committed        ❌  Error occurred
+  ⏭️  Skipped (rebase/merge/... in progress)
 
 EXAMPLES:
   gx create --files '*.json'                                    # Show matching files (dry-run)
   gx create --files '*.json' -p frontend                        # Show matches in frontend repos only
+  gx create --files '**/*.json' --no-gitignore                  # Include tracked-but-ignored files
   gx create --files '*.json' add config.json '{\"debug\": true}' # Create files (dry-run)
+  gx create --files '*.json' add .ci.yml @ci.yml.tmpl            # Content from a file (- reads stdin)
+  gx create --commit 'Ignore .env' append --if-missing .gitignore .env  # Append once, idempotently
+  gx create --files package.json --commit 'Build' set-key scripts.build 'tsc -b'  # Structured edit
+  gx create --files '*.md' --interpolate add CODEOWNERS '* @{org}/{repo}-owners'  # Per-repo content
   gx create --files '*.md' --commit 'Update docs' sub 'old-text' 'new-text'
+  gx create --files '*.md' --commit 'Rename' sub --ignore-case --whole-word 'foo' 'bar'
   gx create --files 'package.json' --commit 'Bump version' regex '\"version\": \"[^\"]+\"' '\"version\": \"1.2.3\"'
+  gx create --files '*.txt' delete --detailed                   # Preview what delete would remove
+  gx create --files '*.md' --json sub 'old' 'new' | jq .        # Dry-run results as JSON
   gx create --files '*.txt' --commit 'Remove old files' --pr delete
-  gx create --files '*.md' --commit 'Draft update' --pr --draft sub 'old' 'new'")]
+  gx create --files '*.md' --commit 'Draft update' --pr --draft sub 'old' 'new'
+  gx create --files '*.md' --commit 'Docs' --pr --pr-body-file body.md sub 'old' 'new'  # {change_id} {repo} {files}
+  gx create --files '*.md' --commit 'Docs' --pr --base develop sub 'old' 'new'  # PRs against develop
+  gx create --files '*.md' --commit 'Docs' --pr --reviewer tatari-tv/platform --assignee alice sub 'old' 'new'
+  gx create --files 'go.mod' --commit 'Bump deps' --pr --label dependencies --create-labels sub 'v1.2.0' 'v1.3.0'
+  gx create --files '*.md' --commit 'Signed update' --sign sub 'old' 'new'
+  gx create --files '*.md' --commit 'Fix typo' --trailer 'Refs: OPS-42' --change-id-trailer sub 'teh' 'the'
+  gx create --files '*' --commit 'Rename' --limit-files 50 sub 'old' 'new'  # Fail repos touching >50 files
+  gx create --files '*.go' --commit 'Rename' --parallel-files 16 sub 'old' 'new'  # 16 files at a time per repo
+  gx create --files '*.rs' --commit 'Rename' --format-cmd 'rustfmt --edition 2021' sub 'old_name' 'new_name'
+  gx create --files 'VERSION' --commit 'Release 1.2.3' --tag v1.2.3 sub '1.2.2' '1.2.3'  # Tag + push each commit
+  gx create --files '*.md' --commit 'Docs' --pr --plan-out plan.json sub 'old' 'new'  # Plan for approval
+  gx create --plan-in plan.json                                 # Execute the approved plan")]
     Create {
         /// Files to target (glob patterns)
         #[arg(short = 'f', long = "files", help = "File patterns to match")]
         files: Vec<String>,
 
-        /// Change ID for branch and PR naming
+        /// Change ID for branch and PR naming
+        #[arg(
+            short = 'x',
+            long = "change-id",
+            help = "Change ID for branch/PR (auto-generated if not provided)",
+            value_parser = validate_change_id
+        )]
+        change_id: Option<String>,
+
+        /// Repository patterns to filter
+        #[arg(
+            short = 'p',
+            long = "patterns",
+            help = "Repository patterns to filter"
+        )]
+        patterns: Vec<String>,
+
+        /// Commit changes with message
+        #[arg(
+            short = 'c',
+            long = "commit",
+            help = "Commit changes with message"
+        )]
+        commit: Option<String>,
+
+        /// Create PR after committing (use --pr --draft for draft mode)
+        #[arg(long, help = "Create pull request after committing")]
+        pr: bool,
+
+        /// Make the PR a draft; requires --pr (a bare --draft is a clap error,
+        /// not a silent no-op)
+        #[arg(
+            long,
+            help = "Make the PR a draft (requires --pr)",
+            requires = "pr"
+        )]
+        draft: bool,
+
+        /// PR body template in place of the configured one
+        #[arg(
+            long,
+            value_name = "TEXT",
+            requires = "pr",
+            conflicts_with = "pr_body_file",
+            help = "PR body; {change_id}, {repo}, {files} and {commit_message} are interpolated"
+        )]
+        pr_body: Option<String>,
+
+        /// Read the PR body template from a file
+        #[arg(
+            long,
+            value_name = "PATH",
+            requires = "pr",
+            help = "Read the PR body from PATH (same placeholders as --pr-body)"
+        )]
+        pr_body_file: Option<PathBuf>,
+
+        /// Use each repo's own `.github/PULL_REQUEST_TEMPLATE.md` as the body
+        #[arg(
+            long,
+            requires = "pr",
+            help = "Use the repo's .github/PULL_REQUEST_TEMPLATE.md as the PR body when it has one"
+        )]
+        pr_use_template: bool,
+
+        /// Branch every PR targets instead of the repo's default branch
+        #[arg(
+            long,
+            value_name = "BRANCH",
+            requires = "pr",
+            help = "Open each PR against BRANCH (must exist on origin); {default} is each repo's default branch"
+        )]
+        base: Option<String>,
+
+        /// Request a review on every PR from a user or an `org/team`
+        #[arg(
+            long = "reviewer",
+            value_name = "USER|ORG/TEAM",
+            requires = "pr",
+            value_parser = validate_pr_participant,
+            help = "Request a review from USER or ORG/TEAM on each PR (repeatable)"
+        )]
+        reviewers: Vec<String>,
+
+        /// Assign every PR to a user
+        #[arg(
+            long = "assignee",
+            value_name = "USER",
+            requires = "pr",
+            value_parser = validate_pr_participant,
+            help = "Assign each PR to USER (repeatable)"
+        )]
+        assignees: Vec<String>,
+
+        /// Label every PR (e.g. `automated`, `dependencies`)
+        #[arg(
+            long = "label",
+            value_name = "NAME",
+            requires = "pr",
+            help = "Add label NAME to each PR (repeatable)"
+        )]
+        labels: Vec<String>,
+
+        /// Create a --label a repo does not have yet instead of dropping it
+        #[arg(
+            long,
+            requires = "labels",
+            help = "Create any --label missing from a repo (gh label create) before opening its PR"
+        )]
+        create_labels: bool,
+
+        /// Sign the commit (`git commit -S`); a signing failure rolls the repo
+        /// back rather than producing an unsigned commit
+        #[arg(
+            long,
+            help = "Sign the commit (git commit -S)",
+            requires = "commit"
+        )]
+        sign: bool,
+
+        /// Signing key for this run only (`-c user.signingkey=<KEYID>`)
+        #[arg(
+            long,
+            value_name = "KEYID",
+            help = "Key to sign with (requires --sign)",
+            requires = "sign"
+        )]
+        sign_key: Option<String>,
+
+        /// Bypass the commit and pre-push hooks (`--no-verify` on the
+        /// commit and the push); opt-in, and warned about when used
+        #[arg(
+            long,
+            requires = "commit",
+            help = "Skip the repos' pre-commit/commit-msg/pre-push hooks (git commit/push --no-verify)"
+        )]
+        no_verify: bool,
+
+        /// `Key: Value` trailers appended to the commit message, in order
+        #[arg(
+            long = "trailer",
+            value_name = "KEY: VALUE",
+            requires = "commit",
+            value_parser = validate_trailer,
+            help = "Append a 'Key: Value' trailer to the commit message (repeatable)"
+        )]
+        trailers: Vec<String>,
+
+        /// Append `Change-Id: <change-id>` after any --trailer
+        #[arg(
+            long,
+            requires = "commit",
+            help = "Append a 'Change-Id: <change-id>' trailer to the commit message"
+        )]
+        change_id_trailer: bool,
+
+        /// Make every written/added file end with exactly one newline
+        #[arg(
+            long,
+            help = "Ensure each written or added file ends with exactly one newline"
+        )]
+        ensure_final_newline: bool,
+
+        /// Formatter run on the changed files before committing; a failure
+        /// rolls the repo back
+        #[arg(
+            long,
+            value_name = "CMD",
+            requires = "commit",
+            help = "Run CMD on each repo's changed files (passed as arguments) before committing"
+        )]
+        format_cmd: Option<String>,
+
+        /// Annotated tag for each repo's commit, pushed after the branch
+        #[arg(
+            long,
+            value_name = "NAME",
+            requires = "commit",
+            help = "Tag each repo's commit (git tag -a) and push the tag; {change_id} is interpolated"
+        )]
+        tag: Option<String>,
+
+        /// Message for the --tag annotation (defaults to the commit message)
+        #[arg(
+            long,
+            value_name = "MSG",
+            requires = "tag",
+            help = "Message for the --tag annotation [Default: the commit message]"
+        )]
+        tag_message: Option<String>,
+
+        /// Fail a repo whose sub/regex would leave a file empty
+        #[arg(
+            long,
+            help = "Refuse (per repo) a sub/regex change that would leave any file empty or whitespace-only"
+        )]
+        no_empty_files: bool,
+
+        /// Also match tracked files the repo's .gitignore covers (vendored
+        /// trees, force-added output); they are skipped by default
         #[arg(
-            short = 'x',
-            long = "change-id",
-            help = "Change ID for branch/PR (auto-generated if not provided)",
-            value_parser = validate_change_id
+            long,
+            help = "Include tracked files matched by the repo's .gitignore (skipped by default)"
         )]
-        change_id: Option<String>,
+        no_gitignore: bool,
 
-        /// Repository patterns to filter
+        /// Fail a repo whose sub/regex would modify more than N files
         #[arg(
-            short = 'p',
-            long = "patterns",
-            help = "Repository patterns to filter"
+            long,
+            value_name = "N",
+            help = "Refuse (per repo) a sub/regex change that would modify more than N files [Default: no limit]"
         )]
-        patterns: Vec<String>,
+        limit_files: Option<usize>,
 
-        /// Commit changes with message
+        /// Files one repo's sub/regex/set-key works on at once
         #[arg(
-            short = 'c',
-            long = "commit",
-            help = "Commit changes with message"
+            long,
+            value_name = "INT",
+            help = "Work on INT of a repo's matched files at once, apart from -j repos [default: share the -j pool; 1 = serial]"
         )]
-        commit: Option<String>,
+        parallel_files: Option<usize>,
 
-        /// Create PR after committing (use --pr --draft for draft mode)
-        #[arg(long, help = "Create pull request after committing")]
-        pr: bool,
+        /// Commit onto each repo's current branch instead of a new change-id
+        /// branch; no PR, and a repo on its default branch is refused
+        #[arg(
+            long,
+            requires = "commit",
+            conflicts_with = "pr",
+            help = "Commit directly to each repo's current branch (refused on the default branch; no PR)"
+        )]
+        target_existing_branch: bool,
 
-        /// Make the PR a draft; requires --pr (a bare --draft is a clap error,
-        /// not a silent no-op)
+        /// Expand {repo}, {slug} and {org} in `add` content per repo
         #[arg(
             long,
-            help = "Make the PR a draft (requires --pr)",
-            requires = "pr"
+            help = "Expand {repo}, {slug} and {org} in add content for each repo (braces are literal without it)"
         )]
-        draft: bool,
+        interpolate: bool,
+
+        /// Dry run: print each repo's diff beneath its files (a delete shows
+        /// the content that would be removed)
+        #[arg(
+            long,
+            conflicts_with = "commit",
+            help = "In a dry run, show each file's diff (for delete, the content that would be removed)"
+        )]
+        detailed: bool,
+
+        /// Print the per-repo results as a JSON array on stdout instead of
+        /// the human display and pattern analysis
+        #[arg(
+            long,
+            conflicts_with = "plan_out",
+            help = "Print the results as a JSON array instead of the human output"
+        )]
+        json: bool,
 
         /// Skip the confirmation prompt before committing (for automation)
         #[arg(
@@ -331,6 +1081,56 @@ EXAMPLES:
         )]
         report: Option<PathBuf>,
 
+        /// Write the full plan (repos, branches, files, commit, PR) as JSON
+        /// and stop; nothing is changed
+        #[arg(
+            long = "plan-out",
+            value_name = "PATH",
+            conflicts_with = "plan_in",
+            help = "Write the run's plan as JSON to PATH and exit without changing anything"
+        )]
+        plan_out: Option<PathBuf>,
+
+        /// Execute a plan written by --plan-out; it carries every other input
+        #[arg(
+            long = "plan-in",
+            value_name = "PATH",
+            conflicts_with_all = [
+                "files",
+                "change_id",
+                "patterns",
+                "commit",
+                "pr",
+                "draft",
+                "pr_body",
+                "pr_body_file",
+                "pr_use_template",
+                "base",
+                "reviewers",
+                "assignees",
+                "labels",
+                "create_labels",
+                "sign",
+                "sign_key",
+                "no_verify",
+                "trailers",
+                "change_id_trailer",
+                "ensure_final_newline",
+                "format_cmd",
+                "tag",
+                "tag_message",
+                "no_empty_files",
+                "no_gitignore",
+                "limit_files",
+                "parallel_files",
+                "target_existing_branch",
+                "interpolate",
+                "detailed",
+            ],
+            help = "Execute a previously written plan (refused if the repos drifted from it)"
+        )]
+        plan_in: Option<PathBuf>,
+
         #[command(subcommand)]
         action: Option<CreateAction>,
     },
@@ -373,15 +1173,22 @@ EXAMPLES:
     #[command(after_help = "REVIEW LEGEND:
   📋  PR listed             📥  Repository cloned   ✅  PR approved
   ❌  PR deleted            🧹  Repository purged   📊  Summary stats
+  🔓  PR reopened
 
 EXAMPLES:
   gx review ls GX-2024-01-15                    # List PRs (auto-detect org)
   gx review ls --org tatari-tv GX-2024-01-15    # List PRs for specific org
+  gx review ls --json GX-2024-01-15 | jq .      # List PRs as a JSON array
+  gx review ls --state all --author octocat     # Open, closed and merged PRs by one author
   gx review clone GX-2024-01-15                 # Clone repos with PRs (auto-detect)
   gx review approve GX-2024-01-15 --admin       # Approve and merge PRs (auto-detect)
   gx review delete GX-2024-01-15                # Delete PRs and branches (auto-detect)
+  gx review reopen GX-2024-01-15                # Reopen PRs closed by a delete
   gx review sync GX-2024-01-15                  # True-up state against GitHub (merged/closed)
-  gx review purge --org tatari-tv                # Clean up GX branches (explicit org)")]
+  gx review status GX-2024-01-15                # Per-repo PR state and \"3/5 merged\" progress
+  gx review purge --org tatari-tv                # Clean up GX branches (explicit org)
+  gx review purge --merged-only                  # Delete only branches whose PR merged
+  gx review purge --older-than 30d               # Only branches untouched for 30 days")]
     Review {
         /// GitHub organization (auto-detected if not specified)
         #[arg(
@@ -504,9 +1311,127 @@ EXAMPLES:
         yes: bool,
     },
 
-    /// Check required tools and report orphaned gx artifacts
-    #[command(after_help = "EXAMPLES:
-  gx doctor            # Check git/gh versions and list orphaned artifacts
+    /// Delete stale gx branches from local clones
+    #[command(after_help = "PRUNE:
+  Deletes local branches matching --prefix (default GX-) that are merged into
+  the default branch or whose upstream is gone. The checked-out branch is
+  never pruned. `gx review purge` is the remote-branch counterpart.
+
+EXAMPLES:
+  gx prune --dry-run                 # List what would be deleted
+  gx prune                           # Delete merged or upstream-gone GX-* branches
+  gx prune --merged-only             # Only branches fully merged into the default branch
+  gx prune --prefix fix/ -p frontend # Another prefix, in matching repos only")]
+    Prune {
+        /// Branch name prefix to prune
+        #[arg(
+            long,
+            default_value = "GX-",
+            help = "Only prune local branches starting with this prefix"
+        )]
+        prefix: String,
+
+        /// Repository name patterns to filter
+        #[arg(
+            short = 'p',
+            long = "patterns",
+            help = "Repository name patterns to filter"
+        )]
+        patterns: Vec<String>,
+
+        /// Only prune branches fully merged into the default branch
+        #[arg(
+            long,
+            help = "Only prune branches merged into the default branch (ignore upstream-gone)"
+        )]
+        merged_only: bool,
+
+        /// List the branches that would be pruned without deleting them
+        #[arg(
+            long,
+            help = "List branches that would be pruned without deleting"
+        )]
+        dry_run: bool,
+
+        /// Skip the confirmation prompt before deleting local branches
+        #[arg(
+            short = 'y',
+            long = "yes",
+            help = "Skip the confirmation prompt before deleting local branches"
+        )]
+        yes: bool,
+    },
+
+    /// Run a command in every repo
+    #[command(after_help = "EXEC:
+  Runs the command in each matching repo, in parallel, with the repo as its
+  working directory. The command is run directly, not through a shell; use
+  `sh -c '...'` for pipes or globs. Put `--` before the command so its own
+  flags aren't parsed as gx's. Exit status is the number of failed repos.
+
+LEGEND:
+  ✅ Command exited 0     ❌ Non-zero exit or failed to run
+  ⏭️  Skipped after an earlier failure (--fail-fast)
+
+EXAMPLES:
+  gx exec -- make test               # Run the tests in every repo
+  gx exec -p frontend -- npm ci      # Only in repos matching 'frontend'
+  gx exec --fail-fast -- cargo check # Stop starting new repos after a failure
+  gx exec -- sh -c 'git log -1 --oneline | cat'")]
+    Exec {
+        /// Stop starting new repos once one fails
+        #[arg(
+            long,
+            help = "Skip the remaining repos once one fails (in-flight repos finish)"
+        )]
+        fail_fast: bool,
+
+        /// Repository name patterns to filter
+        #[arg(
+            short = 'p',
+            long = "patterns",
+            help = "Repository name patterns to filter"
+        )]
+        patterns: Vec<String>,
+
+        /// The command and its arguments
+        #[arg(
+            required = true,
+            num_args = 1..,
+            trailing_var_arg = true,
+            allow_hyphen_values = true,
+            help = "Command to run in each repo, with its arguments"
+        )]
+        command: Vec<String>,
+    },
+
+    /// Re-run the last status/checkout/clone/create on just the repos that failed
+    #[command(after_help = "RETRY:
+  Every status, checkout, clone and create run records its arguments and the
+  repos that failed. `gx retry` re-runs that command, from the same directory,
+  restricted to the failed repos with --repos-from-file.
+
+EXAMPLES:
+  gx checkout release          # 3 repos fail on a flaky network
+  gx retry                     # Same checkout, only those 3 repos
+  gx retry --dry-run           # Print the command and repos without running")]
+    Retry {
+        /// Print the command that would be re-run without running it
+        #[arg(
+            long,
+            help = "Print the command and failed repos without re-running"
+        )]
+        dry_run: bool,
+    },
+
+    /// Check required tools and the environment, and report orphaned gx artifacts
+    #[command(after_help = "CHECKS:
+  Tools (git, gh, rg), GitHub SSH, gh auth status, the config file, and
+  that the log/state/recovery dirs are writable. Exits non-zero if git or
+  gh is missing or too old, or any environment check shows ❌.
+
+EXAMPLES:
+  gx doctor            # Check tools and environment, list orphaned artifacts
   gx doctor --purge    # Also remove orphaned recovery/backup artifacts (via rkvr)")]
     Doctor {
         /// Remove orphaned recovery/backup artifacts (via rkvr, not rm)
@@ -517,6 +1442,18 @@ EXAMPLES:
         purge: bool,
     },
 
+    /// Print or check the configuration gx runs with
+    #[command(after_help = "EXAMPLES:
+  gx config show                # Effective config as YAML, defaults filled in
+  gx config show --json | jq .  # Same, as JSON
+  gx config path                # Which config file is loaded
+  gx config validate            # Check the config; exits non-zero on problems
+  gx --config ./gx.yml config validate  # Check a file before installing it")]
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+
     /// Build the local intel catalog: index every repo under `catalog.root`
     /// into the SQLite catalog. LOCAL only (no network); `--fetch` (a network
     /// refresh) is not yet implemented (Phase 4).
@@ -544,12 +1481,49 @@ EXAMPLES:
     Mcp(mcp_io::McpCmd),
 }
 
+#[derive(Debug, Subcommand)]
+pub enum ConfigAction {
+    /// Print the fully resolved config, defaults included
+    Show {
+        #[arg(long, help = "Print JSON instead of YAML")]
+        json: bool,
+    },
+    /// Print the path of the config file in use
+    Path,
+    /// Check the config for unknown keys and invalid values
+    Validate,
+}
+
 #[derive(Debug, Subcommand)]
 pub enum ReviewAction {
     /// List PRs by change ID
     Ls {
         #[arg(help = "Change ID patterns to match")]
         change_ids: Vec<String>,
+        #[arg(
+            long,
+            help = "Print the PRs as a JSON array instead of the human listing"
+        )]
+        json: bool,
+        #[arg(
+            long,
+            value_name = "N",
+            help = "List at most N PRs (default: every page GitHub returns)"
+        )]
+        limit: Option<usize>,
+        #[arg(
+            long,
+            value_enum,
+            default_value_t = PrStateFilter::Open,
+            help = "Only list PRs in this state (closed includes merged)"
+        )]
+        state: PrStateFilter,
+        #[arg(
+            long,
+            value_name = "LOGIN",
+            help = "Only list PRs opened by this GitHub login"
+        )]
+        author: Option<String>,
     },
     /// Clone repositories with PRs
     Clone {
@@ -587,6 +1561,16 @@ pub enum ReviewAction {
         )]
         yes: bool,
     },
+    /// Reopen closed (unmerged) PRs, re-pushing deleted branches from local clones
+    Reopen {
+        #[arg(help = "Change ID to reopen")]
+        change_id: String,
+    },
+    /// Show per-repo PR state and aggregate merge progress for a change
+    Status {
+        #[arg(help = "Change ID to report on")]
+        change_id: String,
+    },
     /// True-up recorded change state against GitHub PR reality (merged/closed)
     Sync {
         #[arg(help = "Change ID to sync")]
@@ -601,6 +1585,21 @@ pub enum ReviewAction {
             help = "Skip the confirmation prompt before purging"
         )]
         yes: bool,
+
+        /// Only delete branches whose PR has merged
+        #[arg(
+            long = "merged-only",
+            help = "Only delete GX- branches whose PR has merged (routine post-rollout cleanup)"
+        )]
+        merged_only: bool,
+
+        /// Only delete branches whose last commit is older than this
+        #[arg(
+            long,
+            value_name = "DURATION",
+            help = "Only delete branches whose last commit is older than DURATION (e.g. 30d, 2w, 12h)"
+        )]
+        older_than: Option<String>,
     },
 }
 
@@ -632,7 +1631,7 @@ pub enum RollbackAction {
         transaction_id: Option<String>,
         #[arg(
             long,
-            help = "Clean up states older than specified duration (e.g., 7d, 24h)"
+            help = "Clean up states older than specified duration (e.g., 7d, 24h, 2w)"
         )]
         older_than: Option<String>,
     },
@@ -644,8 +1643,24 @@ pub enum CreateAction {
     Add {
         #[arg(help = "File path to create")]
         path: String,
-        #[arg(help = "File content")]
+        #[arg(
+            help = "File content: inline text, @<file> to read it from a file, or - to read stdin (@@ for a literal leading @)"
+        )]
+        content: String,
+    },
+    /// Append to a file, creating it if absent
+    Append {
+        #[arg(help = "File path to append to (created if absent)")]
+        path: String,
+        #[arg(
+            help = "Content to append: inline text, @<file> to read it from a file, or - to read stdin (@@ for a literal leading @)"
+        )]
         content: String,
+        #[arg(
+            long,
+            help = "Leave a file alone when it already contains the content (as whole lines)"
+        )]
+        if_missing: bool,
     },
     /// Delete matching files
     Delete,
@@ -655,6 +1670,13 @@ pub enum CreateAction {
         pattern: String,
         #[arg(help = "Replacement text")]
         replacement: String,
+        #[arg(long, help = "Match the pattern regardless of case")]
+        ignore_case: bool,
+        #[arg(
+            long,
+            help = "Only match the pattern as a whole word (not inside a longer word)"
+        )]
+        whole_word: bool,
     },
     /// Regex substitution
     Regex {
@@ -663,6 +1685,18 @@ pub enum CreateAction {
         #[arg(help = "Replacement text")]
         replacement: String,
     },
+    /// Set a key in matched JSON/YAML files
+    SetKey {
+        #[arg(help = "Dotted key path, e.g. scripts.build (a number indexes a list)")]
+        key_path: String,
+        #[arg(help = "New value: a JSON literal (true, 3, \"1.2\", {...}), else a plain string")]
+        value: String,
+        #[arg(
+            long,
+            help = "Add the key, and any objects above it, where it is missing"
+        )]
+        create: bool,
+    },
     /// Run an agent per repo in an isolated worktree and propose the diff
     #[command(
         after_help = "One generation per repo per propose; re-propose to retry.
@@ -684,6 +1718,29 @@ EXAMPLES:
         )]
         propose: bool,
     },
+    /// Revert a committed change in every repo it was recorded for
+    #[command(
+        after_help = "Unmerged: the change's commits are reverted on its branch and pushed.
+Merged: refused without --force; with it, reverted on a new <change-id>-revert
+branch cut from the default branch, and pushed (the default branch is never
+pushed to).
+
+EXAMPLES:
+  gx create revert GX-2026-07-12
+  gx create revert GX-2026-07-12 --force   # also revert repos whose PR merged"
+    )]
+    Revert {
+        /// The change to revert
+        #[arg(help = "Change ID to revert", value_parser = validate_change_id)]
+        change_id: String,
+
+        /// Also revert repos whose PR already merged
+        #[arg(
+            long,
+            help = "Also revert merged repos (on a new <change-id>-revert branch)"
+        )]
+        force: bool,
+    },
 }
 
 #[cfg(test)]
@@ -798,4 +1855,259 @@ mod tests {
             "a bare --draft with no --pr must be a clap error on Apply"
         );
     }
+
+    #[test]
+    fn test_clone_requires_owner_or_search() {
+        assert!(Cli::try_parse_from(["gx", "clone"]).is_err());
+        assert!(Cli::try_parse_from(["gx", "clone", "tatari-tv", "--search", "org:x"]).is_err());
+
+        let cli = Cli::try_parse_from(["gx", "clone", "--search", "org:tatari-tv topic:service"])
+            .expect("--search alone must satisfy clone's repo-set requirement");
+        match cli.command {
+            Commands::Clone {
+                user_or_org,
+                search,
+                ..
+            } => {
+                assert_eq!(user_or_org, None);
+                assert_eq!(search.as_deref(), Some("org:tatari-tv topic:service"));
+            }
+            other => panic!("expected Clone, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_color_choice_resolves_against_tty() {
+        assert!(ColorChoice::Auto.resolve(true, false));
+        assert!(!ColorChoice::Auto.resolve(false, false));
+        assert!(ColorChoice::Always.resolve(false, false));
+        assert!(!ColorChoice::Never.resolve(true, false));
+    }
+
+    #[test]
+    fn test_color_choice_precedence_always_then_no_color_then_default() {
+        // --color=always beats both NO_COLOR and a pipe.
+        assert!(ColorChoice::Always.resolve(false, true));
+        assert!(ColorChoice::Always.resolve(true, true));
+        // The deprecated --no-color is `never`, whatever --color says.
+        assert_eq!(ColorChoice::Always.with_no_color(true), ColorChoice::Never);
+        assert_eq!(
+            ColorChoice::Always.with_no_color(false),
+            ColorChoice::Always
+        );
+        // NO_COLOR turns auto off even on a terminal.
+        assert!(!ColorChoice::Auto.resolve(true, true));
+        assert!(!ColorChoice::Auto.resolve(false, true));
+        // Otherwise auto colors exactly when stdout is a terminal.
+        assert!(ColorChoice::Auto.resolve(true, false));
+        assert!(!ColorChoice::Never.resolve(true, false));
+    }
+
+    #[test]
+    fn test_color_flag_parses_and_defaults_to_auto() {
+        let cli = Cli::try_parse_from(["gx", "status"]).unwrap();
+        assert_eq!(cli.color, ColorChoice::Auto);
+
+        // Global, so it is accepted after the subcommand too.
+        let cli = Cli::try_parse_from(["gx", "status", "--color", "always"]).unwrap();
+        assert_eq!(cli.color, ColorChoice::Always);
+
+        assert!(Cli::try_parse_from(["gx", "--color", "sometimes", "status"]).is_err());
+    }
+
+    #[test]
+    fn test_create_plan_in_carries_every_other_input() {
+        assert!(Cli::try_parse_from(["gx", "create", "--plan-in", "plan.json"]).is_ok());
+        assert!(
+            Cli::try_parse_from(["gx", "create", "--plan-in", "plan.json", "--commit", "x"])
+                .is_err()
+        );
+        assert!(Cli::try_parse_from([
+            "gx",
+            "create",
+            "--plan-in",
+            "a.json",
+            "--plan-out",
+            "b.json"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_validate_trailer() {
+        assert_eq!(validate_trailer("Refs:  OPS-42").unwrap(), "Refs: OPS-42");
+        assert_eq!(
+            validate_trailer("Co-authored-by: A <a@example.com>").unwrap(),
+            "Co-authored-by: A <a@example.com>"
+        );
+        assert!(validate_trailer("no colon").is_err());
+        assert!(validate_trailer("Bad Key: x").is_err());
+        assert!(validate_trailer("Refs:").is_err());
+        assert!(validate_trailer("Refs: a\nb").is_err());
+    }
+
+    #[test]
+    fn test_validate_pr_participant() {
+        assert!(validate_pr_participant("octocat").is_ok());
+        assert!(validate_pr_participant("tatari-tv/platform").is_ok());
+        assert!(validate_pr_participant("").is_err());
+        assert!(validate_pr_participant("a/b/c").is_err());
+        assert!(validate_pr_participant("two words").is_err());
+        assert!(validate_pr_participant("org/").is_err());
+    }
+
+    #[test]
+    fn test_create_reviewer_and_assignee_require_pr() {
+        let base = ["gx", "create", "--files", "*.md", "--commit", "m"];
+        let with = |extra: &[&'static str]| {
+            let mut args = base.to_vec();
+            args.extend_from_slice(extra);
+            args.push("delete");
+            Cli::try_parse_from(args)
+        };
+        assert!(with(&["--reviewer", "octocat"]).is_err());
+        assert!(with(&["--assignee", "octocat"]).is_err());
+        assert!(with(&["--label", "automated"]).is_err());
+        assert!(with(&["--pr", "--create-labels"]).is_err());
+        assert!(with(&["--pr", "--label", "automated", "--create-labels"]).is_ok());
+
+        let cli = with(&[
+            "--pr",
+            "--reviewer",
+            "octocat",
+            "--reviewer",
+            "tatari-tv/platform",
+            "--assignee",
+            "alice",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Create {
+                reviewers,
+                assignees,
+                ..
+            } => {
+                assert_eq!(reviewers, ["octocat", "tatari-tv/platform"]);
+                assert_eq!(assignees, ["alice"]);
+            }
+            _ => panic!("expected create"),
+        }
+    }
+
+    #[test]
+    fn test_create_trailers_repeat_in_order() {
+        let cli = Cli::try_parse_from([
+            "gx",
+            "create",
+            "--files",
+            "*.md",
+            "--commit",
+            "m",
+            "--trailer",
+            "A: 1",
+            "--trailer",
+            "B: 2",
+            "--change-id-trailer",
+            "delete",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Create {
+                trailers,
+                change_id_trailer,
+                ..
+            } => {
+                assert_eq!(trailers, ["A: 1", "B: 2"]);
+                assert!(change_id_trailer);
+            }
+            _ => panic!("expected create"),
+        }
+        assert!(
+            Cli::try_parse_from(["gx", "create", "--files", "*", "--trailer", "A: 1"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_clone_archived_flags_are_exclusive() {
+        assert!(Cli::try_parse_from([
+            "gx",
+            "clone",
+            "tatari-tv",
+            "--include-archived",
+            "--archived-only"
+        ])
+        .is_err());
+        assert!(Cli::try_parse_from(["gx", "clone", "tatari-tv", "--archived-only"]).is_ok());
+    }
+
+    #[test]
+    fn test_prune_prefix_defaults_to_gx() {
+        let cli = Cli::try_parse_from(["gx", "prune", "--dry-run"]).unwrap();
+        match cli.command {
+            Commands::Prune {
+                prefix,
+                dry_run,
+                merged_only,
+                ..
+            } => {
+                assert_eq!(prefix, "GX-");
+                assert!(dry_run);
+                assert!(!merged_only);
+            }
+            other => panic!("expected Commands::Prune, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_exec_takes_the_command_after_double_dash() {
+        let cli = Cli::try_parse_from([
+            "gx",
+            "exec",
+            "--fail-fast",
+            "-p",
+            "web",
+            "--",
+            "make",
+            "-j4",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Exec {
+                fail_fast,
+                patterns,
+                command,
+            } => {
+                assert!(fail_fast);
+                assert_eq!(patterns, ["web"]);
+                assert_eq!(command, ["make", "-j4"]);
+            }
+            other => panic!("expected Commands::Exec, got: {other:?}"),
+        }
+        assert!(Cli::try_parse_from(["gx", "exec"]).is_err());
+    }
+
+    #[test]
+    fn test_quiet_conflicts_with_verbose() {
+        let cli = Cli::try_parse_from(["gx", "-q", "status"]).unwrap();
+        assert!(cli.quiet);
+        assert!(Cli::try_parse_from(["gx", "--quiet", "--verbose", "status"]).is_err());
+    }
+
+    #[test]
+    fn test_sync_only_rejects_a_branch() {
+        // --sync-only never switches, so a BRANCH would be silently ignored.
+        assert!(Cli::try_parse_from(["gx", "checkout", "--sync-only"]).is_ok());
+        assert!(Cli::try_parse_from(["gx", "checkout", "--sync-only", "main"]).is_err());
+    }
+
+    #[test]
+    fn test_no_fail_on_error_defaults_off() {
+        assert!(
+            !Cli::try_parse_from(["gx", "status"])
+                .unwrap()
+                .no_fail_on_error
+        );
+        let cli = Cli::try_parse_from(["gx", "--no-fail-on-error", "status"]).unwrap();
+        assert!(cli.no_fail_on_error);
+    }
 }
@@ -4,33 +4,69 @@
 
 use crate::cli::Cli;
 use crate::output::StatusOptions;
-use crate::{git, github, output};
+use crate::stats::RunStats;
+use crate::{git, github, output, retry};
 use eyre::{Context, Result};
-use local::config::Config;
+use local::config::{CloneProtocol, Config};
+use local::manifest::{self, ManifestEntry};
 use local::repo;
-use local::utils::{get_jobs_from_config, get_nproc};
-use log::{debug, info};
+use local::utils::get_nproc;
+use log::{debug, info, warn};
 use rayon::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
 use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
-/// Process the clone subcommand
+/// Process the clone subcommand. The repo set is either everything
+/// `user_or_org` owns or, with `search`, whatever a GitHub search returns.
+/// With `write_manifest`, every repo that ends up checked out is recorded in
+/// that workspace manifest (see [`local::manifest`]). `limit` caps the GitHub
+/// listing at its first N repos, before pattern filtering; `since` (a
+/// duration like `30d`) keeps only repos pushed to within it. `layout` is
+/// the target directory template (see [`resolve_target_dir`]); `protocol`
+/// (SSH or HTTPS) overrides the configured `clone.protocol`. `skip_existing`
+/// leaves repos that are already cloned alone, so a re-run after an
+/// interrupted clone only does the missing ones.
+#[allow(clippy::too_many_arguments)]
 pub fn process_clone_command(
     cli: &Cli,
     config: &Config,
-    user_or_org: &str,
-    include_archived: bool,
+    user_or_org: Option<&str>,
+    search: Option<&str>,
+    archived: github::ArchivedFilter,
+    add_upstream: Option<&str>,
+    submodules: bool,
+    mirror: bool,
+    write_manifest: Option<&Path>,
+    limit: Option<usize>,
+    since: Option<&str>,
+    layout: &str,
+    protocol: Option<CloneProtocol>,
+    skip_existing: bool,
     patterns: &[String],
 ) -> Result<()> {
+    let started = Instant::now();
+    let stats = RunStats::new();
+    let source = search.or(user_or_org).unwrap_or_default();
+    // Parse before touching GitHub, so a typo fails fast.
+    validate_layout(layout)?;
+    let protocol = protocol.unwrap_or_else(|| config.clone_protocol());
+    debug!("Using clone protocol: {protocol:?}");
+    let since = since
+        .map(|s| local::utils::parse_duration(s).map(|age| chrono::Utc::now() - age))
+        .transpose()
+        .context("Invalid --since")?;
     info!(
-        "Processing clone command for user/org '{}' with {} patterns",
-        user_or_org,
+        "Processing clone command for '{}' with {} patterns",
+        source,
         patterns.len()
     );
 
     // Determine jobs
     let jobs = cli
         .parallel
-        .or_else(|| get_jobs_from_config(config))
+        .or_else(|| config.for_org(source).jobs)
         .unwrap_or_else(|| get_nproc().unwrap_or(4));
 
     debug!("Using jobs: {jobs}");
@@ -42,18 +78,28 @@ pub fn process_clone_command(
         .context("Failed to initialize thread pool")?;
 
     // 1. Get repositories from GitHub
-    let all_repos = github::get_user_repos(user_or_org, include_archived, config)
-        .context("Failed to get repositories from GitHub")?;
+    let all_repos = stats.phase("discovery", || match search {
+        Some(query) => github::search_repos(query, archived, limit, since, config)
+            .context("Failed to search repositories on GitHub"),
+        None => github::get_user_repos(source, archived, limit, since, config)
+            .context("Failed to get repositories from GitHub"),
+    })?;
 
-    info!("Found {} repositories for {}", all_repos.len(), user_or_org);
+    info!("Found {} repositories for {}", all_repos.len(), source);
 
     if all_repos.is_empty() {
-        println!("🔍 No repositories found for {user_or_org}");
+        println!("🔍 No repositories found for {source}");
         return Ok(());
     }
 
     // 2. Filter repositories using existing repo filtering logic
-    let filtered_slugs = filter_repository_slugs(&all_repos, patterns);
+    let filtered_slugs = stats.phase("filtering", || {
+        let mut slugs = filter_repository_slugs(&all_repos, patterns);
+        if let Some(only) = &repo::discovery_settings().only_slugs {
+            slugs.retain(|slug| only.contains(slug));
+        }
+        slugs
+    });
 
     info!("Filtered to {} repositories", filtered_slugs.len());
 
@@ -62,14 +108,68 @@ pub fn process_clone_command(
         return Ok(());
     }
 
-    // 3. Read GitHub token
-    let token = github::read_token(user_or_org, config).context("Failed to read GitHub token")?;
+    // Refuse a layout that clones two repos into one directory: the second
+    // would update the first's checkout instead of cloning.
+    let collisions = colliding_targets(&filtered_slugs, search.is_some(), source, layout);
+    if !collisions.is_empty() {
+        let listed: Vec<String> = collisions
+            .iter()
+            .map(|(target, slugs)| format!("{} ({})", target.display(), slugs.join(", ")))
+            .collect();
+        eyre::bail!(
+            "--layout '{layout}' puts several repos in one directory: {}; add {{org}} to the layout",
+            listed.join("; ")
+        );
+    }
+
+    // 3. Read GitHub tokens. A search can span owners, so each repo clones
+    //    with its own owner's token and `{user}` is that owner; a plain clone
+    //    keeps the directory name exactly as the user typed it.
+    let mut tokens: HashMap<&str, String> = HashMap::new();
+    for slug in &filtered_slugs {
+        let owner = clone_owner(slug, search.is_some(), source);
+        if !tokens.contains_key(owner) {
+            let token =
+                github::resolve_token(owner, config).context("Failed to read GitHub token")?;
+            tokens.insert(owner, token);
+        }
+    }
 
     // 4. Process repositories in parallel with streaming output
+    let display_opts = StatusOptions {
+        quiet: cli.quiet,
+        ..Default::default()
+    };
     let results = Mutex::new(Vec::new());
+    let progress = output::ProgressReporter::new(
+        filtered_slugs.len(),
+        "cloning",
+        output::progress_wanted(cli.progress, cli.quiet, false),
+    );
 
     filtered_slugs.par_iter().for_each(|repo_slug| {
-        let result = git::clone_or_update_repo(repo_slug, user_or_org, &token);
+        let owner = clone_owner(repo_slug, search.is_some(), source);
+        let target_dir = resolve_target_dir(Path::new(""), repo_slug, owner, layout);
+        let result = stats.repo(repo_slug, || {
+            if mirror {
+                git::mirror_or_update_repo(
+                    repo_slug,
+                    &mirror_dir(&target_dir),
+                    protocol,
+                    skip_existing,
+                )
+            } else {
+                git::clone_or_update_repo(
+                    repo_slug,
+                    &target_dir,
+                    &tokens[owner],
+                    protocol,
+                    add_upstream,
+                    submodules,
+                    skip_existing,
+                )
+            }
+        });
 
         // Store result and display immediately. Poison-recovery
         // belt-and-suspenders (the panic hook in `main` is the primary fix):
@@ -78,26 +178,179 @@ pub fn process_clone_command(
             .lock()
             .unwrap_or_else(|e| e.into_inner())
             .push(result.clone());
-        if let Err(e) = output::display_clone_result_immediate(&result) {
-            log::error!("Failed to display clone result: {e}");
-        }
+        progress.print_above(|| {
+            if let Err(e) = output::display_clone_result_immediate(&result, &display_opts) {
+                log::error!("Failed to display clone result: {e}");
+            }
+        });
+        progress.tick();
     });
+    progress.finish();
 
-    // 5. Categorize results and show unified summary
+    // 5. Tally the results for the summary and exit code
     let results_vec = results.into_inner().unwrap_or_else(|e| e.into_inner());
-    let (clean_count, dirty_count, error_count) = categorize_clone_results(&results_vec);
+    let tally = tally_clone_results(&results_vec);
+    // A directory in the way of a clone fails the run as an error does.
+    let error_count = tally.errors + tally.not_git;
 
-    let status_opts = StatusOptions::default();
-    output::display_unified_summary(clean_count, dirty_count, error_count, &status_opts);
+    if let Some(manifest_path) = write_manifest {
+        let entries = manifest_entries(&results_vec, search.is_some(), source, layout);
+        let count = entries.len();
+        manifest::update_manifest(manifest_path, entries)?;
+        println!(
+            "📝 Recorded {count} repo(s) in manifest {}",
+            manifest_path.display()
+        );
+    }
+
+    // Printed under --quiet too: it is the one line a quiet run leaves.
+    println!(
+        "\n{}",
+        render_clone_summary(&tally, started.elapsed(), display_opts.use_emoji)
+    );
+    retry::record_last_run(&failed_slugs(&results_vec));
+    if cli.stats {
+        eprintln!("{}", stats.render());
+    }
 
     // 6. Exit with error count
-    if error_count > 0 {
-        std::process::exit(error_count.min(255) as i32);
+    output::exit_on_errors(error_count, !cli.no_fail_on_error);
+
+    Ok(())
+}
+
+/// Slugs of the clones that failed, for `gx retry`.
+fn failed_slugs(results: &[git::CloneResult]) -> Vec<&str> {
+    results
+        .iter()
+        .filter(|result| result.error.is_some())
+        .map(|result| result.repo_slug.as_str())
+        .collect()
+}
+
+/// The owner directory (and token owner) a slug clones under: the slug's own
+/// owner for search results, otherwise the `user_or_org` argument verbatim.
+fn clone_owner<'a>(repo_slug: &'a str, from_search: bool, user_or_org: &'a str) -> &'a str {
+    if from_search {
+        repo_slug.split('/').next().unwrap_or(user_or_org)
+    } else {
+        user_or_org
+    }
+}
+
+/// The default `--layout`: `<owner>/<repo>`, with the owner as
+/// [`clone_owner`] picks it.
+pub const DEFAULT_LAYOUT: &str = "{user}/{repo}";
+
+const LAYOUT_PLACEHOLDERS: [&str; 3] = ["{user}", "{org}", "{repo}"];
+
+/// The directory `slug` clones into under `base`, from a `--layout` template:
+/// `{org}` and `{repo}` are the slug's owner and name, `{user}` the clone
+/// owner (the USER|ORG argument as typed, or the slug's owner for
+/// `--search`). `/` in the template nests directories, and an absolute
+/// template is used as is rather than placed under `base`.
+pub fn resolve_target_dir(base: &Path, slug: &str, user: &str, layout: &str) -> PathBuf {
+    let (org, repo) = slug.split_once('/').unwrap_or((user, slug));
+    let resolved = layout
+        .replace("{user}", user)
+        .replace("{org}", org)
+        .replace("{repo}", repo);
+    base.join(resolved)
+}
+
+/// Target directories `layout` gives more than one of `slugs` (e.g. a
+/// `{repo}`-only layout over a `--search` spanning owners), each with the
+/// slugs that would share it. Compared case-insensitively, as the default
+/// macOS and Windows file systems do.
+fn colliding_targets(
+    slugs: &[String],
+    from_search: bool,
+    user_or_org: &str,
+    layout: &str,
+) -> Vec<(PathBuf, Vec<String>)> {
+    let mut by_target: BTreeMap<String, (PathBuf, Vec<String>)> = BTreeMap::new();
+    for slug in slugs {
+        let owner = clone_owner(slug, from_search, user_or_org);
+        let target = resolve_target_dir(Path::new(""), slug, owner, layout);
+        by_target
+            .entry(target.to_string_lossy().to_lowercase())
+            .or_insert_with(|| (target, Vec::new()))
+            .1
+            .push(slug.clone());
     }
+    by_target
+        .into_values()
+        .filter(|(_, slugs)| slugs.len() > 1)
+        .collect()
+}
 
+/// Reject a `--layout` that would put every repo in the same directory (no
+/// `{repo}`) or that names a placeholder we don't know.
+fn validate_layout(layout: &str) -> Result<()> {
+    if !layout.contains("{repo}") {
+        eyre::bail!("Invalid --layout '{layout}': it must contain {{repo}}");
+    }
+    let mut rest = layout.to_string();
+    for placeholder in LAYOUT_PLACEHOLDERS {
+        rest = rest.replace(placeholder, "");
+    }
+    if rest.contains('{') || rest.contains('}') {
+        eyre::bail!(
+            "Invalid --layout '{layout}': only {{user}}, {{org}} and {{repo}} are supported"
+        );
+    }
     Ok(())
 }
 
+/// A `--mirror` target: the layout's directory with `.git` appended, as bare
+/// repos are conventionally named.
+fn mirror_dir(target_dir: &Path) -> PathBuf {
+    let mut name = target_dir.as_os_str().to_os_string();
+    name.push(".git");
+    PathBuf::from(name)
+}
+
+/// Manifest entries for the repos a clone run left checked out. A repo
+/// whose default branch can't be read is left out with a warning rather
+/// than recorded half-known.
+fn manifest_entries(
+    results: &[git::CloneResult],
+    from_search: bool,
+    user_or_org: &str,
+    layout: &str,
+) -> Vec<ManifestEntry> {
+    results
+        .iter()
+        .filter(|result| {
+            result.error.is_none()
+                && matches!(
+                    result.action,
+                    git::CloneAction::Cloned
+                        | git::CloneAction::Updated
+                        | git::CloneAction::Stashed
+                        | git::CloneAction::Skipped
+                )
+        })
+        .filter_map(|result| {
+            let owner = clone_owner(&result.repo_slug, from_search, user_or_org);
+            let path = resolve_target_dir(Path::new(""), &result.repo_slug, owner, layout);
+            let default_branch = repo::Repo::new(path.clone())
+                .and_then(|repo| local::git::get_default_branch_local(&repo));
+            match default_branch {
+                Ok(default_branch) => Some(ManifestEntry {
+                    slug: result.repo_slug.clone(),
+                    path: path.display().to_string(),
+                    default_branch,
+                }),
+                Err(e) => {
+                    warn!("Leaving {} out of the manifest: {e}", result.repo_slug);
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
 /// Filter repository slugs using the existing repo filtering logic
 fn filter_repository_slugs(all_repos: &[String], patterns: &[String]) -> Vec<String> {
     // Convert repo slugs to fake Repo objects for filtering
@@ -110,25 +363,79 @@ fn filter_repository_slugs(all_repos: &[String], patterns: &[String]) -> Vec<Str
     filtered_repos.iter().map(|r| r.slug.clone()).collect()
 }
 
-/// Categorize clone results into clean/dirty/error counts
-fn categorize_clone_results(results: &[git::CloneResult]) -> (usize, usize, usize) {
-    let mut clean_count = 0;
-    let mut dirty_count = 0;
-    let mut error_count = 0;
+/// Per-action totals for the end-of-run clone summary. A result with an
+/// error counts only under `errors`, whatever its action.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct CloneTally {
+    cloned: usize,
+    updated: usize,
+    stashed: usize,
+    skipped: usize,
+    mirrored: usize,
+    mirror_updated: usize,
+    different_remote: usize,
+    not_git: usize,
+    errors: usize,
+}
 
+fn tally_clone_results(results: &[git::CloneResult]) -> CloneTally {
+    let mut tally = CloneTally::default();
     for result in results {
         if result.error.is_some() {
-            error_count += 1;
-        } else {
-            match result.action {
-                git::CloneAction::Cloned => clean_count += 1,
-                git::CloneAction::Updated => clean_count += 1,
-                git::CloneAction::Stashed => dirty_count += 1, // Had uncommitted changes during update
-                git::CloneAction::DirectoryNotGitRepo => error_count += 1, // Directory exists but not git
-                git::CloneAction::DifferentRemote => dirty_count += 1, // Different remote URL detected
-            }
+            tally.errors += 1;
+            continue;
         }
+        let count = match result.action {
+            git::CloneAction::Cloned => &mut tally.cloned,
+            git::CloneAction::Updated => &mut tally.updated,
+            git::CloneAction::Stashed => &mut tally.stashed,
+            git::CloneAction::Skipped => &mut tally.skipped,
+            git::CloneAction::Mirrored => &mut tally.mirrored,
+            git::CloneAction::MirrorUpdated => &mut tally.mirror_updated,
+            git::CloneAction::DifferentRemote => &mut tally.different_remote,
+            git::CloneAction::DirectoryNotGitRepo => &mut tally.not_git,
+        };
+        *count += 1;
     }
+    tally
+}
 
-    (clean_count, dirty_count, error_count)
+/// The one-line wrap-up, e.g. `Cloned 120, updated 30, skipped 50, 8 errors
+/// in 3m12s`. Actions nothing ended in are left out; errors always show.
+fn render_clone_summary(tally: &CloneTally, elapsed: Duration, use_emoji: bool) -> String {
+    let parts = [
+        ("cloned", tally.cloned),
+        ("updated", tally.updated),
+        ("updated with stash", tally.stashed),
+        ("skipped", tally.skipped),
+        ("mirrored", tally.mirrored),
+        ("mirror updated", tally.mirror_updated),
+        ("different remote", tally.different_remote),
+        ("not a git repo", tally.not_git),
+    ];
+    let mut line: Vec<String> = parts
+        .iter()
+        .filter(|(_, count)| *count > 0)
+        .map(|(label, count)| format!("{label} {count}"))
+        .collect();
+    line.push(format!("{} errors", tally.errors));
+    let mut line = line.join(", ");
+    line[..1].make_ascii_uppercase();
+    let prefix = if use_emoji { "📊 " } else { "" };
+    format!("{prefix}{line} in {}", format_elapsed(elapsed))
+}
+
+/// `42.3s`, `3m12s` or `1h05m`.
+fn format_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    if secs < 60 {
+        format!("{:.1}s", elapsed.as_secs_f64())
+    } else if secs < 3600 {
+        format!("{}m{:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}h{:02}m", secs / 3600, secs % 3600 / 60)
+    }
 }
+
+#[cfg(test)]
+mod tests;
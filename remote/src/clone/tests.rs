@@ -0,0 +1,129 @@
+use super::*;
+
+#[test]
+fn test_resolve_target_dir_default_layout_is_owner_then_repo() {
+    let dir = resolve_target_dir(Path::new(""), "tatari-tv/api", "tatari-tv", DEFAULT_LAYOUT);
+    assert_eq!(dir, PathBuf::from("tatari-tv/api"));
+}
+
+#[test]
+fn test_resolve_target_dir_flat_layout() {
+    let dir = resolve_target_dir(Path::new("/work"), "tatari-tv/api", "tatari-tv", "{repo}");
+    assert_eq!(dir, PathBuf::from("/work/api"));
+}
+
+#[test]
+fn test_resolve_target_dir_nested_layout_uses_slug_owner_for_org() {
+    // `{user}` is the owner as typed; `{org}` always comes from the slug.
+    let dir = resolve_target_dir(
+        Path::new("/work"),
+        "Tatari-TV/api",
+        "tatari-tv",
+        "repos/{org}/{user}/{repo}",
+    );
+    assert_eq!(dir, PathBuf::from("/work/repos/Tatari-TV/tatari-tv/api"));
+}
+
+#[test]
+fn test_resolve_target_dir_keeps_an_absolute_layout() {
+    let dir = resolve_target_dir(
+        Path::new(""),
+        "tatari-tv/api",
+        "tatari-tv",
+        "/srv/{org}/{repo}",
+    );
+    assert_eq!(dir, PathBuf::from("/srv/tatari-tv/api"));
+}
+
+#[test]
+fn test_colliding_targets_under_search() {
+    let slugs = vec![
+        "tatari-tv/api".to_string(),
+        "Other/API".to_string(),
+        "tatari-tv/web".to_string(),
+    ];
+    let collisions = colliding_targets(&slugs, true, "ignored", "{repo}");
+    assert_eq!(collisions.len(), 1);
+    assert_eq!(
+        collisions[0].1,
+        vec!["tatari-tv/api".to_string(), "Other/API".to_string()]
+    );
+
+    // The owner in the layout keeps them apart.
+    assert!(colliding_targets(&slugs, true, "ignored", DEFAULT_LAYOUT).is_empty());
+}
+
+#[test]
+fn test_validate_layout() {
+    assert!(validate_layout(DEFAULT_LAYOUT).is_ok());
+    assert!(validate_layout("repos/{org}/{repo}").is_ok());
+    assert!(
+        validate_layout("{org}").is_err(),
+        "every repo would collide"
+    );
+    assert!(validate_layout("{owner}/{repo}").is_err());
+}
+
+#[test]
+fn test_mirror_dir_appends_git_suffix() {
+    assert_eq!(
+        mirror_dir(Path::new("tatari-tv/api")),
+        PathBuf::from("tatari-tv/api.git")
+    );
+}
+
+#[test]
+fn test_clone_summary_tallies_each_action() {
+    let result = |slug: &str, action: git::CloneAction, error: Option<&str>| git::CloneResult {
+        repo_slug: slug.to_string(),
+        action,
+        error: error.map(str::to_string),
+    };
+    let results = vec![
+        result("o/a", git::CloneAction::Cloned, None),
+        result("o/b", git::CloneAction::Cloned, None),
+        result("o/c", git::CloneAction::Updated, None),
+        result("o/d", git::CloneAction::Stashed, None),
+        result("o/e", git::CloneAction::Skipped, None),
+        result("o/f", git::CloneAction::Skipped, None),
+        result("o/g", git::CloneAction::Skipped, None),
+        result("o/h", git::CloneAction::DifferentRemote, None),
+        result("o/i", git::CloneAction::DirectoryNotGitRepo, None),
+        // An error counts as an error whatever action it carries.
+        result("o/j", git::CloneAction::Cloned, Some("boom")),
+        result("o/k", git::CloneAction::Updated, Some("boom")),
+    ];
+
+    let tally = tally_clone_results(&results);
+    assert_eq!(
+        tally,
+        CloneTally {
+            cloned: 2,
+            updated: 1,
+            stashed: 1,
+            skipped: 3,
+            different_remote: 1,
+            not_git: 1,
+            errors: 2,
+            ..CloneTally::default()
+        }
+    );
+    assert_eq!(
+        render_clone_summary(&tally, Duration::from_secs(192), false),
+        "Cloned 2, updated 1, updated with stash 1, skipped 3, different remote 1, \
+         not a git repo 1, 2 errors in 3m12s"
+    );
+
+    let none = tally_clone_results(&[]);
+    assert_eq!(
+        render_clone_summary(&none, Duration::from_millis(1500), true),
+        "📊 0 errors in 1.5s"
+    );
+}
+
+#[test]
+fn test_format_elapsed() {
+    assert_eq!(format_elapsed(Duration::from_millis(42_300)), "42.3s");
+    assert_eq!(format_elapsed(Duration::from_secs(192)), "3m12s");
+    assert_eq!(format_elapsed(Duration::from_secs(3900)), "1h05m");
+}
@@ -0,0 +1,90 @@
+//! `gx config`: print and check the configuration gx actually runs with.
+//!
+//! Dispatched by the bin before the normal config load, so `validate` can
+//! report a file that fails to parse instead of gx refusing to start.
+
+use crate::cli::{Cli, ConfigAction};
+use eyre::{Context, Result};
+use local::config::{default_config_path, Config};
+use std::path::Path;
+
+/// Run `gx config <action>`.
+pub fn process_config_command(cli: &Cli, action: &ConfigAction) -> Result<()> {
+    let source = Config::source_path(cli.config.as_ref());
+    match action {
+        ConfigAction::Show { json } => {
+            let config =
+                Config::load(cli.config.as_ref())?.with_overrides(&cli.config_overrides)?;
+            println!("{}", render_config(config, *json)?);
+            Ok(())
+        }
+        ConfigAction::Path => {
+            match source {
+                Some(path) => println!("{}", path.display()),
+                None => println!("{}", no_config_note()),
+            }
+            Ok(())
+        }
+        ConfigAction::Validate => match source {
+            Some(path) => {
+                let problems = validate_config_file(&path)?;
+                if !problems.is_empty() {
+                    for problem in &problems {
+                        println!("❌ {problem}");
+                    }
+                    return Err(eyre::eyre!(
+                        "{} has {} problem(s)",
+                        path.display(),
+                        problems.len()
+                    ));
+                }
+                println!("✅ {} is valid", path.display());
+                Ok(())
+            }
+            None => {
+                println!("✅ {}", no_config_note());
+                Ok(())
+            }
+        },
+    }
+}
+
+/// What `path`/`validate` say when no config file is in play.
+fn no_config_note() -> String {
+    match default_config_path() {
+        Some(path) => format!(
+            "no config file (none at {}); using built-in defaults",
+            path.display()
+        ),
+        None => "no config file; using built-in defaults".to_string(),
+    }
+}
+
+/// The fully resolved config (defaults filled in) as YAML, the config file's
+/// own format, or as JSON.
+fn render_config(config: Config, json: bool) -> Result<String> {
+    let resolved = config.resolved();
+    if json {
+        serde_json::to_string_pretty(&resolved).context("Failed to serialize config")
+    } else {
+        serde_yaml::to_string(&resolved)
+            .map(|yaml| yaml.trim_end().to_string())
+            .context("Failed to serialize config")
+    }
+}
+
+/// Parse the config at `path` and list what is wrong with it. A file that
+/// does not parse (bad YAML, an unknown key, a wrongly typed value) is a
+/// single problem naming the parse error; a file that parses is checked with
+/// [`Config::problems`]. Only an unreadable file is an `Err`.
+fn validate_config_file(path: &Path) -> Result<Vec<String>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read config file {}", path.display()))?;
+    match serde_yaml::from_str::<Config>(&content) {
+        Ok(config) => Ok(config.problems()),
+        Err(e) => Ok(vec![e.to_string()]),
+    }
+}
+
+#[cfg(test)]
+mod tests;
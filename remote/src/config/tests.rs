@@ -0,0 +1,55 @@
+use super::*;
+use tempfile::TempDir;
+
+#[test]
+fn test_show_includes_resolved_defaults() {
+    let yaml = render_config(Config::default(), false).unwrap();
+    assert!(
+        yaml.contains("max-depth: 3"),
+        "default depth missing:\n{yaml}"
+    );
+    assert!(yaml.contains("subprocess-timeout-secs: 300"), "{yaml}");
+    assert!(yaml.contains("protocol: ssh"), "{yaml}");
+
+    // A config that sets nothing at all still shows every default.
+    let empty: Config = serde_yaml::from_str("{}").unwrap();
+    let json: serde_json::Value =
+        serde_json::from_str(&render_config(empty, true).unwrap()).unwrap();
+    assert_eq!(json["repo-discovery"]["max-depth"], 3);
+    assert_eq!(json["retries"], 2);
+
+    // A configured value wins over the default.
+    let set: Config = serde_yaml::from_str("repo-discovery:\n  max-depth: 7\n").unwrap();
+    assert!(render_config(set, false).unwrap().contains("max-depth: 7"));
+}
+
+#[test]
+fn test_validate_rejects_malformed_config() {
+    let dir = TempDir::new().unwrap();
+    let write = |name: &str, content: &str| {
+        let path = dir.path().join(name);
+        std::fs::write(&path, content).unwrap();
+        path
+    };
+
+    let good = write("good.yml", "jobs: 4\nrepo-discovery:\n  max-depth: 2\n");
+    assert!(validate_config_file(&good).unwrap().is_empty());
+
+    let unknown_key = write("unknown.yml", "repo-discovery:\n  max-dpeth: 2\n");
+    let problems = validate_config_file(&unknown_key).unwrap();
+    assert_eq!(problems.len(), 1);
+    assert!(problems[0].contains("max-dpeth"), "{problems:?}");
+
+    let bad_yaml = write("bad.yml", "jobs: [4\n");
+    assert_eq!(validate_config_file(&bad_yaml).unwrap().len(), 1);
+
+    let bad_values = write(
+        "values.yml",
+        "jobs: lots\nsubprocess-timeout-secs: 0\nmin-tool-versions:\n  git: latest\n",
+    );
+    let problems = validate_config_file(&bad_values).unwrap();
+    assert_eq!(problems.len(), 3, "{problems:?}");
+    assert!(problems[0].starts_with("jobs:"), "{problems:?}");
+
+    assert!(validate_config_file(&dir.path().join("missing.yml")).is_err());
+}
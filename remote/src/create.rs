@@ -9,8 +9,9 @@
 // `gx::create::core::apply::execute_apply`). This is the first cross-crate
 // consumer; before Phase 8 there was none, so it was private.
 pub mod core;
+mod plan;
 
-pub use core::{generate_change_id, Change, CreateAction, CreateResult};
+pub use core::{file_pool, generate_change_id, Change, CreateAction, CreateOptions, CreateResult};
 // Re-exported so the proposal-artifact retention callers outside `create`
 // (`gx undo`'s local-only Proposed arm, `gx cleanup`, `gx doctor`) can reach
 // the manifest layout/removal helpers through a stable `crate::create::manifest`
@@ -19,22 +20,30 @@ pub use core::manifest;
 
 use crate::cli::Cli;
 use crate::confirm::Confirmation;
-use crate::output::{display_unified_results, StatusOptions};
+use crate::output::{self, display_unified_results, StatusOptions};
+use crate::retry;
+use crate::stats::RunStats;
 use colored::Colorize;
 use eyre::{Context, Result};
 use local::config::Config;
 use local::file;
-use local::repo::{discover_repos, filter_repos, Repo};
-use log::debug;
+use local::repo::{
+    discover_repos, discover_repos_including_bare, filter_repos, partition_bare, Repo,
+};
+use log::{debug, warn};
 use serde::Serialize;
 use std::path::Path;
+use std::sync::Arc;
 
-/// Show matched repositories and files without performing any actions (dry-run mode)
+/// Show matched repositories and files without performing any actions (dry-run
+/// mode); with `json`, as the array [`render_matches_json`] prints.
 pub fn show_matches(
     cli: &Cli,
     config: &Config,
     files: &[String],
     patterns: &[String],
+    include_ignored: bool,
+    json: bool,
 ) -> Result<()> {
     let current_dir = std::env::current_dir()?;
     let start_dir = cli.cwd.as_ref().unwrap_or(&current_dir);
@@ -66,7 +75,7 @@ pub fn show_matches(
         let mut matched_files = Vec::new();
 
         if !files.is_empty() {
-            if let Ok(files_found) = file::FileSet::matching_any(&repo.path, files) {
+            if let Ok(files_found) = file::FileSet::matching(&repo.path, files, include_ignored) {
                 for file in files_found {
                     matched_files.push(file.display().to_string());
                     total_files += 1;
@@ -90,6 +99,11 @@ pub fn show_matches(
         status.push(format!("{total_files}{files_emoji}"));
     }
 
+    if json {
+        println!("{}", render_matches_json(&matched_repos)?);
+        return Ok(());
+    }
+
     // Display results exactly like SLAM
     if matched_repos.is_empty() {
         println!("No repositories matched your criteria.");
@@ -114,6 +128,51 @@ pub fn show_matches(
     Ok(())
 }
 
+/// One matched repo in `gx create --json` without a change.
+#[derive(Debug, Serialize)]
+struct RepoMatches<'a> {
+    repo: &'a str,
+    files: &'a [String],
+}
+
+/// Render matched repos as a pretty-printed JSON array of `{"repo": <slug>,
+/// "files": [...]}` objects (`[]` when nothing matched).
+fn render_matches_json(matched_repos: &[(Repo, Vec<String>)]) -> Result<String> {
+    let matches: Vec<RepoMatches> = matched_repos
+        .iter()
+        .map(|(repo, files)| RepoMatches {
+            repo: &repo.slug,
+            files,
+        })
+        .collect();
+    serde_json::to_string_pretty(&matches).context("Failed to serialize matched repositories")
+}
+
+/// The content `gx create add <path> <content>` writes: the argument itself,
+/// the contents of `<file>` for `@<file>`, or all of stdin for `-`. A leading
+/// `@@` escapes a literal `@` (`@@team` writes `@team`).
+pub fn resolve_add_content(content: &str) -> Result<String> {
+    read_add_content(content, &mut std::io::stdin().lock())
+}
+
+/// [`resolve_add_content`] with the reader `-` drains, for tests.
+fn read_add_content(content: &str, stdin: &mut impl std::io::Read) -> Result<String> {
+    if content == "-" {
+        let mut text = String::new();
+        stdin
+            .read_to_string(&mut text)
+            .context("Failed to read add content from stdin")?;
+        Ok(text)
+    } else if let Some(literal) = content.strip_prefix("@@") {
+        Ok(format!("@{literal}"))
+    } else if let Some(path) = content.strip_prefix('@') {
+        std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read add content from @{path}"))
+    } else {
+        Ok(content.to_string())
+    }
+}
+
 /// Process create command across multiple repositories: discover/filter,
 /// confirm the blast radius, run the core, and display the results.
 #[allow(clippy::too_many_arguments)]
@@ -130,6 +189,8 @@ pub fn process_create_command(
     change: Change,
     propose_only: bool,
     report: Option<&Path>,
+    options: &CreateOptions,
+    plan_out: Option<&Path>,
 ) -> Result<()> {
     log::info!("Starting create command with change: {change:?}");
 
@@ -137,6 +198,21 @@ pub fn process_create_command(
     // not the per-repo commit pipeline handled below; `--propose` stops after
     // persisting proposals (the design's dry-run equivalent for llm).
     if let Change::Llm(prompt) = &change {
+        if plan_out.is_some() {
+            return Err(eyre::eyre!(
+                "--plan-out does not support `llm` changes; use `llm --propose` and `gx apply`"
+            ));
+        }
+        // The propose/apply pipeline commits through `gx apply`'s path, which
+        // has no signing support; refuse rather than silently commit unsigned.
+        if options.commit.sign {
+            return Err(eyre::eyre!(
+                "--sign is not supported for `llm` changes yet; commits would be unsigned"
+            ));
+        }
+        if options.json {
+            return Err(eyre::eyre!("--json does not support `llm` changes"));
+        }
         return run_llm(
             cli,
             config,
@@ -159,34 +235,197 @@ pub fn process_create_command(
         .unwrap_or(3);
 
     // Discover and filter repositories
-    let repos = discover_repos(start_dir, max_depth, &config.ignore_patterns())
+    let repos = options
+        .stats
+        .phase("discovery", || {
+            discover_repos_including_bare(start_dir, max_depth, &config.ignore_patterns())
+        })
         .context("Failed to discover repositories")?;
+    output::warn_slug_collisions(&repos, cli.quiet);
 
     log::info!("Discovered {} repositories", repos.len());
 
-    let filtered_repos = filter_repos(repos, patterns);
+    let filtered_repos = options
+        .stats
+        .phase("filtering", || filter_repos(repos, patterns));
     log::info!(
         "Filtered to {} repositories matching patterns",
         filtered_repos.len()
     );
 
     if filtered_repos.is_empty() {
-        println!("No repositories found matching the specified patterns.");
+        if options.json {
+            println!("[]");
+        } else {
+            println!("No repositories found matching the specified patterns.");
+        }
+        return Ok(());
+    }
+    // Bare repos (mirrors) have no work tree to change.
+    let (filtered_repos, bare_repos) = partition_bare(filtered_repos);
+    output::display_bare_skips(
+        &bare_repos,
+        &StatusOptions {
+            quiet: cli.quiet || options.json,
+            ..Default::default()
+        },
+    );
+    if filtered_repos.is_empty() {
+        if options.json {
+            println!("[]");
+        }
+        return Ok(());
+    }
+
+    // `--plan-out`: resolve everything, write it down, touch nothing.
+    if let Some(plan_path) = plan_out {
+        let plan = plan::build_plan(
+            &filtered_repos,
+            &change_id,
+            files,
+            &change,
+            commit_message.as_deref(),
+            pr,
+            draft,
+            options,
+            config,
+        )?;
+        plan::write_plan(plan_path, &plan)?;
+        let planned_files: usize = plan.repos.iter().map(|r| r.files.len()).sum();
+        println!(
+            "📝 Wrote plan {} ({} repos, {planned_files} files) to {}",
+            plan.change_id,
+            plan.repos.len(),
+            plan_path.display()
+        );
+        println!(
+            "   Execute it with: gx create --plan-in {}",
+            plan_path.display()
+        );
         return Ok(());
     }
 
+    run_create(
+        cli,
+        config,
+        &filtered_repos,
+        &change_id,
+        files,
+        &change,
+        commit_message.as_deref(),
+        pr,
+        draft,
+        yes,
+        patterns,
+        report,
+        options,
+    )
+}
+
+/// Execute a plan written by `gx create --plan-out`: re-discover the planned
+/// repos, refuse if the tree drifted from the plan, then run it through the
+/// same confirm/lock/execute/report path as a direct `gx create`.
+pub fn process_plan_command(
+    cli: &Cli,
+    config: &Config,
+    plan_path: &Path,
+    yes: bool,
+    json: bool,
+    report: Option<&Path>,
+) -> Result<()> {
+    let stats = Arc::new(RunStats::new());
+    let plan = plan::read_plan(plan_path)?;
+    log::info!(
+        "Executing plan {} from {} ({} repos)",
+        plan.change_id,
+        plan_path.display(),
+        plan.repos.len()
+    );
+
+    let current_dir = std::env::current_dir()?;
+    let start_dir = cli.cwd.as_deref().unwrap_or(&current_dir);
+    let max_depth = cli
+        .max_depth
+        .or_else(|| config.repo_discovery.as_ref().and_then(|rd| rd.max_depth))
+        .unwrap_or(3);
+    let repos = stats
+        .phase("discovery", || {
+            discover_repos(start_dir, max_depth, &config.ignore_patterns())
+        })
+        .context("Failed to discover repositories")?;
+    output::warn_slug_collisions(&repos, cli.quiet);
+
+    let planned_repos = plan.select_repos(repos)?;
+    plan.verify(&planned_repos, config)?;
+
+    // No `-p` patterns: the blast-radius prompt always runs unless --yes.
+    run_create(
+        cli,
+        config,
+        &planned_repos,
+        &plan.change_id,
+        &plan.files,
+        &plan.change.to_change(),
+        plan.commit_message.as_deref(),
+        plan.pr.is_some(),
+        plan.pr.as_ref().is_some_and(|pr| pr.draft),
+        yes,
+        &[],
+        report,
+        &CreateOptions {
+            progress: output::progress_wanted(cli.progress, cli.quiet, false),
+            json,
+            stats,
+            ..plan.options()
+        },
+    )
+}
+
+/// Everything after repo selection: confirm the blast radius, take the change
+/// lock, run the core, display results, write the report, and set the exit
+/// code. Shared by a direct `gx create` and `--plan-in`.
+#[allow(clippy::too_many_arguments)]
+fn run_create(
+    cli: &Cli,
+    config: &Config,
+    filtered_repos: &[Repo],
+    change_id: &str,
+    files: &[String],
+    change: &Change,
+    commit_message: Option<&str>,
+    pr: bool,
+    draft: bool,
+    yes: bool,
+    patterns: &[String],
+    report: Option<&Path>,
+    options: &CreateOptions,
+) -> Result<()> {
     // Confirmation gate: in commit mode, show the blast radius and (unless --yes)
     // prompt before mutating. Always prompt when no -p patterns were given; for
     // patterned runs, prompt only when the repo count exceeds the threshold ([A9]).
     if commit_message.is_some() {
         let threshold = config.confirm_threshold();
         let needs_prompt = patterns.is_empty() || filtered_repos.len() > threshold;
-        if !confirm_blast_radius(&filtered_repos, patterns, needs_prompt, yes)? {
+        // The repo listing and prompt would interleave with the JSON on stdout.
+        if options.json {
+            if needs_prompt && !yes {
+                return Err(eyre::eyre!(
+                    "--json cannot prompt for confirmation; pass --yes to commit"
+                ));
+            }
+        } else if !confirm_blast_radius(filtered_repos, patterns, needs_prompt, yes)? {
             println!("Aborted; no changes made.");
             return Ok(());
         }
     }
 
+    // Bypassing hooks can land commits a repo's own checks would refuse, so
+    // say so every time.
+    if commit_message.is_some() && options.commit.no_verify {
+        warn!("--no-verify: skipping commit and pre-push hooks for {change_id}");
+        eprintln!("⚠️  --no-verify: commit and pre-push hooks will NOT run");
+    }
+
     // Determine parallelism
     let parallel_jobs = cli
         .parallel
@@ -199,7 +438,7 @@ pub fn process_create_command(
     // audit fix #1). A dry run mutates no state and needs no lock.
     let _change_lock = if commit_message.is_some() {
         Some(
-            crate::lock::ChangeLock::acquire(&change_id)
+            crate::lock::ChangeLock::acquire(change_id)
                 .map_err(|e| eyre::eyre!("Cannot start create for {change_id}: {e}"))?,
         )
     } else {
@@ -209,13 +448,14 @@ pub fn process_create_command(
     // The wrapper already confirmed (TTY prompt above, or --yes); the core
     // never prompts, so it always receives an already-satisfied confirmation.
     let results = core::execute_create(
-        &filtered_repos,
-        &change_id,
+        filtered_repos,
+        change_id,
         files,
-        &change,
-        commit_message.as_deref(),
+        change,
+        commit_message,
         pr,
         draft,
+        options,
         config,
         parallel_jobs,
         crate::confirm::already_confirmed(),
@@ -234,11 +474,16 @@ pub fn process_create_command(
             local::config::OutputVerbosity::Summary
         },
         use_emoji: true,
-        use_colors: true,
+        use_colors: output::should_use_color(cli.color),
+        quiet: cli.quiet,
+        ..Default::default()
     };
 
-    display_unified_results(&results, &opts);
-    display_create_summary(&results, &opts);
+    if options.json {
+        println!("{}", render_create_json(&results)?);
+    } else {
+        display_create_results(&results, change, commit_message, options, &opts);
+    }
 
     // Machine-readable failure summary (Data Model `RunReport`): written to a
     // FILE, never reshaping stdout ([2026-07-12 gx-production-hardening] Phase
@@ -252,13 +497,113 @@ pub fn process_create_command(
     // `status`/`checkout`/`clone` (`status.rs:138`) so `gx create` is airtight
     // and scriptable rather than always ending `Ok(())` on partial failure.
     let error_count = count_errors(&results);
-    if error_count > 0 {
-        std::process::exit(error_count.min(255) as i32);
+    // A dry run (no `--commit`) changes nothing, so it leaves the last-run
+    // record to the command that did.
+    if commit_message.is_some() {
+        let failed: Vec<&str> = results
+            .iter()
+            .filter(|r| r.error.is_some() || matches!(r.action, CreateAction::Skipped))
+            .map(|r| r.repo.slug.as_str())
+            .collect();
+        retry::record_last_run(&failed);
+    }
+    if cli.stats {
+        eprintln!("{}", options.stats.render());
     }
+    output::exit_on_errors(error_count, !cli.no_fail_on_error);
 
     Ok(())
 }
 
+/// The human display of a run's results: per-repo lines, warnings, the dry
+/// run preview, then the summary and pattern analysis.
+fn display_create_results(
+    results: &[CreateResult],
+    change: &Change,
+    commit_message: Option<&str>,
+    options: &CreateOptions,
+    opts: &StatusOptions,
+) {
+    display_unified_results(results, opts);
+    for result in results {
+        if let Some(warning) = &result.warning {
+            println!("⚠️  {}: {warning}", result.repo.slug);
+        }
+    }
+    if commit_message.is_none() && !opts.quiet {
+        for result in results {
+            for line in dry_run_preview_lines(result, change, options.detailed) {
+                println!("{line}");
+            }
+        }
+    }
+    display_create_summary(results, opts);
+}
+
+/// Render results as the pretty-printed JSON array `gx create --json` prints.
+fn render_create_json(results: &[CreateResult]) -> Result<String> {
+    serde_json::to_string_pretty(results).context("Failed to serialize create results")
+}
+
+/// The lines printed under a dry run's results for one repo: a `delete`
+/// names every file it would remove with a `will DELETE` marker (other
+/// changes are already summarized by the result line and pattern analysis);
+/// `detailed` adds the repo's diff, which for a delete is the content lost.
+fn dry_run_preview_lines(result: &CreateResult, change: &Change, detailed: bool) -> Vec<String> {
+    if result.error.is_some() || !matches!(result.action, CreateAction::DryRun) {
+        return Vec::new();
+    }
+    let mut lines = Vec::new();
+    if matches!(change, Change::Delete) && !result.files_affected.is_empty() {
+        lines.push(format!(
+            "  {} will DELETE {} file(s):",
+            result.repo.slug,
+            result.files_affected.len()
+        ));
+        for file in &result.files_affected {
+            lines.push(format!("    ❌ {file}"));
+        }
+    }
+    if detailed {
+        if let Some(diff) = &result.diff {
+            if lines.is_empty() {
+                lines.push(format!("  {}:", result.repo.slug));
+            }
+            lines.extend(diff.lines().map(|line| format!("  {line}")));
+        }
+    }
+    lines
+}
+
+/// Process `gx create revert <change-id>`: revert the change's commits in
+/// every repo its change state records and print the results.
+pub fn process_revert_command(cli: &Cli, change_id: &str, force: bool) -> Result<()> {
+    log::info!("Starting revert for change ID: {change_id}");
+
+    // The revert rewrites `changes/<id>.json`; hold the change lock across it.
+    let _change_lock = crate::lock::ChangeLock::acquire(change_id)
+        .map_err(|e| eyre::eyre!("Cannot start revert for {change_id}: {e}"))?;
+    let state_manager = crate::state::StateManager::new()?;
+    let results = core::revert::execute_revert(change_id, force, &state_manager)?;
+
+    let opts = StatusOptions {
+        verbosity: if cli.verbose {
+            local::config::OutputVerbosity::Detailed
+        } else {
+            local::config::OutputVerbosity::Summary
+        },
+        use_emoji: true,
+        use_colors: output::should_use_color(cli.color),
+        quiet: cli.quiet,
+        ..Default::default()
+    };
+    display_unified_results(&results, &opts);
+    display_create_summary(&results, &opts);
+
+    output::exit_on_errors(count_errors(&results), !cli.no_fail_on_error);
+    Ok(())
+}
+
 /// Count repo results carrying an error (mirrors the categorize helpers in
 /// `status`/`checkout`/`clone`).
 fn count_errors(results: &[CreateResult]) -> usize {
@@ -304,6 +649,8 @@ fn phase_label(action: &CreateAction) -> &'static str {
         CreateAction::DryRun => "dry-run",
         CreateAction::Committed => "committed",
         CreateAction::PrCreated => "pr-created",
+        CreateAction::Reverted => "reverted",
+        CreateAction::Skipped => "skipped",
     }
 }
 
@@ -352,6 +699,7 @@ fn run_llm(
 
     let repos = discover_repos(start_dir, max_depth, &config.ignore_patterns())
         .context("Failed to discover repositories")?;
+    output::warn_slug_collisions(&repos, cli.quiet);
     let filtered_repos = filter_repos(repos, patterns);
     if filtered_repos.is_empty() {
         println!("No repositories found matching the specified patterns.");
@@ -613,7 +961,9 @@ fn render_apply_report(cli: &Cli, report: &core::apply::ApplyReport) {
             local::config::OutputVerbosity::Summary
         },
         use_emoji: true,
-        use_colors: true,
+        use_colors: output::should_use_color(cli.color),
+        quiet: cli.quiet,
+        ..Default::default()
     };
     display_unified_results(&report.results, &opts);
     println!(
@@ -720,6 +1070,18 @@ fn display_pattern_analysis(results: &[CreateResult], opts: &StatusOptions) {
         .map(|s| s.files_skipped_binary)
         .sum::<usize>();
 
+    let files_skipped_unparsable = results
+        .iter()
+        .filter_map(|r| r.substitution_stats.as_ref())
+        .map(|s| s.files_skipped_unparsable)
+        .sum::<usize>();
+
+    let files_emptied = results
+        .iter()
+        .filter_map(|r| r.substitution_stats.as_ref())
+        .map(|s| s.files_emptied.len())
+        .sum::<usize>();
+
     if total_files_scanned > 0 {
         if opts.use_emoji {
             println!("\n🔍 Pattern Analysis:");
@@ -737,6 +1099,12 @@ fn display_pattern_analysis(results: &[CreateResult], opts: &StatusOptions) {
             if files_skipped_binary > 0 {
                 println!("   ⏩  Binary files skipped: {files_skipped_binary}");
             }
+            if files_skipped_unparsable > 0 {
+                println!("   ⏩  Unparsable files skipped: {files_skipped_unparsable}");
+            }
+            if files_emptied > 0 {
+                println!("   🕳️  Files that would become empty: {files_emptied}");
+            }
 
             if files_changed == 0 && total_files_scanned > 0 {
                 println!("   🚨  No files were modified by the pattern");
@@ -757,6 +1125,12 @@ fn display_pattern_analysis(results: &[CreateResult], opts: &StatusOptions) {
             if files_skipped_binary > 0 {
                 println!("   Binary files skipped: {files_skipped_binary}");
             }
+            if files_skipped_unparsable > 0 {
+                println!("   Unparsable files skipped: {files_skipped_unparsable}");
+            }
+            if files_emptied > 0 {
+                println!("   Files that would become empty: {files_emptied}");
+            }
 
             if files_changed == 0 && total_files_scanned > 0 {
                 println!("   Warning: No files were modified by the pattern");
@@ -805,6 +1179,14 @@ fn display_create_summary(results: &[CreateResult], opts: &StatusOptions) {
         .iter()
         .filter(|r| matches!(r.action, CreateAction::PrCreated))
         .count();
+    let reverted = results
+        .iter()
+        .filter(|r| matches!(r.action, CreateAction::Reverted))
+        .count();
+    let skipped = results
+        .iter()
+        .filter(|r| matches!(r.action, CreateAction::Skipped))
+        .count();
 
     let total_files: usize = results.iter().map(|r| r.files_affected.len()).sum();
 
@@ -822,6 +1204,12 @@ fn display_create_summary(results: &[CreateResult], opts: &StatusOptions) {
         if prs_created > 0 {
             println!("   📥 {prs_created} PRs created");
         }
+        if reverted > 0 {
+            println!("   ⏪ {reverted} reverted");
+        }
+        if skipped > 0 {
+            println!("   ⏭️  {skipped} skipped");
+        }
         println!("   📄 {total_files} files affected");
         if errors > 0 {
             println!("   ❌ {errors} errors");
@@ -840,6 +1228,12 @@ fn display_create_summary(results: &[CreateResult], opts: &StatusOptions) {
         if prs_created > 0 {
             println!("   {prs_created} PRs created");
         }
+        if reverted > 0 {
+            println!("   {reverted} reverted");
+        }
+        if skipped > 0 {
+            println!("   {skipped} skipped");
+        }
         println!("   {total_files} files affected");
         if errors > 0 {
             println!("   {errors} errors");
@@ -11,41 +11,55 @@
 pub mod apply;
 pub mod manifest;
 pub mod propose;
+pub mod revert;
 
 use crate::confirm::Confirmation;
 use crate::git;
 use crate::github;
 use crate::state::{ChangeState, StateManager};
+use crate::stats::RunStats;
 use crate::transaction::{RollbackStep, Transaction};
 use chrono::Local;
 use eyre::{Context, Result};
-use local::config::Config;
+use local::config::{Config, PrType};
 use local::diff;
 use local::file;
+use local::git::CommitOptions;
 use local::repo::Repo;
 use log::{debug, info, warn};
 use manifest::{FileAction, ProposalManifest, ProposalOutcome};
 use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
 /// Statistics for substitution operations
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct SubstitutionStats {
     pub files_scanned: usize,
     pub files_changed: usize,
     pub files_no_matches: usize,
     pub files_no_change: usize,
     pub files_skipped_binary: usize,
+    /// `set-key` files that didn't parse as JSON/YAML (JSONC, multi-document
+    /// YAML, ...), skipped with a warning.
+    #[serde(default)]
+    pub files_skipped_unparsable: usize,
     pub total_matches: usize,
+    /// Changed files whose new content is empty or whitespace-only: usually
+    /// a runaway pattern rather than the intent.
+    pub files_emptied: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
 pub enum Change {
-    Add(String, String),   // path, content
-    Delete,                // delete matched files
-    Sub(String, String),   // pattern, replacement
-    Regex(String, String), // regex pattern, replacement
+    Add(String, String),                 // path, content
+    Append(String, String, bool),        // path, content, only if missing
+    Delete,                              // delete matched files
+    Sub(String, String, diff::SubMatch), // pattern, replacement, match mode
+    Regex(String, String),               // regex pattern, replacement
+    SetKey(String, String, bool),        // dotted key path, value, create missing keys
     /// An agent-generated change (the prompt). Handled by the fleet-level
     /// PROPOSE pass ([`propose::execute_propose`]), NOT by the per-repo
     /// `process_single_repo` pipeline: propose/present/confirm is a fleet
@@ -70,8 +84,138 @@ pub enum Change {
     },
 }
 
-#[derive(Debug, Clone)]
+/// `--base` sentinel for "this repo's default branch", so one run can target
+/// orgs whose repos default to different branches.
+pub const DEFAULT_PR_BASE: &str = "{default}";
+
+/// Per-run knobs for [`execute_create`] that shape HOW a change is written
+/// and committed, as opposed to WHAT the change is ([`Change`]). `Default` is
+/// the historical behavior.
+#[derive(Debug, Clone, Default)]
+pub struct CreateOptions {
+    /// Flags for the `git commit` itself (signing, trailers, ...).
+    pub commit: CommitOptions,
+    /// Append a `Change-Id: <change_id>` trailer after any `--trailer`s, so
+    /// the commit can be traced back to its gx change.
+    pub change_id_trailer: bool,
+    /// Normalize every written/added file to end with exactly one newline.
+    pub ensure_final_newline: bool,
+    /// Shell command run in each repo, with the changed files as its
+    /// arguments, after the change is applied and before it is committed.
+    pub format_cmd: Option<String>,
+    /// Annotated tag created on each repo's commit and pushed after the
+    /// branch; `{change_id}` is interpolated.
+    pub tag: Option<String>,
+    /// The tag's message; defaults to the commit message.
+    pub tag_message: Option<String>,
+    /// Fail a repo whose `sub`/`regex` change would leave a file empty (or
+    /// whitespace-only) instead of just counting it.
+    pub no_empty_files: bool,
+    /// Keep tracked files the repo's `.gitignore` matches when resolving
+    /// `--files` patterns; they are skipped by default.
+    pub no_gitignore: bool,
+    /// Fail a repo whose `sub`/`regex` change would modify more than this
+    /// many files, rolling it back. `None` is no limit.
+    pub limit_files: Option<usize>,
+    /// Commit onto the branch each repo is already on instead of a new
+    /// change-id branch (no pull, no PR). A repo on its default branch is
+    /// refused.
+    pub target_existing_branch: bool,
+    /// Expand `{repo}`, `{slug}` and `{org}` in `add`/`append` content per repo.
+    pub interpolate: bool,
+    /// PR body template (`--pr-body`, or `--pr-body-file`'s contents) in
+    /// place of the configured one; `{change_id}`, `{repo}`, `{files}` and
+    /// `{commit_message}` are interpolated per repo.
+    pub pr_body: Option<String>,
+    /// Use the repo's `.github/PULL_REQUEST_TEMPLATE.md` as the PR body
+    /// template when it has one (an explicit `pr_body` still wins).
+    pub pr_use_template: bool,
+    /// Branch the PRs target (`--base`); `None` or [`DEFAULT_PR_BASE`] is
+    /// each repo's default branch.
+    pub pr_base: Option<String>,
+    /// Reviewers, assignees and labels for every PR the run opens.
+    pub pr_metadata: github::PrMetadata,
+    /// Draw a `[n/total] creating...` counter on stderr as repos finish. The
+    /// caller decides (`--progress`, a terminal, not `--quiet`).
+    pub progress: bool,
+    /// Dry run: print each repo's diff (a deleted file's content) under its
+    /// file list. Display only; the core ignores it.
+    pub detailed: bool,
+    /// Print the results as a JSON array instead of the human display and
+    /// pattern analysis. Display only; the core ignores it.
+    pub json: bool,
+    /// The pool one repo's matched files for a `sub`/`regex`/`set-key`
+    /// change are worked on (`--parallel-files`, built once per run by
+    /// [`file_pool`] and shared by every repo). `None` shares the repo
+    /// pool's (`-j`) threads.
+    pub file_pool: Option<Arc<rayon::ThreadPool>>,
+    /// Where the run's phase and per-repo timings go; the core times each
+    /// repo into it, and `--stats` prints it.
+    pub stats: Arc<RunStats>,
+}
+
+impl CreateOptions {
+    /// The commit options for `change_id`'s commit: `--trailer`s in order,
+    /// then the `Change-Id` trailer if asked for.
+    fn commit_for(&self, change_id: &str) -> CommitOptions {
+        let mut commit = self.commit.clone();
+        if self.change_id_trailer {
+            commit.trailers.push(format!("Change-Id: {change_id}"));
+        }
+        commit
+    }
+
+    /// The branch `repo`'s PR targets: `--base`, or the repo's default
+    /// branch when unset or [`DEFAULT_PR_BASE`]. An explicit base must exist
+    /// on `origin`; GitHub would otherwise reject the PR after the push.
+    fn pr_base_for(&self, repo: &Repo, config: &Config) -> Result<String> {
+        match self.pr_base.as_deref() {
+            None | Some(DEFAULT_PR_BASE) => Ok(resolve_base_branch(repo, config)),
+            Some(base) => {
+                if git::remote_branch_exists_probe(&repo.path, base)? {
+                    Ok(base.to_string())
+                } else {
+                    Err(eyre::eyre!(
+                        "PR base branch '{base}' does not exist on origin"
+                    ))
+                }
+            }
+        }
+    }
+
+    /// The tag name and message for `change_id`'s commit, if tagging.
+    fn tag_for(&self, change_id: &str, commit_message: &str) -> Option<(String, String)> {
+        self.tag.as_ref().map(|tag| {
+            (
+                tag.replace("{change_id}", change_id),
+                self.tag_message
+                    .clone()
+                    .unwrap_or_else(|| commit_message.to_string()),
+            )
+        })
+    }
+
+    /// The content an `add` writes into `repo`: with `{repo}` (its name),
+    /// `{slug}` and `{org}` expanded under `--interpolate`, as given otherwise.
+    fn add_content_for<'a>(&self, content: &'a str, repo: &Repo) -> Cow<'a, str> {
+        if !self.interpolate {
+            return Cow::Borrowed(content);
+        }
+        let org = repo.slug.split_once('/').map_or("", |(org, _)| org);
+        Cow::Owned(
+            content
+                .replace("{repo}", &repo.name)
+                .replace("{slug}", &repo.slug)
+                .replace("{org}", org),
+        )
+    }
+}
+
+/// One repo's outcome. Serializes (`gx create --json`) with the repo as its
+/// slug.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateResult {
+    #[serde(with = "repo_as_slug")]
     pub repo: Repo,
     pub change_id: String,
     pub action: CreateAction,
@@ -81,6 +225,9 @@ pub struct CreateResult {
     pub pr_url: Option<String>,
     /// The branch the repo was on before the change (for state tracking).
     pub original_branch: Option<String>,
+    /// The branch the commit landed on: the change-id branch, or the current
+    /// branch with `--target-existing-branch`. `None` until a commit lands.
+    pub branch: Option<String>,
     /// The pre-commit HEAD of the base branch (the safe point), set once a
     /// commit lands. `None` for dry runs and pre-commit failures.
     pub base_sha: Option<String>,
@@ -91,14 +238,35 @@ pub struct CreateResult {
     /// mutation started, or no files affected).
     pub diff: Option<String>,
     pub error: Option<String>,
+    /// A problem that did not fail the repo (a PR opened without the
+    /// reviewers/assignees it was asked for).
+    pub warning: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum CreateAction {
     DryRun, // No changes made (preview)
 
     Committed, // Changes committed to branch
     PrCreated, // PR created successfully
+    Reverted,  // A committed change reverted (`gx create revert`)
+    Skipped,   // Left untouched: an operation is in progress (`warning` says which)
+}
+
+/// (De)serialize a [`Repo`] as its `org/name` slug. The path is local to
+/// the machine that ran the change, so it is not part of the JSON.
+mod repo_as_slug {
+    use local::repo::Repo;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(repo: &Repo, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&repo.slug)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Repo, D::Error> {
+        String::deserialize(deserializer).map(Repo::from_slug)
+    }
 }
 
 /// Generate a default change ID based on current timestamp
@@ -141,6 +309,7 @@ pub fn execute_create(
     commit_message: Option<&str>,
     pr: bool,
     draft: bool,
+    options: &CreateOptions,
     config: &Config,
     parallel_jobs: usize,
     confirmation: Confirmation,
@@ -203,25 +372,32 @@ pub fn execute_create(
     // the whole result (including any PR) is known. This fold is display-only;
     // the `Mutex<ChangeState>` + `StateManager` are passed in, and each worker
     // locks briefly to update just its own repo's entry, same as before.
+    let progress = crate::output::ProgressReporter::new(repos.len(), "creating", options.progress);
     let results: Vec<CreateResult> = pool.install(|| {
         repos
             .par_iter()
             .map(|repo| {
-                process_single_repo(
-                    repo,
-                    change_id,
-                    files,
-                    change,
-                    commit_message,
-                    pr,
-                    draft,
-                    config,
-                    change_state.as_ref(),
-                    state_manager.as_ref(),
-                )
+                let result = options.stats.repo(&repo.slug, || {
+                    process_single_repo(
+                        repo,
+                        change_id,
+                        files,
+                        change,
+                        commit_message,
+                        pr,
+                        draft,
+                        options,
+                        config,
+                        change_state.as_ref(),
+                        state_manager.as_ref(),
+                    )
+                });
+                progress.tick();
+                result
             })
             .collect()
     });
+    progress.finish();
 
     if let Some(state_mutex) = change_state {
         if let Ok(state) = state_mutex.into_inner() {
@@ -239,8 +415,12 @@ fn update_change_state(state: &mut ChangeState, result: &CreateResult, draft: bo
     // Only track if the operation actually did something
     match result.action {
         CreateAction::Committed | CreateAction::PrCreated => {
-            // Add repository to state
-            state.add_repository(result.repo.slug.clone(), result.change_id.clone());
+            // Add repository to state, under the branch the commit landed on
+            let branch = result
+                .branch
+                .clone()
+                .unwrap_or_else(|| result.change_id.clone());
+            state.add_repository(result.repo.slug.clone(), branch);
 
             // Update local path and files modified
             if let Some(repo_state) = state.repositories.get_mut(&result.repo.slug) {
@@ -256,8 +436,8 @@ fn update_change_state(state: &mut ChangeState, result: &CreateResult, draft: bo
                 }
             }
         }
-        CreateAction::DryRun => {
-            // Don't track dry runs
+        CreateAction::DryRun | CreateAction::Reverted | CreateAction::Skipped => {
+            // Don't track dry runs or skips; a revert records its own state
         }
     }
 }
@@ -278,9 +458,11 @@ fn dry_run_error(
         pr_number: None,
         pr_url: None,
         original_branch: None,
+        branch: None,
         base_sha: None,
         diff: join_diff(diff_parts),
         error: Some(error),
+        warning: None,
     }
 }
 
@@ -306,6 +488,7 @@ fn process_single_repo(
     commit_message: Option<&str>,
     pr: bool,
     draft: bool,
+    options: &CreateOptions,
     config: &Config,
     change_state: Option<&Mutex<ChangeState>>,
     state_manager: Option<&StateManager>,
@@ -317,6 +500,9 @@ fn process_single_repo(
     let repo_path = &repo.path;
     let committing = commit_message.is_some();
     let mut diff_parts: Vec<String> = Vec::new();
+    // `--pr` without `--draft` takes the org's default PR type.
+    let org = repo.slug.split('/').next().unwrap_or_default();
+    let draft = draft || config.for_org(org).default_pr_type == PrType::Draft;
 
     // Test-only fault injection (inert unless GX_TEST_FORCE_REPO_ERROR names
     // this repo), same "compiled in, inert by default" shape as
@@ -357,6 +543,17 @@ fn process_single_repo(
         }
     };
 
+    // A half-done rebase/merge/... is the user's; gx stashing, switching and
+    // pulling over it would corrupt it.
+    if let Some(skip) = git::in_progress_skip(repo) {
+        return CreateResult {
+            action: CreateAction::Skipped,
+            error: None,
+            warning: Some(skip),
+            ..dry_run_error(repo, change_id, String::new(), &diff_parts)
+        };
+    }
+
     let mut transaction = Transaction::new(repo_path.clone(), change_id.to_string(), committing);
     let mut files_affected = Vec::new();
 
@@ -489,7 +686,19 @@ fn process_single_repo(
             &diff_parts,
         );
     }
-    if head != original_branch {
+    // Committing onto the current branch must never land on the default one.
+    if options.target_existing_branch && head == original_branch {
+        transaction.rollback();
+        return dry_run_error(
+            repo,
+            change_id,
+            format!(
+                "Refusing to commit to default branch '{head}' (--target-existing-branch); check out a feature branch first"
+            ),
+            &diff_parts,
+        );
+    }
+    if !options.target_existing_branch && head != original_branch {
         if let Err(e) = local::git::switch_branch(repo_path, &head) {
             transaction.rollback();
             return dry_run_error(
@@ -501,8 +710,11 @@ fn process_single_repo(
         }
     }
 
-    // 4. Pull latest changes.
-    if let Err(e) = git::pull_latest_changes(repo_path) {
+    // 4. Pull latest changes. An in-progress branch is left exactly as the
+    //    user has it (it may have no upstream at all).
+    if options.target_existing_branch {
+        debug!("process_single_repo: committing onto {original_branch}; skipping pull");
+    } else if let Err(e) = git::pull_latest_changes(repo_path) {
         transaction.rollback();
         return dry_run_error(
             repo,
@@ -512,13 +724,38 @@ fn process_single_repo(
         );
     }
 
+    // 4b. Resolve the PR's base now, so a missing `--base` fails the repo
+    //     before anything is changed or pushed.
+    let pr_base = if committing && pr && !options.target_existing_branch {
+        match options.pr_base_for(repo, config) {
+            Ok(base) => Some(base),
+            Err(e) => {
+                transaction.rollback();
+                return dry_run_error(repo, change_id, e.to_string(), &diff_parts);
+            }
+        }
+    } else {
+        None
+    };
+
     // 5. Apply the change (each registers its undo step write-ahead).
     let mut substitution_stats = None;
     let change_result = match change {
         Change::Add(path, content) => apply_add_change(
             repo_path,
             path,
-            content,
+            &options.add_content_for(content, repo),
+            options.ensure_final_newline,
+            &mut transaction,
+            &mut files_affected,
+            &mut diff_parts,
+        ),
+        Change::Append(path, content, if_missing) => apply_append_change(
+            repo_path,
+            path,
+            &options.add_content_for(content, repo),
+            *if_missing,
+            options.ensure_final_newline,
             &mut transaction,
             &mut files_affected,
             &mut diff_parts,
@@ -526,15 +763,20 @@ fn process_single_repo(
         Change::Delete => apply_delete_change(
             repo_path,
             file_patterns,
+            options.no_gitignore,
             &mut transaction,
             &mut files_affected,
             &mut diff_parts,
         ),
-        Change::Sub(pattern, replacement) => apply_substitution_change(
+        Change::Sub(pattern, replacement, matching) => apply_substitution_change(
             repo_path,
             file_patterns,
+            options.no_gitignore,
             pattern,
             replacement,
+            *matching,
+            options.ensure_final_newline,
+            options.file_pool.as_deref(),
             &mut transaction,
             &mut files_affected,
             &mut diff_parts,
@@ -543,8 +785,25 @@ fn process_single_repo(
         Change::Regex(pattern, replacement) => apply_regex_change(
             repo_path,
             file_patterns,
+            options.no_gitignore,
             pattern,
             replacement,
+            options.ensure_final_newline,
+            options.file_pool.as_deref(),
+            &mut transaction,
+            &mut files_affected,
+            &mut diff_parts,
+        )
+        .map(|stats| substitution_stats = Some(stats)),
+        Change::SetKey(key_path, value, create) => apply_set_key_change(
+            repo_path,
+            file_patterns,
+            options.no_gitignore,
+            key_path,
+            value,
+            *create,
+            options.ensure_final_newline,
+            options.file_pool.as_deref(),
             &mut transaction,
             &mut files_affected,
             &mut diff_parts,
@@ -586,6 +845,39 @@ fn process_single_repo(
         return result;
     }
 
+    // 5a. Refuse a change that empties files, before it can be committed.
+    if options.no_empty_files {
+        let emptied = substitution_stats
+            .as_ref()
+            .map(|stats| stats.files_emptied.as_slice())
+            .unwrap_or_default();
+        if !emptied.is_empty() {
+            let error = format!(
+                "Change would empty {} file(s) (--no-empty-files): {}",
+                emptied.len(),
+                emptied.join(", ")
+            );
+            transaction.rollback();
+            let mut result = dry_run_error(repo, change_id, error, &diff_parts);
+            result.substitution_stats = substitution_stats;
+            return result;
+        }
+    }
+
+    // 5a'. Refuse a change wider than --limit-files, before it can be committed.
+    if let (Some(limit), Some(stats)) = (options.limit_files, substitution_stats.as_ref()) {
+        if stats.files_changed > limit {
+            let error = format!(
+                "Change would modify {} files (limit {limit})",
+                stats.files_changed
+            );
+            transaction.rollback();
+            let mut result = dry_run_error(repo, change_id, error, &diff_parts);
+            result.substitution_stats = substitution_stats;
+            return result;
+        }
+    }
+
     // No files affected, or dry run: roll back (restores worktree, branch, stash).
     if files_affected.is_empty() || !committing {
         transaction.rollback();
@@ -602,23 +894,71 @@ fn process_single_repo(
             pr_number: None,
             pr_url: None,
             original_branch: Some(original_branch.clone()),
+            branch: None,
             base_sha: None,
             diff: join_diff(&diff_parts),
             error: None,
+            warning: None,
         };
     }
 
+    // 5b. Run the formatter over the changed files. Every file it is handed
+    //     already has its restore step registered, so a formatter failure
+    //     rolls back to the untouched tree rather than committing half of it.
+    //     Files it wrote beyond those have no restore step: they are put back
+    //     here and fail the repo. The diff is then redone from what the
+    //     formatter left, which is what gets committed.
+    if let Some(format_cmd) = &options.format_cmd {
+        let formatted = run_formatter(repo_path, format_cmd, &files_affected)
+            .map_err(|e| format!("Formatter failed: {e}"));
+        let strays = restore_stray_writes(repo_path, &files_affected)
+            .map_err(|e| format!("could not restore the formatter's other writes: {e}"));
+        let error = match (formatted, strays) {
+            (Ok(()), Ok(strays)) if strays.is_empty() => {
+                match head_diff_parts(repo_path, &files_affected) {
+                    Ok(parts) => {
+                        diff_parts = parts;
+                        None
+                    }
+                    Err(e) => Some(format!("Failed to diff the formatted files: {e}")),
+                }
+            }
+            (Ok(()), Ok(strays)) => Some(format!(
+                "Formatter changed files outside the change: {}",
+                strays.join(", ")
+            )),
+            (Err(e), Ok(_)) | (Ok(()), Err(e)) => Some(e),
+            (Err(e), Err(restore)) => Some(format!("{e}; {restore}")),
+        };
+        if let Some(error) = error {
+            transaction.rollback();
+            let mut result = dry_run_error(repo, change_id, error, &diff_parts);
+            result.substitution_stats = substitution_stats;
+            return result;
+        }
+    }
+
     let commit_message = commit_message.unwrap_or_default();
+    let tag = options.tag_for(change_id, commit_message);
+    let commit_branch = if options.target_existing_branch {
+        original_branch.clone()
+    } else {
+        change_id.to_string()
+    };
 
-    // 6. branch → stage → commit → push (each undo persisted write-ahead).
-    let base_sha = match commit_changes_with_rollback(
+    // 6. branch → stage → commit → tag → push (each undo persisted write-ahead).
+    let pushed = match commit_changes_with_rollback(
         repo_path,
-        change_id,
+        &commit_branch,
+        !options.target_existing_branch,
         commit_message,
         &files_affected,
+        &options.commit_for(change_id),
+        tag.as_ref()
+            .map(|(name, message)| (name.as_str(), message.as_str())),
         &mut transaction,
     ) {
-        Ok(base_sha) => base_sha,
+        Ok(pushed) => pushed,
         Err(e) => {
             transaction.rollback();
             let mut result = dry_run_error(
@@ -643,8 +983,9 @@ fn process_single_repo(
         change_id,
         &original_branch,
         &files_affected,
-        &base_sha,
+        &pushed,
     );
+    let base_sha = pushed.base_sha;
 
     // 6c. F12 fail-closed (post-audit hardening): the recovery file may be
     //     deleted (which finalize() does) ONLY when the pushed safe point was
@@ -679,9 +1020,11 @@ fn process_single_repo(
             pr_number: None,
             pr_url: None,
             original_branch: Some(original_branch.clone()),
+            branch: Some(commit_branch.clone()),
             base_sha: Some(base_sha),
             diff: join_diff(&diff_parts),
             error: Some(error),
+            warning: None,
         };
     }
 
@@ -700,36 +1043,68 @@ fn process_single_repo(
                 pr_number: None,
                 pr_url: None,
                 original_branch: Some(original_branch.clone()),
+                branch: Some(commit_branch.clone()),
                 base_sha: Some(base_sha),
                 diff: join_diff(&diff_parts),
                 error: Some(format!("Committed and pushed, but finalize failed: {e}")),
+                warning: None,
             };
             record_final_state(change_state, state_manager, &result, draft);
             return result;
         }
     };
 
+    // 7b. Push the tag. The branch is already shared, so a failure here is
+    //     surfaced on the result (the local tag stays for a manual push). The
+    //     tag is already recorded at the pushed safe point, so `gx undo`
+    //     deletes it from the remote along with the branch; a failed push
+    //     drops it from state again, since origin may hold someone else's tag
+    //     of that name.
+    let tag_error = tag.and_then(|(name, _)| {
+        let e = git::push_tag(repo_path, &name, options.commit.no_verify).err()?;
+        forget_pushed_tag(change_state, repo);
+        Some(format!("Tag push failed: {e}"))
+    });
+
     // 8. Create the PR against the (already-restored) remote. A PR failure is
     //    surfaced on the result, not swallowed ([A4]; Phase 5 refines).
-    let (action, pr_number, pr_url, mut error) = if pr {
-        match create_pull_request(repo, change_id, commit_message, draft, config) {
+    let (action, pr_number, pr_url, mut error, warning) = if let Some(base) = pr_base {
+        match create_pull_request(
+            repo,
+            &base,
+            change_id,
+            commit_message,
+            &files_affected,
+            draft,
+            options,
+            config,
+        ) {
             Ok(result) => (
                 CreateAction::PrCreated,
                 Some(result.number),
                 Some(result.url),
                 None,
+                result.warning,
             ),
             Err(e) => (
                 CreateAction::Committed,
                 None,
                 None,
                 Some(format!("PR creation failed: {e}")),
+                None,
             ),
         }
     } else {
-        (CreateAction::Committed, None, None, None)
+        (CreateAction::Committed, None, None, None, None)
     };
 
+    if let Some(tag_err) = tag_error {
+        error = Some(match error {
+            Some(existing) => format!("{existing}; {tag_err}"),
+            None => tag_err,
+        });
+    }
+
     // A stash-restore conflict is surfaced (design Q2): committed, but the user's
     // WIP could not be re-applied; the stash is preserved for manual recovery.
     if let Some((sha, msg)) = finalize_outcome.stash_error {
@@ -751,14 +1126,126 @@ fn process_single_repo(
         pr_number,
         pr_url,
         original_branch: Some(original_branch.clone()),
+        branch: Some(commit_branch),
         base_sha: Some(base_sha),
         diff: join_diff(&diff_parts),
         error,
+        warning,
     };
     record_final_state(change_state, state_manager, &result, draft);
     result
 }
 
+/// Run `format_cmd` through `sh -c` in `repo_path` with the changed files that
+/// still exist as its arguments (a deleted file has nothing to format).
+fn run_formatter(repo_path: &Path, format_cmd: &str, files: &[String]) -> Result<()> {
+    let existing: Vec<&String> = files
+        .iter()
+        .filter(|f| repo_path.join(f).is_file())
+        .collect();
+    debug!(
+        "run_formatter: repo_path={} cmd={format_cmd:?} files={}",
+        repo_path.display(),
+        existing.len()
+    );
+    if existing.is_empty() {
+        return Ok(());
+    }
+
+    let output = local::subprocess::run_checked(
+        std::process::Command::new("sh")
+            .arg("-c")
+            .arg(format!("{format_cmd} \"$@\""))
+            .arg("gx-format")
+            .args(&existing)
+            .current_dir(repo_path),
+        local::subprocess::subprocess_timeout(),
+    )
+    .with_context(|| format!("Failed to run `{format_cmd}`"))?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(eyre::eyre!(
+            "`{format_cmd}` exited with {}: {}",
+            output.status,
+            stderr.trim()
+        ))
+    }
+}
+
+/// Put back every path dirty in `repo_path` other than `files` and return
+/// them: the user's work is stashed before the change is applied, so these
+/// are a formatter's writes outside the change (`cargo fmt` and
+/// `prettier --write .` ignore their arguments). Tracked files are checked
+/// out again and new ones removed.
+fn restore_stray_writes(repo_path: &Path, files: &[String]) -> Result<Vec<String>> {
+    let output = local::git::GitCmd::new(repo_path)
+        .args(["status", "--porcelain=v1", "-z", "--untracked-files=all"])
+        .run("list the formatter's writes")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut strays = Vec::new();
+    for entry in stdout.split('\0').filter(|entry| entry.len() > 3) {
+        let (code, path) = entry.split_at(3);
+        if files.iter().any(|file| file == path) {
+            continue;
+        }
+        if code == "?? " {
+            std::fs::remove_file(repo_path.join(path))
+                .with_context(|| format!("Failed to remove {path}"))?;
+        } else {
+            local::git::GitCmd::new(repo_path)
+                .args(["checkout", "--", path])
+                .run(&format!("restore {path}"))?;
+        }
+        strays.push(path.to_string());
+    }
+    debug!(
+        "restore_stray_writes: repo_path={} strays={strays:?}",
+        repo_path.display()
+    );
+    Ok(strays)
+}
+
+/// The diff of each of `files` against HEAD, in the `diff_parts` format.
+fn head_diff_parts(repo_path: &Path, files: &[String]) -> Result<Vec<String>> {
+    files
+        .iter()
+        .map(|path| {
+            let head = local::git::GitCmd::new(repo_path)
+                .arg("show")
+                .arg(format!("HEAD:{path}"))
+                .output()?;
+            let original = head
+                .status
+                .success()
+                .then(|| String::from_utf8_lossy(&head.stdout).into_owned());
+            let full_path = repo_path.join(path);
+            let current = full_path
+                .exists()
+                .then(|| std::fs::read(&full_path))
+                .transpose()
+                .with_context(|| format!("Failed to read {path}"))?
+                .map(|bytes| String::from_utf8_lossy(&bytes).into_owned());
+            let code = match (&original, &current) {
+                (None, _) => "A",
+                (_, None) => "D",
+                _ => "M",
+            };
+            let diff = diff::generate_diff(
+                original.as_deref().unwrap_or_default(),
+                current.as_deref().unwrap_or_default(),
+                3,
+            );
+            Ok(format!(
+                "  {code} {path}\n{}",
+                local::utils::indent(&diff, 4)
+            ))
+        })
+        .collect()
+}
+
 /// Record the just-pushed branch in change state (the F12 safe point): saved
 /// BEFORE `finalize()` runs, so a crash during finalize (which deletes the
 /// recovery file) still leaves this repo recorded in at least one store.
@@ -776,11 +1263,11 @@ fn record_pushed_state(
     change_id: &str,
     original_branch: &str,
     files_affected: &[String],
-    base_sha: &str,
+    pushed: &PushedCommit,
 ) -> bool {
     debug!(
-        "record_pushed_state: repo={} change_id={change_id} base_sha={base_sha}",
-        repo.slug
+        "record_pushed_state: repo={} change_id={change_id} branch={} base_sha={}",
+        repo.slug, pushed.branch, pushed.base_sha
     );
     let Some(state_mutex) = change_state else {
         warn!(
@@ -796,12 +1283,15 @@ fn record_pushed_state(
         );
         return false;
     };
-    state.add_repository(repo.slug.clone(), change_id.to_string());
+    state.add_repository(repo.slug.clone(), pushed.branch.clone());
     if let Some(repo_state) = state.repositories.get_mut(&repo.slug) {
         repo_state.local_path = Some(repo.path.to_string_lossy().to_string());
         repo_state.files_modified = files_affected.to_vec();
         repo_state.original_branch = Some(original_branch.to_string());
-        repo_state.base_sha = Some(base_sha.to_string());
+        repo_state.base_sha = Some(pushed.base_sha.clone());
+        repo_state.existing_branch = pushed.existing_branch;
+        repo_state.commit_sha = Some(pushed.commit_sha.clone());
+        repo_state.tag = pushed.tag.clone();
     }
     let Some(manager) = state_manager else {
         warn!(
@@ -822,9 +1312,22 @@ fn record_pushed_state(
     }
 }
 
+/// Drop the tag recorded at the pushed safe point after its push failed, so
+/// `gx undo` never deletes a remote tag gx did not push. Saved along with the
+/// repo's final state.
+fn forget_pushed_tag(change_state: Option<&Mutex<ChangeState>>, repo: &Repo) {
+    let Some(Ok(mut state)) = change_state.map(Mutex::lock) else {
+        return;
+    };
+    if let Some(repo_state) = state.repositories.get_mut(&repo.slug) {
+        repo_state.tag = None;
+    }
+}
+
 /// Fold a finished repo's result into change state and save. This is now the
 /// ONLY place a finished repo's outcome is saved (the caller's outer rayon
 /// fold is display-only, Phase 4 control-flow refactor). Re-records `base_sha`
+/// and carries over the pushed safe point's `existing_branch`/`commit_sha`/`tag`,
 /// since `update_change_state` -> `add_repository` resets the entry.
 fn record_final_state(
     change_state: Option<&Mutex<ChangeState>>,
@@ -846,9 +1349,21 @@ fn record_final_state(
         );
         return;
     };
+    let pushed = state.repositories.get(&result.repo.slug).map(|prev| {
+        (
+            prev.existing_branch,
+            prev.commit_sha.clone(),
+            prev.tag.clone(),
+        )
+    });
     update_change_state(&mut state, result, draft);
     if let Some(repo_state) = state.repositories.get_mut(&result.repo.slug) {
         repo_state.base_sha = result.base_sha.clone();
+        if let Some((existing_branch, commit_sha, tag)) = pushed {
+            repo_state.existing_branch = existing_branch;
+            repo_state.commit_sha = commit_sha;
+            repo_state.tag = tag;
+        }
     }
     if matches!(
         result.action,
@@ -867,6 +1382,7 @@ fn apply_add_change(
     repo_path: &Path,
     file_path: &str,
     content: &str,
+    ensure_final_newline: bool,
     transaction: &mut Transaction,
     files_affected: &mut Vec<String>,
     diff_parts: &mut Vec<String>,
@@ -886,7 +1402,7 @@ fn apply_add_change(
     })?;
 
     // Create file and generate diff
-    let (_, diff) = file::create_file_with_content(&full_path, content, 3)?;
+    let (_, diff) = file::create_file_with_content(&full_path, content, 3, ensure_final_newline)?;
 
     files_affected.push(file_path.to_string());
     diff_parts.push(format!(
@@ -898,16 +1414,119 @@ fn apply_add_change(
     Ok(())
 }
 
+/// Append `content` to `file_path`, creating the file (as `add` would) when
+/// it is absent. With `if_missing`, a file that already holds `content` as
+/// whole lines is left alone, so re-running the same append is a no-op.
+#[allow(clippy::too_many_arguments)]
+fn apply_append_change(
+    repo_path: &Path,
+    file_path: &str,
+    content: &str,
+    if_missing: bool,
+    ensure_final_newline: bool,
+    transaction: &mut Transaction,
+    files_affected: &mut Vec<String>,
+    diff_parts: &mut Vec<String>,
+) -> Result<()> {
+    let full_path = file::validate_new_file_path(repo_path, file_path)?;
+    if !full_path.exists() {
+        return apply_add_change(
+            repo_path,
+            file_path,
+            content,
+            ensure_final_newline,
+            transaction,
+            files_affected,
+            diff_parts,
+        );
+    }
+
+    let Some(original) = file::read_utf8_or_skip(&full_path)? else {
+        return Err(eyre::eyre!("Cannot append to binary file: {}", file_path));
+    };
+    if if_missing && contains_lines(&original, content) {
+        debug!("apply_append_change: {file_path} already has the content; skipping");
+        return Ok(());
+    }
+
+    let mut updated = original.clone();
+    if !updated.is_empty() && !updated.ends_with('\n') {
+        updated.push('\n');
+    }
+    updated.push_str(content);
+    if !updated.ends_with('\n') {
+        updated.push('\n');
+    }
+    if ensure_final_newline {
+        updated = file::ensure_final_newline(&updated);
+    }
+
+    // Out-of-tree backup, then write-ahead register the restore.
+    let backup_path = transaction.backup_path_for(Path::new(file_path))?;
+    let mode = file::create_backup(&full_path, &backup_path)?;
+    transaction.push_step(crate::transaction::RollbackStep::RestoreBackup {
+        backup: backup_path,
+        original: full_path.clone(),
+        mode,
+    })?;
+    file::write_file_content(&full_path, &updated, ensure_final_newline)?;
+
+    let diff = diff::generate_diff(&original, &updated, 3);
+    files_affected.push(file_path.to_string());
+    diff_parts.push(format!(
+        "  M {}\n{}",
+        file_path,
+        local::utils::indent(&diff, 4)
+    ));
+
+    Ok(())
+}
+
+/// `updated` as `--ensure-final-newline` writes it, with `diff` redone
+/// against the file still on disk when normalizing changed it, so the diff
+/// (and a dry run's preview) shows exactly what is written.
+fn normalize_final_newline(
+    full_path: &Path,
+    updated: String,
+    diff: String,
+    ensure_final_newline: bool,
+) -> Result<(String, String)> {
+    if !ensure_final_newline {
+        return Ok((updated, diff));
+    }
+    let normalized = file::ensure_final_newline(&updated);
+    if normalized == updated {
+        return Ok((updated, diff));
+    }
+    let original = std::fs::read_to_string(full_path)
+        .with_context(|| format!("Failed to read {}", full_path.display()))?;
+    let diff = diff::generate_diff(&original, &normalized, 3);
+    Ok((normalized, diff))
+}
+
+/// Whether `content`'s lines appear, consecutively and as whole lines, in
+/// `text`: `target` is in a `.gitignore` holding `target`, not one holding
+/// only `target-dir`.
+fn contains_lines(text: &str, content: &str) -> bool {
+    let wanted: Vec<&str> = content.lines().collect();
+    if wanted.is_empty() {
+        return true;
+    }
+    let lines: Vec<&str> = text.lines().collect();
+    lines.windows(wanted.len()).any(|window| window == wanted)
+}
+
 /// Apply delete change (remove matching files)
 fn apply_delete_change(
     repo_path: &Path,
     file_patterns: &[String],
+    include_ignored: bool,
     transaction: &mut Transaction,
     files_affected: &mut Vec<String>,
     diff_parts: &mut Vec<String>,
 ) -> Result<()> {
     // Find tracked files matching all patterns (deduped + sorted).
-    let all_files = file::FileSet::matching_any(repo_path, file_patterns)?;
+    let all_files = file::FileSet::matching(repo_path, file_patterns, include_ignored)?;
 
     for file_path in all_files {
         let full_path = repo_path.join(&file_path);
@@ -945,12 +1564,77 @@ fn apply_delete_change(
     Ok(())
 }
 
+/// The `--parallel-files` pool for a run: `n` threads, shared by every repo,
+/// so `-j` repos at once never multiply into `-j` pools. `None` when unset.
+pub fn file_pool(parallel_files: Option<usize>) -> Result<Option<Arc<rayon::ThreadPool>>> {
+    parallel_files
+        .map(|n| {
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(n)
+                .build()
+                .map(Arc::new)
+                .context("Failed to create file thread pool")
+        })
+        .transpose()
+}
+
+/// How many files [`substitute_files`] computes before writing them: bounds
+/// the changed content held in memory at once.
+const SUBSTITUTE_CHUNK: usize = 64;
+
+/// Run `substitute` over a repo's matched `files` (relative; ones missing
+/// from the worktree are passed over), several at once: on `file_pool` when
+/// set, otherwise nested on the pool the repo itself runs on. Only the
+/// read-and-compute side is concurrent; each result is handed to `write` in
+/// turn, so a `Changed` file's backup is still registered with the
+/// [`Transaction`] before the write. Files go a chunk at a time, in `files`
+/// order (sorted by [`file::FileSet`]), keeping `files_affected`/`diff_parts`
+/// deterministic however the files finish.
+fn substitute_files<F, W>(
+    repo_path: &Path,
+    files: Vec<PathBuf>,
+    file_pool: Option<&rayon::ThreadPool>,
+    substitute: F,
+    mut write: W,
+) -> Result<()>
+where
+    F: Fn(&Path) -> Result<diff::SubstitutionResult> + Sync,
+    W: FnMut(PathBuf, diff::SubstitutionResult) -> Result<()>,
+{
+    let files: Vec<PathBuf> = files
+        .into_iter()
+        .filter(|file_path| repo_path.join(file_path).exists())
+        .collect();
+
+    for chunk in files.chunks(SUBSTITUTE_CHUNK) {
+        let compute = || -> Result<Vec<diff::SubstitutionResult>> {
+            chunk
+                .par_iter()
+                .map(|file_path| substitute(&repo_path.join(file_path)))
+                .collect()
+        };
+        let results = match file_pool {
+            Some(pool) => pool.install(compute)?,
+            None => compute()?,
+        };
+        for (file_path, result) in chunk.iter().zip(results) {
+            write(file_path.clone(), result)?;
+        }
+    }
+    Ok(())
+}
+
 /// Apply substitution change
+#[allow(clippy::too_many_arguments)]
 fn apply_substitution_change(
     repo_path: &Path,
     file_patterns: &[String],
+    include_ignored: bool,
     pattern: &str,
     replacement: &str,
+    matching: diff::SubMatch,
+    ensure_final_newline: bool,
+    file_pool: Option<&rayon::ThreadPool>,
     transaction: &mut Transaction,
     files_affected: &mut Vec<String>,
     diff_parts: &mut Vec<String>,
@@ -958,77 +1642,99 @@ fn apply_substitution_change(
     let mut stats = SubstitutionStats::default();
 
     // Find tracked files matching all patterns (deduped + sorted).
-    let all_files = file::FileSet::matching_any(repo_path, file_patterns)?;
+    let all_files = file::FileSet::matching(repo_path, file_patterns, include_ignored)?;
     stats.files_scanned = all_files.len();
 
-    for file_path in all_files {
-        let full_path = repo_path.join(&file_path);
-
-        if !full_path.exists() {
-            continue;
-        }
-
-        // Try to apply substitution
-        match file::apply_substitution_to_file(&full_path, pattern, replacement, 3)? {
-            diff::SubstitutionResult::Changed {
-                content: updated_content,
-                diff,
-                matches,
-            } => {
-                // Out-of-tree backup, then write-ahead register the restore.
-                let backup_path = transaction.backup_path_for(&file_path)?;
-                let mode = file::create_backup(&full_path, &backup_path)?;
-                transaction.push_step(crate::transaction::RollbackStep::RestoreBackup {
-                    backup: backup_path,
-                    original: full_path.clone(),
-                    mode,
-                })?;
-
-                // Write updated content
-                file::write_file_content(&full_path, &updated_content)?;
-
-                files_affected.push(file_path.to_string_lossy().to_string());
-                diff_parts.push(format!(
-                    "  M {}\n{}",
-                    file_path.display(),
-                    local::utils::indent(&diff, 4)
-                ));
+    substitute_files(
+        repo_path,
+        all_files,
+        file_pool,
+        |full_path| file::apply_substitution_to_file(full_path, pattern, replacement, matching, 3),
+        |file_path, result| {
+            let full_path = repo_path.join(&file_path);
+            match result {
+                diff::SubstitutionResult::Changed {
+                    content: updated_content,
+                    diff,
+                    matches,
+                } => {
+                    let (updated_content, diff) = normalize_final_newline(
+                        &full_path,
+                        updated_content,
+                        diff,
+                        ensure_final_newline,
+                    )?;
+
+                    // Out-of-tree backup, then write-ahead register the restore.
+                    let backup_path = transaction.backup_path_for(&file_path)?;
+                    let mode = file::create_backup(&full_path, &backup_path)?;
+                    transaction.push_step(crate::transaction::RollbackStep::RestoreBackup {
+                        backup: backup_path,
+                        original: full_path.clone(),
+                        mode,
+                    })?;
+
+                    // Write updated content
+                    file::write_file_content(&full_path, &updated_content, ensure_final_newline)?;
+
+                    if updated_content.trim().is_empty() {
+                        warn!("Change would leave {} empty", file_path.display());
+                        stats
+                            .files_emptied
+                            .push(file_path.to_string_lossy().to_string());
+                    }
+                    files_affected.push(file_path.to_string_lossy().to_string());
+                    diff_parts.push(format!(
+                        "  M {}\n{}",
+                        file_path.display(),
+                        local::utils::indent(&diff, 4)
+                    ));
 
-                stats.files_changed += 1;
-                stats.total_matches += matches;
-            }
-            diff::SubstitutionResult::NoMatches => {
-                debug!(
-                    "No matches found for pattern '{}' in {}",
-                    pattern,
-                    file_path.display()
-                );
-                stats.files_no_matches += 1;
-            }
-            diff::SubstitutionResult::NoChange { matches } => {
-                debug!(
-                    "Pattern '{}' matched but no changes resulted in {}",
-                    pattern,
-                    file_path.display()
-                );
-                stats.files_no_change += 1;
-                stats.total_matches += matches;
-            }
-            diff::SubstitutionResult::SkippedBinary => {
-                stats.files_skipped_binary += 1;
+                    stats.files_changed += 1;
+                    stats.total_matches += matches;
+                }
+                diff::SubstitutionResult::NoMatches => {
+                    debug!(
+                        "No matches found for pattern '{}' in {}",
+                        pattern,
+                        file_path.display()
+                    );
+                    stats.files_no_matches += 1;
+                }
+                diff::SubstitutionResult::NoChange { matches } => {
+                    debug!(
+                        "Pattern '{}' matched but no changes resulted in {}",
+                        pattern,
+                        file_path.display()
+                    );
+                    stats.files_no_change += 1;
+                    stats.total_matches += matches;
+                }
+                diff::SubstitutionResult::SkippedBinary => {
+                    stats.files_skipped_binary += 1;
+                }
+                diff::SubstitutionResult::SkippedUnparsable(reason) => {
+                    warn!("Skipping {}: {reason}", file_path.display());
+                    stats.files_skipped_unparsable += 1;
+                }
             }
-        }
-    }
+            Ok(())
+        },
+    )?;
 
     Ok(stats)
 }
 
 /// Apply regex change
+#[allow(clippy::too_many_arguments)]
 fn apply_regex_change(
     repo_path: &Path,
     file_patterns: &[String],
+    include_ignored: bool,
     pattern: &str,
     replacement: &str,
+    ensure_final_newline: bool,
+    file_pool: Option<&rayon::ThreadPool>,
     transaction: &mut Transaction,
     files_affected: &mut Vec<String>,
     diff_parts: &mut Vec<String>,
@@ -1036,67 +1742,175 @@ fn apply_regex_change(
     let mut stats = SubstitutionStats::default();
 
     // Find tracked files matching all patterns (deduped + sorted).
-    let all_files = file::FileSet::matching_any(repo_path, file_patterns)?;
+    let all_files = file::FileSet::matching(repo_path, file_patterns, include_ignored)?;
     stats.files_scanned = all_files.len();
 
-    for file_path in all_files {
-        let full_path = repo_path.join(&file_path);
+    substitute_files(
+        repo_path,
+        all_files,
+        file_pool,
+        |full_path| file::apply_regex_to_file(full_path, pattern, replacement, 3),
+        |file_path, result| {
+            let full_path = repo_path.join(&file_path);
+            match result {
+                diff::SubstitutionResult::Changed {
+                    content: updated_content,
+                    diff,
+                    matches,
+                } => {
+                    let (updated_content, diff) = normalize_final_newline(
+                        &full_path,
+                        updated_content,
+                        diff,
+                        ensure_final_newline,
+                    )?;
+
+                    // Out-of-tree backup, then write-ahead register the restore.
+                    let backup_path = transaction.backup_path_for(&file_path)?;
+                    let mode = file::create_backup(&full_path, &backup_path)?;
+                    transaction.push_step(crate::transaction::RollbackStep::RestoreBackup {
+                        backup: backup_path,
+                        original: full_path.clone(),
+                        mode,
+                    })?;
+
+                    // Write updated content
+                    file::write_file_content(&full_path, &updated_content, ensure_final_newline)?;
+
+                    if updated_content.trim().is_empty() {
+                        warn!("Change would leave {} empty", file_path.display());
+                        stats
+                            .files_emptied
+                            .push(file_path.to_string_lossy().to_string());
+                    }
+                    files_affected.push(file_path.to_string_lossy().to_string());
+                    diff_parts.push(format!(
+                        "  M {}\n{}",
+                        file_path.display(),
+                        local::utils::indent(&diff, 4)
+                    ));
 
-        if !full_path.exists() {
-            continue;
-        }
+                    stats.files_changed += 1;
+                    stats.total_matches += matches;
+                }
+                diff::SubstitutionResult::NoMatches => {
+                    debug!(
+                        "No matches found for regex pattern '{}' in {}",
+                        pattern,
+                        file_path.display()
+                    );
+                    stats.files_no_matches += 1;
+                }
+                diff::SubstitutionResult::NoChange { matches } => {
+                    debug!(
+                        "Regex pattern '{}' matched but no changes resulted in {}",
+                        pattern,
+                        file_path.display()
+                    );
+                    stats.files_no_change += 1;
+                    stats.total_matches += matches;
+                }
+                diff::SubstitutionResult::SkippedBinary => {
+                    stats.files_skipped_binary += 1;
+                }
+                diff::SubstitutionResult::SkippedUnparsable(reason) => {
+                    warn!("Skipping {}: {reason}", file_path.display());
+                    stats.files_skipped_unparsable += 1;
+                }
+            }
+            Ok(())
+        },
+    )?;
 
-        // Try to apply regex substitution
-        match file::apply_regex_to_file(&full_path, pattern, replacement, 3)? {
-            diff::SubstitutionResult::Changed {
-                content: updated_content,
-                diff,
-                matches,
-            } => {
-                // Out-of-tree backup, then write-ahead register the restore.
-                let backup_path = transaction.backup_path_for(&file_path)?;
-                let mode = file::create_backup(&full_path, &backup_path)?;
-                transaction.push_step(crate::transaction::RollbackStep::RestoreBackup {
-                    backup: backup_path,
-                    original: full_path.clone(),
-                    mode,
-                })?;
+    Ok(stats)
+}
 
-                // Write updated content
-                file::write_file_content(&full_path, &updated_content)?;
+/// Set `key_path` to `value` in every matched JSON/YAML file
+/// ([`file::set_key_in_file`]); other matched files are passed over. A file
+/// without the key is counted as having no match unless `create` adds it.
+#[allow(clippy::too_many_arguments)]
+fn apply_set_key_change(
+    repo_path: &Path,
+    file_patterns: &[String],
+    include_ignored: bool,
+    key_path: &str,
+    value: &str,
+    create: bool,
+    ensure_final_newline: bool,
+    file_pool: Option<&rayon::ThreadPool>,
+    transaction: &mut Transaction,
+    files_affected: &mut Vec<String>,
+    diff_parts: &mut Vec<String>,
+) -> Result<SubstitutionStats> {
+    let mut stats = SubstitutionStats::default();
 
-                files_affected.push(file_path.to_string_lossy().to_string());
-                diff_parts.push(format!(
-                    "  M {}\n{}",
-                    file_path.display(),
-                    local::utils::indent(&diff, 4)
-                ));
+    // Find tracked files matching all patterns (deduped + sorted).
+    let all_files = file::FileSet::matching(repo_path, file_patterns, include_ignored)?;
+    stats.files_scanned = all_files.len();
 
-                stats.files_changed += 1;
-                stats.total_matches += matches;
-            }
-            diff::SubstitutionResult::NoMatches => {
-                debug!(
-                    "No matches found for regex pattern '{}' in {}",
-                    pattern,
-                    file_path.display()
-                );
-                stats.files_no_matches += 1;
-            }
-            diff::SubstitutionResult::NoChange { matches } => {
-                debug!(
-                    "Regex pattern '{}' matched but no changes resulted in {}",
-                    pattern,
-                    file_path.display()
-                );
-                stats.files_no_change += 1;
-                stats.total_matches += matches;
-            }
-            diff::SubstitutionResult::SkippedBinary => {
-                stats.files_skipped_binary += 1;
+    let structured = all_files
+        .into_iter()
+        .filter(|file_path| file::StructuredFormat::for_path(file_path).is_some())
+        .collect();
+    substitute_files(
+        repo_path,
+        structured,
+        file_pool,
+        |full_path| file::set_key_in_file(full_path, key_path, value, create, 3),
+        |file_path, result| {
+            let full_path = repo_path.join(&file_path);
+            match result {
+                diff::SubstitutionResult::Changed {
+                    content: updated_content,
+                    diff,
+                    matches,
+                } => {
+                    let (updated_content, diff) = normalize_final_newline(
+                        &full_path,
+                        updated_content,
+                        diff,
+                        ensure_final_newline,
+                    )?;
+
+                    // Out-of-tree backup, then write-ahead register the restore.
+                    let backup_path = transaction.backup_path_for(&file_path)?;
+                    let mode = file::create_backup(&full_path, &backup_path)?;
+                    transaction.push_step(crate::transaction::RollbackStep::RestoreBackup {
+                        backup: backup_path,
+                        original: full_path.clone(),
+                        mode,
+                    })?;
+                    file::write_file_content(&full_path, &updated_content, ensure_final_newline)?;
+
+                    files_affected.push(file_path.to_string_lossy().to_string());
+                    diff_parts.push(format!(
+                        "  M {}\n{}",
+                        file_path.display(),
+                        local::utils::indent(&diff, 4)
+                    ));
+                    stats.files_changed += 1;
+                    stats.total_matches += matches;
+                }
+                diff::SubstitutionResult::NoMatches => {
+                    debug!("No key '{key_path}' in {}", file_path.display());
+                    stats.files_no_matches += 1;
+                }
+                diff::SubstitutionResult::NoChange { matches } => {
+                    debug!("'{key_path}' already set in {}", file_path.display());
+                    stats.files_no_change += 1;
+                    stats.total_matches += matches;
+                }
+                diff::SubstitutionResult::SkippedBinary => {
+                    stats.files_skipped_binary += 1;
+                }
+                diff::SubstitutionResult::SkippedUnparsable(reason) => {
+                    warn!("Skipping {}: {reason}", file_path.display());
+                    stats.files_skipped_unparsable += 1;
+                }
             }
-        }
-    }
+            Ok(())
+        },
+    )?;
 
     Ok(stats)
 }
@@ -1267,38 +2081,67 @@ fn apply_patchset_change(
     Ok(())
 }
 
-/// Create the gx branch, stage, commit, and push - registering each undo step
+/// What `commit_changes_with_rollback` pushed, for the pushed-state safe point.
+#[derive(Debug)]
+struct PushedCommit {
+    /// The branch committed onto.
+    branch: String,
+    /// Whether `branch` is the user's own (`--target-existing-branch`).
+    existing_branch: bool,
+    /// The pre-commit HEAD (the safe point `ResetCommit` already captures).
+    base_sha: String,
+    /// The commit gx made, which undo reverts on an existing branch.
+    commit_sha: String,
+    /// The tag created on `commit_sha`, pushed after finalize.
+    tag: Option<String>,
+}
+
+/// Create the gx branch (unless `create_branch` is off and `branch` is the one
+/// already checked out), stage, commit, and push - registering each undo step
 /// write-ahead. The success-path branch restoration and stash pop are handled by
-/// `Transaction::finalize`, not here. Returns the pre-commit HEAD (the safe
-/// point `ResetCommit` already captures), so the caller can record `base_sha`
-/// (F11/F12) at the pushed-state safe point before `finalize()` runs.
+/// `Transaction::finalize`, not here. Returns the pushed commit, so the caller
+/// can record `base_sha` (F11/F12) and the branch committed onto at the
+/// pushed-state safe point before `finalize()` runs.
+#[allow(clippy::too_many_arguments)]
 fn commit_changes_with_rollback(
     repo_path: &Path,
-    change_id: &str,
+    branch: &str,
+    create_branch: bool,
     commit_message: &str,
     files_affected: &[String],
+    commit_options: &CommitOptions,
+    tag: Option<(&str, &str)>,
     transaction: &mut Transaction,
-) -> Result<String> {
+) -> Result<PushedCommit> {
     use crate::transaction::Phase;
 
-    // Whether the branch pre-existed gx's run (so rollback won't delete it).
-    let branch_existed = local::git::branch_exists_locally(repo_path, change_id).unwrap_or(false);
-
-    // Record the GX branch name so recovery (phase reporting, the `pushing`
-    // probe, `gx undo`) need not re-derive it.
-    transaction.set_branch(change_id.to_string());
+    // Record the branch name so recovery (phase reporting, the `pushing`
+    // probe, `gx undo`) need not re-derive it, and whether it is the user's.
+    if create_branch {
+        transaction.set_branch(branch.to_string());
+    } else {
+        transaction.set_existing_branch(branch.to_string());
+    }
 
-    // Write-ahead: register branch deletion before creating the branch.
-    transaction.push_step(RollbackStep::DeleteLocalBranch {
-        repo: repo_path.to_path_buf(),
-        branch: change_id.to_string(),
-        branch_existed,
-    })?;
-    local::git::create_branch(repo_path, change_id)
-        .with_context(|| format!("Failed to create or switch to branch: {change_id}"))?;
-    // Crash hook (Phase 8): the GX branch exists and its delete step is
-    // persisted (phase `mutating`); recovery full-reverses, remote branch absent.
-    crate::crash::maybe_crash("after-branch");
+    // The current branch (`--target-existing-branch`) is committed onto as
+    // is; only the GX branch is created here, and so only it is deleted on
+    // rollback (the reset below undoes the commit either way).
+    if create_branch {
+        // Whether the branch pre-existed gx's run (so rollback won't delete it).
+        let branch_existed = local::git::branch_exists_locally(repo_path, branch).unwrap_or(false);
+
+        // Write-ahead: register branch deletion before creating the branch.
+        transaction.push_step(RollbackStep::DeleteLocalBranch {
+            repo: repo_path.to_path_buf(),
+            branch: branch.to_string(),
+            branch_existed,
+        })?;
+        local::git::create_branch(repo_path, branch)
+            .with_context(|| format!("Failed to create or switch to branch: {branch}"))?;
+        // Crash hook (Phase 8): the GX branch exists and its delete step is
+        // persisted (phase `mutating`); recovery full-reverses, remote branch absent.
+        crate::crash::maybe_crash("after-branch");
+    }
 
     // Record the pre-commit HEAD so rollback resets to a known target, and
     // register the reset write-ahead before committing.
@@ -1310,11 +2153,27 @@ fn commit_changes_with_rollback(
 
     // Stage only the specific files we modified - never "git add .".
     local::git::add_files(repo_path, files_affected).context("Failed to stage files")?;
-    local::git::commit_changes(repo_path, commit_message).context("Failed to commit changes")?;
+    // A signing failure lands here too, so the caller rolls the branch back
+    // rather than leaving an unsigned commit behind.
+    local::git::commit_changes(repo_path, commit_message, commit_options)
+        .context("Failed to commit changes")?;
+    let commit_sha = local::git::get_head_sha(repo_path)?;
+    transaction.set_commit_sha(commit_sha.clone());
     // Crash hook (Phase 8): the commit is on the GX branch and the reset step is
     // persisted (phase `mutating`); recovery full-reverses, remote branch absent.
     crate::crash::maybe_crash("after-commit");
 
+    // Write-ahead: register tag deletion before tagging the commit.
+    if let Some((tag_name, tag_message)) = tag {
+        let tag_existed = local::git::tag_exists_locally(repo_path, tag_name).unwrap_or(false);
+        transaction.push_step(RollbackStep::DeleteLocalTag {
+            repo: repo_path.to_path_buf(),
+            tag: tag_name.to_string(),
+            tag_existed,
+        })?;
+        local::git::create_tag(repo_path, tag_name, tag_message).context("Failed to tag commit")?;
+    }
+
     // Stamp `pushing` write-ahead: a kill after this stamp but before the push
     // completes is classified at recovery time by a read-only ls-remote probe.
     // Rollback no longer registers a remote-delete step - `gx undo` owns remote
@@ -1323,29 +2182,54 @@ fn commit_changes_with_rollback(
     // Crash hook (Phase 8): `pushing` is stamped but the push has NOT run; the
     // ls-remote probe finds the branch absent and dispatches a full reverse.
     crate::crash::maybe_crash("before-push");
-    git::push_branch(repo_path, change_id).context("Failed to push branch")?;
+    git::push_branch(repo_path, branch, commit_options.no_verify)
+        .context("Failed to push branch")?;
     // Stamp `pushed`: the branch is now shared; recovery keeps the work.
     transaction.set_phase(Phase::Pushed)?;
     // Crash hook (Phase 8): the branch is pushed and `pushed` is stamped;
     // recovery keeps the shared work (remote branch retained).
     crate::crash::maybe_crash("after-push");
 
-    Ok(expected_sha)
+    Ok(PushedCommit {
+        branch: branch.to_string(),
+        existing_branch: !create_branch,
+        base_sha: expected_sha,
+        commit_sha,
+        tag: tag.map(|(name, _)| name.to_string()),
+    })
 }
 
 /// Create a pull request for the changes
 /// Returns the PR number and URL on success
+#[allow(clippy::too_many_arguments)]
 fn create_pull_request(
     repo: &Repo,
+    base: &str,
     change_id: &str,
     commit_message: &str,
+    files: &[String],
     draft: bool,
+    options: &CreateOptions,
     config: &Config,
 ) -> Result<github::CreatePrResult> {
     let repo_slug = &repo.slug;
-    let base = resolve_base_branch(repo, config);
-    let result = github::create_pr(repo_slug, change_id, commit_message, &base, draft, config)
-        .with_context(|| format!("Failed to create PR for {repo_slug}"))?;
+    let body = github::render_pr_body(
+        &pr_body_template(&repo.path, options, config),
+        commit_message,
+        change_id,
+        repo_slug,
+        files,
+    );
+    let result = github::create_pr(
+        repo_slug,
+        change_id,
+        &body,
+        base,
+        draft,
+        &options.pr_metadata,
+        config,
+    )
+    .with_context(|| format!("Failed to create PR for {repo_slug}"))?;
     info!(
         "Created PR #{} for repository: {} - {}",
         result.number, repo_slug, result.url
@@ -1353,6 +2237,38 @@ fn create_pull_request(
     Ok(result)
 }
 
+/// Where a repo's PR template lives when `--pr-use-template` asks for it.
+const REPO_PR_TEMPLATES: &[&str] = &[
+    ".github/PULL_REQUEST_TEMPLATE.md",
+    ".github/pull_request_template.md",
+];
+
+/// The un-interpolated PR body for the repo at `repo_path`: `--pr-body` /
+/// `--pr-body-file` first, then (with `--pr-use-template`) the repo's own
+/// `.github` PR template, then the configured `github.pr-body-template`.
+pub(crate) fn pr_body_template(
+    repo_path: &Path,
+    options: &CreateOptions,
+    config: &Config,
+) -> String {
+    if let Some(body) = &options.pr_body {
+        return body.clone();
+    }
+    if options.pr_use_template {
+        let template = REPO_PR_TEMPLATES
+            .iter()
+            .find_map(|path| std::fs::read_to_string(repo_path.join(path)).ok());
+        if let Some(template) = template {
+            return template;
+        }
+        debug!(
+            "pr_body_template: no PR template in {}; using the configured body",
+            repo_path.display()
+        );
+    }
+    config.pr_body_template()
+}
+
 /// Resolve the repo's default base branch: prefer the local head branch, then
 /// the GitHub API's default_branch, falling back to `main` with a warning - a
 /// lookup failure must never drop the PR ([A4]).
@@ -1361,7 +2277,7 @@ fn resolve_base_branch(repo: &Repo, config: &Config) -> String {
         return branch;
     }
     let org = repo.slug.split('/').next().unwrap_or("");
-    if let Ok(token) = github::read_token(org, config) {
+    if let Ok(token) = github::resolve_token(org, config) {
         if let Ok(branch) = github::get_default_branch(&repo.slug, &token) {
             return branch;
         }
@@ -152,6 +152,9 @@ pub fn execute_apply(
         Some(&msg),
         pr,
         draft,
+        // `gx apply` has no signing/normalizing flags yet: the blobs are
+        // written verbatim and committed plainly, as before.
+        &super::CreateOptions::default(),
         config,
         parallel_jobs,
         confirmation,
@@ -55,6 +55,7 @@ fn llm_config(agent_command: &str) -> Config {
                 agent_command: Some(agent_command.to_string()),
                 timeout_seconds: Some(60),
             }),
+            default_pr_type: None,
         }),
         ..Config::default()
     }
@@ -48,6 +48,7 @@ fn llm_config(agent_command: &str, timeout: u64) -> Config {
                 agent_command: Some(agent_command.to_string()),
                 timeout_seconds: Some(timeout),
             }),
+            default_pr_type: None,
         }),
         ..Config::default()
     }
@@ -486,6 +487,7 @@ fn test_process_single_repo_rejects_llm_change() {
             Some("msg"),
             false,
             false,
+            &super::super::CreateOptions::default(),
             &Config::default(),
             None,
             None,
@@ -0,0 +1,210 @@
+//! `gx create revert <change-id>`: back a committed change out of every repo
+//! recorded for it in change state.
+//!
+//! For each recorded repo, the change's commits (`base_sha..<change branch>`,
+//! or just gx's recorded commit when it was made on the user's own branch) are
+//! reverted with `git revert` and the result is pushed:
+//! - unmerged: onto the change branch itself, so its PR (if any) carries no
+//!   net diff any more;
+//! - merged (`--force` only): onto a new `<change-id>-revert` branch cut from
+//!   the fetched default branch, recorded in change state so `gx undo` and
+//!   `gx cleanup` delete it. The base branch itself is never pushed to.
+//!
+//! A reverted repo is marked [`RepoChangeStatus::Reverted`], so running the
+//! revert again never reverts the revert. Never prints; the CLI wrapper
+//! (`create::process_revert_command`) renders. Like [`super::execute_create`],
+//! the CALLER holds the [`crate::lock::ChangeLock`] for `change_id`.
+
+use super::{CreateAction, CreateResult};
+use crate::git;
+use crate::state::{RepoChangeState, RepoChangeStatus, StateManager};
+use eyre::{Context, Result};
+use local::repo::Repo;
+use log::{debug, warn};
+use std::path::{Path, PathBuf};
+
+/// Revert `change_id` in every repo its change state records. A merged repo
+/// is refused unless `force`. Per-repo failures are carried on the results;
+/// a missing change state is an `Err`.
+pub fn execute_revert(
+    change_id: &str,
+    force: bool,
+    state_manager: &StateManager,
+) -> Result<Vec<CreateResult>> {
+    debug!("execute_revert: change_id={change_id} force={force}");
+    let mut state = state_manager
+        .load(change_id)?
+        .ok_or_else(|| eyre::eyre!("No change state recorded for {change_id}"))?;
+
+    let repo_states: Vec<RepoChangeState> = state.repositories.values().cloned().collect();
+    let mut results = Vec::with_capacity(repo_states.len());
+    for repo_state in &repo_states {
+        let outcome = revert_repo(repo_state, change_id, force);
+        if let Ok(branch) = &outcome {
+            // Landing anywhere but the change branch means a new revert branch.
+            let revert_branch = (*branch != repo_state.branch_name).then(|| branch.clone());
+            state.mark_reverted(&repo_state.repo_slug, revert_branch);
+        }
+        results.push(revert_result(repo_state, change_id, outcome));
+    }
+
+    if results.iter().any(|r| r.error.is_none()) {
+        state_manager
+            .save(&state)
+            .with_context(|| format!("Failed to save change state for {change_id}"))?;
+    }
+    Ok(results)
+}
+
+/// The `CreateResult` for one repo's revert outcome (`Ok` carries the branch
+/// the revert landed on).
+fn revert_result(
+    repo_state: &RepoChangeState,
+    change_id: &str,
+    outcome: Result<String>,
+) -> CreateResult {
+    let repo = match &repo_state.local_path {
+        Some(path) => Repo {
+            path: PathBuf::from(path),
+            ..Repo::from_slug(repo_state.repo_slug.clone())
+        },
+        None => Repo::from_slug(repo_state.repo_slug.clone()),
+    };
+    // A failed revert changed nothing, so it carries no files: the summary
+    // would otherwise count it as a pending change.
+    let (action, branch, files_affected, error) = match outcome {
+        Ok(branch) => (
+            CreateAction::Reverted,
+            Some(branch),
+            repo_state.files_modified.clone(),
+            None,
+        ),
+        Err(e) => (
+            CreateAction::DryRun,
+            None,
+            Vec::new(),
+            Some(format!("{e:#}")),
+        ),
+    };
+    CreateResult {
+        repo,
+        change_id: change_id.to_string(),
+        action,
+        files_affected,
+        substitution_stats: None,
+        pr_number: repo_state.pr_number,
+        pr_url: repo_state.pr_url.clone(),
+        original_branch: repo_state.original_branch.clone(),
+        branch,
+        base_sha: repo_state.base_sha.clone(),
+        diff: None,
+        error,
+        warning: None,
+    }
+}
+
+/// Revert one repo's change and push it; returns the branch it landed on.
+/// The repo is left on the branch it was on before.
+fn revert_repo(repo_state: &RepoChangeState, change_id: &str, force: bool) -> Result<String> {
+    debug!(
+        "revert_repo: slug={} status={:?}",
+        repo_state.repo_slug, repo_state.status
+    );
+    let merged = match &repo_state.status {
+        RepoChangeStatus::Reverted => {
+            return Err(eyre::eyre!("already reverted"));
+        }
+        RepoChangeStatus::Proposed => {
+            return Err(eyre::eyre!(
+                "only proposed, nothing committed to revert (use `gx undo {change_id}`)"
+            ));
+        }
+        RepoChangeStatus::PrMerged => true,
+        _ => false,
+    };
+    if merged && !force {
+        return Err(eyre::eyre!(
+            "already merged; pass --force to revert it on a new {change_id}-revert branch \
+             (or `gx undo {change_id}` to open a revert PR)"
+        ));
+    }
+
+    let repo_path = PathBuf::from(
+        repo_state
+            .local_path
+            .as_deref()
+            .ok_or_else(|| eyre::eyre!("no local checkout recorded"))?,
+    );
+    let base_sha = repo_state.base_sha.as_deref().ok_or_else(|| {
+        eyre::eyre!("no base commit recorded; cannot tell the change's commits apart")
+    })?;
+    let change_branch = &repo_state.branch_name;
+    if !local::git::branch_exists_locally(&repo_path, change_branch)? {
+        return Err(eyre::eyre!(
+            "change branch {change_branch} not found locally"
+        ));
+    }
+    if local::git::has_uncommitted_changes(&repo_path)? {
+        return Err(eyre::eyre!(
+            "uncommitted changes; commit or stash them first"
+        ));
+    }
+
+    // Per-repo lock, as for create: no concurrent gx may switch branches here.
+    let _lock = crate::lock::RepoLock::acquire(&repo_path)
+        .map_err(|e| eyre::eyre!("Repository is locked: {e}"))?;
+    let original_branch = local::git::get_current_branch_name(&repo_path)?;
+
+    let target = if merged {
+        let head = git::get_head_branch(&repo_path)?;
+        git::fetch_origin(&repo_path)?;
+        let revert_branch = format!("{change_id}-revert");
+        local::git::create_branch_at(&repo_path, &revert_branch, &format!("origin/{head}"))?;
+        revert_branch
+    } else {
+        local::git::switch_branch(&repo_path, change_branch)?;
+        change_branch.clone()
+    };
+
+    // On the user's own branch only gx's commit is the change; anything after
+    // `base_sha` may be theirs.
+    let commits = match (repo_state.existing_branch, &repo_state.commit_sha) {
+        (true, Some(sha)) => sha.clone(),
+        _ => format!("{base_sha}..{change_branch}"),
+    };
+    let outcome = revert_and_push(&repo_path, &commits, &target);
+    // Always return to the user's branch, whatever the revert did.
+    let restored = if original_branch.is_empty() {
+        Ok(())
+    } else {
+        local::git::switch_branch(&repo_path, &original_branch)
+    };
+    // A failed revert branch is neither pushed nor recorded: drop it, or a
+    // re-run could not cut it again.
+    if merged && outcome.is_err() {
+        if let Err(e) = local::git::delete_local_branch(&repo_path, &target) {
+            warn!(
+                "Failed to delete revert branch {target} in {}: {e}",
+                repo_path.display()
+            );
+        }
+    }
+    outcome?;
+    restored.context("Reverted and pushed, but could not switch back")?;
+    Ok(target)
+}
+
+/// Revert `commits` (a sha or range) onto the checked-out `target` and push
+/// it. A conflicting revert is aborted, leaving `target` untouched.
+fn revert_and_push(repo_path: &Path, commits: &str, target: &str) -> Result<()> {
+    if let Err(e) = local::git::revert_commit(repo_path, commits, None) {
+        if let Err(abort) = local::git::revert_abort(repo_path) {
+            warn!("Failed to abort revert in {}: {abort}", repo_path.display());
+        }
+        return Err(e);
+    }
+    git::push_branch(repo_path, target, false).context("Failed to push the revert")
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,248 @@
+//! `gx create revert` tests: a real create (commit, no PR) against a repo with
+//! a bare remote, then a revert, checked against the pre-change tree.
+
+use super::*;
+use crate::create::core::{process_single_repo, Change, CreateOptions};
+use crate::state::ChangeState;
+use local::config::Config;
+use local::test_utils::run_git_command;
+use std::sync::Mutex;
+use tempfile::TempDir;
+
+/// A repo at `dir/repo` on its default branch with a bare remote at
+/// `dir/repo.git`, containing `README.md` = "# repo\n".
+fn repo_with_remote(dir: &Path) -> PathBuf {
+    let repo = dir.join("repo");
+    let bare = dir.join("repo.git");
+    run_git_command(&["init", "--quiet", "--bare", bare.to_str().unwrap()], dir);
+    std::fs::create_dir_all(&repo).unwrap();
+    std::fs::write(repo.join("README.md"), "# repo\n").unwrap();
+    run_git_command(&["init", "--quiet"], &repo);
+    run_git_command(&["config", "user.email", "t@e.com"], &repo);
+    run_git_command(&["config", "user.name", "T"], &repo);
+    run_git_command(&["config", "commit.gpgsign", "false"], &repo);
+    run_git_command(&["add", "-A"], &repo);
+    run_git_command(&["commit", "--quiet", "-m", "init"], &repo);
+    run_git_command(&["remote", "add", "origin", bare.to_str().unwrap()], &repo);
+    let branch = local::git::get_current_branch_name(&repo).unwrap();
+    run_git_command(&["push", "--quiet", "-u", "origin", &branch], &repo);
+    run_git_command(&["remote", "set-head", "origin", &branch], &repo);
+    repo
+}
+
+fn with_data_home<F: FnOnce()>(f: F) {
+    let guard = local::test_utils::env_lock();
+    let prior = std::env::var("XDG_DATA_HOME").ok();
+    let tmp = TempDir::new().unwrap();
+    unsafe { std::env::set_var("XDG_DATA_HOME", tmp.path()) };
+    f();
+    match prior {
+        Some(v) => unsafe { std::env::set_var("XDG_DATA_HOME", v) },
+        None => unsafe { std::env::remove_var("XDG_DATA_HOME") },
+    }
+    drop(guard);
+}
+
+/// Commit a `sub repo -> changed` change to `README.md` under `change_id`,
+/// recording change state the way `gx create --commit` does.
+fn create_change(repo_path: &Path, change_id: &str) -> Repo {
+    let repo = Repo::new(repo_path.to_path_buf()).unwrap();
+    let change_state = Mutex::new(ChangeState::new(change_id.to_string(), None));
+    let state_manager = StateManager::new().unwrap();
+    let result = process_single_repo(
+        &repo,
+        change_id,
+        &["README.md".to_string()],
+        &Change::Sub(
+            "repo".to_string(),
+            "changed".to_string(),
+            local::diff::SubMatch::default(),
+        ),
+        Some("rename repo"),
+        false,
+        false,
+        &CreateOptions::default(),
+        &Config::default(),
+        Some(&change_state),
+        Some(&state_manager),
+    );
+    assert!(result.error.is_none(), "create failed: {:?}", result.error);
+    repo
+}
+
+fn readme_on(repo: &Path, branch: &str) -> String {
+    let out = run_git_command(&["show", &format!("{branch}:README.md")], repo);
+    assert!(out.status.success(), "git show {branch}:README.md failed");
+    String::from_utf8(out.stdout).unwrap()
+}
+
+#[test]
+fn test_revert_restores_pre_change_tree() {
+    with_data_home(|| {
+        let ws = TempDir::new().unwrap();
+        let repo_path = repo_with_remote(ws.path());
+        let change_id = "GX-revert";
+        let repo = create_change(&repo_path, change_id);
+        assert_eq!(readme_on(&repo_path, change_id), "# changed\n");
+        let before = local::git::get_current_branch_name(&repo_path).unwrap();
+
+        let state_manager = StateManager::new().unwrap();
+        let results = execute_revert(change_id, false, &state_manager).unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert!(results[0].error.is_none(), "{:?}", results[0].error);
+        assert!(matches!(results[0].action, CreateAction::Reverted));
+        assert_eq!(results[0].branch.as_deref(), Some(change_id));
+        // Content matches the pre-change state, on the branch and its remote.
+        assert_eq!(readme_on(&repo_path, change_id), "# repo\n");
+        assert_eq!(
+            readme_on(&repo_path, &format!("origin/{change_id}")),
+            "# repo\n"
+        );
+        // The user's branch is restored.
+        assert_eq!(
+            local::git::get_current_branch_name(&repo_path).unwrap(),
+            before
+        );
+        let state = state_manager.load(change_id).unwrap().unwrap();
+        assert_eq!(
+            state.repositories[&repo.slug].status,
+            RepoChangeStatus::Reverted
+        );
+
+        // Idempotent: a second run refuses rather than reverting the revert.
+        let again = execute_revert(change_id, false, &state_manager).unwrap();
+        assert!(again[0]
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("already reverted"));
+        assert_eq!(readme_on(&repo_path, change_id), "# repo\n");
+    });
+}
+
+#[test]
+fn test_revert_refuses_merged_change_without_force() {
+    with_data_home(|| {
+        let ws = TempDir::new().unwrap();
+        let repo_path = repo_with_remote(ws.path());
+        let change_id = "GX-revert-merged";
+        let repo = create_change(&repo_path, change_id);
+
+        let state_manager = StateManager::new().unwrap();
+        let mut state = state_manager.load(change_id).unwrap().unwrap();
+        state.mark_merged(&repo.slug);
+        state_manager.save(&state).unwrap();
+
+        let results = execute_revert(change_id, false, &state_manager).unwrap();
+        assert!(results[0]
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("already merged"));
+        assert!(
+            results[0].files_affected.is_empty(),
+            "a refused revert is not a pending change"
+        );
+        // Nothing touched: the change branch still carries the change.
+        assert_eq!(readme_on(&repo_path, change_id), "# changed\n");
+        assert!(
+            !local::git::branch_exists_locally(&repo_path, &format!("{change_id}-revert")).unwrap()
+        );
+    });
+}
+
+#[test]
+fn test_force_revert_of_merged_change_records_the_revert_branch() {
+    with_data_home(|| {
+        let ws = TempDir::new().unwrap();
+        let repo_path = repo_with_remote(ws.path());
+        let change_id = "GX-revert-forced";
+        let repo = create_change(&repo_path, change_id);
+        // Land the change on the remote default branch, as a merged PR would.
+        let base = local::git::get_current_branch_name(&repo_path).unwrap();
+        let merge = run_git_command(
+            &["push", "--quiet", "origin", &format!("{change_id}:{base}")],
+            &repo_path,
+        );
+        assert!(merge.status.success());
+
+        let state_manager = StateManager::new().unwrap();
+        let mut state = state_manager.load(change_id).unwrap().unwrap();
+        state.mark_merged(&repo.slug);
+        state_manager.save(&state).unwrap();
+
+        let revert_branch = format!("{change_id}-revert");
+        let results = execute_revert(change_id, true, &state_manager).unwrap();
+        assert!(results[0].error.is_none(), "{:?}", results[0].error);
+        assert_eq!(results[0].branch.as_deref(), Some(revert_branch.as_str()));
+        assert!(local::git::branch_exists_locally(&repo_path, &revert_branch).unwrap());
+
+        // Recorded, so `gx undo` and `gx cleanup` can delete it.
+        let state = state_manager.load(change_id).unwrap().unwrap();
+        let repo_state = &state.repositories[&repo.slug];
+        assert_eq!(repo_state.status, RepoChangeStatus::Reverted);
+        assert_eq!(
+            repo_state.revert_branch.as_deref(),
+            Some(revert_branch.as_str())
+        );
+        assert!(state
+            .get_repos_needing_cleanup()
+            .iter()
+            .any(|r| r.repo_slug == repo.slug));
+    });
+}
+
+#[test]
+fn test_failed_force_revert_drops_the_revert_branch() {
+    with_data_home(|| {
+        let ws = TempDir::new().unwrap();
+        let repo_path = repo_with_remote(ws.path());
+        let change_id = "GX-revert-rejected";
+        let repo = create_change(&repo_path, change_id);
+        let base = local::git::get_current_branch_name(&repo_path).unwrap();
+        let merge = run_git_command(
+            &["push", "--quiet", "origin", &format!("{change_id}:{base}")],
+            &repo_path,
+        );
+        assert!(merge.status.success());
+
+        let state_manager = StateManager::new().unwrap();
+        let mut state = state_manager.load(change_id).unwrap().unwrap();
+        state.mark_merged(&repo.slug);
+        state_manager.save(&state).unwrap();
+
+        // A remote that rejects every push fails the revert after branching.
+        let hook = ws.path().join("repo.git/hooks/pre-receive");
+        std::fs::write(&hook, "#!/bin/sh\nexit 1\n").unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&hook, std::fs::Permissions::from_mode(0o755)).unwrap();
+        }
+
+        let revert_branch = format!("{change_id}-revert");
+        let results = execute_revert(change_id, true, &state_manager).unwrap();
+        assert!(results[0].error.is_some());
+        assert!(!local::git::branch_exists_locally(&repo_path, &revert_branch).unwrap());
+        assert_eq!(
+            local::git::get_current_branch_name(&repo_path).unwrap(),
+            base
+        );
+
+        // Once the remote accepts pushes again, a re-run cuts the branch anew.
+        std::fs::remove_file(&hook).unwrap();
+        let again = execute_revert(change_id, true, &state_manager).unwrap();
+        assert!(again[0].error.is_none(), "{:?}", again[0].error);
+        assert_eq!(again[0].branch.as_deref(), Some(revert_branch.as_str()));
+    });
+}
+
+#[test]
+fn test_revert_missing_change_state_errors() {
+    with_data_home(|| {
+        let state_manager = StateManager::new().unwrap();
+        let err = execute_revert("GX-nope", false, &state_manager).unwrap_err();
+        assert!(err.to_string().contains("No change state"));
+    });
+}
@@ -45,6 +45,7 @@ fn test_process_single_repo_hard_errors_on_head_branch_failure() {
         None,
         false,
         false,
+        &CreateOptions::default(),
         &Config::default(),
         None,
         None,
@@ -65,6 +66,39 @@ fn test_process_single_repo_hard_errors_on_head_branch_failure() {
     );
 }
 
+#[test]
+fn test_process_single_repo_skips_a_repo_mid_rebase() {
+    let temp_dir = TempDir::new().unwrap();
+    let repo_path = temp_dir.path().to_path_buf();
+    fs::write(repo_path.join("README.md"), "# repo").unwrap();
+    init_git_repo(&repo_path);
+    fs::create_dir(repo_path.join(".git/rebase-merge")).unwrap();
+    let repo = Repo::new(repo_path.clone()).unwrap();
+
+    let result = process_single_repo(
+        &repo,
+        "GX-test",
+        &["**/*.md".to_string()],
+        &Change::Delete,
+        Some("Remove docs"),
+        false,
+        false,
+        &CreateOptions::default(),
+        &Config::default(),
+        None,
+        None,
+    );
+
+    assert!(matches!(result.action, CreateAction::Skipped));
+    assert_eq!(result.error, None);
+    assert_eq!(
+        result.warning.as_deref(),
+        Some("skipped: rebase in progress")
+    );
+    assert!(result.files_affected.is_empty());
+    assert!(repo_path.join("README.md").exists());
+}
+
 #[test]
 fn test_apply_add_change() {
     let temp_dir = TempDir::new().unwrap();
@@ -77,6 +111,7 @@ fn test_apply_add_change() {
         repo_path,
         "new_file.txt",
         "Hello, world!",
+        false,
         &mut transaction,
         &mut files_affected,
         &mut diff_parts,
@@ -108,6 +143,7 @@ fn test_apply_add_change_file_exists() {
         repo_path,
         "existing.txt",
         "new content",
+        false,
         &mut transaction,
         &mut files_affected,
         &mut diff_parts,
@@ -120,6 +156,93 @@ fn test_apply_add_change_file_exists() {
         .contains("File already exists"));
 }
 
+/// `apply_append_change` on `file` in a fresh temp repo whose `.gitignore`
+/// starts as `existing` (`None`: absent). Returns the file's content after
+/// the append and after rolling it back, and the diff parts.
+fn append_to_gitignore(
+    existing: Option<&str>,
+    content: &str,
+    if_missing: bool,
+) -> (Option<String>, Option<String>, Vec<String>) {
+    let data_home = TempDir::new().unwrap();
+    let mut outcome = (None, None, Vec::new());
+    with_data_home(data_home.path(), || {
+        let temp_dir = TempDir::new().unwrap();
+        let repo_path = temp_dir.path();
+        let gitignore = repo_path.join(".gitignore");
+        if let Some(existing) = existing {
+            fs::write(&gitignore, existing).unwrap();
+        }
+        let mut transaction =
+            Transaction::new(repo_path.to_path_buf(), "GX-test".to_string(), false);
+        let mut files_affected = Vec::new();
+        let mut diff_parts = Vec::new();
+
+        apply_append_change(
+            repo_path,
+            ".gitignore",
+            content,
+            if_missing,
+            false,
+            &mut transaction,
+            &mut files_affected,
+            &mut diff_parts,
+        )
+        .unwrap();
+        assert_eq!(files_affected.len(), diff_parts.len());
+        let appended = fs::read_to_string(&gitignore).ok();
+        transaction.rollback();
+        outcome = (appended, fs::read_to_string(&gitignore).ok(), diff_parts);
+    });
+    outcome
+}
+
+#[test]
+fn test_apply_append_change_appends_to_an_existing_file() {
+    let (appended, rolled_back, diff_parts) =
+        append_to_gitignore(Some("target/\n*.log"), ".env", false);
+
+    assert_eq!(appended.as_deref(), Some("target/\n*.log\n.env\n"));
+    assert_eq!(diff_parts.len(), 1);
+    assert!(
+        diff_parts[0].starts_with("  M .gitignore\n"),
+        "{}",
+        diff_parts[0]
+    );
+    assert!(diff_parts[0].contains(".env"), "{}", diff_parts[0]);
+    // Rollback restores the original bytes, missing newline included.
+    assert_eq!(rolled_back.as_deref(), Some("target/\n*.log"));
+}
+
+#[test]
+fn test_apply_append_change_creates_an_absent_file() {
+    let (appended, rolled_back, diff_parts) = append_to_gitignore(None, ".env", true);
+
+    assert_eq!(appended.as_deref(), Some(".env\n"));
+    assert!(
+        diff_parts[0].starts_with("  A .gitignore\n"),
+        "{}",
+        diff_parts[0]
+    );
+    // Rollback deletes the file it created.
+    assert_eq!(rolled_back, None);
+}
+
+#[test]
+fn test_apply_append_change_if_missing_skips_content_already_present() {
+    let (appended, _, diff_parts) = append_to_gitignore(Some("target/\n.env\n"), ".env", true);
+    assert_eq!(appended.as_deref(), Some("target/\n.env\n"));
+    assert!(diff_parts.is_empty());
+
+    // Whole lines only: `.env` is not present in `.env.local`.
+    let (appended, _, _) = append_to_gitignore(Some(".env.local\n"), ".env", true);
+    assert_eq!(appended.as_deref(), Some(".env.local\n.env\n"));
+
+    // Without --if-missing the same append is repeated.
+    let (appended, _, _) = append_to_gitignore(Some(".env\n"), ".env", false);
+    assert_eq!(appended.as_deref(), Some(".env\n.env\n"));
+}
+
 #[test]
 fn test_apply_delete_change() {
     // XDG-isolated (Phase 5 flock-fix): `apply_delete_change` writes an
@@ -151,6 +274,7 @@ fn test_apply_delete_change() {
         let result = apply_delete_change(
             repo_path,
             &patterns,
+            false,
             &mut transaction,
             &mut files_affected,
             &mut diff_parts,
@@ -192,8 +316,12 @@ fn test_apply_substitution_change() {
         let result = apply_substitution_change(
             repo_path,
             &patterns,
+            false,
             "Hello",
             "Hi",
+            diff::SubMatch::default(),
+            false,
+            None,
             &mut transaction,
             &mut files_affected,
             &mut diff_parts,
@@ -212,6 +340,158 @@ fn test_apply_substitution_change() {
     });
 }
 
+#[test]
+fn test_substitution_diff_shows_the_final_newline_normalization() {
+    // With --ensure-final-newline the diff (the dry-run preview) must be the
+    // diff of what is written, not of the pre-normalization content.
+    let data_home = TempDir::new().unwrap();
+    with_data_home(data_home.path(), || {
+        let temp_dir = TempDir::new().unwrap();
+        let repo_path = temp_dir.path();
+        fs::write(repo_path.join("test.txt"), "Hello world\n\n\n").unwrap();
+        init_git_repo(repo_path);
+
+        let mut transaction =
+            Transaction::new(repo_path.to_path_buf(), "GX-test".to_string(), false);
+        let mut files_affected = Vec::new();
+        let mut diff_parts = Vec::new();
+        apply_substitution_change(
+            repo_path,
+            &["*.txt".to_string()],
+            false,
+            "Hello",
+            "Hi",
+            diff::SubMatch::default(),
+            true,
+            None,
+            &mut transaction,
+            &mut files_affected,
+            &mut diff_parts,
+        )
+        .unwrap();
+
+        let written = fs::read_to_string(repo_path.join("test.txt")).unwrap();
+        assert_eq!(written, "Hi world\n");
+        let expected = diff::generate_diff("Hello world\n\n\n", &written, 3);
+        assert_eq!(
+            diff_parts,
+            vec![format!(
+                "  M test.txt\n{}",
+                local::utils::indent(&expected, 4)
+            )]
+        );
+        transaction.rollback();
+    });
+}
+
+#[test]
+fn test_set_key_honors_ensure_final_newline_in_the_diff() {
+    // set-key writes through the same normalization as sub/regex, and its
+    // diff is of the normalized content.
+    let data_home = TempDir::new().unwrap();
+    with_data_home(data_home.path(), || {
+        let temp_dir = TempDir::new().unwrap();
+        let repo_path = temp_dir.path();
+        fs::write(repo_path.join("package.json"), "{\"version\": \"1.0.0\"}").unwrap();
+        init_git_repo(repo_path);
+
+        let mut transaction =
+            Transaction::new(repo_path.to_path_buf(), "GX-test".to_string(), false);
+        let mut files_affected = Vec::new();
+        let mut diff_parts = Vec::new();
+        apply_set_key_change(
+            repo_path,
+            &["package.json".to_string()],
+            false,
+            "version",
+            "2.0.0",
+            false,
+            true,
+            None,
+            &mut transaction,
+            &mut files_affected,
+            &mut diff_parts,
+        )
+        .unwrap();
+
+        let written = fs::read_to_string(repo_path.join("package.json")).unwrap();
+        assert!(written.contains("2.0.0"), "{written:?}");
+        assert!(written.ends_with("}\n"), "{written:?}");
+        let expected = diff::generate_diff("{\"version\": \"1.0.0\"}", &written, 3);
+        assert_eq!(
+            diff_parts,
+            vec![format!(
+                "  M package.json\n{}",
+                local::utils::indent(&expected, 4)
+            )]
+        );
+        transaction.rollback();
+    });
+}
+
+#[test]
+fn test_apply_substitution_change_across_many_files_is_deterministic() {
+    let data_home = TempDir::new().unwrap();
+    with_data_home(data_home.path(), || {
+        let temp_dir = TempDir::new().unwrap();
+        let repo_path = temp_dir.path();
+        for n in 0..200 {
+            fs::write(
+                repo_path.join(format!("f{n:03}.txt")),
+                format!("Hello {n}\n"),
+            )
+            .unwrap();
+        }
+        init_git_repo(repo_path);
+        let patterns = vec!["*.txt".to_string()];
+
+        // Each run rolls back, so the next one sees the same starting tree.
+        let run = |file_pool: Option<&rayon::ThreadPool>| {
+            let mut transaction =
+                Transaction::new(repo_path.to_path_buf(), "GX-test".to_string(), false);
+            let mut files_affected = Vec::new();
+            let mut diff_parts = Vec::new();
+            let stats = apply_substitution_change(
+                repo_path,
+                &patterns,
+                false,
+                "Hello",
+                "Hi",
+                diff::SubMatch::default(),
+                false,
+                file_pool,
+                &mut transaction,
+                &mut files_affected,
+                &mut diff_parts,
+            )
+            .unwrap();
+            assert_eq!(stats.files_changed, 200);
+            assert_eq!(
+                fs::read_to_string(repo_path.join("f137.txt")).unwrap(),
+                "Hi 137\n"
+            );
+            transaction.rollback();
+            (files_affected, diff_parts)
+        };
+
+        let serial = super::file_pool(Some(1)).unwrap();
+        let eight = super::file_pool(Some(8)).unwrap();
+        let (serial_files, serial_diffs) = run(serial.as_deref());
+        let expected: Vec<String> = (0..200).map(|n| format!("f{n:03}.txt")).collect();
+        assert_eq!(serial_files, expected);
+
+        for file_pool in [None, eight.as_deref()] {
+            let (files, diffs) = run(file_pool);
+            assert_eq!(files, serial_files);
+            assert_eq!(diffs, serial_diffs);
+        }
+        assert_eq!(
+            fs::read_to_string(repo_path.join("f137.txt")).unwrap(),
+            "Hello 137\n"
+        );
+    });
+}
+
 // ---- Phase 4: pushed-state safe point (F12) ----
 
 /// Init `repo` with a bare `origin` remote at `bare`, push the initial
@@ -246,6 +526,36 @@ fn with_data_home<F: FnOnce()>(dir: &Path, f: F) {
     drop(guard);
 }
 
+#[test]
+fn test_pr_base_resolves_default_and_checks_explicit_base_on_origin() {
+    let temp = TempDir::new().unwrap();
+    let repo_path = temp.path().join("repo");
+    let default_branch = init_repo_with_bare_remote(&repo_path, &temp.path().join("origin.git"));
+    run_git_command(
+        &["push", "--quiet", "origin", "HEAD:refs/heads/develop"],
+        &repo_path,
+    );
+    let repo = Repo::new(repo_path).unwrap();
+    let config = Config::default();
+    let base = |pr_base: Option<&str>| {
+        CreateOptions {
+            pr_base: pr_base.map(str::to_string),
+            ..Default::default()
+        }
+        .pr_base_for(&repo, &config)
+    };
+
+    // Unset and `{default}` both resolve to the repo's own default branch.
+    assert_eq!(base(None).unwrap(), default_branch);
+    assert_eq!(base(Some(DEFAULT_PR_BASE)).unwrap(), default_branch);
+    assert_eq!(base(Some("develop")).unwrap(), "develop");
+    let missing = base(Some("release/1.0")).unwrap_err().to_string();
+    assert!(
+        missing.contains("'release/1.0' does not exist on origin"),
+        "{missing}"
+    );
+}
+
 #[test]
 fn test_pushed_state_recorded_before_finalize_deletes_recovery() {
     // F12, "state-saved-first" order: the pushed safe-point save happens
@@ -263,11 +573,14 @@ fn test_pushed_state_recorded_before_finalize_deletes_recovery() {
 
         let change_id = "GX-safepoint";
         let mut transaction = Transaction::new(repo_path.clone(), change_id.to_string(), true);
-        let base_sha = commit_changes_with_rollback(
+        let pushed = commit_changes_with_rollback(
             &repo_path,
             change_id,
+            true,
             "test commit",
             &["README.md".to_string()],
+            &CommitOptions::default(),
+            None,
             &mut transaction,
         )
         .expect("commit+push should succeed");
@@ -283,7 +596,7 @@ fn test_pushed_state_recorded_before_finalize_deletes_recovery() {
             change_id,
             &branch,
             &["README.md".to_string()],
-            &base_sha,
+            &pushed,
         );
         assert!(saved, "a successful save must report durably saved (true)");
 
@@ -307,7 +620,79 @@ fn test_pushed_state_recorded_before_finalize_deletes_recovery() {
             .get(&repo.slug)
             .expect("repo must be recorded");
         assert_eq!(repo_state.branch_name, change_id);
-        assert_eq!(repo_state.base_sha.as_deref(), Some(base_sha.as_str()));
+        assert_eq!(
+            repo_state.base_sha.as_deref(),
+            Some(pushed.base_sha.as_str())
+        );
+        assert!(!repo_state.existing_branch);
+    });
+}
+
+#[test]
+fn test_pushed_state_records_the_existing_branch_committed_onto() {
+    // `--target-existing-branch`: state records the user's branch (not the
+    // change id) and the commit made on it, so undo can revert that commit.
+    let data_home = TempDir::new().unwrap();
+    with_data_home(data_home.path(), || {
+        let ws = TempDir::new().unwrap();
+        let repo_path = ws.path().join("repo");
+        let bare = ws.path().join("repo.git");
+        let branch = init_repo_with_bare_remote(&repo_path, &bare);
+        fs::write(repo_path.join("README.md"), "# repo\nupdated\n").unwrap();
+
+        let change_id = "GX-existing";
+        let mut transaction = Transaction::new(repo_path.clone(), change_id.to_string(), true);
+        let pushed = commit_changes_with_rollback(
+            &repo_path,
+            &branch,
+            false,
+            "test commit",
+            &["README.md".to_string()],
+            &CommitOptions::default(),
+            None,
+            &mut transaction,
+        )
+        .expect("commit+push should succeed");
+        assert_eq!(
+            pushed.commit_sha,
+            local::git::get_head_sha(&repo_path).unwrap()
+        );
+
+        let recoveries = Transaction::list_recovery_states().unwrap();
+        let recorded = recoveries
+            .iter()
+            .find(|r| r.repo_path == repo_path)
+            .expect("recovery file must exist for the pushed commit");
+        assert!(recorded.existing_branch);
+        assert_eq!(recorded.branch.as_deref(), Some(branch.as_str()));
+        assert_eq!(
+            recorded.commit_sha.as_deref(),
+            Some(pushed.commit_sha.as_str())
+        );
+
+        let repo = Repo::new(repo_path.clone()).unwrap();
+        let change_state = Mutex::new(ChangeState::new(change_id.to_string(), None));
+        let state_manager = StateManager::new().unwrap();
+        let saved = record_pushed_state(
+            Some(&change_state),
+            Some(&state_manager),
+            &repo,
+            change_id,
+            &branch,
+            &["README.md".to_string()],
+            &pushed,
+        );
+        assert!(saved);
+        transaction.finalize().expect("finalize should succeed");
+
+        let loaded = state_manager.load(change_id).unwrap().unwrap();
+        let repo_state = loaded.repositories.get(&repo.slug).unwrap();
+        assert_eq!(repo_state.branch_name, branch);
+        assert!(repo_state.existing_branch);
+        assert_eq!(
+            repo_state.commit_sha.as_deref(),
+            Some(pushed.commit_sha.as_str())
+        );
     });
 }
 
@@ -330,8 +715,11 @@ fn test_pushed_branch_recorded_via_recovery_when_state_save_not_reached() {
         commit_changes_with_rollback(
             &repo_path,
             change_id,
+            true,
             "test commit",
             &["README.md".to_string()],
+            &CommitOptions::default(),
+            None,
             &mut transaction,
         )
         .expect("commit+push should succeed");
@@ -384,6 +772,7 @@ fn test_process_single_repo_records_state_with_base_sha() {
             Some("delete file1"),
             false,
             false,
+            &CreateOptions::default(),
             &Config::default(),
             Some(&change_state),
             Some(&state_manager),
@@ -409,6 +798,537 @@ fn test_process_single_repo_records_state_with_base_sha() {
     });
 }
 
+// ---- --format-cmd: formatter between apply and commit ----
+
+#[test]
+fn test_format_cmd_output_is_what_gets_committed() {
+    let data_home = TempDir::new().unwrap();
+    with_data_home(data_home.path(), || {
+        let ws = TempDir::new().unwrap();
+        let repo_path = ws.path().join("repo");
+        let bare = ws.path().join("repo.git");
+        init_repo_with_bare_remote(&repo_path, &bare);
+
+        // A "formatter" that upper-cases every file it is given.
+        let formatter = ws.path().join("fmt.sh");
+        fs::write(
+            &formatter,
+            "#!/bin/sh\nfor f; do tr a-z A-Z < \"$f\" > \"$f.tmp\" && mv \"$f.tmp\" \"$f\"; done\n",
+        )
+        .unwrap();
+        let options = CreateOptions {
+            format_cmd: Some(format!("sh {}", formatter.display())),
+            ..Default::default()
+        };
+
+        let repo = Repo::new(repo_path.clone()).unwrap();
+        let change_id = "GX-format";
+        let change_state = Mutex::new(ChangeState::new(change_id.to_string(), None));
+        let state_manager = StateManager::new().unwrap();
+        let result = process_single_repo(
+            &repo,
+            change_id,
+            &["README.md".to_string()],
+            &Change::Sub(
+                "repo".to_string(),
+                "formatted repo".to_string(),
+                diff::SubMatch::default(),
+            ),
+            Some("format"),
+            false,
+            false,
+            &options,
+            &Config::default(),
+            Some(&change_state),
+            Some(&state_manager),
+        );
+        assert!(result.error.is_none(), "got: {:?}", result.error);
+
+        let committed = local::test_utils::run_git_command(
+            &["show", &format!("{change_id}:README.md")],
+            &repo_path,
+        );
+        assert_eq!(
+            String::from_utf8_lossy(&committed.stdout),
+            "# FORMATTED REPO\n"
+        );
+        let diff = result.diff.expect("a committed change carries its diff");
+        assert!(diff.contains("+# FORMATTED REPO"), "got: {diff}");
+    });
+}
+
+#[test]
+fn test_format_cmd_writing_outside_the_change_fails_and_is_undone() {
+    let data_home = TempDir::new().unwrap();
+    with_data_home(data_home.path(), || {
+        let ws = TempDir::new().unwrap();
+        let repo_path = ws.path().join("repo");
+        let bare = ws.path().join("repo.git");
+        init_repo_with_bare_remote(&repo_path, &bare);
+
+        // A "formatter" that ignores its arguments, like `prettier --write .`.
+        let options = CreateOptions {
+            format_cmd: Some("echo formatted > stray.txt; true".to_string()),
+            ..Default::default()
+        };
+        let repo = Repo::new(repo_path.clone()).unwrap();
+        let result = process_single_repo(
+            &repo,
+            "GX-format-stray",
+            &["README.md".to_string()],
+            &Change::Sub(
+                "repo".to_string(),
+                "formatted repo".to_string(),
+                diff::SubMatch::default(),
+            ),
+            Some("format"),
+            false,
+            false,
+            &options,
+            &Config::default(),
+            None,
+            None,
+        );
+
+        let error = result
+            .error
+            .expect("a write outside the change must fail the repo");
+        assert!(error.contains("stray.txt"), "got: {error}");
+        assert!(!repo_path.join("stray.txt").exists());
+        assert_eq!(
+            fs::read_to_string(repo_path.join("README.md")).unwrap(),
+            "# repo\n"
+        );
+        assert!(!local::git::branch_exists_locally(&repo_path, "GX-format-stray").unwrap());
+    });
+}
+
+#[test]
+fn test_format_cmd_failure_rolls_the_repo_back() {
+    let data_home = TempDir::new().unwrap();
+    with_data_home(data_home.path(), || {
+        let ws = TempDir::new().unwrap();
+        let repo_path = ws.path().join("repo");
+        let bare = ws.path().join("repo.git");
+        init_repo_with_bare_remote(&repo_path, &bare);
+
+        let options = CreateOptions {
+            format_cmd: Some("false".to_string()),
+            ..Default::default()
+        };
+        let repo = Repo::new(repo_path.clone()).unwrap();
+        let result = process_single_repo(
+            &repo,
+            "GX-format-fails",
+            &["README.md".to_string()],
+            &Change::Sub(
+                "repo".to_string(),
+                "changed".to_string(),
+                diff::SubMatch::default(),
+            ),
+            Some("format"),
+            false,
+            false,
+            &options,
+            &Config::default(),
+            None,
+            None,
+        );
+
+        let error = result
+            .error
+            .expect("a failing formatter must fail the repo");
+        assert!(error.contains("Formatter failed"), "got: {error}");
+        assert_eq!(
+            fs::read_to_string(repo_path.join("README.md")).unwrap(),
+            "# repo\n",
+            "the applied change must be rolled back"
+        );
+        assert!(!local::git::branch_exists_locally(&repo_path, "GX-format-fails").unwrap());
+    });
+}
+
+#[test]
+fn test_tag_is_created_on_the_commit_and_pushed() {
+    let data_home = TempDir::new().unwrap();
+    with_data_home(data_home.path(), || {
+        let ws = TempDir::new().unwrap();
+        let repo_path = ws.path().join("repo");
+        let bare = ws.path().join("repo.git");
+        init_repo_with_bare_remote(&repo_path, &bare);
+
+        let options = CreateOptions {
+            tag: Some("release-{change_id}".to_string()),
+            tag_message: Some("Release".to_string()),
+            ..Default::default()
+        };
+        let repo = Repo::new(repo_path.clone()).unwrap();
+        let change_id = "GX-tag";
+        let change_state = Mutex::new(ChangeState::new(change_id.to_string(), None));
+        let state_manager = StateManager::new().unwrap();
+        let result = process_single_repo(
+            &repo,
+            change_id,
+            &["README.md".to_string()],
+            &Change::Sub(
+                "repo".to_string(),
+                "tagged".to_string(),
+                diff::SubMatch::default(),
+            ),
+            Some("tag it"),
+            false,
+            false,
+            &options,
+            &Config::default(),
+            Some(&change_state),
+            Some(&state_manager),
+        );
+        assert!(result.error.is_none(), "got: {:?}", result.error);
+
+        let tag = "release-GX-tag";
+        let tagged = run_git_command(&["rev-parse", &format!("{tag}^{{commit}}")], &repo_path);
+        let branch = run_git_command(&["rev-parse", change_id], &repo_path);
+        assert_eq!(
+            tagged.stdout, branch.stdout,
+            "the tag must point at the commit"
+        );
+        assert!(
+            local::git::tag_exists_locally(&bare, tag).unwrap(),
+            "the tag must be pushed"
+        );
+    });
+}
+
+#[test]
+fn test_rollback_deletes_the_tag_when_the_push_fails() {
+    let data_home = TempDir::new().unwrap();
+    with_data_home(data_home.path(), || {
+        let ws = TempDir::new().unwrap();
+        let repo_path = ws.path().join("repo");
+        let bare = ws.path().join("repo.git");
+        init_repo_with_bare_remote(&repo_path, &bare);
+        // A remote that rejects every push fails the run after tagging.
+        let hook = bare.join("hooks/pre-receive");
+        fs::write(
+            &hook,
+            "#!/bin/sh
+exit 1
+",
+        )
+        .unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&hook, fs::Permissions::from_mode(0o755)).unwrap();
+        }
+
+        let options = CreateOptions {
+            tag: Some("v1.2.3".to_string()),
+            ..Default::default()
+        };
+        let repo = Repo::new(repo_path.clone()).unwrap();
+        let result = process_single_repo(
+            &repo,
+            "GX-tag-fails",
+            &["README.md".to_string()],
+            &Change::Sub(
+                "repo".to_string(),
+                "tagged".to_string(),
+                diff::SubMatch::default(),
+            ),
+            Some("tag it"),
+            false,
+            false,
+            &options,
+            &Config::default(),
+            None,
+            None,
+        );
+
+        let error = result.error.expect("a rejected push must fail the repo");
+        assert!(error.contains("Failed to commit changes"), "got: {error}");
+        assert!(!local::git::tag_exists_locally(&repo_path, "v1.2.3").unwrap());
+        assert!(!local::git::tag_exists_locally(&bare, "v1.2.3").unwrap());
+    });
+}
+
+#[test]
+fn test_rejected_tag_push_is_not_recorded_for_undo() {
+    let data_home = TempDir::new().unwrap();
+    with_data_home(data_home.path(), || {
+        let ws = TempDir::new().unwrap();
+        let repo_path = ws.path().join("repo");
+        let bare = ws.path().join("repo.git");
+        let branch = init_repo_with_bare_remote(&repo_path, &bare);
+        // Someone else's release tag is already on origin.
+        run_git_command(
+            &[
+                "--git-dir",
+                bare.to_str().unwrap(),
+                "tag",
+                "v1.2.3",
+                &branch,
+            ],
+            ws.path(),
+        );
+
+        let options = CreateOptions {
+            tag: Some("v1.2.3".to_string()),
+            ..Default::default()
+        };
+        let repo = Repo::new(repo_path.clone()).unwrap();
+        let change_id = "GX-tag-rejected";
+        let change_state = Mutex::new(ChangeState::new(change_id.to_string(), None));
+        let state_manager = StateManager::new().unwrap();
+        let result = process_single_repo(
+            &repo,
+            change_id,
+            &["README.md".to_string()],
+            &Change::Sub(
+                "repo".to_string(),
+                "tagged".to_string(),
+                diff::SubMatch::default(),
+            ),
+            Some("tag it"),
+            false,
+            false,
+            &options,
+            &Config::default(),
+            Some(&change_state),
+            Some(&state_manager),
+        );
+
+        let error = result.error.expect("a rejected tag push must be reported");
+        assert!(error.contains("Tag push failed"), "got: {error}");
+        let state = change_state.lock().unwrap();
+        let repo_state = &state.repositories[&repo.slug];
+        assert!(repo_state.commit_sha.is_some());
+        assert_eq!(
+            repo_state.tag, None,
+            "undo must not be pointed at a tag gx never pushed"
+        );
+    });
+}
+
+#[test]
+fn test_regex_that_empties_a_file_is_counted_in_dry_run() {
+    let ws = TempDir::new().unwrap();
+    let repo_path = ws.path().join("repo");
+    let bare = ws.path().join("repo.git");
+    init_repo_with_bare_remote(&repo_path, &bare);
+
+    let repo = Repo::new(repo_path.clone()).unwrap();
+    let result = process_single_repo(
+        &repo,
+        "GX-empties",
+        &["README.md".to_string()],
+        &Change::Regex("(?s).*".to_string(), String::new()),
+        None,
+        false,
+        false,
+        &CreateOptions::default(),
+        &Config::default(),
+        None,
+        None,
+    );
+
+    assert!(result.error.is_none(), "got: {:?}", result.error);
+    let stats = result.substitution_stats.expect("regex reports stats");
+    assert_eq!(stats.files_emptied, ["README.md"]);
+}
+
+#[test]
+fn test_no_empty_files_refuses_and_restores_the_file() {
+    let ws = TempDir::new().unwrap();
+    let repo_path = ws.path().join("repo");
+    let bare = ws.path().join("repo.git");
+    init_repo_with_bare_remote(&repo_path, &bare);
+
+    let options = CreateOptions {
+        no_empty_files: true,
+        ..Default::default()
+    };
+    let repo = Repo::new(repo_path.clone()).unwrap();
+    let result = process_single_repo(
+        &repo,
+        "GX-empties",
+        &["README.md".to_string()],
+        &Change::Sub(
+            "# repo".to_string(),
+            " ".to_string(),
+            diff::SubMatch::default(),
+        ),
+        None,
+        false,
+        false,
+        &options,
+        &Config::default(),
+        None,
+        None,
+    );
+
+    let error = result.error.expect("an emptied file must fail the repo");
+    assert!(error.contains("would empty 1 file(s)"), "got: {error}");
+    assert!(error.contains("README.md"), "got: {error}");
+    assert_eq!(
+        fs::read_to_string(repo_path.join("README.md")).unwrap(),
+        "# repo\n"
+    );
+}
+
+#[test]
+fn test_limit_files_refuses_and_rolls_back_a_wide_change() {
+    let data_home = TempDir::new().unwrap();
+    with_data_home(data_home.path(), || {
+        let ws = TempDir::new().unwrap();
+        let repo_path = ws.path().join("repo");
+        let bare = ws.path().join("repo.git");
+        fs::create_dir_all(&repo_path).unwrap();
+        fs::write(repo_path.join("a.md"), "old a\n").unwrap();
+        fs::write(repo_path.join("b.md"), "old b\n").unwrap();
+        init_repo_with_bare_remote(&repo_path, &bare);
+
+        let options = CreateOptions {
+            limit_files: Some(2),
+            ..Default::default()
+        };
+        let repo = Repo::new(repo_path.clone()).unwrap();
+        let result = process_single_repo(
+            &repo,
+            "GX-too-wide",
+            &["*.md".to_string()],
+            &Change::Regex("(old|repo)".to_string(), "new".to_string()),
+            Some("too wide"),
+            false,
+            false,
+            &options,
+            &Config::default(),
+            None,
+            None,
+        );
+
+        let error = result
+            .error
+            .expect("a change over the limit must fail the repo");
+        assert!(
+            error.contains("would modify 3 files (limit 2)"),
+            "got: {error}"
+        );
+        // Rolled back: every file is untouched and nothing was committed.
+        assert_eq!(
+            fs::read_to_string(repo_path.join("a.md")).unwrap(),
+            "old a\n"
+        );
+        assert_eq!(
+            fs::read_to_string(repo_path.join("b.md")).unwrap(),
+            "old b\n"
+        );
+        assert_eq!(
+            fs::read_to_string(repo_path.join("README.md")).unwrap(),
+            "# repo\n"
+        );
+        assert!(!local::git::branch_exists_locally(&repo_path, "GX-too-wide").unwrap());
+    });
+}
+
+#[test]
+fn test_target_existing_branch_commits_onto_the_current_branch() {
+    let data_home = TempDir::new().unwrap();
+    with_data_home(data_home.path(), || {
+        let ws = TempDir::new().unwrap();
+        let repo_path = ws.path().join("repo");
+        let bare = ws.path().join("repo.git");
+        init_repo_with_bare_remote(&repo_path, &bare);
+        run_git_command(&["checkout", "--quiet", "-b", "feature"], &repo_path);
+
+        let options = CreateOptions {
+            target_existing_branch: true,
+            ..Default::default()
+        };
+        let repo = Repo::new(repo_path.clone()).unwrap();
+        let change_id = "GX-existing";
+        let change_state = Mutex::new(ChangeState::new(change_id.to_string(), None));
+        let state_manager = StateManager::new().unwrap();
+        let result = process_single_repo(
+            &repo,
+            change_id,
+            &["README.md".to_string()],
+            &Change::Sub(
+                "repo".to_string(),
+                "fixed".to_string(),
+                diff::SubMatch::default(),
+            ),
+            Some("fix it"),
+            false,
+            false,
+            &options,
+            &Config::default(),
+            Some(&change_state),
+            Some(&state_manager),
+        );
+
+        assert!(result.error.is_none(), "got: {:?}", result.error);
+        assert_eq!(result.branch.as_deref(), Some("feature"));
+        assert!(!local::git::branch_exists_locally(&repo_path, change_id).unwrap());
+        assert_eq!(
+            local::git::get_current_branch_name(&repo_path).unwrap(),
+            "feature"
+        );
+        let log = run_git_command(&["log", "-1", "--format=%s", "feature"], &bare);
+        assert_eq!(
+            String::from_utf8_lossy(&log.stdout).trim(),
+            "fix it",
+            "the commit must be pushed"
+        );
+    });
+}
+
+#[test]
+fn test_target_existing_branch_refuses_the_default_branch() {
+    let ws = TempDir::new().unwrap();
+    let repo_path = ws.path().join("repo");
+    let bare = ws.path().join("repo.git");
+    let default_branch = init_repo_with_bare_remote(&repo_path, &bare);
+    let head = run_git_command(&["rev-parse", "HEAD"], &repo_path);
+
+    let options = CreateOptions {
+        target_existing_branch: true,
+        ..Default::default()
+    };
+    let repo = Repo::new(repo_path.clone()).unwrap();
+    let result = process_single_repo(
+        &repo,
+        "GX-existing",
+        &["README.md".to_string()],
+        &Change::Sub(
+            "repo".to_string(),
+            "fixed".to_string(),
+            diff::SubMatch::default(),
+        ),
+        Some("fix it"),
+        false,
+        false,
+        &options,
+        &Config::default(),
+        None,
+        None,
+    );
+
+    let error = result.error.expect("the default branch must be refused");
+    assert!(
+        error.contains(&format!("default branch '{default_branch}'")),
+        "got: {error}"
+    );
+    assert_eq!(
+        run_git_command(&["rev-parse", "HEAD"], &repo_path).stdout,
+        head.stdout
+    );
+    assert_eq!(
+        fs::read_to_string(repo_path.join("README.md")).unwrap(),
+        "# repo\n"
+    );
+}
+
 // ---- Phase 3: diff surfaced on CreateResult (previously computed and
 // discarded); execute_create orchestration + the Confirmation seam ----
 
@@ -432,6 +1352,7 @@ fn test_apply_add_change_surfaces_diff_on_dry_run_result() {
         None, // dry run: no commit_message
         false,
         false,
+        &CreateOptions::default(),
         &Config::default(),
         None,
         None,
@@ -453,6 +1374,52 @@ fn test_apply_add_change_surfaces_diff_on_dry_run_result() {
     );
 }
 
+#[test]
+fn test_add_content_is_interpolated_per_repo_only_when_asked() {
+    let ws = TempDir::new().unwrap();
+    let repo_path = ws.path().join("widget");
+    init_repo_with_bare_remote(&repo_path, &ws.path().join("widget.git"));
+    let mut repo = Repo::new(repo_path).unwrap();
+    repo.slug = "acme/widget".to_string();
+    let add = Change::Add(
+        "CODEOWNERS".to_string(),
+        "# {repo} ({slug}) owned by @{org}/team\n".to_string(),
+    );
+    let dry_run_diff = |options: &CreateOptions| {
+        let result = process_single_repo(
+            &repo,
+            "GX-interpolate",
+            &[],
+            &add,
+            None,
+            false,
+            false,
+            options,
+            &Config::default(),
+            None,
+            None,
+        );
+        assert!(result.error.is_none(), "{:?}", result.error);
+        result.diff.expect("dry-run add must surface its diff")
+    };
+
+    let diff = dry_run_diff(&CreateOptions {
+        interpolate: true,
+        ..Default::default()
+    });
+    assert!(
+        diff.contains("# widget (acme/widget) owned by @acme/team"),
+        "{diff}"
+    );
+
+    // Without --interpolate the braces are content like any other.
+    let diff = dry_run_diff(&CreateOptions::default());
+    assert!(
+        diff.contains("# {repo} ({slug}) owned by @{org}/team"),
+        "{diff}"
+    );
+}
+
 #[test]
 fn test_dry_run_error_reports_no_diff_before_any_mutation() {
     // An error before any file was touched (RepoLock unavailable, detached
@@ -484,10 +1451,15 @@ fn test_execute_create_dry_run_returns_result_per_repo() {
         std::slice::from_ref(&repo),
         "GX-exec",
         &["*.md".to_string()],
-        &Change::Sub("repo".to_string(), "REPO".to_string()),
+        &Change::Sub(
+            "repo".to_string(),
+            "REPO".to_string(),
+            diff::SubMatch::default(),
+        ),
         None, // dry run
         false,
         false,
+        &CreateOptions::default(),
         &Config::default(),
         1,
         Confirmation::AlreadyConfirmed,
@@ -519,6 +1491,7 @@ fn test_execute_create_accepts_token_confirmation_with_no_repos() {
         None,
         false,
         false,
+        &CreateOptions::default(),
         &Config::default(),
         1,
         Confirmation::Token("deadbeef".to_string()),
@@ -527,3 +1500,103 @@ fn test_execute_create_accepts_token_confirmation_with_no_repos() {
 
     assert!(results.is_empty());
 }
+
+#[test]
+fn test_commit_for_appends_change_id_trailer_after_user_trailers() {
+    let options = CreateOptions {
+        commit: CommitOptions {
+            trailers: vec!["Refs: OPS-42".to_string()],
+            ..Default::default()
+        },
+        change_id_trailer: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        options.commit_for("GX-trailers").trailers,
+        ["Refs: OPS-42", "Change-Id: GX-trailers"]
+    );
+
+    let without = CreateOptions {
+        change_id_trailer: false,
+        ..options
+    };
+    assert_eq!(without.commit_for("GX-trailers").trailers, ["Refs: OPS-42"]);
+}
+
+#[test]
+fn test_dry_run_delete_lists_files_and_removes_nothing() {
+    let data_home = TempDir::new().unwrap();
+    with_data_home(data_home.path(), || {
+        let ws = TempDir::new().unwrap();
+        let repo_path = ws.path().join("repo");
+        let bare = ws.path().join("repo.git");
+        init_repo_with_bare_remote(&repo_path, &bare);
+
+        let repo = Repo::new(repo_path.clone()).unwrap();
+        let result = process_single_repo(
+            &repo,
+            "GX-dry-delete",
+            &["README.md".to_string()],
+            &Change::Delete,
+            None,
+            false,
+            false,
+            &CreateOptions::default(),
+            &Config::default(),
+            None,
+            None,
+        );
+
+        assert!(result.error.is_none(), "got: {:?}", result.error);
+        assert!(matches!(result.action, CreateAction::DryRun));
+        assert_eq!(result.files_affected, ["README.md"]);
+        let diff = result.diff.expect("a dry-run delete carries its diff");
+        assert!(diff.contains("D README.md"), "got: {diff}");
+        assert!(
+            repo_path.join("README.md").exists(),
+            "a dry run must not delete anything"
+        );
+    });
+}
+
+#[test]
+fn test_pr_body_template_precedence() {
+    let temp = TempDir::new().unwrap();
+    let config = Config::default();
+    let with_template = CreateOptions {
+        pr_use_template: true,
+        ..Default::default()
+    };
+
+    // No repo template: --pr-use-template falls back to the configured body.
+    assert_eq!(
+        pr_body_template(temp.path(), &with_template, &config),
+        config.pr_body_template()
+    );
+
+    fs::create_dir_all(temp.path().join(".github")).unwrap();
+    fs::write(
+        temp.path().join(".github/PULL_REQUEST_TEMPLATE.md"),
+        "## Why\n{commit_message}\n",
+    )
+    .unwrap();
+    assert_eq!(
+        pr_body_template(temp.path(), &with_template, &config),
+        "## Why\n{commit_message}\n"
+    );
+    // Without the flag the repo template is ignored.
+    assert_eq!(
+        pr_body_template(temp.path(), &CreateOptions::default(), &config),
+        config.pr_body_template()
+    );
+
+    // An explicit --pr-body beats the repo template.
+    let explicit = CreateOptions {
+        pr_body: Some("{change_id} for {repo}".to_string()),
+        ..with_template
+    };
+    assert_eq!(
+        pr_body_template(temp.path(), &explicit, &config),
+        "{change_id} for {repo}"
+    );
+}
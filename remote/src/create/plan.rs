@@ -0,0 +1,472 @@
+//! `gx create --plan-out` / `--plan-in`: the full plan of a create run as
+//! JSON, so a gated rollout can approve exactly what will happen before it
+//! happens.
+//!
+//! A plan records every input the run depends on (change id, file patterns,
+//! change, commit message, PR title/body, commit, format and tag options) plus
+//! what those inputs resolved to at planning time: the repos, each repo's
+//! branch, and the files each repo's change will touch. Executing a plan
+//! re-resolves the same inputs against the tree and refuses to run if
+//! anything drifted, so an approved plan is never silently widened or
+//! narrowed.
+
+use super::core::pr_body_template;
+use super::{Change, CreateOptions};
+use crate::github;
+use eyre::{Context, Result};
+use local::config::Config;
+use local::file;
+use local::git::CommitOptions;
+use local::repo::Repo;
+use log::debug;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Format version written into every plan; a plan with another version is
+/// refused rather than half-understood.
+pub const PLAN_VERSION: u32 = 1;
+
+/// A complete, replayable `gx create` run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct CreatePlan {
+    pub version: u32,
+    pub change_id: String,
+    /// The `--files` glob patterns.
+    pub files: Vec<String>,
+    pub change: PlannedChange,
+    /// `None` plans a dry run.
+    pub commit_message: Option<String>,
+    pub pr: Option<PlannedPr>,
+    pub sign: bool,
+    pub sign_key: Option<String>,
+    #[serde(default)]
+    pub trailers: Vec<String>,
+    #[serde(default)]
+    pub no_verify: bool,
+    #[serde(default)]
+    pub change_id_trailer: bool,
+    pub ensure_final_newline: bool,
+    #[serde(default)]
+    pub format_cmd: Option<String>,
+    #[serde(default)]
+    pub tag: Option<String>,
+    #[serde(default)]
+    pub tag_message: Option<String>,
+    #[serde(default)]
+    pub no_empty_files: bool,
+    #[serde(default)]
+    pub no_gitignore: bool,
+    #[serde(default)]
+    pub limit_files: Option<usize>,
+    #[serde(default)]
+    pub target_existing_branch: bool,
+    #[serde(default)]
+    pub interpolate: bool,
+    pub repos: Vec<PlannedRepo>,
+}
+
+/// The change a plan applies. Only the deterministic changes can be planned;
+/// an `llm` run has its own propose/apply split.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum PlannedChange {
+    Add {
+        path: String,
+        content: String,
+    },
+    Append {
+        path: String,
+        content: String,
+        #[serde(default)]
+        if_missing: bool,
+    },
+    Delete,
+    Sub {
+        pattern: String,
+        replacement: String,
+        #[serde(default)]
+        ignore_case: bool,
+        #[serde(default)]
+        whole_word: bool,
+    },
+    Regex {
+        pattern: String,
+        replacement: String,
+    },
+    SetKey {
+        key_path: String,
+        value: String,
+        #[serde(default)]
+        create: bool,
+    },
+}
+
+/// The PR each repo will get. Each repo's interpolated body is on its
+/// [`PlannedRepo`]; `body` is the template they were rendered from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct PlannedPr {
+    pub title: String,
+    pub body: String,
+    /// `--pr-body`/`--pr-body-file` text, replayed as-is by `--plan-in`.
+    #[serde(default)]
+    pub body_override: Option<String>,
+    #[serde(default)]
+    pub use_repo_template: bool,
+    /// `--base`, resolved per repo at execution time like a direct run.
+    #[serde(default)]
+    pub base: Option<String>,
+    pub draft: bool,
+    #[serde(default)]
+    pub reviewers: Vec<String>,
+    #[serde(default)]
+    pub assignees: Vec<String>,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub create_labels: bool,
+}
+
+/// One repo in the plan: its branch and the files its change touches.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct PlannedRepo {
+    pub slug: String,
+    pub branch: String,
+    pub files: Vec<String>,
+    /// The PR body exactly as it will be sent; `None` when no PR is planned.
+    #[serde(default)]
+    pub pr_body: Option<String>,
+}
+
+impl PlannedChange {
+    /// The plannable form of `change`; `llm` and internal changes are refused.
+    pub fn from_change(change: &Change) -> Result<Self> {
+        Ok(match change {
+            Change::Add(path, content) => PlannedChange::Add {
+                path: path.clone(),
+                content: content.clone(),
+            },
+            Change::Append(path, content, if_missing) => PlannedChange::Append {
+                path: path.clone(),
+                content: content.clone(),
+                if_missing: *if_missing,
+            },
+            Change::Delete => PlannedChange::Delete,
+            Change::Sub(pattern, replacement, matching) => PlannedChange::Sub {
+                pattern: pattern.clone(),
+                replacement: replacement.clone(),
+                ignore_case: matching.ignore_case,
+                whole_word: matching.whole_word,
+            },
+            Change::Regex(pattern, replacement) => PlannedChange::Regex {
+                pattern: pattern.clone(),
+                replacement: replacement.clone(),
+            },
+            Change::SetKey(key_path, value, create) => PlannedChange::SetKey {
+                key_path: key_path.clone(),
+                value: value.clone(),
+                create: *create,
+            },
+            Change::Llm(_) => {
+                return Err(eyre::eyre!(
+                    "--plan-out does not support `llm` changes; use `llm --propose` and `gx apply`"
+                ))
+            }
+            Change::Patchset { .. } => {
+                return Err(eyre::eyre!("a proposal apply cannot be written as a plan"))
+            }
+        })
+    }
+
+    /// The [`Change`] this plan entry executes as.
+    pub fn to_change(&self) -> Change {
+        match self {
+            PlannedChange::Add { path, content } => Change::Add(path.clone(), content.clone()),
+            PlannedChange::Append {
+                path,
+                content,
+                if_missing,
+            } => Change::Append(path.clone(), content.clone(), *if_missing),
+            PlannedChange::Delete => Change::Delete,
+            PlannedChange::Sub {
+                pattern,
+                replacement,
+                ignore_case,
+                whole_word,
+            } => Change::Sub(
+                pattern.clone(),
+                replacement.clone(),
+                local::diff::SubMatch {
+                    ignore_case: *ignore_case,
+                    whole_word: *whole_word,
+                },
+            ),
+            PlannedChange::Regex {
+                pattern,
+                replacement,
+            } => Change::Regex(pattern.clone(), replacement.clone()),
+            PlannedChange::SetKey {
+                key_path,
+                value,
+                create,
+            } => Change::SetKey(key_path.clone(), value.clone(), *create),
+        }
+    }
+}
+
+/// Resolve a create run's inputs against `repos` into a plan.
+#[allow(clippy::too_many_arguments)]
+pub fn build_plan(
+    repos: &[Repo],
+    change_id: &str,
+    files: &[String],
+    change: &Change,
+    commit_message: Option<&str>,
+    pr: bool,
+    draft: bool,
+    options: &CreateOptions,
+    config: &Config,
+) -> Result<CreatePlan> {
+    debug!(
+        "build_plan: change_id={change_id} repos={} files={files:?}",
+        repos.len()
+    );
+    let change = PlannedChange::from_change(change)?;
+
+    let repos = repos
+        .iter()
+        .map(|repo| {
+            let branch = if options.target_existing_branch {
+                local::git::get_current_branch_name(&repo.path)
+                    .with_context(|| format!("Failed to get current branch of {}", repo.slug))?
+            } else {
+                change_id.to_string()
+            };
+            let files = planned_files(repo, files, options.no_gitignore)?;
+            let pr_body = match (pr, commit_message) {
+                (true, Some(message)) => Some(github::render_pr_body(
+                    &pr_body_template(&repo.path, options, config),
+                    message,
+                    change_id,
+                    &repo.slug,
+                    &files,
+                )),
+                _ => None,
+            };
+            Ok(PlannedRepo {
+                slug: repo.slug.clone(),
+                branch,
+                files,
+                pr_body,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let pr = match (pr, commit_message) {
+        (true, Some(_)) => Some(PlannedPr {
+            title: change_id.to_string(),
+            body: options
+                .pr_body
+                .clone()
+                .unwrap_or_else(|| config.pr_body_template()),
+            body_override: options.pr_body.clone(),
+            use_repo_template: options.pr_use_template,
+            base: options.pr_base.clone(),
+            draft,
+            reviewers: options.pr_metadata.reviewers.clone(),
+            assignees: options.pr_metadata.assignees.clone(),
+            labels: options.pr_metadata.labels.clone(),
+            create_labels: options.pr_metadata.create_labels,
+        }),
+        _ => None,
+    };
+
+    Ok(CreatePlan {
+        version: PLAN_VERSION,
+        change_id: change_id.to_string(),
+        files: files.to_vec(),
+        change,
+        commit_message: commit_message.map(str::to_string),
+        pr,
+        sign: options.commit.sign,
+        sign_key: options.commit.sign_key.clone(),
+        trailers: options.commit.trailers.clone(),
+        no_verify: options.commit.no_verify,
+        change_id_trailer: options.change_id_trailer,
+        ensure_final_newline: options.ensure_final_newline,
+        format_cmd: options.format_cmd.clone(),
+        tag: options.tag.clone(),
+        tag_message: options.tag_message.clone(),
+        no_empty_files: options.no_empty_files,
+        no_gitignore: options.no_gitignore,
+        limit_files: options.limit_files,
+        target_existing_branch: options.target_existing_branch,
+        interpolate: options.interpolate,
+        repos,
+    })
+}
+
+/// The tracked files `files` matches in `repo`, as sorted relative paths.
+fn planned_files(repo: &Repo, files: &[String], include_ignored: bool) -> Result<Vec<String>> {
+    if files.is_empty() {
+        return Ok(Vec::new());
+    }
+    let matched = file::FileSet::matching(&repo.path, files, include_ignored)
+        .with_context(|| format!("Failed to match files in {}", repo.slug))?;
+    Ok(matched.iter().map(|p| p.display().to_string()).collect())
+}
+
+impl CreatePlan {
+    /// The commit/write options the plan was made with.
+    pub fn options(&self) -> CreateOptions {
+        CreateOptions {
+            commit: CommitOptions {
+                sign: self.sign,
+                sign_key: self.sign_key.clone(),
+                trailers: self.trailers.clone(),
+                no_verify: self.no_verify,
+            },
+            change_id_trailer: self.change_id_trailer,
+            ensure_final_newline: self.ensure_final_newline,
+            format_cmd: self.format_cmd.clone(),
+            tag: self.tag.clone(),
+            tag_message: self.tag_message.clone(),
+            no_empty_files: self.no_empty_files,
+            no_gitignore: self.no_gitignore,
+            limit_files: self.limit_files,
+            target_existing_branch: self.target_existing_branch,
+            interpolate: self.interpolate,
+            pr_body: self.pr.as_ref().and_then(|pr| pr.body_override.clone()),
+            pr_use_template: self.pr.as_ref().is_some_and(|pr| pr.use_repo_template),
+            pr_base: self.pr.as_ref().and_then(|pr| pr.base.clone()),
+            pr_metadata: self
+                .pr
+                .as_ref()
+                .map(|pr| github::PrMetadata {
+                    reviewers: pr.reviewers.clone(),
+                    assignees: pr.assignees.clone(),
+                    labels: pr.labels.clone(),
+                    create_labels: pr.create_labels,
+                })
+                .unwrap_or_default(),
+            progress: false,
+            detailed: false,
+            json: false,
+            file_pool: None,
+            stats: Default::default(),
+        }
+    }
+
+    /// Pick the planned repos out of `discovered`, in plan order. Every
+    /// planned repo must be present: a plan is never executed partially.
+    pub fn select_repos(&self, discovered: Vec<Repo>) -> Result<Vec<Repo>> {
+        let mut by_slug: HashMap<String, Repo> = discovered
+            .into_iter()
+            .map(|repo| (repo.slug.clone(), repo))
+            .collect();
+        let mut missing = Vec::new();
+        let mut selected = Vec::with_capacity(self.repos.len());
+        for planned in &self.repos {
+            match by_slug.remove(&planned.slug) {
+                Some(repo) => selected.push(repo),
+                None => missing.push(planned.slug.as_str()),
+            }
+        }
+        if !missing.is_empty() {
+            return Err(eyre::eyre!(
+                "plan {} names repos not found under the working directory: {}",
+                self.change_id,
+                missing.join(", ")
+            ));
+        }
+        Ok(selected)
+    }
+
+    /// Re-resolve the plan against `repos` (from [`Self::select_repos`]) and
+    /// the current config, and refuse if the result differs from what was
+    /// approved: a file that appeared or vanished, or a changed PR body.
+    pub fn verify(&self, repos: &[Repo], config: &Config) -> Result<()> {
+        let fresh = build_plan(
+            repos,
+            &self.change_id,
+            &self.files,
+            &self.change.to_change(),
+            self.commit_message.as_deref(),
+            self.pr.is_some(),
+            self.pr.as_ref().is_some_and(|pr| pr.draft),
+            &self.options(),
+            config,
+        )?;
+
+        let mut drift = Vec::new();
+        for (planned, now) in self.repos.iter().zip(&fresh.repos) {
+            if planned.branch != now.branch {
+                drift.push(format!(
+                    "{}: branch {} is now {}",
+                    planned.slug, planned.branch, now.branch
+                ));
+            }
+            if planned.files != now.files {
+                drift.push(format!(
+                    "{}: planned files {:?}, now {:?}",
+                    planned.slug, planned.files, now.files
+                ));
+            }
+            if planned.pr_body.is_some() && planned.pr_body != now.pr_body {
+                drift.push(format!(
+                    "{}: PR body no longer matches the plan (PR template changed?)",
+                    planned.slug
+                ));
+            }
+        }
+        if self.pr != fresh.pr {
+            drift.push("PR title/body no longer matches the plan (config changed?)".to_string());
+        }
+
+        if drift.is_empty() {
+            Ok(())
+        } else {
+            Err(eyre::eyre!(
+                "plan {} no longer matches the tree; re-plan and re-approve:\n  {}",
+                self.change_id,
+                drift.join("\n  ")
+            ))
+        }
+    }
+}
+
+/// Write `plan` to `path` as pretty JSON (atomic: an approver never reads a
+/// half-written plan).
+pub fn write_plan(path: &Path, plan: &CreatePlan) -> Result<()> {
+    debug!(
+        "write_plan: path={} repos={}",
+        path.display(),
+        plan.repos.len()
+    );
+    let json = serde_json::to_string_pretty(plan).context("Failed to serialize plan to JSON")?;
+    file::atomic_write(path, json.as_bytes())
+        .with_context(|| format!("Failed to write plan to {}", path.display()))
+}
+
+/// Read a plan written by [`write_plan`], refusing an unknown version.
+pub fn read_plan(path: &Path) -> Result<CreatePlan> {
+    debug!("read_plan: path={}", path.display());
+    let json = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read plan from {}", path.display()))?;
+    let plan: CreatePlan = serde_json::from_str(&json)
+        .with_context(|| format!("Failed to parse plan {}", path.display()))?;
+    if plan.version != PLAN_VERSION {
+        return Err(eyre::eyre!(
+            "plan {} has version {}, this gx understands version {PLAN_VERSION}",
+            path.display(),
+            plan.version
+        ));
+    }
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,145 @@
+use super::*;
+use local::test_utils::{create_minimal_test_repo, run_git_command};
+use tempfile::TempDir;
+
+/// A temp workspace with two repos, each tracking one `.md` file besides the
+/// README, and the `Repo`s discovered from it.
+fn workspace() -> (TempDir, Vec<Repo>) {
+    let temp = TempDir::new().unwrap();
+    let mut repos = Vec::new();
+    for name in ["alpha", "beta"] {
+        let path = create_minimal_test_repo(temp.path(), name);
+        std::fs::write(path.join("NOTES.md"), "old text\n").unwrap();
+        run_git_command(&["add", "NOTES.md"], &path);
+        run_git_command(&["commit", "--quiet", "-m", "notes"], &path);
+        repos.push(Repo::new(path).unwrap());
+    }
+    (temp, repos)
+}
+
+fn sample_plan(repos: &[Repo]) -> CreatePlan {
+    build_plan(
+        repos,
+        "GX-plan-test",
+        &["*.md".to_string()],
+        &Change::Sub(
+            "old".to_string(),
+            "new".to_string(),
+            local::diff::SubMatch::default(),
+        ),
+        Some("Update docs"),
+        true,
+        true,
+        &CreateOptions::default(),
+        &Config::default(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_build_plan_captures_repos_files_and_pr() {
+    let (_temp, repos) = workspace();
+    let plan = sample_plan(&repos);
+
+    assert_eq!(plan.version, PLAN_VERSION);
+    assert_eq!(plan.change_id, "GX-plan-test");
+    assert_eq!(
+        plan.change,
+        PlannedChange::Sub {
+            pattern: "old".to_string(),
+            replacement: "new".to_string(),
+            ignore_case: false,
+            whole_word: false,
+        }
+    );
+    let slugs: Vec<&str> = plan.repos.iter().map(|r| r.slug.as_str()).collect();
+    assert_eq!(slugs, ["testorg/alpha", "testorg/beta"]);
+    for repo in &plan.repos {
+        assert_eq!(repo.branch, "GX-plan-test");
+        assert_eq!(repo.files, ["NOTES.md", "README.md"]);
+        let body = repo
+            .pr_body
+            .as_deref()
+            .expect("each repo's PR body is planned");
+        assert!(body.contains("Update docs"), "{body}");
+    }
+
+    let pr = plan.pr.as_ref().expect("--pr with a commit plans a PR");
+    assert_eq!(pr.title, "GX-plan-test");
+    assert_eq!(pr.body, Config::default().pr_body_template());
+    assert!(pr.draft);
+}
+
+#[test]
+fn test_plan_round_trips_through_json() {
+    let (temp, repos) = workspace();
+    let plan = sample_plan(&repos);
+
+    let path = temp.path().join("plan.json");
+    write_plan(&path, &plan).unwrap();
+    assert_eq!(read_plan(&path).unwrap(), plan);
+
+    // A plan from a different format version is refused, not half-read.
+    let json = std::fs::read_to_string(&path)
+        .unwrap()
+        .replace("\"version\": 1", "\"version\": 99");
+    std::fs::write(&path, json).unwrap();
+    let err = read_plan(&path).unwrap_err().to_string();
+    assert!(err.contains("version 99"), "{err}");
+}
+
+#[test]
+fn test_verify_refuses_a_drifted_tree() {
+    let (_temp, repos) = workspace();
+    let plan = sample_plan(&repos);
+    plan.verify(&repos, &Config::default()).unwrap();
+
+    // A new tracked file matching the pattern widens the run: refuse it.
+    let beta = &repos[1].path;
+    std::fs::write(beta.join("EXTRA.md"), "old\n").unwrap();
+    run_git_command(&["add", "EXTRA.md"], beta);
+    run_git_command(&["commit", "--quiet", "-m", "extra"], beta);
+
+    let err = plan.verify(&repos, &Config::default()).unwrap_err();
+    let message = err.to_string();
+    assert!(message.contains("testorg/beta"), "{message}");
+    assert!(message.contains("EXTRA.md"), "{message}");
+}
+
+#[test]
+fn test_select_repos_requires_every_planned_repo() {
+    let (_temp, repos) = workspace();
+    let plan = sample_plan(&repos);
+
+    let selected = plan.select_repos(repos.clone()).unwrap();
+    assert_eq!(selected.len(), 2);
+
+    let err = plan
+        .select_repos(vec![repos[0].clone()])
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("testorg/beta"), "{err}");
+}
+
+#[test]
+fn test_llm_changes_cannot_be_planned() {
+    assert!(PlannedChange::from_change(&Change::Llm("do it".to_string())).is_err());
+    let planned = PlannedChange::from_change(&Change::Delete).unwrap();
+    assert_eq!(planned, PlannedChange::Delete);
+    assert!(matches!(planned.to_change(), Change::Delete));
+}
+
+#[test]
+fn test_append_change_round_trips_through_a_plan() {
+    let change = Change::Append(".gitignore".to_string(), ".env".to_string(), true);
+    let planned = PlannedChange::from_change(&change).unwrap();
+    let json = serde_json::to_string(&planned).unwrap();
+    assert!(json.contains(r#""kind":"append""#), "{json}");
+    assert!(json.contains(r#""if_missing":true"#), "{json}");
+
+    let read: PlannedChange = serde_json::from_str(&json).unwrap();
+    assert!(matches!(
+        read.to_change(),
+        Change::Append(path, content, true) if path == ".gitignore" && content == ".env"
+    ));
+}
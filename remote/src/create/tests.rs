@@ -35,9 +35,11 @@ fn make_result(slug: &str, action: CreateAction, error: Option<&str>) -> CreateR
         pr_number: None,
         pr_url: None,
         original_branch: None,
+        branch: None,
         base_sha: None,
         diff: None,
         error: error.map(str::to_string),
+        warning: None,
     }
 }
 
@@ -71,6 +73,45 @@ fn test_phase_label_matches_every_create_action() {
     assert_eq!(phase_label(&CreateAction::PrCreated), "pr-created");
 }
 
+#[test]
+fn test_dry_run_preview_marks_each_deleted_file() {
+    let mut result = make_result("org/repo", CreateAction::DryRun, None);
+    result.files_affected = vec!["a.txt".to_string(), "b.txt".to_string()];
+    result.diff = Some("  D a.txt\n    -alpha\n  D b.txt\n    -beta".to_string());
+
+    let lines = dry_run_preview_lines(&result, &Change::Delete, false);
+    assert_eq!(
+        lines,
+        [
+            "  org/repo will DELETE 2 file(s):",
+            "    ❌ a.txt",
+            "    ❌ b.txt"
+        ]
+    );
+
+    let detailed = dry_run_preview_lines(&result, &Change::Delete, true);
+    assert_eq!(&detailed[..3], &lines[..]);
+    assert!(detailed.contains(&"      -alpha".to_string()));
+    assert!(detailed.contains(&"      -beta".to_string()));
+}
+
+#[test]
+fn test_dry_run_preview_is_silent_for_other_changes_unless_detailed() {
+    let mut result = make_result("org/repo", CreateAction::DryRun, None);
+    result.files_affected = vec!["a.md".to_string()];
+    result.diff = Some("  M a.md".to_string());
+    let change = Change::Regex("x".to_string(), "y".to_string());
+
+    assert!(dry_run_preview_lines(&result, &change, false).is_empty());
+    assert_eq!(
+        dry_run_preview_lines(&result, &change, true),
+        ["  org/repo:", "    M a.md"]
+    );
+
+    let committed = make_result("org/repo", CreateAction::Committed, None);
+    assert!(dry_run_preview_lines(&committed, &Change::Delete, true).is_empty());
+}
+
 // Break-the-guard: a `build_run_report` that (wrongly) includes successful
 // repos, or drops the failing one, fails this assertion on both count and
 // content.
@@ -129,3 +170,64 @@ fn test_write_run_report_writes_empty_array_when_nothing_failed() {
     let parsed: serde_json::Value = serde_json::from_str(&contents).unwrap();
     assert_eq!(parsed.as_array().unwrap().len(), 0);
 }
+
+#[test]
+fn test_read_add_content_inline_file_and_stdin() {
+    let mut no_stdin = std::io::empty();
+    assert_eq!(
+        read_add_content("inline\n", &mut no_stdin).unwrap(),
+        "inline\n"
+    );
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let template = dir.path().join("content.tmpl");
+    std::fs::write(&template, "line one\nline two\n").unwrap();
+    let arg = format!("@{}", template.display());
+    assert_eq!(
+        read_add_content(&arg, &mut no_stdin).unwrap(),
+        "line one\nline two\n"
+    );
+
+    let mut stdin = std::io::Cursor::new("piped\ncontent\n");
+    assert_eq!(
+        read_add_content("-", &mut stdin).unwrap(),
+        "piped\ncontent\n"
+    );
+}
+
+#[test]
+fn test_read_add_content_double_at_is_a_literal_at() {
+    assert_eq!(
+        read_add_content("@@scottidler/owners", &mut std::io::empty()).unwrap(),
+        "@scottidler/owners"
+    );
+}
+
+#[test]
+fn test_read_add_content_missing_file_names_it() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let arg = format!("@{}", dir.path().join("missing.tmpl").display());
+    let err = read_add_content(&arg, &mut std::io::empty()).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains(&format!("Failed to read add content from {arg}")),
+        "got: {err}"
+    );
+}
+
+#[test]
+fn test_render_matches_json_lists_each_repo_and_its_files() {
+    let repo = Repo {
+        path: std::path::PathBuf::from("/repos/scottidler/gx"),
+        name: "gx".to_string(),
+        slug: "scottidler/gx".to_string(),
+        layout: local::repo::Layout::Flat,
+    };
+    let json = render_matches_json(&[(repo, vec!["README.md".to_string()])]).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        parsed,
+        serde_json::json!([{"repo": "scottidler/gx", "files": ["README.md"]}])
+    );
+    assert_eq!(render_matches_json(&[]).unwrap(), "[]");
+}
@@ -1,19 +1,20 @@
-//! `gx doctor`: check required tools and report (optionally purge) orphaned
-//! recovery/backup artifacts left under `$XDG_DATA_HOME/gx` by interrupted runs
-//! or deleted repos ([A2], [A24]).
+//! `gx doctor`: check required tools and the environment (GitHub SSH, `gh`
+//! auth, config file, writable data dirs), and report (optionally purge)
+//! orphaned recovery/backup artifacts left under `$XDG_DATA_HOME/gx` by
+//! interrupted runs or deleted repos ([A2], [A24]).
 
+use crate::ssh::SshCommandDetector;
 use crate::state::{ChangeStatus, StateManager};
 use crate::transaction::Transaction;
 use chrono::{DateTime, Utc};
 use eyre::Result;
-use local::config::xdg_data_dir;
+use local::config::{default_config_path, xdg_data_dir, Config};
+use local::subprocess::{run_checked, subprocess_timeout};
 use log::{debug, warn};
 use serde::Serialize;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
-const GIT_MIN_VERSION: &str = "2.20.0";
-const GH_MIN_VERSION: &str = "2.0.0";
 /// Minimum `rg` (ripgrep) version. `search` shells out to `rg` (design doc
 /// `2026-07-17-gx-intel-catalog.md`), so `gx doctor` flags a missing/old one.
 const RG_MIN_VERSION: &str = "13.0.0";
@@ -68,14 +69,175 @@ pub struct DoctorReport {
     pub stuck_proposals: Vec<StuckProposal>,
 }
 
+/// How an environment check came out. `Fail` is a hard requirement gx cannot
+/// work without and makes `gx doctor` exit non-zero; `Warn` is worth knowing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckLevel {
+    Ok,
+    Warn,
+    Fail,
+}
+
+impl CheckLevel {
+    fn icon(self) -> &'static str {
+        match self {
+            CheckLevel::Ok => "✅",
+            CheckLevel::Warn => "⚠️",
+            CheckLevel::Fail => "❌",
+        }
+    }
+}
+
+/// One environment check: what was checked, how it came out, and why.
+#[derive(Debug, Clone, Serialize)]
+pub struct EnvCheck {
+    pub name: String,
+    pub level: CheckLevel,
+    pub detail: String,
+}
+
+impl EnvCheck {
+    fn new(name: &str, level: CheckLevel, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            level,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Run the environment checks against the real system. These reach the
+/// network (`ssh -T git@github.com`, `gh auth status`), so they are not part
+/// of [`collect_report`], which stays a local read for the MCP `doctor` tool.
+pub fn environment_checks(config_path: Option<&Path>) -> Vec<EnvCheck> {
+    debug!("environment_checks: config_path={config_path:?}");
+    let mut checks = vec![
+        check_ssh(SshCommandDetector::test_github_ssh_connection),
+        check_gh_auth(gh_auth_status),
+        check_config_file(config_path, default_config_path()),
+    ];
+    let data = xdg_data_dir().map(|d| d.join("gx"));
+    for (name, sub) in [
+        ("log dir", "logs"),
+        ("state dir", "changes"),
+        ("recovery dir", "recovery"),
+    ] {
+        checks.push(match &data {
+            Some(base) => check_writable_dir(name, &base.join(sub)),
+            None => EnvCheck::new(
+                name,
+                CheckLevel::Fail,
+                "could not determine data dir (set HOME or XDG_DATA_HOME)",
+            ),
+        });
+    }
+    checks
+}
+
+/// GitHub SSH connectivity: clones and pushes go over SSH, so a failure is
+/// a hard one.
+fn check_ssh(probe: impl FnOnce() -> Result<String>) -> EnvCheck {
+    match probe() {
+        Ok(user) => EnvCheck::new(
+            "github ssh",
+            CheckLevel::Ok,
+            format!("authenticated as {user}"),
+        ),
+        Err(e) => EnvCheck::new("github ssh", CheckLevel::Fail, e.to_string()),
+    }
+}
+
+/// `gh` authentication: every GitHub listing and PR operation goes through it.
+fn check_gh_auth(probe: impl FnOnce() -> Result<String>) -> EnvCheck {
+    match probe() {
+        Ok(account) => EnvCheck::new("gh auth", CheckLevel::Ok, account),
+        Err(e) => EnvCheck::new("gh auth", CheckLevel::Fail, e.to_string()),
+    }
+}
+
+/// `gh auth status`, reduced to its "Logged in to ..." line on success.
+fn gh_auth_status() -> Result<String> {
+    let output = run_checked(
+        Command::new("gh").args(["auth", "status"]),
+        subprocess_timeout(),
+    )?;
+    // Older gh prints the status on stderr, newer on stdout.
+    let text = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    if !output.status.success() {
+        let reason = text.lines().map(str::trim).find(|l| !l.is_empty());
+        return Err(eyre::eyre!(
+            "not logged in: {}",
+            reason.unwrap_or("gh auth status failed")
+        ));
+    }
+    Ok(text
+        .lines()
+        .map(|l| l.trim().trim_start_matches('✓').trim())
+        .find(|l| l.starts_with("Logged in"))
+        .unwrap_or("logged in")
+        .to_string())
+}
+
+/// The config file: an explicit `--config` that is missing is a hard failure
+/// (loading it fails too); no default config file only means defaults apply.
+fn check_config_file(explicit: Option<&Path>, default: Option<PathBuf>) -> EnvCheck {
+    match (explicit, default) {
+        (Some(path), _) if path.is_file() => {
+            EnvCheck::new("config file", CheckLevel::Ok, path.display().to_string())
+        }
+        (Some(path), _) => EnvCheck::new(
+            "config file",
+            CheckLevel::Fail,
+            format!("{} not found", path.display()),
+        ),
+        (None, Some(path)) if path.is_file() => {
+            EnvCheck::new("config file", CheckLevel::Ok, path.display().to_string())
+        }
+        (None, Some(path)) => EnvCheck::new(
+            "config file",
+            CheckLevel::Warn,
+            format!("{} not found; using defaults", path.display()),
+        ),
+        (None, None) => EnvCheck::new(
+            "config file",
+            CheckLevel::Warn,
+            "could not determine config dir; using defaults",
+        ),
+    }
+}
+
+/// A data directory gx writes to: created if missing, then probed with a
+/// throwaway file.
+fn check_writable_dir(name: &str, dir: &Path) -> EnvCheck {
+    let probe = dir.join(format!(".gx-doctor-{}", std::process::id()));
+    let result = std::fs::create_dir_all(dir)
+        .and_then(|()| std::fs::write(&probe, b""))
+        .and_then(|()| std::fs::remove_file(&probe));
+    match result {
+        Ok(()) => EnvCheck::new(name, CheckLevel::Ok, dir.display().to_string()),
+        Err(e) => EnvCheck::new(
+            name,
+            CheckLevel::Fail,
+            format!("{} not writable: {e}", dir.display()),
+        ),
+    }
+}
+
 /// Gather the full structured doctor report WITHOUT printing or purging: the
-/// read core behind both `run_doctor` and the MCP `doctor` tool.
-pub fn collect_report() -> Result<DoctorReport> {
+/// read core behind both `run_doctor` and the MCP `doctor` tool. git and gh
+/// are held to the config's `min-tool-versions`, as the startup check is.
+pub fn collect_report(config: &Config) -> Result<DoctorReport> {
     debug!("collect_report: gathering doctor report");
-    let mut tools: Vec<ToolCheck> = [("git", GIT_MIN_VERSION), ("gh", GH_MIN_VERSION)]
+    let mut tools: Vec<ToolCheck> = config
+        .min_tool_versions()
         .into_iter()
         .map(|(tool, min)| {
-            let status = check_tool_version(tool, min);
+            let status = check_tool_version(tool, &min);
             ToolCheck {
                 name: tool.to_string(),
                 version: status.version,
@@ -108,9 +270,12 @@ pub fn collect_report() -> Result<DoctorReport> {
     })
 }
 
-/// Run the doctor command: render the structured report and (optionally) purge.
-pub fn run_doctor(purge: bool) -> Result<()> {
-    let report = collect_report()?;
+/// Run the doctor command: render the structured report and the environment
+/// checks, and (optionally) purge. Fails when a hard requirement is missing:
+/// git or gh, or any environment check at [`CheckLevel::Fail`].
+pub fn run_doctor(config: &Config, config_path: Option<&Path>, purge: bool) -> Result<()> {
+    let report = collect_report(config)?;
+    let environment = environment_checks(config_path);
 
     println!("REQUIRED TOOLS:");
     for tool in &report.tools {
@@ -124,12 +289,45 @@ pub fn run_doctor(purge: bool) -> Result<()> {
         println!("  {} {:<3} {:>12}", icon, tool.name, tool.version);
     }
 
+    println!("\nENVIRONMENT:");
+    for check in &environment {
+        println!(
+            "  {} {:<12} {}",
+            check.level.icon(),
+            check.name,
+            check.detail
+        );
+    }
+
     println!("\nLOG PATH:\n  {}", report.log_path);
 
     render_orphans(&report, purge);
     render_proposal_orphans(&report, purge);
     render_stuck_proposals(&report);
-    Ok(())
+
+    let failed = hard_failures(&report.tools, &environment);
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(eyre::eyre!("doctor: failed {}", failed.join(", ")))
+    }
+}
+
+/// The hard requirements that failed: git and gh (missing or too old), and
+/// every environment check at [`CheckLevel::Fail`]. `rg` only backs
+/// `search`, so it never fails the run.
+fn hard_failures(tools: &[ToolCheck], environment: &[EnvCheck]) -> Vec<String> {
+    tools
+        .iter()
+        .filter(|t| !t.ok && (t.name == "git" || t.name == "gh"))
+        .map(|t| t.name.clone())
+        .chain(
+            environment
+                .iter()
+                .filter(|c| c.level == CheckLevel::Fail)
+                .map(|c| c.name.clone()),
+        )
+        .collect()
 }
 
 /// Report bare `Proposed` campaigns (ringer addendum #3): a change state whose
@@ -246,6 +444,49 @@ pub fn log_path() -> PathBuf {
         .join("gx.log")
 }
 
+/// Startup gate: fail before any operation when a required tool is missing or
+/// older than its configured minimum (`min-tool-versions`), rather than
+/// letting an old `gh` fail confusingly mid-run. `--skip-tool-check` bypasses.
+pub fn require_tool_versions(minimums: &[(&str, String)]) -> Result<()> {
+    validate_tool_versions(minimums, |tool| check_tool_version(tool, "0").version)
+}
+
+/// [`require_tool_versions`] over an injectable version probe, which returns
+/// what [`check_tool_version`] reports (`"not found"`, `"unknown"` or the
+/// version). An unparseable version is let through with a warning, and so is
+/// a missing `gh`: status, checkout and local-only create never call it, and a
+/// command that does fails on its own spawn error. A missing `git` stops gx.
+fn validate_tool_versions(
+    minimums: &[(&str, String)],
+    probe: impl Fn(&str) -> String,
+) -> Result<()> {
+    let mut problems = Vec::new();
+    for (tool, min) in minimums {
+        let version = probe(tool);
+        debug!("validate_tool_versions: {tool} {version} (min {min})");
+        match version.as_str() {
+            "not found" if *tool != "git" => {
+                warn!("{tool} not found on PATH; commands that need it will fail")
+            }
+            "not found" => problems.push(format!(
+                "{tool} not found on PATH; gx needs {tool} >= {min}"
+            )),
+            "unknown" => warn!("Could not read the {tool} version; skipping its minimum check"),
+            found if !version_compare(found.trim_start_matches('v'), min) => problems.push(
+                format!("{tool} {found} is older than the required {min}; upgrade {tool}"),
+            ),
+            _ => {}
+        }
+    }
+    if problems.is_empty() {
+        return Ok(());
+    }
+    Err(eyre::eyre!(
+        "{}\n(minimums are set by `min-tool-versions` in the config; pass --skip-tool-check to run anyway)",
+        problems.join("\n")
+    ))
+}
+
 struct ToolStatus {
     version: String,
     /// Whether the found version meets the minimum. `false` when the tool is
@@ -60,7 +60,7 @@ fn test_collect_report_surfaces_stuck_proposal_and_tools() {
     );
     StateManager::new().unwrap().save(&st).unwrap();
 
-    let report = collect_report().unwrap();
+    let report = collect_report(&Config::default()).unwrap();
     assert!(
         report
             .stuck_proposals
@@ -127,7 +127,7 @@ fn test_collect_report_includes_rg_check() {
     let dir = tempfile::TempDir::new().unwrap();
     unsafe { std::env::set_var("XDG_DATA_HOME", dir.path()) };
 
-    let report = collect_report().unwrap();
+    let report = collect_report(&Config::default()).unwrap();
     assert!(
         report.tools.iter().any(|t| t.name == "rg"),
         "collect_report must report the rg tool check"
@@ -140,6 +140,33 @@ fn test_collect_report_includes_rg_check() {
     drop(guard);
 }
 
+/// `gx doctor` holds git to the configured `min-tool-versions`, not the
+/// built-in minimum.
+#[test]
+fn test_collect_report_uses_configured_min_tool_versions() {
+    let guard = local::test_utils::env_lock();
+    let prior = std::env::var("XDG_DATA_HOME").ok();
+    let dir = tempfile::TempDir::new().unwrap();
+    unsafe { std::env::set_var("XDG_DATA_HOME", dir.path()) };
+
+    let config = Config {
+        min_tool_versions: Some(local::config::ToolVersionsConfig {
+            git: Some("999.0.0".to_string()),
+            gh: None,
+        }),
+        ..Default::default()
+    };
+    let report = collect_report(&config).unwrap();
+    let git = report.tools.iter().find(|t| t.name == "git").unwrap();
+    assert!(!git.ok, "git {} cannot meet a 999.0.0 minimum", git.version);
+
+    match prior {
+        Some(v) => unsafe { std::env::set_var("XDG_DATA_HOME", v) },
+        None => unsafe { std::env::remove_var("XDG_DATA_HOME") },
+    }
+    drop(guard);
+}
+
 #[test]
 fn test_extract_version() {
     assert_eq!(extract_version("git version 2.34.1"), "2.34.1");
@@ -147,3 +174,146 @@ fn test_extract_version() {
     assert_eq!(extract_version("no version here at all xyz"), "here");
     assert_eq!(extract_version(""), "unknown");
 }
+
+#[test]
+fn test_check_ssh_passes_and_fails_with_the_probe() {
+    let ok = check_ssh(|| Ok("octocat".to_string()));
+    assert_eq!(ok.level, CheckLevel::Ok);
+    assert!(ok.detail.contains("octocat"), "{}", ok.detail);
+
+    let failed = check_ssh(|| Err(eyre::eyre!("SSH authentication failed: Permission denied")));
+    assert_eq!(failed.level, CheckLevel::Fail);
+    assert!(
+        failed.detail.contains("Permission denied"),
+        "{}",
+        failed.detail
+    );
+}
+
+#[test]
+fn test_check_gh_auth_passes_and_fails_with_the_probe() {
+    let ok = check_gh_auth(|| Ok("Logged in to github.com account octocat".to_string()));
+    assert_eq!(ok.level, CheckLevel::Ok);
+    assert!(ok.detail.contains("octocat"), "{}", ok.detail);
+
+    let failed = check_gh_auth(|| Err(eyre::eyre!("not logged in: run gh auth login")));
+    assert_eq!(failed.level, CheckLevel::Fail);
+}
+
+#[test]
+fn test_check_config_file_levels() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let present = dir.path().join("gx.yml");
+    std::fs::write(&present, "").unwrap();
+    let missing = dir.path().join("missing.yml");
+
+    assert_eq!(
+        check_config_file(Some(&present), None).level,
+        CheckLevel::Ok
+    );
+    // An explicit --config that is missing fails; Config::load would too.
+    assert_eq!(
+        check_config_file(Some(&missing), Some(present.clone())).level,
+        CheckLevel::Fail
+    );
+    assert_eq!(check_config_file(None, Some(present)).level, CheckLevel::Ok);
+    // No default config file only means the defaults apply.
+    assert_eq!(
+        check_config_file(None, Some(missing)).level,
+        CheckLevel::Warn
+    );
+}
+
+#[test]
+fn test_check_writable_dir_creates_and_probes() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let target = dir.path().join("gx").join("logs");
+    let ok = check_writable_dir("log dir", &target);
+    assert_eq!(ok.level, CheckLevel::Ok, "{}", ok.detail);
+    assert!(target.is_dir());
+    assert_eq!(
+        std::fs::read_dir(&target).unwrap().count(),
+        0,
+        "probe removed"
+    );
+
+    // A regular file where the directory should be: cannot be created.
+    let blocker = dir.path().join("blocker");
+    std::fs::write(&blocker, "").unwrap();
+    let failed = check_writable_dir("state dir", &blocker.join("changes"));
+    assert_eq!(failed.level, CheckLevel::Fail);
+    assert!(failed.detail.contains("not writable"), "{}", failed.detail);
+}
+
+#[test]
+fn test_hard_failures_counts_git_gh_and_failed_checks_only() {
+    let tool = |name: &str, ok: bool| ToolCheck {
+        name: name.to_string(),
+        version: "1.0.0".to_string(),
+        ok,
+    };
+    let tools = [tool("git", true), tool("gh", false), tool("rg", false)];
+    let environment = [
+        EnvCheck::new("github ssh", CheckLevel::Fail, "denied"),
+        EnvCheck::new("config file", CheckLevel::Warn, "using defaults"),
+        EnvCheck::new("log dir", CheckLevel::Ok, "/tmp"),
+    ];
+
+    assert_eq!(hard_failures(&tools, &environment), ["gh", "github ssh"]);
+    assert!(hard_failures(&tools[..1], &environment[1..]).is_empty());
+}
+
+fn minimums() -> Vec<(&'static str, String)> {
+    vec![("git", "2.20.0".to_string()), ("gh", "2.0.0".to_string())]
+}
+
+#[test]
+fn test_validate_tool_versions_passes_current_tools() {
+    let probe = |tool: &str| match tool {
+        "git" => "2.43.0".to_string(),
+        _ => "2.40.1".to_string(),
+    };
+    assert!(validate_tool_versions(&minimums(), probe).is_ok());
+}
+
+/// A fake old `gh` stops gx before any operation, naming the tool, both
+/// versions and the escape hatch.
+#[test]
+fn test_validate_tool_versions_rejects_an_old_tool() {
+    let probe = |tool: &str| match tool {
+        "git" => "2.43.0".to_string(),
+        _ => "1.9.2".to_string(),
+    };
+    let err = validate_tool_versions(&minimums(), probe)
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("gh 1.9.2 is older than the required 2.0.0"),
+        "{err}"
+    );
+    assert!(err.contains("--skip-tool-check"), "{err}");
+    assert!(!err.contains("git"), "git meets its minimum: {err}");
+}
+
+#[test]
+fn test_validate_tool_versions_honors_a_raised_minimum() {
+    let raised = vec![("git", "2.40.0".to_string())];
+    let err = validate_tool_versions(&raised, |_| "2.39.5".to_string()).unwrap_err();
+    assert!(err.to_string().contains("git 2.39.5"), "{err}");
+}
+
+#[test]
+fn test_validate_tool_versions_missing_git_fails_missing_gh_warns() {
+    let no_gh = |tool: &str| match tool {
+        "git" => "2.43.0".to_string(),
+        _ => "not found".to_string(),
+    };
+    assert!(validate_tool_versions(&minimums(), no_gh).is_ok());
+
+    let no_git = |tool: &str| match tool {
+        "git" => "not found".to_string(),
+        _ => "2.40.1".to_string(),
+    };
+    let err = validate_tool_versions(&minimums(), no_git).unwrap_err();
+    assert!(err.to_string().contains("git not found on PATH"), "{err}");
+}
@@ -0,0 +1,223 @@
+//! Exec subcommand implementation
+//!
+//! Runs an arbitrary command (`make test`, `npm ci`, ...) in every discovered
+//! repo, in parallel, with the repo as its working directory. The command is
+//! spawned directly, not through a shell: `gx exec -- sh -c '...'` for pipes.
+
+use crate::cli::Cli;
+use crate::output;
+use eyre::{Context, Result};
+use local::config::Config;
+use local::repo::{discover_repos, filter_repos, Repo};
+use local::utils::get_nproc;
+use log::{debug, info};
+use rayon::prelude::*;
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Outcome of running the command in one repo.
+#[derive(Debug, Clone)]
+pub struct ExecResult {
+    pub repo: Repo,
+    /// The command's exit code; `None` when it never ran (spawn failure,
+    /// `--fail-fast` skip) or was killed by a signal.
+    pub exit_code: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+    /// Why the command did not run or finish normally.
+    pub error: Option<String>,
+    /// Not run: an earlier repo failed under `--fail-fast`.
+    pub skipped: bool,
+}
+
+impl ExecResult {
+    /// The command ran and exited 0.
+    pub fn succeeded(&self) -> bool {
+        self.exit_code == Some(0)
+    }
+}
+
+/// Process the exec subcommand
+pub fn process_exec_command(
+    cli: &Cli,
+    config: &Config,
+    command: &[String],
+    fail_fast: bool,
+    patterns: &[String],
+) -> Result<()> {
+    info!("Executing {command:?} (fail_fast={fail_fast})");
+
+    let org_config = config.for_user_org(cli.user_org.as_deref());
+
+    let start_dir = std::env::current_dir().context("Failed to get current directory")?;
+    let max_depth = cli.max_depth.or(org_config.max_depth).unwrap_or(3);
+
+    let repos = discover_repos(&start_dir, max_depth, &config.ignore_patterns())
+        .context("Failed to discover repositories")?;
+    output::warn_slug_collisions(&repos, cli.quiet);
+    let filtered_repos = filter_repos(repos, patterns);
+
+    if filtered_repos.is_empty() {
+        println!("🔍 No repositories found matching the criteria");
+        return Ok(());
+    }
+
+    let parallel_jobs = cli
+        .parallel
+        .or(org_config.jobs)
+        .unwrap_or_else(|| get_nproc().unwrap_or(4));
+
+    let results = run_exec(
+        &filtered_repos,
+        command,
+        fail_fast,
+        parallel_jobs,
+        |result| display_exec_result(result, cli.quiet),
+    )?;
+
+    let (succeeded, failed, skipped) = tally_exec_results(&results);
+    println!(
+        "\n📊 {succeeded}/{} succeeded, {failed} failed{}",
+        results.len(),
+        if skipped > 0 {
+            format!(", {skipped} skipped (--fail-fast)")
+        } else {
+            String::new()
+        }
+    );
+
+    output::exit_on_errors(failed, !cli.no_fail_on_error);
+    Ok(())
+}
+
+/// Run `command` in each of `repos` on a `jobs`-thread pool, calling
+/// `on_result` as each repo finishes. With `fail_fast`, once one repo fails
+/// the repos not yet started are skipped (those already running finish).
+pub fn run_exec<F>(
+    repos: &[Repo],
+    command: &[String],
+    fail_fast: bool,
+    jobs: usize,
+    on_result: F,
+) -> Result<Vec<ExecResult>>
+where
+    F: Fn(&ExecResult) + Sync,
+{
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build()
+        .context("Failed to create thread pool")?;
+
+    let stop = AtomicBool::new(false);
+    let results = pool.install(|| {
+        repos
+            .par_iter()
+            .map(|repo| {
+                let result = if fail_fast && stop.load(Ordering::SeqCst) {
+                    skipped_result(repo)
+                } else {
+                    run_in_repo(repo, command)
+                };
+                if fail_fast && !result.skipped && !result.succeeded() {
+                    stop.store(true, Ordering::SeqCst);
+                }
+                on_result(&result);
+                result
+            })
+            .collect()
+    });
+    Ok(results)
+}
+
+/// Run `command` with `repo` as its working directory, capturing its output.
+fn run_in_repo(repo: &Repo, command: &[String]) -> ExecResult {
+    debug!("run_in_repo: repo={} command={command:?}", repo.slug);
+    let mut result = ExecResult {
+        repo: repo.clone(),
+        exit_code: None,
+        stdout: String::new(),
+        stderr: String::new(),
+        error: None,
+        skipped: false,
+    };
+    let Some((program, args)) = command.split_first() else {
+        result.error = Some("No command given".to_string());
+        return result;
+    };
+
+    match Command::new(program)
+        .args(args)
+        .current_dir(&repo.path)
+        .stdin(Stdio::null())
+        .output()
+    {
+        Ok(output) => {
+            result.exit_code = output.status.code();
+            result.stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+            result.stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+            if result.exit_code.is_none() {
+                result.error = Some("Terminated by a signal".to_string());
+            }
+        }
+        Err(e) => result.error = Some(format!("Failed to run {program}: {e}")),
+    }
+    result
+}
+
+fn skipped_result(repo: &Repo) -> ExecResult {
+    ExecResult {
+        repo: repo.clone(),
+        exit_code: None,
+        stdout: String::new(),
+        stderr: String::new(),
+        error: None,
+        skipped: true,
+    }
+}
+
+/// Count `(succeeded, failed, skipped)` results.
+fn tally_exec_results(results: &[ExecResult]) -> (usize, usize, usize) {
+    let skipped = results.iter().filter(|r| r.skipped).count();
+    let succeeded = results.iter().filter(|r| r.succeeded()).count();
+    (succeeded, results.len() - succeeded - skipped, skipped)
+}
+
+/// Print one repo's outcome as it finishes: ✅/❌ and the slug, then the
+/// command's output indented beneath (unless `quiet`; a failure's stderr is
+/// always shown).
+fn display_exec_result(result: &ExecResult, quiet: bool) {
+    if result.skipped {
+        if !quiet {
+            println!("⏭️  {} (skipped)", result.repo.slug);
+        }
+        return;
+    }
+    if result.succeeded() {
+        println!("✅ {}", result.repo.slug);
+        if !quiet {
+            print_indented(&result.stdout);
+            print_indented(&result.stderr);
+        }
+        return;
+    }
+
+    match (&result.error, result.exit_code) {
+        (Some(error), _) => println!("❌ {}: {error}", result.repo.slug),
+        (None, Some(code)) => println!("❌ {} (exit {code})", result.repo.slug),
+        (None, None) => println!("❌ {}", result.repo.slug),
+    }
+    if !quiet {
+        print_indented(&result.stdout);
+    }
+    print_indented(&result.stderr);
+}
+
+fn print_indented(text: &str) {
+    let text = text.trim_end();
+    if !text.is_empty() {
+        println!("{}", local::utils::indent(text, 4));
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,72 @@
+use super::*;
+use local::test_utils::create_minimal_test_repo;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+fn test_repos(temp_dir: &TempDir, names: &[&str]) -> Vec<Repo> {
+    names
+        .iter()
+        .map(|name| Repo::new(create_minimal_test_repo(temp_dir.path(), name)).unwrap())
+        .collect()
+}
+
+fn command(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+}
+
+#[test]
+fn test_exec_tallies_success_across_repos() {
+    let temp_dir = TempDir::new().unwrap();
+    let repos = test_repos(&temp_dir, &["alpha", "beta", "gamma"]);
+
+    let results = run_exec(&repos, &command(&["true"]), false, 2, |_| {}).unwrap();
+    assert_eq!(tally_exec_results(&results), (3, 0, 0));
+    assert!(results.iter().all(|r| r.exit_code == Some(0)));
+}
+
+#[test]
+fn test_exec_tallies_failure_across_repos() {
+    let temp_dir = TempDir::new().unwrap();
+    let repos = test_repos(&temp_dir, &["alpha", "beta"]);
+
+    let results = run_exec(&repos, &command(&["false"]), false, 2, |_| {}).unwrap();
+    assert_eq!(tally_exec_results(&results), (0, 2, 0));
+    assert!(results.iter().all(|r| r.exit_code == Some(1)));
+}
+
+#[test]
+fn test_exec_runs_in_the_repo_directory() {
+    let temp_dir = TempDir::new().unwrap();
+    let repos = test_repos(&temp_dir, &["alpha"]);
+
+    let results = run_exec(&repos, &command(&["pwd"]), false, 1, |_| {}).unwrap();
+    let pwd = PathBuf::from(results[0].stdout.trim());
+    assert_eq!(
+        pwd.canonicalize().unwrap(),
+        repos[0].path.canonicalize().unwrap()
+    );
+}
+
+#[test]
+fn test_exec_fail_fast_skips_remaining_repos() {
+    let temp_dir = TempDir::new().unwrap();
+    let repos = test_repos(&temp_dir, &["alpha", "beta", "gamma"]);
+
+    // One thread, so the first failure is seen before the next repo starts.
+    let results = run_exec(&repos, &command(&["false"]), true, 1, |_| {}).unwrap();
+    assert_eq!(tally_exec_results(&results), (0, 1, 2));
+}
+
+#[test]
+fn test_exec_reports_a_missing_program() {
+    let temp_dir = TempDir::new().unwrap();
+    let repos = test_repos(&temp_dir, &["alpha"]);
+
+    let results = run_exec(&repos, &command(&["gx-no-such-program"]), false, 1, |_| {}).unwrap();
+    assert!(!results[0].succeeded());
+    assert!(results[0]
+        .error
+        .as_deref()
+        .unwrap()
+        .contains("gx-no-such-program"));
+}
@@ -1,14 +1,16 @@
-use crate::ssh::{SshCommandDetector, SshUrlBuilder};
+use crate::ssh::{RemoteUrlBuilder, SshCommandDetector, SshUrlBuilder};
 use eyre::{Context, Result};
+use local::config::CloneProtocol;
 use local::git::{
-    branch_changes_in_base, get_current_branch, get_current_commit_sha, get_remote_origin,
-    get_remote_status_native, get_status_changes, get_status_changes_for_path, is_same_repo,
-    resolve_update_work_tree, RemoteStatus, RepoStatus, StatusChanges,
+    branch_changes_in_base, get_branch_remote_status, get_current_branch, get_current_commit_sha,
+    get_remote_origin, get_remote_status_against, get_remote_status_native, get_status_changes,
+    get_status_changes_for_path, get_status_changes_with_untracked, git_command, is_same_repo,
+    noninteractive_git, resolve_update_work_tree, GitCmd, RemoteStatus, RepoStatus, StatusChanges,
+    UntrackedFiles,
 };
 use local::repo::Repo;
 use local::subprocess::{run_checked, subprocess_timeout};
 use log::{debug, warn};
-use std::process::Command;
 
 #[derive(Debug, Clone)]
 pub struct CheckoutResult {
@@ -21,10 +23,21 @@ pub struct CheckoutResult {
 
 #[derive(Debug, Clone)]
 pub enum CheckoutAction {
-    CheckedOutSynced,  // Checked out and synced with remote
+    CheckedOutSynced,  // Checked out and pulled from remote (`--pull`)
+    Switched,          // Checked out locally; nothing pulled
     CreatedFromRemote, // Created new branch from remote
-    Stashed,           // Stashed uncommitted changes
-    HasUntracked,      // Has untracked files after checkout
+    // Stashed uncommitted changes; the entry (`stash_ref`, e.g. `stash@{0}`)
+    // is left in place for the user to pop
+    Stashed { stash_ref: String },
+    HasUntracked,  // Has untracked files after checkout
+    AddedWorktree, // Added a linked worktree; primary checkout untouched
+    // Current branch fast-forwarded by `pulled` commits (`--sync-only`);
+    // `ahead` local commits remain unpushed
+    Synced { pulled: u32, ahead: u32 },
+    Detached, // HEAD detached at a tag or commit (`--detach`)
+    // Left untouched: a rebase, merge, ... is half done in the repo
+    // (`reason` is e.g. `skipped: rebase in progress`)
+    Skipped { reason: String },
 }
 
 #[derive(Debug, Clone)]
@@ -41,209 +54,559 @@ pub enum CloneAction {
     Stashed,             // 📦 Stashed changes during update
     DirectoryNotGitRepo, // 🏠 Directory exists but not git
     DifferentRemote,     // 🔗 Different remote URL
+    Mirrored,            // 🪞 New bare mirror clone (`--mirror`)
+    MirrorUpdated,       // 🪞 Existing mirror refreshed (`git remote update`)
+    Skipped,             // ⏭️ Already cloned, left untouched (`--skip-existing`)
 }
 
-/// Get git status for a single repository with options
-pub fn get_repo_status_with_options(repo: &Repo, fetch_first: bool, no_remote: bool) -> RepoStatus {
+/// Get git status for a single repository with options. `remote` compares
+/// against that remote's branch of the same name instead of the upstream
+/// (`None` keeps the configured-upstream behavior). `branch` reports
+/// ahead/behind for that local branch instead of the checked-out one; the
+/// working-tree changes always describe HEAD. `untracked` is the `-u<mode>`
+/// the working-tree scan runs with.
+pub fn get_repo_status_with_options(
+    repo: &Repo,
+    fetch_first: bool,
+    no_remote: bool,
+    remote: Option<&str>,
+    branch: Option<&str>,
+    untracked: UntrackedFiles,
+) -> RepoStatus {
     debug!(
-        "Getting status for repo: {} (fetch_first: {}, no_remote: {})",
-        repo.name, fetch_first, no_remote
+        "Getting status for repo: {} (fetch_first: {}, no_remote: {}, remote: {:?}, branch: {:?})",
+        repo.name, fetch_first, no_remote, remote, branch
     );
 
-    let branch = get_current_branch(repo);
+    let current_branch = get_current_branch(repo);
     let commit_sha = get_current_commit_sha(repo);
     let remote_status = if no_remote {
         RemoteStatus::NoRemote
     } else {
-        get_remote_status_with_fetch(repo, fetch_first)
+        get_remote_status_with_fetch(repo, fetch_first, remote, branch)
     };
 
-    match get_status_changes(repo) {
+    match get_status_changes_with_untracked(repo, untracked) {
         Ok(changes) => {
             let is_clean = changes.is_empty();
             RepoStatus {
                 repo: repo.clone(),
-                branch,
+                branch: current_branch,
                 commit_sha,
                 is_clean,
                 changes,
                 remote_status,
                 error: None,
+                diff_stat: None,
+                branch_sync: None,
+                submodule_changes: 0,
+                commit_subject: None,
             }
         }
         Err(e) => RepoStatus {
             repo: repo.clone(),
-            branch,
+            branch: current_branch,
             commit_sha,
             is_clean: false,
             changes: StatusChanges::default(),
             remote_status,
             error: Some(e.to_string()),
+            diff_stat: None,
+            branch_sync: None,
+            submodule_changes: 0,
+            commit_subject: None,
         },
     }
 }
 
-/// Enhanced remote status with optional fetch
-fn get_remote_status_with_fetch(repo: &Repo, fetch_first: bool) -> RemoteStatus {
-    if fetch_first {
-        debug!("Fetching latest remote refs for {}", repo.name);
-        // Perform lightweight fetch to update tracking refs
-        let fetch_result = run_checked(
-            Command::new("git").args(["-C", &repo.path.to_string_lossy(), "fetch", "--quiet"]),
-            subprocess_timeout(),
-        );
+/// Lightweight `git fetch` of `remote` (default: the upstream's remote) to
+/// update tracking refs before a status check. Best effort: a failed fetch is
+/// logged and the status check goes ahead on the refs already there.
+pub fn fetch_for_status(repo: &Repo, remote: Option<&str>) {
+    debug!("Fetching latest remote refs for {}", repo.name);
+    let fetch_result = run_checked(
+        git_command(&repo.path)
+            .args(["fetch", "--quiet"])
+            .args(remote),
+        subprocess_timeout(),
+    );
 
-        match fetch_result {
-            Ok(output) if output.status.success() => {
-                debug!("Successfully fetched remote refs for {}", repo.name);
-            }
-            Ok(output) => {
-                let stderr = String::from_utf8_lossy(&output.stderr);
-                debug!("Fetch failed for {}: {}", repo.name, stderr);
-                // Continue with status check even if fetch fails
-            }
-            Err(e) => {
-                debug!("Fetch command failed for {}: {}", repo.name, e);
-                // Continue with status check even if fetch fails
-            }
+    match fetch_result {
+        Ok(output) if output.status.success() => {
+            debug!("Successfully fetched remote refs for {}", repo.name);
+        }
+        Ok(output) => {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            debug!("Fetch failed for {}: {}", repo.name, stderr);
+        }
+        Err(e) => {
+            debug!("Fetch command failed for {}: {}", repo.name, e);
         }
     }
+}
 
-    get_remote_status_native(repo)
+/// Enhanced remote status with optional fetch
+fn get_remote_status_with_fetch(
+    repo: &Repo,
+    fetch_first: bool,
+    remote: Option<&str>,
+    branch: Option<&str>,
+) -> RemoteStatus {
+    if fetch_first {
+        fetch_for_status(repo, remote);
+    }
+
+    match (branch, remote) {
+        (Some(branch), remote) => get_branch_remote_status(repo, branch, remote),
+        (None, Some(remote)) => get_remote_status_against(repo, remote),
+        (None, None) => get_remote_status_native(repo),
+    }
 }
 
-/// Checkout or create a branch in a repository, with stashing and sync
+/// Checkout or create a branch in a repository, with stashing and sync.
+///
+/// With `worktree: Some(dir)` the primary working tree is never touched:
+/// the branch is checked out into a linked worktree at `<dir>/<repo>`
+/// instead (see [`checkout_into_worktree`]). With `submodules`, a successful
+/// checkout is followed by [`update_submodules`] in the tree checked out to.
+/// An existing branch is only fast-forwarded from its upstream with `pull`.
+/// With `detach`, `branch_name` is any ref (a tag or SHA) and HEAD is
+/// detached at it (`git checkout --detach`); nothing is pulled.
+#[allow(clippy::too_many_arguments)]
 pub fn checkout_branch(
     repo: &Repo,
     branch_name: &str,
     create_branch: bool,
     from_branch: Option<&str>,
     stash: bool,
+    pull: bool,
+    worktree: Option<&std::path::Path>,
+    submodules: bool,
+    detach: bool,
 ) -> CheckoutResult {
     debug!(
-        "Checking out branch '{}' in repo: {}",
-        branch_name, repo.name
+        "Checking out branch '{}' in repo: {} (pull: {}, worktree: {:?}, submodules: {}, detach: {})",
+        branch_name, repo.name, pull, worktree, submodules, detach
     );
 
-    let mut stashed = false;
-    let mut has_untracked = false;
+    if let Some(worktree_dir) = worktree {
+        let result = checkout_into_worktree(repo, branch_name, worktree_dir);
+        return if submodules {
+            update_checkout_submodules(result, &worktree_dir.join(&repo.name))
+        } else {
+            result
+        };
+    }
 
-    // Check for uncommitted changes
-    if stash {
-        if let Ok(status) = get_status_changes(repo) {
-            if !status.is_empty() {
-                // Stash changes (excluding untracked files)
-                let stash_result = run_checked(
-                    Command::new("git").args([
-                        "-C",
-                        &repo.path.to_string_lossy(),
-                        "stash",
-                        "push",
-                        "-m",
-                        &format!("gx auto-stash for {branch_name}"),
-                    ]),
-                    subprocess_timeout(),
-                );
-
-                if let Ok(output) = stash_result {
-                    if output.status.success() {
-                        stashed = true;
-                        debug!("Stashed changes in {}", repo.name);
-                    }
-                }
-            }
-        }
+    if let Some(reason) = in_progress_skip(repo) {
+        return CheckoutResult {
+            repo: repo.clone(),
+            branch_name: branch_name.to_string(),
+            commit_sha: get_current_commit_sha(repo),
+            action: CheckoutAction::Skipped { reason },
+            error: None,
+        };
     }
 
+    let mut has_untracked = false;
+    let original_branch = local::git::get_current_branch_name(&repo.path)
+        .ok()
+        .filter(|b| !b.is_empty());
+
+    // Stash uncommitted changes (excluding untracked files). The stash's SHA
+    // is kept so a failed checkout/pull can pop exactly this entry back.
+    let auto_stash = if stash {
+        auto_stash_changes(repo, branch_name)
+    } else {
+        None
+    };
+
     // Perform checkout
     let checkout_result = if create_branch {
         // Create new branch
-        let mut cmd = Command::new("git");
-        cmd.args([
-            "-C",
-            &repo.path.to_string_lossy(),
-            "checkout",
-            "-b",
-            branch_name,
-        ]);
-
-        if let Some(from) = from_branch {
-            cmd.arg(from);
-        }
-
-        run_checked(&mut cmd, subprocess_timeout())
+        GitCmd::new(&repo.path)
+            .args(["checkout", "-b", branch_name])
+            .args(from_branch)
+            .run(&format!("create branch '{branch_name}'"))
+    } else if detach {
+        GitCmd::new(&repo.path)
+            .args(["checkout", "--detach", branch_name])
+            .run(&format!("detach HEAD at '{branch_name}'"))
     } else {
         // Checkout existing branch
-        run_checked(
-            Command::new("git").args(["-C", &repo.path.to_string_lossy(), "checkout", branch_name]),
-            subprocess_timeout(),
-        )
+        GitCmd::new(&repo.path)
+            .args(["checkout", branch_name])
+            .run(&format!("check out '{branch_name}'"))
     };
 
     // Handle checkout result
     match checkout_result {
-        Ok(output) if output.status.success() => {
-            // Try to pull/sync with remote if not creating a new branch
-            if !create_branch {
-                let _ = run_checked(
-                    Command::new("git").args([
-                        "-C",
-                        &repo.path.to_string_lossy(),
-                        "pull",
-                        "--ff-only",
-                    ]),
-                    subprocess_timeout(),
-                );
+        Ok(_) => {
+            // Only `--pull` touches the network; otherwise this is a pure
+            // local branch switch. A branch without an upstream has nothing
+            // to pull and is not an error.
+            let mut pulled = false;
+            let mut pull_error = None;
+            if pull
+                && !create_branch
+                && !detach
+                && !matches!(
+                    get_remote_status_native(repo),
+                    RemoteStatus::NoRemote | RemoteStatus::NoUpstream
+                )
+            {
+                match pull_latest_changes(&repo.path) {
+                    Ok(()) => pulled = true,
+                    Err(e) => pull_error = Some(format!("Checked out, but pull failed: {e:#}")),
+                }
+            }
+
+            // A failed pull with an auto-stash rolls back to where the user
+            // was: the original branch with their changes popped back.
+            if let (Some(error), Some(stash_sha)) = (&pull_error, &auto_stash) {
+                let error = rollback_auto_stash(repo, original_branch.as_deref(), stash_sha, error);
+                return CheckoutResult {
+                    repo: repo.clone(),
+                    branch_name: branch_name.to_string(),
+                    commit_sha: get_current_commit_sha(repo),
+                    action: CheckoutAction::CheckedOutSynced,
+                    error: Some(error),
+                };
             }
 
             // Check for untracked files after checkout
             if let Ok(status) = get_status_changes(repo) {
-                has_untracked = status.untracked > 0;
+                has_untracked = status.has_untracked();
             }
 
-            let action = if create_branch {
+            let action = if let Some(stash_sha) = &auto_stash {
+                // The stash stays put on success; say where, so it can be popped.
+                CheckoutAction::Stashed {
+                    stash_ref: local::git::stash_ref_for_sha(&repo.path, stash_sha)
+                        .unwrap_or_else(|_| stash_sha.clone()),
+                }
+            } else if create_branch {
                 CheckoutAction::CreatedFromRemote
-            } else if stashed {
-                CheckoutAction::Stashed
+            } else if detach {
+                CheckoutAction::Detached
             } else if has_untracked {
                 CheckoutAction::HasUntracked
-            } else {
+            } else if pulled {
                 CheckoutAction::CheckedOutSynced
+            } else {
+                CheckoutAction::Switched
             };
 
             // Get commit SHA after successful checkout
             let commit_sha = get_current_commit_sha(repo);
 
-            CheckoutResult {
+            let result = CheckoutResult {
                 repo: repo.clone(),
                 branch_name: branch_name.to_string(),
                 commit_sha,
                 action,
-                error: None,
+                error: pull_error,
+            };
+            if submodules {
+                update_checkout_submodules(result, &repo.path)
+            } else {
+                result
             }
         }
-        Ok(output) => {
-            let error_msg = String::from_utf8_lossy(&output.stderr);
+        Err(e) => {
+            let mut error = e.to_string();
+            if let Some(stash_sha) = &auto_stash {
+                error = rollback_auto_stash(repo, original_branch.as_deref(), stash_sha, &error);
+            }
             CheckoutResult {
                 repo: repo.clone(),
                 branch_name: branch_name.to_string(),
                 commit_sha: None,
                 action: CheckoutAction::CheckedOutSynced,
-                error: Some(error_msg.trim().to_string()),
+                error: Some(error),
             }
         }
-        Err(e) => CheckoutResult {
-            repo: repo.clone(),
-            branch_name: branch_name.to_string(),
-            commit_sha: None,
-            action: CheckoutAction::CheckedOutSynced,
-            error: Some(e.to_string()),
+    }
+}
+
+/// `git stash push` the repo's uncommitted tracked changes for a checkout to
+/// `branch_name`, returning the new stash's SHA. `None` when nothing was
+/// stashed: a clean tree, only untracked files (which `push` leaves alone and
+/// then creates no entry for), or a failed push.
+fn auto_stash_changes(repo: &Repo, branch_name: &str) -> Option<String> {
+    let status = get_status_changes(repo).ok()?;
+    if status.is_empty() {
+        return None;
+    }
+    let stash_top = || {
+        run_checked(
+            git_command(&repo.path).args(["rev-parse", "-q", "--verify", "refs/stash"]),
+            subprocess_timeout(),
+        )
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    let before = stash_top();
+
+    let output = run_checked(
+        git_command(&repo.path).args([
+            "stash",
+            "push",
+            "-m",
+            &format!("gx auto-stash for {branch_name}"),
+        ]),
+        subprocess_timeout(),
+    )
+    .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    // Compare the top of the stash before and after: a push with nothing to
+    // save succeeds without adding an entry.
+    let after = stash_top();
+    if after.is_some() && after != before {
+        debug!("Stashed changes in {}", repo.name);
+        after
+    } else {
+        None
+    }
+}
+
+/// Roll back an auto-stashed checkout that failed with `error`: switch back
+/// to `original_branch` (if the checkout got off it) and pop the stash there.
+/// Returns `error` extended with what the rollback did, or how to recover by
+/// hand when it could not.
+fn rollback_auto_stash(
+    repo: &Repo,
+    original_branch: Option<&str>,
+    stash_sha: &str,
+    error: &str,
+) -> String {
+    debug!(
+        "rollback_auto_stash: repo={} original_branch={original_branch:?} stash={stash_sha}",
+        repo.name
+    );
+    let restore = || -> Result<()> {
+        if let Some(branch) = original_branch {
+            let current = local::git::get_current_branch_name(&repo.path)?;
+            if current != branch {
+                local::git::switch_branch(&repo.path, branch)?;
+            }
+        }
+        let stash_ref = local::git::stash_ref_for_sha(&repo.path, stash_sha)?;
+        local::git::stash_pop(&repo.path, &stash_ref)
+    };
+    match restore() {
+        Ok(()) => format!("{error} (auto-stash restored)"),
+        Err(e) => {
+            warn!("Failed to restore auto-stash in {}: {e:#}", repo.name);
+            format!(
+                "{error} (auto-stash {} left in place: {e:#})",
+                &stash_sha[..stash_sha.len().min(7)]
+            )
+        }
+    }
+}
+
+/// `skipped: <operation> in progress` when git has a rebase, merge, ...
+/// half done in `repo`: switching or pulling over it would corrupt it.
+pub fn in_progress_skip(repo: &Repo) -> Option<String> {
+    local::git::repo_operation_in_progress(&repo.path)
+        .map(|operation| format!("skipped: {operation} in progress"))
+}
+
+/// Fast-forward the currently checked-out branch to its upstream without
+/// switching branches (`checkout --sync-only`). A detached HEAD, a branch
+/// with no upstream, or one that has diverged from it is reported rather
+/// than merged.
+pub fn sync_current_branch(repo: &Repo) -> CheckoutResult {
+    debug!("Syncing current branch in repo: {}", repo.name);
+    let branch_name = get_current_branch(repo);
+    let result = |pulled: u32, ahead: u32, error: Option<String>| CheckoutResult {
+        repo: repo.clone(),
+        branch_name: branch_name.clone().unwrap_or_else(|| "HEAD".to_string()),
+        commit_sha: get_current_commit_sha(repo),
+        action: CheckoutAction::Synced { pulled, ahead },
+        error,
+    };
+    if let Some(reason) = in_progress_skip(repo) {
+        return CheckoutResult {
+            action: CheckoutAction::Skipped { reason },
+            ..result(0, 0, None)
+        };
+    }
+    if branch_name.is_none() {
+        return result(0, 0, Some("HEAD is detached; nothing to sync".to_string()));
+    }
+
+    let fetched = run_checked(
+        git_command(&repo.path).args(["fetch", "--quiet"]),
+        subprocess_timeout(),
+    );
+    match fetched {
+        Ok(output) if output.status.success() => {}
+        Ok(output) => {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return result(0, 0, Some(format!("Failed to fetch: {}", stderr.trim())));
+        }
+        Err(e) => return result(0, 0, Some(format!("Failed to fetch: {e}"))),
+    }
+
+    match get_remote_status_native(repo) {
+        RemoteStatus::UpToDate => result(0, 0, None),
+        RemoteStatus::Ahead(ahead) => result(0, ahead, None),
+        RemoteStatus::Behind(behind) => match pull_latest_changes(&repo.path) {
+            Ok(()) => result(behind, 0, None),
+            Err(e) => result(0, 0, Some(format!("{e:#}"))),
         },
+        RemoteStatus::Diverged(ahead, behind) => result(
+            0,
+            ahead,
+            Some(format!(
+                "Diverged from upstream ({ahead} ahead, {behind} behind); not fast-forwarding"
+            )),
+        ),
+        RemoteStatus::NoRemote | RemoteStatus::NoUpstream => {
+            result(0, 0, Some("No upstream branch to sync with".to_string()))
+        }
+        RemoteStatus::DetachedHead => {
+            result(0, 0, Some("HEAD is detached; nothing to sync".to_string()))
+        }
+        // Only `status --branch` produces this; the current branch exists.
+        RemoteStatus::NoBranch => result(0, 0, Some("No such branch to sync".to_string())),
+        RemoteStatus::Error(e) => result(0, 0, Some(e)),
+    }
+}
+
+/// Bring a successful checkout's submodules in line with the branch. The
+/// checkout itself stands, so a failure here is reported on the result
+/// rather than undoing it.
+fn update_checkout_submodules(
+    mut result: CheckoutResult,
+    work_tree: &std::path::Path,
+) -> CheckoutResult {
+    if result.error.is_none() {
+        if let Err(e) = update_submodules(work_tree) {
+            result.error = Some(format!("Checked out, but submodule update failed: {e}"));
+        }
+    }
+    result
+}
+
+/// Check `branch_name` out into a linked worktree at `<worktree_dir>/<repo>`,
+/// leaving the repo's own HEAD and working tree exactly as they were. An
+/// already-existing target is refused with cleanup guidance rather than
+/// reused: it may be a stale worktree from an earlier run, or unrelated files.
+fn checkout_into_worktree(
+    repo: &Repo,
+    branch_name: &str,
+    worktree_dir: &std::path::Path,
+) -> CheckoutResult {
+    let worktree_path = worktree_dir.join(&repo.name);
+    let error_result = |error: String| CheckoutResult {
+        repo: repo.clone(),
+        branch_name: branch_name.to_string(),
+        commit_sha: None,
+        action: CheckoutAction::AddedWorktree,
+        error: Some(error),
+    };
+
+    if worktree_path.exists() {
+        return error_result(format!(
+            "Worktree path already exists: {}; remove it with `git -C {} worktree remove {}` (or pick another --worktree dir)",
+            worktree_path.display(),
+            repo.path.display(),
+            worktree_path.display()
+        ));
+    }
+
+    if let Err(e) = add_worktree(&repo.path, &worktree_path, branch_name) {
+        return error_result(e.to_string());
+    }
+
+    let commit_sha = local::git::get_head_sha(&worktree_path)
+        .ok()
+        .map(|sha| sha.chars().take(7).collect());
+
+    CheckoutResult {
+        repo: repo.clone(),
+        branch_name: branch_name.to_string(),
+        commit_sha,
+        action: CheckoutAction::AddedWorktree,
+        error: None,
     }
 }
 
-/// Clone or update a repository
-pub fn clone_or_update_repo(repo_slug: &str, user_or_org: &str, token: &str) -> CloneResult {
+/// Initialize and update every submodule, recursively
+/// (`git submodule update --init --recursive`). A no-op in a repo without
+/// submodules. Fails with git's stderr.
+pub fn update_submodules(repo_path: &std::path::Path) -> Result<()> {
+    debug!("update_submodules: repo_path={}", repo_path.display());
+    let output = run_checked(
+        git_command(repo_path).args(["submodule", "update", "--init", "--recursive"]),
+        subprocess_timeout(),
+    )
+    .context("Failed to execute git submodule update")?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(eyre::eyre!(
+            "{}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+    }
+}
+
+/// Add a linked worktree at `worktree_path` with `branch` checked out
+/// (`git worktree add <path> <branch>`). A branch that only exists on the
+/// remote is DWIM-created as a tracking branch by git itself. Fails (with
+/// git's stderr) when the branch is already checked out in another worktree.
+pub fn add_worktree(
+    repo_path: &std::path::Path,
+    worktree_path: &std::path::Path,
+    branch: &str,
+) -> Result<()> {
+    debug!(
+        "add_worktree: repo_path={} worktree_path={} branch={branch}",
+        repo_path.display(),
+        worktree_path.display()
+    );
+    let output = run_checked(
+        git_command(repo_path)
+            .args(["worktree", "add"])
+            .arg(worktree_path)
+            .arg(branch),
+        subprocess_timeout(),
+    )
+    .context("Failed to execute git worktree add")?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(eyre::eyre!(
+            "Failed to add worktree at {}: {}",
+            worktree_path.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+    }
+}
+
+/// Clone or update a repository. A fresh clone uses `protocol`'s URL scheme.
+/// With `add_upstream: Some(owner)`, a fresh clone also gets an `upstream`
+/// remote at `<owner>/<repo>` (the fork's parent). With `submodules`, a
+/// successful clone or update is followed by [`update_submodules`]. With
+/// `skip_existing`, a checkout already pointing at the right remote is
+/// reported [`CloneAction::Skipped`] without any fetch, checkout or pull.
+pub fn clone_or_update_repo(
+    repo_slug: &str,
+    target_dir: &std::path::Path,
+    token: &str,
+    protocol: CloneProtocol,
+    add_upstream: Option<&str>,
+    submodules: bool,
+    skip_existing: bool,
+) -> CloneResult {
     debug!("Processing repo: {repo_slug}");
 
     let parts: Vec<&str> = repo_slug.split('/').collect();
@@ -256,11 +619,21 @@ pub fn clone_or_update_repo(repo_slug: &str, user_or_org: &str, token: &str) ->
     }
 
     let repo_name = parts[1];
-    let target_dir = std::path::PathBuf::from(user_or_org).join(repo_name);
 
     if !target_dir.exists() {
         // Clone new repository
-        return clone_repo(repo_slug, &target_dir, token);
+        let result = clone_repo(repo_slug, target_dir, token, protocol);
+        let result = match add_upstream {
+            Some(owner) if result.error.is_none() => {
+                add_upstream_remote(result, target_dir, owner, repo_name, protocol)
+            }
+            _ => result,
+        };
+        return if submodules {
+            update_clone_submodules(result, target_dir)
+        } else {
+            result
+        };
     }
 
     if !target_dir.join(".git").exists() {
@@ -277,11 +650,19 @@ pub fn clone_or_update_repo(repo_slug: &str, user_or_org: &str, token: &str) ->
     }
 
     // Check if existing repo has correct remote
-    match get_remote_origin(&target_dir) {
+    match get_remote_origin(target_dir) {
+        Ok(origin) if is_same_repo(&origin, repo_slug) && skip_existing => {
+            debug!("Skipping existing repo: {repo_slug}");
+            CloneResult {
+                repo_slug: repo_slug.to_string(),
+                action: CloneAction::Skipped,
+                error: None,
+            }
+        }
         Ok(origin) if is_same_repo(&origin, repo_slug) => {
             // Update existing repo: get default branch, checkout, pull.
             debug!("Updating existing repo: {repo_slug}");
-            let update_path = match resolve_update_work_tree(&target_dir) {
+            let update_path = match resolve_update_work_tree(target_dir) {
                 Ok(path) => path,
                 Err(e) => {
                     return CloneResult {
@@ -293,7 +674,12 @@ pub fn clone_or_update_repo(repo_slug: &str, user_or_org: &str, token: &str) ->
                     };
                 }
             };
-            update_existing_repo(&update_path, repo_slug, token)
+            let result = update_existing_repo(&update_path, repo_slug, token);
+            if submodules {
+                update_clone_submodules(result, &update_path)
+            } else {
+                result
+            }
         }
         Ok(origin) => {
             // Different remote URL
@@ -313,103 +699,280 @@ pub fn clone_or_update_repo(repo_slug: &str, user_or_org: &str, token: &str) ->
 }
 
 /// Clone a new repository
-fn clone_repo(repo_slug: &str, target_dir: &std::path::Path, _token: &str) -> CloneResult {
+fn clone_repo(
+    repo_slug: &str,
+    target_dir: &std::path::Path,
+    _token: &str,
+    protocol: CloneProtocol,
+) -> CloneResult {
     debug!(
-        "Cloning new repo: {} to {}",
+        "Cloning new repo: {} to {} over {protocol:?}",
         repo_slug,
         target_dir.display()
     );
+    let failed = |error: String| CloneResult {
+        repo_slug: repo_slug.to_string(),
+        action: CloneAction::Cloned,
+        error: Some(error),
+    };
 
-    // Pre-flight SSH connectivity check
-    match SshCommandDetector::test_github_ssh_connection() {
-        Ok(username) => debug!("SSH authenticated as: {username}"),
-        Err(e) => {
-            return CloneResult {
-                repo_slug: repo_slug.to_string(),
-                action: CloneAction::Cloned,
-                error: Some(format!("SSH connectivity test failed: {e}")),
-            };
+    // Pre-flight SSH connectivity check; HTTPS clones authenticate through
+    // the credential helper instead.
+    if protocol == CloneProtocol::Ssh {
+        match SshCommandDetector::test_github_ssh_connection() {
+            Ok(username) => debug!("SSH authenticated as: {username}"),
+            Err(e) => return failed(format!("SSH connectivity test failed: {e}")),
         }
     }
 
     // Create parent directory if needed
     if let Some(parent) = target_dir.parent() {
         if let Err(e) = std::fs::create_dir_all(parent) {
-            return CloneResult {
-                repo_slug: repo_slug.to_string(),
-                action: CloneAction::Cloned,
-                error: Some(format!("Failed to create parent directory: {e}")),
-            };
+            return failed(format!("Failed to create parent directory: {e}"));
         }
     }
 
-    // Clone the repository using SSH
-    let clone_url = match SshUrlBuilder::build_ssh_url(repo_slug) {
-        Ok(url) => {
-            // Validate the generated SSH URL
-            if let Err(e) = SshUrlBuilder::validate_ssh_url(&url) {
-                return CloneResult {
-                    repo_slug: repo_slug.to_string(),
-                    action: CloneAction::Cloned,
-                    error: Some(format!("Generated invalid SSH URL: {e}")),
-                };
-            }
-            url
+    let clone_url = match RemoteUrlBuilder::build_url(repo_slug, protocol) {
+        Ok(url) => url,
+        Err(e) => return failed(format!("Invalid repository slug: {e}")),
+    };
+    if protocol == CloneProtocol::Ssh {
+        // Validate the generated SSH URL
+        if let Err(e) = SshUrlBuilder::validate_ssh_url(&clone_url) {
+            return failed(format!("Generated invalid SSH URL: {e}"));
         }
-        Err(e) => {
-            return CloneResult {
+    }
+
+    // Transient failures (SSH reset, GitHub rate limit) are retried with
+    // backoff; a leftover partial checkout from a failed attempt is removed
+    // first so the retry clones into a fresh directory.
+    let policy = local::utils::retry_policy();
+    let mut attempted = false;
+    let output = local::utils::retry_with_backoff(policy.attempts(), policy.base_delay, || {
+        // Only ever remove what a previous attempt of ours created.
+        if std::mem::replace(&mut attempted, true) && target_dir.exists() {
+            std::fs::remove_dir_all(target_dir)
+                .context("Failed to remove partial clone before retrying")?;
+        }
+        GitCmd::without_repo()
+            .args(["clone", "--quiet", &clone_url])
+            .arg(target_dir)
+            .run(&format!("clone {repo_slug}"))
+            .map(drop)
+    });
+
+    match output {
+        Ok(()) => {
+            debug!("Successfully cloned: {repo_slug}");
+            CloneResult {
                 repo_slug: repo_slug.to_string(),
                 action: CloneAction::Cloned,
-                error: Some(format!("Invalid repository slug: {e}")),
-            };
+                error: None,
+            }
         }
+        Err(e) => CloneResult {
+            repo_slug: repo_slug.to_string(),
+            action: CloneAction::Cloned,
+            error: Some(format!("{e:#}")),
+        },
+    }
+}
+
+/// Mirror-clone or refresh a repository for backup (`gx clone --mirror`): a
+/// bare `git clone --mirror` into `target_dir` (`<owner>/<repo>.git` by
+/// default), or `git remote update --prune` when that mirror already exists.
+/// There is no working tree, so none of the checkout/pull/stash logic of
+/// [`update_existing_repo`] runs. With `skip_existing`, an existing mirror of
+/// the right repo is [`CloneAction::Skipped`] instead of refreshed.
+pub fn mirror_or_update_repo(
+    repo_slug: &str,
+    target_dir: &std::path::Path,
+    protocol: CloneProtocol,
+    skip_existing: bool,
+) -> CloneResult {
+    debug!("Mirroring repo: {repo_slug}");
+    let result = |action: CloneAction, error: Option<String>| CloneResult {
+        repo_slug: repo_slug.to_string(),
+        action,
+        error,
     };
 
-    let ssh_command = match SshCommandDetector::get_ssh_command() {
-        Ok(cmd) => cmd,
+    if !repo_slug.contains('/') {
+        return result(
+            CloneAction::Mirrored,
+            Some("Invalid repository slug format".to_string()),
+        );
+    }
+
+    if target_dir.exists() {
+        if !is_bare_repository(target_dir) {
+            return result(CloneAction::DirectoryNotGitRepo, None);
+        }
+        return match get_remote_origin(target_dir) {
+            Ok(origin) if is_same_repo(&origin, repo_slug) && skip_existing => {
+                result(CloneAction::Skipped, None)
+            }
+            Ok(origin) if is_same_repo(&origin, repo_slug) => match update_mirror(target_dir) {
+                Ok(()) => result(CloneAction::MirrorUpdated, None),
+                Err(e) => result(CloneAction::MirrorUpdated, Some(format!("{e:#}"))),
+            },
+            Ok(origin) => {
+                debug!("Different remote URL detected. Expected: {repo_slug}, Found: {origin}");
+                result(CloneAction::DifferentRemote, None)
+            }
+            Err(e) => result(
+                CloneAction::MirrorUpdated,
+                Some(format!("Failed to check remote: {e}")),
+            ),
+        };
+    }
+
+    if protocol == CloneProtocol::Ssh {
+        if let Err(e) = SshCommandDetector::test_github_ssh_connection() {
+            return result(
+                CloneAction::Mirrored,
+                Some(format!("SSH connectivity test failed: {e}")),
+            );
+        }
+    }
+    let clone_url = match RemoteUrlBuilder::build_url(repo_slug, protocol) {
+        Ok(url) => url,
         Err(e) => {
-            return CloneResult {
-                repo_slug: repo_slug.to_string(),
-                action: CloneAction::Cloned,
-                error: Some(format!("Failed to get SSH command: {e}")),
-            };
+            return result(
+                CloneAction::Mirrored,
+                Some(format!("Invalid repository slug: {e}")),
+            )
         }
     };
+    if let Some(parent) = target_dir.parent() {
+        if let Err(e) = std::fs::create_dir_all(parent) {
+            return result(
+                CloneAction::Mirrored,
+                Some(format!("Failed to create parent directory: {e}")),
+            );
+        }
+    }
+
+    // Same retry discipline as `clone_repo`: only ever remove what a previous
+    // attempt of ours created.
+    let policy = local::utils::retry_policy();
+    let mut attempted = false;
+    let cloned = local::utils::retry_with_backoff(policy.attempts(), policy.base_delay, || {
+        if std::mem::replace(&mut attempted, true) && target_dir.exists() {
+            std::fs::remove_dir_all(target_dir)
+                .context("Failed to remove partial mirror before retrying")?;
+        }
+        mirror_clone(&clone_url, target_dir)
+    });
+    match cloned {
+        Ok(()) => result(CloneAction::Mirrored, None),
+        Err(e) => result(CloneAction::Mirrored, Some(format!("{e:#}"))),
+    }
+}
+
+/// `git clone` arguments for a bare mirror of `clone_url` (the target
+/// directory follows).
+fn mirror_clone_args(clone_url: &str) -> [&str; 4] {
+    ["clone", "--mirror", "--quiet", clone_url]
+}
 
+/// Bare-mirror `clone_url` into `target_dir`: every ref, no working tree.
+fn mirror_clone(clone_url: &str, target_dir: &std::path::Path) -> Result<()> {
     let output = run_checked(
-        Command::new("git")
-            .env("GIT_SSH_COMMAND", ssh_command)
-            .args([
-                "clone",
-                "--quiet",
-                &clone_url,
-                &target_dir.to_string_lossy(),
-            ]),
+        noninteractive_git()
+            .args(mirror_clone_args(clone_url))
+            .arg(target_dir),
         subprocess_timeout(),
-    );
+    )?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        let error_msg = String::from_utf8_lossy(&output.stderr);
+        Err(eyre::eyre!("{}", error_msg.trim()))
+    }
+}
 
-    match output {
-        Ok(result) if result.status.success() => {
-            debug!("Successfully cloned: {repo_slug}");
-            CloneResult {
-                repo_slug: repo_slug.to_string(),
-                action: CloneAction::Cloned,
-                error: None,
-            }
+/// Refresh an existing mirror: fetch every ref and drop the ones the remote
+/// deleted.
+fn update_mirror(repo_path: &std::path::Path) -> Result<()> {
+    let output = run_checked(
+        git_command(repo_path).args(["remote", "update", "--prune"]),
+        subprocess_timeout(),
+    )
+    .context("Failed to execute git remote update")?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        let error_msg = String::from_utf8_lossy(&output.stderr);
+        Err(eyre::eyre!("Failed to update mirror: {}", error_msg.trim()))
+    }
+}
+
+/// Whether `path` is a bare git repository (a mirror), as git itself sees it.
+fn is_bare_repository(path: &std::path::Path) -> bool {
+    run_checked(
+        git_command(path).args(["rev-parse", "--is-bare-repository"]),
+        subprocess_timeout(),
+    )
+    .map(|out| out.status.success() && String::from_utf8_lossy(&out.stdout).trim() == "true")
+    .unwrap_or(false)
+}
+
+/// Point a new `upstream` remote at `<owner>/<repo_name>` in a fresh clone,
+/// in the same URL scheme as `origin`. The clone itself succeeded, so a
+/// failure here is reported on the result rather than undoing it.
+fn add_upstream_remote(
+    mut result: CloneResult,
+    target_dir: &std::path::Path,
+    owner: &str,
+    repo_name: &str,
+    protocol: CloneProtocol,
+) -> CloneResult {
+    let parent_slug = format!("{owner}/{repo_name}");
+    let added = RemoteUrlBuilder::build_url(&parent_slug, protocol)
+        .and_then(|url| local::git::add_remote(target_dir, "upstream", &url));
+    if let Err(e) = added {
+        result.error = Some(format!(
+            "Cloned, but failed to add upstream remote {parent_slug}: {e}"
+        ));
+    }
+    result
+}
+
+/// Initialize the submodules of a fresh clone (the separate-step equivalent
+/// of `git clone --recurse-submodules`), or update them after a pull. Kept
+/// out of the clone itself so a failing submodule is never retried as, or
+/// counted as, a failed clone: the clone stands and the failure is reported
+/// on the result.
+fn update_clone_submodules(mut result: CloneResult, work_tree: &std::path::Path) -> CloneResult {
+    if result.error.is_none() {
+        if let Err(e) = update_submodules(work_tree) {
+            let done = match result.action {
+                CloneAction::Cloned => "Cloned",
+                _ => "Updated",
+            };
+            result.error = Some(format!("{done}, but submodule update failed: {e}"));
         }
-        Ok(result) => {
-            let error_msg = String::from_utf8_lossy(&result.stderr);
-            CloneResult {
-                repo_slug: repo_slug.to_string(),
-                action: CloneAction::Cloned,
-                error: Some(error_msg.trim().to_string()),
-            }
+    }
+    result
+}
+
+/// The default branch to update an existing clone to: `refs/remotes/origin/HEAD`
+/// when the clone has it (no network), otherwise whatever `from_api` returns.
+/// Re-cloning a whole org used to cost one GitHub API call per repo here.
+fn resolve_update_default_branch<F>(repo_path: &std::path::Path, from_api: F) -> Result<String>
+where
+    F: FnOnce() -> Result<String>,
+{
+    match local::git::get_origin_head_branch(repo_path) {
+        Some(branch) => {
+            debug!("Default branch from origin/HEAD: {branch}");
+            Ok(branch)
+        }
+        None => {
+            debug!("No origin/HEAD in {}; asking GitHub", repo_path.display());
+            from_api()
         }
-        Err(e) => CloneResult {
-            repo_slug: repo_slug.to_string(),
-            action: CloneAction::Cloned,
-            error: Some(e.to_string()),
-        },
     }
 }
 
@@ -421,8 +984,10 @@ fn update_existing_repo(repo_path: &std::path::Path, repo_slug: &str, token: &st
         repo_path.display()
     );
 
-    // Get default branch from GitHub
-    let default_branch = match crate::github::get_default_branch(repo_slug, token) {
+    // `origin/HEAD` answers locally; only ask GitHub when it is unset.
+    let default_branch = match resolve_update_default_branch(repo_path, || {
+        crate::github::get_default_branch(repo_slug, token)
+    }) {
         Ok(branch) => branch,
         Err(e) => {
             return CloneResult {
@@ -443,9 +1008,7 @@ fn update_existing_repo(repo_path: &std::path::Path, repo_slug: &str, token: &st
             debug!("Found uncommitted changes, stashing...");
             // Stash changes
             let stash_result = run_checked(
-                Command::new("git").args([
-                    "-C",
-                    &repo_path.to_string_lossy(),
+                git_command(repo_path).args([
                     "stash",
                     "push",
                     "-m",
@@ -465,7 +1028,7 @@ fn update_existing_repo(repo_path: &std::path::Path, repo_slug: &str, token: &st
 
     // Fetch latest changes from remote
     let fetch_result = run_checked(
-        Command::new("git").args(["-C", &repo_path.to_string_lossy(), "fetch", "origin"]),
+        git_command(repo_path).args(["fetch", "origin"]),
         subprocess_timeout(),
     );
 
@@ -479,12 +1042,7 @@ fn update_existing_repo(repo_path: &std::path::Path, repo_slug: &str, token: &st
 
     // Checkout default branch
     let checkout_result = run_checked(
-        Command::new("git").args([
-            "-C",
-            &repo_path.to_string_lossy(),
-            "checkout",
-            &default_branch,
-        ]),
+        git_command(repo_path).args(["checkout", &default_branch]),
         subprocess_timeout(),
     );
 
@@ -498,7 +1056,7 @@ fn update_existing_repo(repo_path: &std::path::Path, repo_slug: &str, token: &st
 
     // Pull latest (same as checkout: --ff-only)
     let pull_result = run_checked(
-        Command::new("git").args(["-C", &repo_path.to_string_lossy(), "pull", "--ff-only"]),
+        git_command(repo_path).args(["pull", "--ff-only"]),
         subprocess_timeout(),
     );
 
@@ -571,58 +1129,112 @@ pub fn branch_merged_into_base(repo_path: &std::path::Path, branch_name: &str) -
     branch_changes_in_base(repo_path, &base_ref, branch_name)
 }
 
-/// Push branch to remote
-pub fn push_branch(repo_path: &std::path::Path, branch_name: &str) -> Result<()> {
-    let ssh_command =
-        SshCommandDetector::get_ssh_command().context("Failed to get SSH command for push")?;
+/// The `git push` arguments for [`push_branch`]. `no_verify` skips the
+/// pre-push hook.
+pub fn push_branch_args(branch_name: &str, no_verify: bool) -> Vec<&str> {
+    let mut args = vec!["push", "--set-upstream"];
+    if no_verify {
+        args.push("--no-verify");
+    }
+    args.extend(["origin", branch_name]);
+    args
+}
 
-    let output = run_checked(
-        Command::new("git")
-            .env("GIT_SSH_COMMAND", ssh_command)
-            .args([
-                "-C",
-                &repo_path.to_string_lossy(),
-                "push",
-                "--set-upstream",
-                "origin",
-                branch_name,
-            ]),
-        subprocess_timeout(),
-    )
-    .context("Failed to execute git push")?;
+/// Push branch to remote; `no_verify` skips the pre-push hook.
+pub fn push_branch(repo_path: &std::path::Path, branch_name: &str, no_verify: bool) -> Result<()> {
+    GitCmd::new(repo_path)
+        .args(push_branch_args(branch_name, no_verify))
+        .run(&format!("push branch '{branch_name}'"))?;
+    debug!(
+        "Pushed branch '{}' to remote from '{}'",
+        branch_name,
+        repo_path.display()
+    );
+    Ok(())
+}
 
-    if output.status.success() {
-        debug!(
-            "Pushed branch '{}' to remote from '{}'",
-            branch_name,
-            repo_path.display()
+/// Push a tag to `origin` (`git push origin refs/tags/<tag>`); `no_verify`
+/// skips the pre-push hook.
+pub fn push_tag(repo_path: &std::path::Path, tag_name: &str, no_verify: bool) -> Result<()> {
+    GitCmd::new(repo_path)
+        .arg("push")
+        .args(no_verify.then_some("--no-verify"))
+        .args(["origin", &format!("refs/tags/{tag_name}")])
+        .run(&format!("push tag '{tag_name}'"))?;
+    debug!(
+        "Pushed tag '{}' to remote from '{}'",
+        tag_name,
+        repo_path.display()
+    );
+    Ok(())
+}
+
+/// Delete a tag from `origin` (`git push origin --delete refs/tags/<tag>`),
+/// probing first so an absent (never-pushed or already-deleted) tag is a
+/// no-op. A remote tag that does not point at `commit_sha` is not the one gx
+/// pushed (e.g. a release tag its push was rejected against), so it is left
+/// in place.
+pub fn delete_remote_tag(
+    repo_path: &std::path::Path,
+    tag_name: &str,
+    commit_sha: &str,
+) -> Result<()> {
+    let refname = format!("refs/tags/{tag_name}");
+    let peeled = format!("{refname}^{{}}");
+    let probe = GitCmd::new(repo_path)
+        .args(["ls-remote", "--exit-code", "origin", &refname, &peeled])
+        .output()?;
+    match probe.status.code() {
+        Some(0) => {}
+        Some(2) => {
+            debug!("Tag '{tag_name}' already absent on remote; no-op");
+            return Ok(());
+        }
+        other => {
+            return Err(eyre::eyre!(
+                "Could not probe remote for tag {tag_name} (git ls-remote exit {:?}): {}",
+                other,
+                String::from_utf8_lossy(&probe.stderr).trim()
+            ))
+        }
+    }
+    // An annotated tag lists its commit on the peeled `^{}` line; a
+    // lightweight one only has the plain line.
+    let stdout = String::from_utf8_lossy(&probe.stdout);
+    let lines: Vec<(&str, &str)> = stdout
+        .lines()
+        .filter_map(|line| line.split_once('\t'))
+        .collect();
+    let remote_commit = lines
+        .iter()
+        .find(|(_, name)| *name == peeled)
+        .or_else(|| lines.iter().find(|(_, name)| *name == refname))
+        .map(|(sha, _)| *sha);
+    if remote_commit != Some(commit_sha) {
+        warn!(
+            "Remote tag '{tag_name}' points at {}, not gx's commit {commit_sha}; left in place",
+            remote_commit.unwrap_or("an unknown commit")
         );
-        Ok(())
-    } else {
-        let error = String::from_utf8_lossy(&output.stderr);
-        Err(eyre::eyre!(
-            "Failed to push branch '{}': {}",
-            branch_name,
-            error
-        ))
+        return Ok(());
     }
+    GitCmd::new(repo_path)
+        .args(["push", "origin", "--delete", &refname])
+        .run(&format!("delete remote tag '{tag_name}'"))?;
+    debug!(
+        "Deleted tag '{}' from remote of '{}'",
+        tag_name,
+        repo_path.display()
+    );
+    Ok(())
 }
 
 /// Pull latest changes from remote
 pub fn pull_latest(repo_path: &std::path::Path) -> Result<()> {
-    let output = run_checked(
-        Command::new("git").args(["-C", &repo_path.to_string_lossy(), "pull"]),
-        subprocess_timeout(),
-    )
-    .context("Failed to execute git pull")?;
-
-    if output.status.success() {
-        debug!("Pulled latest changes in '{}'", repo_path.display());
-        Ok(())
-    } else {
-        let error = String::from_utf8_lossy(&output.stderr);
-        Err(eyre::eyre!("Failed to pull latest changes: {}", error))
-    }
+    GitCmd::new(repo_path)
+        .arg("pull")
+        .run("pull latest changes")?;
+    debug!("Pulled latest changes in '{}'", repo_path.display());
+    Ok(())
 }
 
 /// Clone a repository to a target directory
@@ -641,7 +1253,9 @@ pub fn clone_repository(clone_url: &str, target_dir: &std::path::Path) -> Result
     }
 
     let output = run_checked(
-        Command::new("git").args(["clone", clone_url, &target_dir.to_string_lossy()]),
+        noninteractive_git()
+            .args(["clone", clone_url])
+            .arg(target_dir),
         subprocess_timeout(),
     )
     .context("Failed to execute git clone")?;
@@ -662,9 +1276,7 @@ pub fn clone_repository(clone_url: &str, target_dir: &std::path::Path) -> Result
 pub fn get_head_branch(repo_path: &std::path::Path) -> Result<String> {
     // First try to get the default branch from remote
     let output = run_checked(
-        Command::new("git")
-            .current_dir(repo_path)
-            .args(["symbolic-ref", "refs/remotes/origin/HEAD"]),
+        git_command(repo_path).args(["symbolic-ref", "refs/remotes/origin/HEAD"]),
         subprocess_timeout(),
     )
     .map_err(|e| eyre::eyre!("Failed to get HEAD branch: {}", e))?;
@@ -691,12 +1303,7 @@ pub fn get_head_branch(repo_path: &std::path::Path) -> Result<String> {
 /// Check if a branch exists on remote
 pub fn branch_exists_remotely(repo_path: &std::path::Path, branch_name: &str) -> Result<bool> {
     let output = run_checked(
-        Command::new("git").current_dir(repo_path).args([
-            "ls-remote",
-            "--heads",
-            "origin",
-            branch_name,
-        ]),
+        git_command(repo_path).args(["ls-remote", "--heads", "origin", branch_name]),
         subprocess_timeout(),
     )
     .map_err(|e| eyre::eyre!("Failed to check remote branch: {}", e))?;
@@ -718,12 +1325,12 @@ pub fn remote_branch_exists_probe(repo_path: &std::path::Path, branch_name: &str
         repo_path.display()
     );
     let output = run_checked(
-        Command::new("git").current_dir(repo_path).args([
+        git_command(repo_path).args([
             "ls-remote",
             "--exit-code",
             "--heads",
             "origin",
-            branch_name,
+            &format!("refs/heads/{branch_name}"),
         ]),
         subprocess_timeout(),
     )
@@ -744,7 +1351,8 @@ pub fn remote_branch_exists_probe(repo_path: &std::path::Path, branch_name: &str
 /// branches -- that is `gx undo`'s job). Existence is checked explicitly
 /// FIRST via [`remote_branch_exists_probe`] (F13) so an already-absent branch
 /// is a no-op, never a caller sniffing the delete's stderr for
-/// "remote ref does not exist".
+/// "remote ref does not exist". The push names `:refs/heads/<branch>` in full,
+/// so a tag of the same name is neither ambiguous nor touched.
 pub fn delete_remote_branch(repo_path: &std::path::Path, branch_name: &str) -> Result<()> {
     if !remote_branch_exists_probe(repo_path, branch_name)? {
         debug!(
@@ -755,12 +1363,7 @@ pub fn delete_remote_branch(repo_path: &std::path::Path, branch_name: &str) -> R
     }
 
     let output = run_checked(
-        Command::new("git").current_dir(repo_path).args([
-            "push",
-            "origin",
-            "--delete",
-            branch_name,
-        ]),
+        git_command(repo_path).args(["push", "origin", &format!(":refs/heads/{branch_name}")]),
         subprocess_timeout(),
     )
     .map_err(|e| eyre::eyre!("Failed to delete remote branch: {}", e))?;
@@ -785,13 +1388,8 @@ pub fn delete_remote_branch(repo_path: &std::path::Path, branch_name: &str) -> R
 /// head that the merge actually landed on.
 pub fn fetch_origin(repo_path: &std::path::Path) -> Result<()> {
     debug!("fetch_origin: repo_path={}", repo_path.display());
-    let ssh_command =
-        SshCommandDetector::get_ssh_command().context("Failed to get SSH command for fetch")?;
     let output = run_checked(
-        Command::new("git")
-            .env("GIT_SSH_COMMAND", ssh_command)
-            .current_dir(repo_path)
-            .args(["fetch", "origin"]),
+        git_command(repo_path).args(["fetch", "origin"]),
         subprocess_timeout(),
     )
     .context("Failed to execute git fetch origin")?;
@@ -814,9 +1412,7 @@ pub fn fetch_origin(repo_path: &std::path::Path) -> Result<()> {
 /// message goes to stdout on a zero exit ([A28]).
 pub fn pull_latest_changes(repo_path: &std::path::Path) -> Result<()> {
     let output = run_checked(
-        Command::new("git")
-            .current_dir(repo_path)
-            .args(["pull", "--ff-only"]),
+        git_command(repo_path).args(["pull", "--ff-only"]),
         subprocess_timeout(),
     )
     .map_err(|e| eyre::eyre!("Failed to run git pull --ff-only: {}", e))?;
@@ -837,6 +1433,142 @@ pub fn pull_latest_changes(repo_path: &std::path::Path) -> Result<()> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_push_branch_args_no_verify_skips_pre_push() {
+        assert_eq!(
+            push_branch_args("GX-1", false),
+            ["push", "--set-upstream", "origin", "GX-1"]
+        );
+        assert_eq!(
+            push_branch_args("GX-1", true),
+            ["push", "--set-upstream", "--no-verify", "origin", "GX-1"]
+        );
+    }
+
+    #[test]
+    fn test_update_default_branch_prefers_origin_head() {
+        use local::test_utils::run_git_command;
+        let bare_dir = tempfile::TempDir::new().unwrap();
+        let bare = bare_dir.path();
+        run_git_command(&["init", "--quiet", "--bare"], bare);
+
+        let repo_dir = tempfile::TempDir::new().unwrap();
+        let repo = repo_dir.path();
+        run_git_command(&["init", "--quiet", "-b", "trunk"], repo);
+        run_git_command(&["config", "user.email", "t@e.com"], repo);
+        run_git_command(&["config", "user.name", "T"], repo);
+        run_git_command(&["config", "commit.gpgsign", "false"], repo);
+        std::fs::write(repo.join("f.txt"), "x").unwrap();
+        run_git_command(&["add", "-A"], repo);
+        run_git_command(&["commit", "--quiet", "-m", "init"], repo);
+        run_git_command(&["remote", "add", "origin", bare.to_str().unwrap()], repo);
+        run_git_command(&["push", "--quiet", "-u", "origin", "trunk"], repo);
+
+        // No origin/HEAD yet: the API answer is used.
+        let mut api_calls = 0;
+        let branch = resolve_update_default_branch(repo, || {
+            api_calls += 1;
+            Ok("from-api".to_string())
+        })
+        .unwrap();
+        assert_eq!(branch, "from-api");
+        assert_eq!(api_calls, 1);
+
+        // With origin/HEAD recorded, the API is never consulted.
+        run_git_command(&["remote", "set-head", "origin", "trunk"], repo);
+        let branch = resolve_update_default_branch(repo, || -> Result<String> {
+            panic!("GitHub API called despite origin/HEAD")
+        })
+        .unwrap();
+        assert_eq!(branch, "trunk");
+    }
+
+    #[test]
+    fn test_skip_existing_reports_clones_as_skipped_without_fetching() {
+        let workspace = tempfile::TempDir::new().unwrap();
+        // As a first run would have left them: checkouts with the right origin.
+        let targets: Vec<_> = ["api", "web"]
+            .iter()
+            .map(|name| local::test_utils::create_minimal_test_repo(workspace.path(), name))
+            .collect();
+
+        for (name, target) in ["api", "web"].iter().zip(&targets) {
+            let slug = format!("testorg/{name}");
+            let result = clone_or_update_repo(
+                &slug,
+                target,
+                "unused-token",
+                CloneProtocol::Ssh,
+                None,
+                false,
+                true,
+            );
+            assert!(
+                matches!(result.action, CloneAction::Skipped),
+                "{slug}: {:?}",
+                result.action
+            );
+            assert_eq!(result.error, None, "{slug}");
+            // Nothing was fetched: the origin is never contacted.
+            assert!(!target.join(".git/FETCH_HEAD").exists(), "{slug}");
+        }
+
+        // A checkout of some other repo is still reported, not skipped.
+        let result = clone_or_update_repo(
+            "otherorg/api",
+            &targets[0],
+            "unused-token",
+            CloneProtocol::Ssh,
+            None,
+            false,
+            true,
+        );
+        assert!(
+            matches!(result.action, CloneAction::DifferentRemote),
+            "{:?}",
+            result.action
+        );
+    }
+
+    #[test]
+    fn test_mirror_clone_is_bare_and_updates() {
+        use local::test_utils::run_git_command;
+        let src_dir = tempfile::TempDir::new().unwrap();
+        let src = src_dir.path();
+        run_git_command(&["init", "--quiet", "-b", "main"], src);
+        run_git_command(&["config", "user.email", "t@e.com"], src);
+        run_git_command(&["config", "user.name", "T"], src);
+        run_git_command(&["config", "commit.gpgsign", "false"], src);
+        std::fs::write(src.join("f.txt"), "x").unwrap();
+        run_git_command(&["add", "-A"], src);
+        run_git_command(&["commit", "--quiet", "-m", "init"], src);
+
+        assert!(mirror_clone_args("url").contains(&"--mirror"));
+
+        let dest_dir = tempfile::TempDir::new().unwrap();
+        let mirror = dest_dir.path().join("repo.git");
+        mirror_clone(src.to_str().unwrap(), &mirror).unwrap();
+        assert!(is_bare_repository(&mirror));
+        assert!(
+            !mirror.join("f.txt").exists(),
+            "a mirror has no working tree"
+        );
+        let is_mirror = run_git_command(&["config", "remote.origin.mirror"], &mirror);
+        assert_eq!(String::from_utf8_lossy(&is_mirror.stdout).trim(), "true");
+
+        // A branch pushed upstream later arrives with `remote update`.
+        run_git_command(&["branch", "feature"], src);
+        update_mirror(&mirror).unwrap();
+        let feature = run_git_command(&["rev-parse", "--verify", "refs/heads/feature"], &mirror);
+        assert!(
+            feature.status.success(),
+            "mirror update missed the new branch"
+        );
+
+        // A regular clone is not mistaken for a mirror.
+        assert!(!is_bare_repository(src));
+    }
+
     #[test]
     fn test_delete_remote_branch_absent_is_no_op() {
         // F13: an already-absent remote branch is a no-op (explicit
@@ -891,13 +1623,64 @@ mod tests {
         assert!(!remote_branch_exists_probe(repo, "GX-pushed").unwrap());
     }
 
+    #[test]
+    fn test_delete_remote_branch_leaves_same_named_tag() {
+        use local::test_utils::run_git_command;
+        let bare_dir = tempfile::TempDir::new().unwrap();
+        let bare = bare_dir.path();
+        run_git_command(&["init", "--quiet", "--bare"], bare);
+
+        let repo_dir = tempfile::TempDir::new().unwrap();
+        let repo = repo_dir.path();
+        run_git_command(&["init", "--quiet", "-b", "main"], repo);
+        run_git_command(&["config", "user.email", "t@e.com"], repo);
+        run_git_command(&["config", "user.name", "T"], repo);
+        run_git_command(&["config", "commit.gpgsign", "false"], repo);
+        std::fs::write(repo.join("f.txt"), "x").unwrap();
+        run_git_command(&["add", "-A"], repo);
+        run_git_command(&["commit", "--quiet", "-m", "init"], repo);
+        run_git_command(&["remote", "add", "origin", bare.to_str().unwrap()], repo);
+        run_git_command(&["push", "--quiet", "-u", "origin", "main"], repo);
+        // A branch and a tag both named `v1`: a bare `push --delete v1` is
+        // ambiguous; the fully-qualified refspec is not.
+        run_git_command(&["branch", "v1"], repo);
+        run_git_command(&["tag", "v1"], repo);
+        run_git_command(
+            &["push", "--quiet", "origin", "refs/heads/v1", "refs/tags/v1"],
+            repo,
+        );
+
+        delete_remote_branch(repo, "v1").unwrap();
+
+        assert!(!remote_branch_exists_probe(repo, "v1").unwrap());
+        let tags = run_git_command(&["ls-remote", "--tags", "origin", "v1"], repo);
+        assert!(!tags.stdout.is_empty(), "the v1 tag must survive");
+    }
+
+    #[test]
+    fn test_remote_branch_exists_probe_errors_when_remote_unreachable() {
+        use local::test_utils::run_git_command;
+        let repo_dir = tempfile::TempDir::new().unwrap();
+        let repo = repo_dir.path();
+        run_git_command(&["init", "--quiet", "-b", "main"], repo);
+        let missing = repo_dir.path().join("no-such-remote.git");
+        run_git_command(
+            &["remote", "add", "origin", missing.to_str().unwrap()],
+            repo,
+        );
+
+        // Unreachable is not "absent": recovery must fail closed on it.
+        assert!(remote_branch_exists_probe(repo, "GX-any").is_err());
+    }
+
     #[test]
     fn test_get_repo_status_with_options_no_remote() {
         // Create a test repo
         let repo = Repo::from_slug("test/repo".to_string());
 
         // Test with no_remote = true
-        let status = get_repo_status_with_options(&repo, false, true);
+        let status =
+            get_repo_status_with_options(&repo, false, true, None, None, UntrackedFiles::Normal);
 
         // Should have NoRemote status regardless of actual git state
         assert!(matches!(status.remote_status, RemoteStatus::NoRemote));
@@ -910,11 +1693,580 @@ mod tests {
         let repo = Repo::from_slug("test/repo".to_string());
 
         // Test default behavior (no fetch, no skip remote)
-        let status = get_repo_status_with_options(&repo, false, false);
+        let status =
+            get_repo_status_with_options(&repo, false, false, None, None, UntrackedFiles::Normal);
 
         // Should have basic repo info
         assert_eq!(status.repo.name, "repo");
         // Remote status will depend on actual git state, but shouldn't be NoRemote
         assert!(!matches!(status.remote_status, RemoteStatus::NoRemote));
     }
+
+    #[test]
+    fn test_get_repo_status_with_options_reports_against_selected_remote() {
+        use local::test_utils::run_git_command;
+        let origin_dir = tempfile::TempDir::new().unwrap();
+        let upstream_dir = tempfile::TempDir::new().unwrap();
+        run_git_command(&["init", "--quiet", "--bare"], origin_dir.path());
+        run_git_command(&["init", "--quiet", "--bare"], upstream_dir.path());
+
+        let repo_dir = tempfile::TempDir::new().unwrap();
+        let path = repo_dir.path().join("fork");
+        std::fs::create_dir(&path).unwrap();
+        run_git_command(&["init", "--quiet", "-b", "main"], &path);
+        run_git_command(&["config", "user.email", "t@e.com"], &path);
+        run_git_command(&["config", "user.name", "T"], &path);
+        run_git_command(&["config", "commit.gpgsign", "false"], &path);
+        std::fs::write(path.join("f.txt"), "x").unwrap();
+        run_git_command(&["add", "-A"], &path);
+        run_git_command(&["commit", "--quiet", "-m", "init"], &path);
+        let origin_url = origin_dir.path().to_str().unwrap();
+        let upstream_url = upstream_dir.path().to_str().unwrap();
+        run_git_command(&["remote", "add", "origin", origin_url], &path);
+        run_git_command(&["remote", "add", "upstream", upstream_url], &path);
+        run_git_command(&["push", "--quiet", "-u", "origin", "main"], &path);
+
+        // upstream moves one commit ahead of the fork; origin stays in sync.
+        std::fs::write(path.join("f.txt"), "y").unwrap();
+        run_git_command(&["commit", "--quiet", "-am", "upstream work"], &path);
+        run_git_command(&["push", "--quiet", "upstream", "main"], &path);
+        run_git_command(&["reset", "--quiet", "--hard", "HEAD~1"], &path);
+
+        let repo = Repo::new(path).unwrap();
+        let status =
+            get_repo_status_with_options(&repo, false, false, None, None, UntrackedFiles::Normal);
+        assert!(
+            matches!(status.remote_status, RemoteStatus::UpToDate),
+            "default compares against origin: {:?}",
+            status.remote_status
+        );
+
+        let status = get_repo_status_with_options(
+            &repo,
+            false,
+            false,
+            Some("upstream"),
+            None,
+            UntrackedFiles::Normal,
+        );
+        assert!(
+            matches!(status.remote_status, RemoteStatus::Behind(1)),
+            "got {:?}",
+            status.remote_status
+        );
+
+        let status = get_repo_status_with_options(
+            &repo,
+            false,
+            false,
+            Some("nope"),
+            None,
+            UntrackedFiles::Normal,
+        );
+        assert!(matches!(status.remote_status, RemoteStatus::NoRemote));
+    }
+
+    #[test]
+    fn test_get_repo_status_with_options_reports_named_branch() {
+        use local::test_utils::run_git_command;
+        let origin_dir = tempfile::TempDir::new().unwrap();
+        run_git_command(&["init", "--quiet", "--bare"], origin_dir.path());
+
+        let repo_dir = tempfile::TempDir::new().unwrap();
+        let path = repo_dir.path().join("app");
+        std::fs::create_dir(&path).unwrap();
+        run_git_command(&["init", "--quiet", "-b", "main"], &path);
+        run_git_command(&["config", "user.email", "t@e.com"], &path);
+        run_git_command(&["config", "user.name", "T"], &path);
+        run_git_command(&["config", "commit.gpgsign", "false"], &path);
+        std::fs::write(path.join("f.txt"), "x").unwrap();
+        run_git_command(&["add", "-A"], &path);
+        run_git_command(&["commit", "--quiet", "-m", "init"], &path);
+        let origin_url = origin_dir.path().to_str().unwrap();
+        run_git_command(&["remote", "add", "origin", origin_url], &path);
+        run_git_command(&["push", "--quiet", "-u", "origin", "main"], &path);
+
+        // release/1.0 diverges: one commit only on origin, two only local.
+        run_git_command(&["switch", "--quiet", "-c", "release/1.0"], &path);
+        run_git_command(
+            &["commit", "--quiet", "--allow-empty", "-m", "remote"],
+            &path,
+        );
+        run_git_command(&["push", "--quiet", "-u", "origin", "release/1.0"], &path);
+        run_git_command(&["reset", "--quiet", "--hard", "HEAD~1"], &path);
+        for msg in ["local 1", "local 2"] {
+            run_git_command(&["commit", "--quiet", "--allow-empty", "-m", msg], &path);
+        }
+        run_git_command(&["switch", "--quiet", "main"], &path);
+        std::fs::write(path.join("f.txt"), "dirty").unwrap();
+
+        let count = |range: &str| -> u32 {
+            let out = run_git_command(&["rev-list", "--count", range], &path);
+            String::from_utf8(out.stdout)
+                .unwrap()
+                .trim()
+                .parse()
+                .unwrap()
+        };
+        let ahead = count("origin/release/1.0..release/1.0");
+        let behind = count("release/1.0..origin/release/1.0");
+        assert_eq!((ahead, behind), (2, 1));
+
+        let repo = Repo::new(path.clone()).unwrap();
+        let status = get_repo_status_with_options(
+            &repo,
+            false,
+            false,
+            None,
+            Some("release/1.0"),
+            UntrackedFiles::Normal,
+        );
+        assert!(
+            matches!(status.remote_status, RemoteStatus::Diverged(a, b) if (a, b) == (ahead, behind)),
+            "got {:?}",
+            status.remote_status
+        );
+        // The working tree stays on main, and the changes describe HEAD.
+        assert_eq!(status.branch.as_deref(), Some("main"));
+        assert_eq!(status.changes.modified, 1);
+        assert_eq!(local::git::get_current_branch_name(&path).unwrap(), "main");
+
+        let status = get_repo_status_with_options(
+            &repo,
+            false,
+            false,
+            None,
+            Some("release/2.0"),
+            UntrackedFiles::Normal,
+        );
+        assert!(matches!(status.remote_status, RemoteStatus::NoBranch));
+        assert!(status.error.is_none());
+    }
+
+    /// Run `f` with git's file transport allowed for the whole process. git
+    /// refuses local-path submodule clones otherwise, and `-c` on the test's
+    /// own commands doesn't reach the `git submodule update` gx runs. The
+    /// variables are set under the env lock and restored afterwards.
+    fn with_file_protocol_allowed<F: FnOnce()>(f: F) {
+        let guard = local::test_utils::env_lock();
+        let vars = [
+            ("GIT_CONFIG_COUNT", "1"),
+            ("GIT_CONFIG_KEY_0", "protocol.file.allow"),
+            ("GIT_CONFIG_VALUE_0", "always"),
+        ];
+        let prior: Vec<Option<String>> = vars.iter().map(|(k, _)| std::env::var(k).ok()).collect();
+        for (key, value) in vars {
+            unsafe { std::env::set_var(key, value) };
+        }
+        f();
+        for ((key, _), value) in vars.iter().zip(prior) {
+            match value {
+                Some(v) => unsafe { std::env::set_var(key, v) },
+                None => unsafe { std::env::remove_var(key) },
+            }
+        }
+        drop(guard);
+    }
+
+    /// A superproject clone at `<dir>/app` whose single submodule `lib` (a
+    /// local repo) has not been initialized yet.
+    fn clone_with_uninitialized_submodule(dir: &std::path::Path) -> std::path::PathBuf {
+        use local::test_utils::run_git_command;
+        let init = |path: &std::path::Path| {
+            std::fs::create_dir(path).unwrap();
+            run_git_command(&["init", "--quiet", "-b", "main"], path);
+            run_git_command(&["config", "user.email", "t@e.com"], path);
+            run_git_command(&["config", "user.name", "T"], path);
+            run_git_command(&["config", "commit.gpgsign", "false"], path);
+        };
+
+        let lib = dir.join("lib");
+        init(&lib);
+        std::fs::write(lib.join("lib.txt"), "lib").unwrap();
+        run_git_command(&["add", "-A"], &lib);
+        run_git_command(&["commit", "--quiet", "-m", "lib"], &lib);
+
+        let upstream = dir.join("upstream");
+        init(&upstream);
+        run_git_command(
+            &[
+                "-c",
+                "protocol.file.allow=always",
+                "submodule",
+                "add",
+                "--quiet",
+                lib.to_str().unwrap(),
+                "lib",
+            ],
+            &upstream,
+        );
+        run_git_command(&["commit", "--quiet", "-m", "add lib"], &upstream);
+
+        let app = dir.join("app");
+        run_git_command(
+            &[
+                "clone",
+                "--quiet",
+                upstream.to_str().unwrap(),
+                app.to_str().unwrap(),
+            ],
+            dir,
+        );
+        assert!(!app.join("lib/lib.txt").exists());
+        app
+    }
+
+    #[test]
+    fn test_checkout_branch_with_submodules_initializes_them() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let app = clone_with_uninitialized_submodule(dir.path());
+        let repo = Repo::new(app.clone()).unwrap();
+
+        with_file_protocol_allowed(|| {
+            let result =
+                checkout_branch(&repo, "main", false, None, false, false, None, true, false);
+            assert_eq!(result.error, None);
+        });
+        assert_eq!(
+            std::fs::read_to_string(app.join("lib/lib.txt")).unwrap(),
+            "lib"
+        );
+    }
+
+    #[test]
+    fn test_checkout_branch_submodule_failure_is_reported_not_fatal() {
+        use local::test_utils::run_git_command;
+        let dir = tempfile::TempDir::new().unwrap();
+        let app = clone_with_uninitialized_submodule(dir.path());
+        // Point the submodule somewhere that doesn't exist.
+        let missing = dir.path().join("missing");
+        run_git_command(
+            &[
+                "config",
+                "--file",
+                ".gitmodules",
+                "submodule.lib.url",
+                missing.to_str().unwrap(),
+            ],
+            &app,
+        );
+        let repo = Repo::new(app.clone()).unwrap();
+
+        let result = checkout_branch(&repo, "main", false, None, false, false, None, true, false);
+        let error = result.error.expect("submodule failure is reported");
+        assert!(
+            error.starts_with("Checked out, but submodule update failed"),
+            "got {error}"
+        );
+        assert!(result.commit_sha.is_some(), "the checkout itself stands");
+    }
+
+    #[test]
+    fn test_moved_submodule_pointer_is_counted_apart_from_modified_files() {
+        use local::test_utils::run_git_command;
+        let dir = tempfile::TempDir::new().unwrap();
+        let app = clone_with_uninitialized_submodule(dir.path());
+        run_git_command(
+            &[
+                "-c",
+                "protocol.file.allow=always",
+                "submodule",
+                "update",
+                "--quiet",
+                "--init",
+            ],
+            &app,
+        );
+        let repo = Repo::new(app.clone()).unwrap();
+        assert_eq!(local::git::count_moved_submodules(&repo).unwrap(), 0);
+
+        // A new commit inside the submodule moves its pointer.
+        let lib = app.join("lib");
+        run_git_command(&["config", "user.email", "t@e.com"], &lib);
+        run_git_command(&["config", "user.name", "T"], &lib);
+        run_git_command(&["config", "commit.gpgsign", "false"], &lib);
+        std::fs::write(lib.join("lib.txt"), "moved").unwrap();
+        run_git_command(&["commit", "--quiet", "-am", "move"], &lib);
+
+        let status =
+            get_repo_status_with_options(&repo, false, true, None, None, UntrackedFiles::Normal);
+        // Porcelain alone can't tell the pointer from an edited file.
+        assert_eq!(status.changes.modified, 1);
+        assert!(!status.is_clean);
+        assert_eq!(local::git::count_moved_submodules(&repo).unwrap(), 1);
+    }
+
+    /// A clone at `<dir>/work` on branch `feature` (tracking origin), plus a
+    /// second clone at `<dir>/other` of the same bare remote.
+    fn clones_of_shared_remote(dir: &std::path::Path) -> (std::path::PathBuf, std::path::PathBuf) {
+        use local::test_utils::run_git_command;
+        let bare = dir.join("remote.git");
+        std::fs::create_dir(&bare).unwrap();
+        run_git_command(&["init", "--quiet", "--bare", "-b", "main"], &bare);
+        let seed = dir.join("seed");
+        std::fs::create_dir(&seed).unwrap();
+        run_git_command(&["init", "--quiet", "-b", "main"], &seed);
+        run_git_command(&["config", "user.email", "t@e.com"], &seed);
+        run_git_command(&["config", "user.name", "T"], &seed);
+        run_git_command(&["config", "commit.gpgsign", "false"], &seed);
+        std::fs::write(seed.join("f.txt"), "x").unwrap();
+        run_git_command(&["add", "-A"], &seed);
+        run_git_command(&["commit", "--quiet", "-m", "init"], &seed);
+        run_git_command(&["remote", "add", "origin", bare.to_str().unwrap()], &seed);
+        run_git_command(
+            &["push", "--quiet", "origin", "main", "main:feature"],
+            &seed,
+        );
+
+        let clone = |name: &str| {
+            let path = dir.join(name);
+            run_git_command(
+                &[
+                    "clone",
+                    "--quiet",
+                    bare.to_str().unwrap(),
+                    path.to_str().unwrap(),
+                ],
+                dir,
+            );
+            run_git_command(&["config", "user.email", "t@e.com"], &path);
+            run_git_command(&["config", "user.name", "T"], &path);
+            run_git_command(&["config", "commit.gpgsign", "false"], &path);
+            path
+        };
+        let work = clone("work");
+        run_git_command(&["checkout", "--quiet", "feature"], &work);
+        let other = clone("other");
+        run_git_command(&["checkout", "--quiet", "feature"], &other);
+        (work, other)
+    }
+
+    #[test]
+    fn test_sync_current_branch_fast_forwards_without_switching() {
+        use local::test_utils::run_git_command;
+        let dir = tempfile::TempDir::new().unwrap();
+        let (work, other) = clones_of_shared_remote(dir.path());
+        std::fs::write(other.join("f.txt"), "y").unwrap();
+        run_git_command(&["commit", "--quiet", "-am", "remote work"], &other);
+        run_git_command(&["push", "--quiet", "origin", "feature"], &other);
+
+        let repo = Repo::new(work.clone()).unwrap();
+        let result = sync_current_branch(&repo);
+        assert_eq!(result.error, None);
+        assert_eq!(result.branch_name, "feature", "never switches branch");
+        assert!(
+            matches!(
+                result.action,
+                CheckoutAction::Synced {
+                    pulled: 1,
+                    ahead: 0
+                }
+            ),
+            "got {:?}",
+            result.action
+        );
+        assert_eq!(std::fs::read_to_string(work.join("f.txt")).unwrap(), "y");
+    }
+
+    #[test]
+    fn test_sync_current_branch_reports_divergence_instead_of_merging() {
+        use local::test_utils::run_git_command;
+        let dir = tempfile::TempDir::new().unwrap();
+        let (work, other) = clones_of_shared_remote(dir.path());
+        std::fs::write(other.join("f.txt"), "y").unwrap();
+        run_git_command(&["commit", "--quiet", "-am", "remote work"], &other);
+        run_git_command(&["push", "--quiet", "origin", "feature"], &other);
+        std::fs::write(work.join("g.txt"), "local").unwrap();
+        run_git_command(&["add", "g.txt"], &work);
+        run_git_command(&["commit", "--quiet", "-m", "local work"], &work);
+        let before = local::git::get_head_sha(&work).unwrap();
+
+        let repo = Repo::new(work.clone()).unwrap();
+        let result = sync_current_branch(&repo);
+        let error = result.error.expect("a diverged branch is not synced");
+        assert!(error.contains("1 ahead, 1 behind"), "got {error}");
+        assert_eq!(local::git::get_head_sha(&work).unwrap(), before);
+    }
+
+    /// A clone whose `feature` branch is one commit behind origin, with
+    /// `main` checked out so the next checkout switches to `feature`.
+    fn clone_with_behind_feature_branch(dir: &std::path::Path) -> std::path::PathBuf {
+        use local::test_utils::run_git_command;
+        let (work, other) = clones_of_shared_remote(dir);
+        std::fs::write(other.join("f.txt"), "y").unwrap();
+        run_git_command(&["commit", "--quiet", "-am", "remote work"], &other);
+        run_git_command(&["push", "--quiet", "origin", "feature"], &other);
+        run_git_command(&["checkout", "--quiet", "main"], &work);
+        work
+    }
+
+    #[test]
+    fn test_checkout_branch_detach_checks_out_a_tag_without_a_branch() {
+        use local::test_utils::run_git_command;
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = local::test_utils::create_minimal_test_repo(dir.path(), "gx");
+        run_git_command(&["tag", "-a", "v1.0", "-m", "v1.0"], &path);
+        let tagged = local::git::get_head_sha(&path).unwrap();
+        std::fs::write(path.join("README.md"), "# later\n").unwrap();
+        run_git_command(&["commit", "--quiet", "-am", "later"], &path);
+        let repo = Repo::new(path.clone()).unwrap();
+
+        let result = checkout_branch(&repo, "v1.0", false, None, false, false, None, false, true);
+        assert_eq!(result.error, None);
+        assert!(
+            matches!(result.action, CheckoutAction::Detached),
+            "got {:?}",
+            result.action
+        );
+        // HEAD is detached at the tagged commit, not at the tag object.
+        assert_eq!(local::git::get_head_sha(&path).unwrap(), tagged);
+        assert_eq!(local::git::get_current_branch_name(&path).unwrap(), "");
+        assert_eq!(
+            get_current_branch(&repo),
+            Some(format!("HEAD@{}", &tagged[..7]))
+        );
+        assert!(matches!(
+            get_remote_status_native(&repo),
+            RemoteStatus::DetachedHead
+        ));
+    }
+
+    #[test]
+    fn test_checkout_and_sync_skip_a_repo_mid_merge() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let work = clone_with_behind_feature_branch(dir.path());
+        let repo = Repo::new(work.clone()).unwrap();
+        let before = local::git::get_current_branch_name(&work).unwrap();
+        std::fs::write(
+            work.join(".git/MERGE_HEAD"),
+            local::git::get_head_sha(&work).unwrap(),
+        )
+        .unwrap();
+
+        let result = checkout_branch(
+            &repo, "feature", false, None, false, false, None, false, false,
+        );
+        assert_eq!(result.error, None);
+        match &result.action {
+            CheckoutAction::Skipped { reason } => assert_eq!(reason, "skipped: merge in progress"),
+            other => panic!("expected Skipped, got {other:?}"),
+        }
+        assert_eq!(local::git::get_current_branch_name(&work).unwrap(), before);
+
+        let synced = sync_current_branch(&repo);
+        assert_eq!(synced.error, None);
+        assert!(
+            matches!(synced.action, CheckoutAction::Skipped { .. }),
+            "got {:?}",
+            synced.action
+        );
+    }
+
+    #[test]
+    fn test_checkout_branch_without_pull_only_switches() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let work = clone_with_behind_feature_branch(dir.path());
+        let repo = Repo::new(work.clone()).unwrap();
+
+        let result = checkout_branch(
+            &repo, "feature", false, None, false, false, None, false, false,
+        );
+        assert_eq!(result.error, None);
+        assert!(
+            matches!(result.action, CheckoutAction::Switched),
+            "got {:?}",
+            result.action
+        );
+        assert_eq!(get_current_branch(&repo).as_deref(), Some("feature"));
+        assert_eq!(std::fs::read_to_string(work.join("f.txt")).unwrap(), "x");
+    }
+
+    #[test]
+    fn test_checkout_branch_with_pull_fast_forwards() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let work = clone_with_behind_feature_branch(dir.path());
+        let repo = Repo::new(work.clone()).unwrap();
+
+        let result = checkout_branch(
+            &repo, "feature", false, None, false, true, None, false, false,
+        );
+        assert_eq!(result.error, None);
+        assert!(
+            matches!(result.action, CheckoutAction::CheckedOutSynced),
+            "got {:?}",
+            result.action
+        );
+        assert_eq!(std::fs::read_to_string(work.join("f.txt")).unwrap(), "y");
+    }
+
+    #[test]
+    fn test_checkout_branch_stash_is_left_in_place_with_hint() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let work = clone_with_behind_feature_branch(dir.path());
+        std::fs::write(work.join("f.txt"), "wip").unwrap();
+        let repo = Repo::new(work.clone()).unwrap();
+
+        let result = checkout_branch(
+            &repo, "feature", false, None, true, false, None, false, false,
+        );
+        assert_eq!(result.error, None);
+        match &result.action {
+            CheckoutAction::Stashed { stash_ref } => assert_eq!(stash_ref, "stash@{0}"),
+            other => panic!("expected Stashed, got {other:?}"),
+        }
+        assert_eq!(get_current_branch(&repo).as_deref(), Some("feature"));
+        assert!(
+            local::git::stash_sha_by_message(&work, "gx auto-stash for feature")
+                .unwrap()
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_checkout_branch_failure_pops_auto_stash_back() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let work = clone_with_behind_feature_branch(dir.path());
+        std::fs::write(work.join("f.txt"), "wip").unwrap();
+        let repo = Repo::new(work.clone()).unwrap();
+
+        let result = checkout_branch(
+            &repo,
+            "no-such-branch",
+            false,
+            None,
+            true,
+            false,
+            None,
+            false,
+            false,
+        );
+
+        let error = result.error.expect("checking out a missing branch fails");
+        assert!(error.contains("auto-stash restored"), "got {error}");
+        assert_eq!(get_current_branch(&repo).as_deref(), Some("main"));
+        assert_eq!(std::fs::read_to_string(work.join("f.txt")).unwrap(), "wip");
+        assert!(
+            local::git::stash_sha_by_message(&work, "gx auto-stash for no-such-branch")
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_checkout_branch_only_untracked_creates_no_stash() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let work = clone_with_behind_feature_branch(dir.path());
+        std::fs::write(work.join("new.txt"), "untracked").unwrap();
+        let repo = Repo::new(work.clone()).unwrap();
+
+        let result = checkout_branch(
+            &repo, "feature", false, None, true, false, None, false, false,
+        );
+        assert_eq!(result.error, None);
+        assert!(
+            matches!(result.action, CheckoutAction::HasUntracked),
+            "got {:?}",
+            result.action
+        );
+    }
 }
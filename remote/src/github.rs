@@ -1,22 +1,40 @@
 use eyre::{Context, Result};
 use local::config::Config;
 use local::subprocess::{run_checked, subprocess_timeout};
+use local::utils::{is_retryable_error, retry_policy, retry_with_backoff};
 use log::{debug, info, warn};
-use serde::Deserialize;
-use std::process::Command;
-use std::thread;
-use std::time::Duration;
-
-/// Maximum number of retry attempts for network operations
-const MAX_RETRIES: u32 = 3;
-/// Base delay between retries in milliseconds
-const RETRY_BASE_DELAY_MS: u64 = 1000;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::process::{Command, Output};
+use std::sync::{Condvar, Mutex, OnceLock};
 
 /// Result of creating a PR, containing the PR info
 #[derive(Debug, Clone)]
 pub struct CreatePrResult {
     pub number: u64,
     pub url: String,
+    /// The PR was opened but its reviewers/assignees/labels could not all be
+    /// added.
+    pub warning: Option<String>,
+}
+
+/// What a new PR is opened with besides its title and body: `--reviewer` (a
+/// user or an `org/team`), `--assignee` and `--label` values, passed through
+/// to `gh pr create` in order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrMetadata {
+    pub reviewers: Vec<String>,
+    pub assignees: Vec<String>,
+    pub labels: Vec<String>,
+    /// Create a label the repo does not have yet (`gh label create`) instead
+    /// of dropping it.
+    pub create_labels: bool,
+}
+
+impl PrMetadata {
+    pub fn is_empty(&self) -> bool {
+        self.reviewers.is_empty() && self.assignees.is_empty() && self.labels.is_empty()
+    }
 }
 
 /// Upper bound on repos listed per owner. `gh repo list` defaults to 30, which
@@ -24,6 +42,80 @@ pub struct CreatePrResult {
 /// user/org count so the listing is never quietly capped.
 const REPO_LIST_LIMIT: u32 = 4000;
 
+/// Which repos a listing keeps by archived state (`gx clone` default,
+/// `--include-archived`, `--archived-only`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ArchivedFilter {
+    /// Active repos only (the default).
+    #[default]
+    Exclude,
+    /// Active and archived repos.
+    Include,
+    /// Archived repos only, for archival/backup clones.
+    Only,
+}
+
+impl ArchivedFilter {
+    /// Map the clone flags (mutually exclusive at the CLI) to a filter.
+    pub fn from_flags(include_archived: bool, archived_only: bool) -> Self {
+        if archived_only {
+            Self::Only
+        } else if include_archived {
+            Self::Include
+        } else {
+            Self::Exclude
+        }
+    }
+
+    /// Whether a repo with this archived flag passes the filter.
+    pub fn keeps(self, is_archived: bool) -> bool {
+        match self {
+            Self::Exclude => !is_archived,
+            Self::Include => true,
+            Self::Only => is_archived,
+        }
+    }
+}
+
+/// One row of a `gh repo list` / `gh search repos` JSON listing: the slug plus
+/// the archived flag and last-push time GitHub reports for it.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+struct ListedRepo {
+    /// `nameWithOwner` from `gh repo list`, `fullName` from `gh search repos`.
+    #[serde(rename = "nameWithOwner", alias = "fullName")]
+    slug: String,
+    #[serde(rename = "isArchived")]
+    is_archived: bool,
+    /// `null` for a repo nobody has pushed to.
+    #[serde(rename = "pushedAt", default)]
+    pushed_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// The JSON fields requested from both listing commands.
+const LISTED_REPO_FIELDS: &str = "nameWithOwner,isArchived,pushedAt";
+
+/// Parse a gh JSON listing and keep the slugs `filter` selects, judged by each
+/// repo's own `isArchived` flag rather than by which gh flag was passed. With
+/// `since`, only repos pushed to after it are kept; a repo with no pushes at
+/// all is dropped.
+fn select_listed_repos(
+    json: &str,
+    filter: ArchivedFilter,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+) -> Result<Vec<String>> {
+    let listed: Vec<ListedRepo> =
+        serde_json::from_str(json).context("Failed to parse GitHub repo listing")?;
+    Ok(listed
+        .into_iter()
+        .filter(|repo| filter.keeps(repo.is_archived))
+        .filter(|repo| match since {
+            Some(since) => repo.pushed_at.is_some_and(|pushed| pushed > since),
+            None => true,
+        })
+        .map(|repo| repo.slug)
+        .collect())
+}
+
 /// Get all repositories owned by a user/org, INCLUDING private repos the token
 /// can see.
 ///
@@ -36,19 +128,31 @@ const REPO_LIST_LIMIT: u32 = 4000;
 /// uniformly for both a user and an org, so the fallback is gone entirely.
 /// (Fix shipped v0.6.3; root-caused live 2026-07-15 against private
 /// `scottidler/*` repos.)
+///
+/// `limit` caps the listing at the first N repos (`gx clone --limit`); `None`
+/// asks for everything, and a listing that comes back exactly at the
+/// [`REPO_LIST_LIMIT`] ceiling is warned about rather than passed off as
+/// complete. `since` keeps only repos pushed to after it (`gx clone --since`).
 pub fn get_user_repos(
     user_or_org: &str,
-    include_archived: bool,
+    archived: ArchivedFilter,
+    limit: Option<usize>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
     config: &Config,
 ) -> Result<Vec<String>> {
-    debug!("Getting repos for user/org: {user_or_org}, include_archived: {include_archived}");
+    debug!("Getting repos for user/org: {user_or_org}, archived: {archived:?}, limit: {limit:?}");
 
-    let token = read_token(user_or_org, config)?;
+    let token = resolve_token(user_or_org, config)?;
     debug!("Using token for user/org: {user_or_org}");
 
-    let repos = query_github_repos(user_or_org, &token, include_archived)
+    let repos = query_github_repos(user_or_org, &token, archived, limit, since)
         .context(format!("Failed to get repositories for {user_or_org}"))?;
 
+    if limit.is_none() && repos.len() >= REPO_LIST_LIMIT as usize {
+        warn!(
+            "{user_or_org}: repo listing hit the {REPO_LIST_LIMIT}-repo ceiling; repos past it are missing"
+        );
+    }
     debug!("Found {} repos for {user_or_org}", repos.len());
     Ok(repos)
 }
@@ -56,65 +160,151 @@ pub fn get_user_repos(
 /// Build the `gh repo list` argument vector for an owner. Pure and total so the
 /// endpoint choice can be asserted in a unit test without a network call.
 ///
-/// `--no-archived` is passed (gh's own filter) only when archived repos are
-/// excluded; when `include_archived` is true no flag is passed so archived
-/// repos ride along.
-fn repo_list_args(owner: &str, include_archived: bool) -> Vec<String> {
+/// gh's own `--no-archived` / `--archived` filter narrows the listing up
+/// front; `isArchived` is still requested so the final selection is made from
+/// each repo's real flag (see [`select_listed_repos`]). A `limit` below the
+/// [`REPO_LIST_LIMIT`] ceiling replaces it; `gh` pages internally either way.
+fn repo_list_args(owner: &str, archived: ArchivedFilter, limit: Option<usize>) -> Vec<String> {
     let mut args = vec![
         "repo".to_string(),
         "list".to_string(),
         owner.to_string(),
         "--limit".to_string(),
-        REPO_LIST_LIMIT.to_string(),
+        capped_limit(limit, REPO_LIST_LIMIT).to_string(),
         "--json".to_string(),
-        "nameWithOwner".to_string(),
-        "--jq".to_string(),
-        ".[].nameWithOwner".to_string(),
+        LISTED_REPO_FIELDS.to_string(),
     ];
-    if !include_archived {
-        args.push("--no-archived".to_string());
+    match archived {
+        ArchivedFilter::Exclude => args.push("--no-archived".to_string()),
+        ArchivedFilter::Include => {}
+        ArchivedFilter::Only => args.push("--archived".to_string()),
     }
     args
 }
 
+/// The `--limit` to hand `gh`: the user's cap when it is below `ceiling`,
+/// otherwise the ceiling itself.
+fn capped_limit(limit: Option<usize>, ceiling: u32) -> u32 {
+    limit.map_or(ceiling, |n| n.min(ceiling as usize) as u32)
+}
+
 /// List an owner's repositories via `gh repo list` (GraphQL; private-visible).
-fn query_github_repos(owner: &str, token: &str, include_archived: bool) -> Result<Vec<String>> {
-    debug!("query_github_repos: owner={owner} include_archived={include_archived}");
+fn query_github_repos(
+    owner: &str,
+    token: &str,
+    archived: ArchivedFilter,
+    limit: Option<usize>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+) -> Result<Vec<String>> {
+    debug!(
+        "query_github_repos: owner={owner} archived={archived:?} limit={limit:?} since={since:?}"
+    );
 
-    let args = repo_list_args(owner, include_archived);
-    let output = run_checked(
-        Command::new("gh").env("GH_TOKEN", token).args(&args),
-        subprocess_timeout(),
-    )
-    .context("Failed to execute gh command")?;
+    let args = repo_list_args(owner, archived, limit);
+    let output = retry_output(|| run_gh(Command::new("gh").env("GH_TOKEN", token).args(&args)))
+        .context("Failed to execute gh command")?;
 
     if !output.status.success() {
         let error = String::from_utf8_lossy(&output.stderr);
         return Err(eyre::eyre!("GitHub repo list failed: {}", error));
     }
 
-    let repos = String::from_utf8(output.stdout)?
-        .lines()
-        .map(|line| line.trim().to_string())
-        .filter(|line| !line.is_empty())
-        .collect();
+    select_listed_repos(&String::from_utf8(output.stdout)?, archived, since)
+}
+
+/// Upper bound on repos returned by a `--search` query. GitHub's search API
+/// never returns more than 1000 results for one query, so asking for more
+/// would only hide that cap behind a bigger number.
+const REPO_SEARCH_LIMIT: u32 = 1000;
+
+/// Resolve a GitHub search query (`org:tatari-tv topic:service`) to the set
+/// of matching repo slugs, via `gh search repos`.
+///
+/// The query must name its owner with an `org:` or `user:` qualifier: that
+/// owner selects the persona token (see [`resolve_token`]), and an unscoped
+/// search across all of GitHub is never what a fleet operation wants.
+///
+/// `limit` caps the result at the first N matches; GitHub's own
+/// [`REPO_SEARCH_LIMIT`] still applies above it. `since` keeps only repos
+/// pushed to after it.
+pub fn search_repos(
+    query: &str,
+    archived: ArchivedFilter,
+    limit: Option<usize>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    config: &Config,
+) -> Result<Vec<String>> {
+    debug!("search_repos: query={query:?} archived={archived:?} limit={limit:?}");
 
+    let owner = search_owner(query).ok_or_else(|| {
+        eyre::eyre!(
+            "--search query must include an org:<owner> or user:<owner> qualifier: {query:?}"
+        )
+    })?;
+
+    let args = search_repo_args(query, archived, limit);
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let output = retry_gh(owner, config, &args)?;
+
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        return Err(eyre::eyre!("GitHub repo search failed: {}", error));
+    }
+
+    let repos = select_listed_repos(&String::from_utf8(output.stdout)?, archived, since)?;
+
+    if limit.is_none() && repos.len() >= REPO_SEARCH_LIMIT as usize {
+        warn!("search {query:?} hit GitHub's {REPO_SEARCH_LIMIT}-result cap; narrow the query");
+    }
+    debug!("search_repos: {} repos matched {query:?}", repos.len());
     Ok(repos)
 }
 
+/// The owner named by the first `org:`/`user:` qualifier in a search query.
+fn search_owner(query: &str) -> Option<&str> {
+    query.split_whitespace().find_map(|term| {
+        term.strip_prefix("org:")
+            .or_else(|| term.strip_prefix("user:"))
+            .filter(|owner| !owner.is_empty())
+    })
+}
+
+/// Build the `gh search repos` argument vector for a query. Pure so the
+/// argument shape can be asserted without a network call. Query terms go
+/// after `--` so a negated qualifier (`-topic:legacy`) is never parsed as a
+/// gh flag.
+fn search_repo_args(query: &str, archived: ArchivedFilter, limit: Option<usize>) -> Vec<String> {
+    let mut args = vec![
+        "search".to_string(),
+        "repos".to_string(),
+        "--limit".to_string(),
+        capped_limit(limit, REPO_SEARCH_LIMIT).to_string(),
+        "--json".to_string(),
+        LISTED_REPO_FIELDS.replace("nameWithOwner", "fullName"),
+    ];
+    match archived {
+        ArchivedFilter::Exclude => args.push("--archived=false".to_string()),
+        ArchivedFilter::Include => {}
+        ArchivedFilter::Only => args.push("--archived=true".to_string()),
+    }
+    args.push("--".to_string());
+    args.extend(query.split_whitespace().map(str::to_string));
+    args
+}
+
 /// Get default branch for a repository
 pub fn get_default_branch(repo_slug: &str, token: &str) -> Result<String> {
     debug!("Getting default branch for repo: {repo_slug}");
 
-    let output = run_checked(
-        Command::new("gh").env("GH_TOKEN", token).args([
+    let endpoint = format!("repos/{repo_slug}");
+    let output = retry_output(|| {
+        run_gh(Command::new("gh").env("GH_TOKEN", token).args([
             "api",
-            &format!("repos/{repo_slug}"),
+            &endpoint,
             "--jq",
             ".default_branch",
-        ]),
-        subprocess_timeout(),
-    )
+        ]))
+    })
     .context("Failed to get default branch")?;
 
     if !output.status.success() {
@@ -127,35 +317,192 @@ pub fn get_default_branch(repo_slug: &str, token: &str) -> Result<String> {
     Ok(branch)
 }
 
-/// Read the GitHub token for `user_or_org` from its persona env var.
-///
-/// Resolves the env-var NAME per org (see [`crate::persona::resolve_token_env`])
-/// then reads that var's value. Signature is unchanged from the retired
-/// file-scheme version, so every call site is untouched. A missing or
-/// trimmed-empty var is a LOUD error naming both the var and the org that
-/// selected it -- never a silent empty string, never a silent ambient
-/// fallback (design doc `2026-07-12-persona-aware-github-auth.md`, Phase 3).
-pub fn read_token(user_or_org: &str, config: &Config) -> Result<String> {
-    debug!("read_token: user_or_org={user_or_org}");
+/// A counting semaphore on `gh` calls: however big the rayon pool (`-j 64`),
+/// at most `limit` GitHub API calls run at once, so a fleet run stays clear
+/// of GitHub's secondary rate limits. Local git work is never gated.
+pub struct ApiGate {
+    limit: usize,
+    in_flight: Mutex<usize>,
+    freed: Condvar,
+}
 
-    let var_name = crate::persona::resolve_token_env(user_or_org, config)?;
+/// One slot in an [`ApiGate`], released on drop.
+pub struct ApiPermit<'a> {
+    gate: &'a ApiGate,
+}
+
+impl ApiGate {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit: limit.max(1),
+            in_flight: Mutex::new(0),
+            freed: Condvar::new(),
+        }
+    }
+
+    /// Block until a slot is free, then take it.
+    pub fn acquire(&self) -> ApiPermit<'_> {
+        let mut in_flight = self.in_flight.lock().unwrap_or_else(|e| e.into_inner());
+        while *in_flight >= self.limit {
+            in_flight = self
+                .freed
+                .wait(in_flight)
+                .unwrap_or_else(|e| e.into_inner());
+        }
+        *in_flight += 1;
+        ApiPermit { gate: self }
+    }
+}
+
+impl Drop for ApiPermit<'_> {
+    fn drop(&mut self) {
+        let mut in_flight = self
+            .gate
+            .in_flight
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        *in_flight -= 1;
+        self.gate.freed.notify_one();
+    }
+}
+
+/// The process-wide gate, sized once from `main` (`--api-concurrency` /
+/// `api-concurrency`). Same write-once shape as [`init_token_override`].
+static API_GATE: OnceLock<ApiGate> = OnceLock::new();
+
+/// Install the `gh` concurrency limit. A second call is a no-op.
+pub fn init_api_concurrency(limit: usize) {
+    debug!("init_api_concurrency: {limit}");
+    if API_GATE.set(ApiGate::new(limit)).is_err() {
+        warn!("init_api_concurrency: already initialized; ignoring second value");
+    }
+}
+
+fn api_gate() -> &'static ApiGate {
+    API_GATE.get_or_init(|| ApiGate::new(local::config::DEFAULT_API_CONCURRENCY))
+}
+
+/// Run a `gh` command under the subprocess timeout, holding an [`ApiGate`]
+/// slot for its duration (not across retry backoff). Every `gh` call in this
+/// module goes through here.
+fn run_gh(cmd: &mut Command) -> Result<Output> {
+    let _permit = api_gate().acquire();
+    run_checked(cmd, subprocess_timeout())
+}
+
+/// Env var with a gx-specific token; beats every persona and ambient token.
+const GX_TOKEN_ENV: &str = "GX_GITHUB_TOKEN";
+/// The conventional ambient token vars (CI, `gh`), tried in this order.
+const AMBIENT_TOKEN_ENVS: [&str; 2] = ["GITHUB_TOKEN", "GH_TOKEN"];
+
+/// The `--token` flag, installed once from `main`. Same write-once shape as
+/// `utils::init_retry_policy`: the token call sites are too deep to thread it.
+static TOKEN_OVERRIDE: OnceLock<String> = OnceLock::new();
 
-    let token = std::env::var(&var_name)
+/// Install the `--token` override. A second call is a no-op.
+pub fn init_token_override(token: String) {
+    debug!("init_token_override: len={}", token.len());
+    let _ = TOKEN_OVERRIDE.set(token);
+}
+
+/// The trimmed value of env var `name`, or `None` when unset or empty.
+fn token_from_env(name: &str) -> Option<String> {
+    std::env::var(name)
         .ok()
         .map(|value| value.trim().to_string())
         .filter(|value| !value.is_empty())
-        .ok_or_else(|| {
-            eyre::eyre!(
-                "GitHub token env var {var_name} is unset or empty (selected for org {user_or_org}); \
-                 decrypt it with `manifest age` or set the correct persona"
-            )
-        })?;
+}
 
-    debug!(
-        "read_token: user_or_org={user_or_org} resolved {var_name} (len={})",
-        token.len()
-    );
-    Ok(token)
+/// The token `gh` itself is logged in with (`gh auth token`), if any. A
+/// missing `gh` binary or a logged-out `gh` is simply no token.
+fn gh_auth_token() -> Option<String> {
+    let output = run_gh(Command::new("gh").args(["auth", "token"])).ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let token = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!token.is_empty()).then_some(token)
+}
+
+/// `gh auth token`, looked up once per `$GH_CONFIG_DIR` (the only input that
+/// changes its answer) rather than spawning `gh` on every token resolution.
+fn cached_gh_auth_token() -> Option<String> {
+    static CACHE: OnceLock<Mutex<HashMap<Option<String>, Option<String>>>> = OnceLock::new();
+    let key = std::env::var("GH_CONFIG_DIR").ok();
+    let mut cache = CACHE
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    cache.entry(key).or_insert_with(gh_auth_token).clone()
+}
+
+/// The ambient token (`$GITHUB_TOKEN`, `$GH_TOKEN`, then `gh auth token`) and
+/// where it came from. That identity is not the org's persona, so falling
+/// back to it is warned about, once per org.
+fn ambient_token(user_or_org: &str, var_name: &str) -> Option<(&'static str, String)> {
+    static WARNED: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
+    let (source, token) = AMBIENT_TOKEN_ENVS
+        .into_iter()
+        .find_map(|name| token_from_env(name).map(|token| (name, token)))
+        .or_else(|| cached_gh_auth_token().map(|token| ("gh auth token", token)))?;
+    let first = WARNED
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(user_or_org.to_string());
+    if first {
+        warn!("{var_name} is unset for org {user_or_org}; falling back to the {source} identity");
+    }
+    Some((source, token))
+}
+
+/// Resolve the GitHub token for `user_or_org`. Every gx call that needs a
+/// token comes through here.
+///
+/// Precedence (highest wins):
+/// 1. `--token`.
+/// 2. `$GX_GITHUB_TOKEN`.
+/// 3. the org's persona env var (see [`crate::persona::resolve_token_env`]),
+///    so a mixed-org fleet still gets the right identity per org.
+/// 4. `$GITHUB_TOKEN`, then `$GH_TOKEN`.
+/// 5. `gh auth token`.
+///
+/// 4 and 5 are the ambient identity: falling back to them is warned about,
+/// and `gh auth token` is looked up once ([`ambient_token`]).
+///
+/// When none yields a token the error names the persona var and org that
+/// were tried and points at `gh auth login` -- never a silent empty string.
+pub fn resolve_token(user_or_org: &str, config: &Config) -> Result<String> {
+    debug!("resolve_token: user_or_org={user_or_org}");
+
+    if let Some(token) = TOKEN_OVERRIDE.get() {
+        debug!("resolve_token: user_or_org={user_or_org} -> --token");
+        return Ok(token.clone());
+    }
+    if let Some(token) = token_from_env(GX_TOKEN_ENV) {
+        debug!("resolve_token: user_or_org={user_or_org} -> {GX_TOKEN_ENV}");
+        return Ok(token);
+    }
+
+    let var_name = crate::persona::resolve_token_env(user_or_org, config)?;
+    if let Some(token) = token_from_env(&var_name) {
+        debug!(
+            "resolve_token: user_or_org={user_or_org} resolved {var_name} (len={})",
+            token.len()
+        );
+        return Ok(token);
+    }
+
+    if let Some((source, token)) = ambient_token(user_or_org, &var_name) {
+        debug!("resolve_token: user_or_org={user_or_org} -> {source}");
+        return Ok(token);
+    }
+
+    Err(eyre::eyre!(
+        "No GitHub token found for org {user_or_org}: GitHub token env var {var_name} is unset or empty, \
+         and so are --token, {GX_TOKEN_ENV}, GITHUB_TOKEN and GH_TOKEN; set one (decrypt {var_name} \
+         with `manifest age`) or log in with `gh auth login`"
+    ))
 }
 
 /// The org/owner portion of a repo slug (`org/repo` -> `org`).
@@ -163,15 +510,15 @@ fn org_of(repo_slug: &str) -> &str {
     repo_slug.split('/').next().unwrap_or(repo_slug)
 }
 
-/// Build a `gh` command with per-org auth: resolve `org`'s persona token via
-/// [`read_token`] and set `GH_TOKEN` from it, so every gh call uses the same
+/// Build a `gh` command with per-org auth: resolve `org`'s token via
+/// [`resolve_token`] and set `GH_TOKEN` from it, so every gh call uses the same
 /// resolved identity instead of a mix of personas or ambient `gh auth`
-/// ([A18]). A missing/empty persona token is a LOUD `Err` -- never a silent
-/// fallback to ambient auth, which is exactly the wrong-identity trap this
-/// change exists to close (design doc `2026-07-12-persona-aware-github-auth.md`,
-/// "Fail-loud vs the current swallow", Phase 4).
+/// ([A18]). When no source has a token this is a LOUD `Err` -- never a `gh`
+/// call left to guess an identity (design doc
+/// `2026-07-12-persona-aware-github-auth.md`, "Fail-loud vs the current
+/// swallow", Phase 4).
 fn gh_command(org: &str, config: &Config) -> Result<Command> {
-    let token = read_token(org, config)?;
+    let token = resolve_token(org, config)?;
     let mut cmd = Command::new("gh");
     cmd.env("GH_TOKEN", token);
     Ok(cmd)
@@ -179,22 +526,107 @@ fn gh_command(org: &str, config: &Config) -> Result<Command> {
 
 /// Create a pull request using GitHub CLI.
 /// Returns the PR number and URL on success.
+///
+/// A PR is never lost to its metadata: if `gh pr create` fails on it (a
+/// reviewer who is not a collaborator, a label the repo lacks), the PR is
+/// looked up - gh may have opened it before failing - or opened again without
+/// it, and the original failure comes back as the result's `warning`. With
+/// `create_labels`, a missing label is created first and the full create is
+/// tried once more.
 pub fn create_pr(
     repo_slug: &str,
     branch_name: &str,
-    commit_message: &str,
+    body: &str,
     base_branch: &str,
     draft: bool,
+    metadata: &PrMetadata,
     config: &Config,
 ) -> Result<CreatePrResult> {
-    debug!("create_pr: repo={repo_slug} branch={branch_name} base={base_branch}");
+    debug!(
+        "create_pr: repo={repo_slug} branch={branch_name} base={base_branch} metadata={metadata:?}"
+    );
 
-    let title = branch_name.to_string();
-    let body = config
-        .pr_body_template()
-        .replace("{commit_message}", commit_message);
+    let title = branch_name;
+    let org = org_of(repo_slug).to_string();
+    let open = |metadata: &PrMetadata| -> Result<CreatePrResult> {
+        let args = pr_create_args(
+            repo_slug,
+            branch_name,
+            title,
+            body,
+            base_branch,
+            draft,
+            metadata,
+        );
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        // Retry network operations, rebuilding the (token-authed) command each try.
+        let output = retry_gh(&org, config, &args)?;
+        if !output.status.success() {
+            let error = String::from_utf8_lossy(&output.stderr);
+            return Err(eyre::eyre!("Failed to create PR: {}", error.trim()));
+        }
 
-    let mut args = vec![
+        let url = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        debug!("PR created: {url}");
+
+        // Extract PR number from URL (e.g., https://github.com/org/repo/pull/123).
+        // A parse failure is a real error, never a stored PR #0 ([A19]).
+        let number = extract_pr_number_from_url(&url)
+            .ok_or_else(|| eyre::eyre!("Could not parse PR number from URL: {url}"))?;
+        Ok(CreatePrResult {
+            number,
+            url,
+            warning: None,
+        })
+    };
+
+    let mut error = match open(metadata) {
+        Ok(result) => return Ok(result),
+        Err(e) if metadata.is_empty() => return Err(e),
+        Err(e) => e,
+    };
+    if metadata.create_labels && is_missing_label_error(&error.to_string()) {
+        create_labels(repo_slug, &metadata.labels, config);
+        error = match open(metadata) {
+            Ok(result) => return Ok(result),
+            Err(e) => e,
+        };
+    }
+    // A missing label costs only the labels; any other refusal drops all of
+    // the metadata, since gh does not say which value it choked on.
+    let (fallback, dropped) = if is_missing_label_error(&error.to_string()) {
+        let unlabeled = PrMetadata {
+            labels: Vec::new(),
+            ..metadata.clone()
+        };
+        (unlabeled, "labels")
+    } else {
+        (PrMetadata::default(), "reviewers/assignees/labels")
+    };
+    warn!("create_pr: {repo_slug}: {error}; retrying without {dropped}");
+    let warning = Some(format!("could not add {dropped}: {error}"));
+    if let Some(existing) = find_open_pr(repo_slug, branch_name, config) {
+        return Ok(CreatePrResult {
+            warning,
+            ..existing
+        });
+    }
+    open(&fallback).map(|result| CreatePrResult { warning, ..result })
+}
+
+/// The `gh pr create` arguments for a gx PR: each reviewer, assignee and label
+/// is its own `--reviewer`/`--assignee`/`--label` flag (gh takes `org/team`
+/// reviewers as-is).
+fn pr_create_args(
+    repo_slug: &str,
+    branch_name: &str,
+    title: &str,
+    body: &str,
+    base_branch: &str,
+    draft: bool,
+    metadata: &PrMetadata,
+) -> Vec<String> {
+    let mut args: Vec<String> = [
         "pr",
         "create",
         "--repo",
@@ -202,35 +634,92 @@ pub fn create_pr(
         "--head",
         branch_name,
         "--title",
-        &title,
+        title,
         "--body",
-        &body,
+        body,
         "--base",
         base_branch,
-    ];
+    ]
+    .iter()
+    .map(|arg| arg.to_string())
+    .collect();
 
     if draft {
-        args.push("--draft");
+        args.push("--draft".to_string());
     }
+    for reviewer in &metadata.reviewers {
+        args.extend(["--reviewer".to_string(), reviewer.clone()]);
+    }
+    for assignee in &metadata.assignees {
+        args.extend(["--assignee".to_string(), assignee.clone()]);
+    }
+    for label in &metadata.labels {
+        args.extend(["--label".to_string(), label.clone()]);
+    }
+    args
+}
 
-    // Retry network operations, rebuilding the (token-authed) command each try.
-    let org = org_of(repo_slug).to_string();
-    let output = retry_gh(&org, config, &args, MAX_RETRIES)?;
-
-    if output.status.success() {
-        let url = String::from_utf8_lossy(&output.stdout).trim().to_string();
-        debug!("PR created: {url}");
+/// `gh pr create` refused a `--label` the repo does not define
+/// (`could not add label: 'automated' not found`).
+fn is_missing_label_error(error: &str) -> bool {
+    error.contains("could not add label") && error.contains("not found")
+}
 
-        // Extract PR number from URL (e.g., https://github.com/org/repo/pull/123).
-        // A parse failure is a real error, never a stored PR #0 ([A19]).
-        let number = extract_pr_number_from_url(&url)
-            .ok_or_else(|| eyre::eyre!("Could not parse PR number from URL: {url}"))?;
+/// Create each of `labels` in `repo_slug`. One that already exists is fine;
+/// any other failure is only logged, leaving the caller's retry to degrade.
+fn create_labels(repo_slug: &str, labels: &[String], config: &Config) {
+    for label in labels {
+        let output = retry_gh(
+            org_of(repo_slug),
+            config,
+            &["label", "create", label, "--repo", repo_slug],
+        );
+        match output {
+            Ok(output) if output.status.success() => {
+                info!("Created label '{label}' in {repo_slug}");
+            }
+            Ok(output) => {
+                let error = String::from_utf8_lossy(&output.stderr);
+                if !error.contains("already exists") {
+                    warn!(
+                        "Failed to create label '{label}' in {repo_slug}: {}",
+                        error.trim()
+                    );
+                }
+            }
+            Err(e) => warn!("Failed to create label '{label}' in {repo_slug}: {e}"),
+        }
+    }
+}
 
-        Ok(CreatePrResult { number, url })
-    } else {
-        let error = String::from_utf8_lossy(&output.stderr);
-        Err(eyre::eyre!("Failed to create PR: {}", error))
+/// The open PR whose head is `branch_name`, if gh can see one.
+fn find_open_pr(repo_slug: &str, branch_name: &str, config: &Config) -> Option<CreatePrResult> {
+    let output = retry_gh(
+        org_of(repo_slug),
+        config,
+        &[
+            "pr",
+            "view",
+            branch_name,
+            "--repo",
+            repo_slug,
+            "--json",
+            "number,url,state",
+        ],
+    )
+    .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let view: serde_json::Value = serde_json::from_slice(&output.stdout).ok()?;
+    if view["state"] != "OPEN" {
+        return None;
     }
+    Some(CreatePrResult {
+        number: view["number"].as_u64()?,
+        url: view["url"].as_str()?.to_string(),
+        warning: None,
+    })
 }
 
 /// Open a revert PR for a merged change (`gx undo` Phase 6 [F4]). The `revert/
@@ -275,20 +764,69 @@ pub fn create_revert_pr(
     ];
 
     let org = org_of(repo_slug).to_string();
-    let output = retry_gh(&org, config, &args, MAX_RETRIES)?;
+    let output = retry_gh(&org, config, &args)?;
 
     if output.status.success() {
         let url = String::from_utf8_lossy(&output.stdout).trim().to_string();
         debug!("Revert PR created: {url}");
         let number = extract_pr_number_from_url(&url)
             .ok_or_else(|| eyre::eyre!("Could not parse PR number from revert PR URL: {url}"))?;
-        Ok(CreatePrResult { number, url })
+        Ok(CreatePrResult {
+            number,
+            url,
+            warning: None,
+        })
     } else {
         let error = String::from_utf8_lossy(&output.stderr);
         Err(eyre::eyre!("Failed to create revert PR: {}", error))
     }
 }
 
+/// Fill a PR body template: `{commit_message}`, `{change_id}`, `{repo}` (the
+/// slug) and `{files}` (the changed files as a markdown list). Placeholders
+/// inside the substituted values are left as written. Shared with
+/// `gx create --plan-out` so an approved plan shows exactly what will be sent.
+pub fn render_pr_body(
+    template: &str,
+    commit_message: &str,
+    change_id: &str,
+    repo_slug: &str,
+    files: &[String],
+) -> String {
+    let files = files
+        .iter()
+        .map(|file| format!("- `{file}`"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let placeholders = [
+        ("{commit_message}", commit_message),
+        ("{change_id}", change_id),
+        ("{repo}", repo_slug),
+        ("{files}", files.as_str()),
+    ];
+
+    // One pass over the template, so a substituted value (a commit message
+    // that mentions `{files}`, say) is never expanded in turn.
+    let mut body = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        body.push_str(&rest[..start]);
+        rest = &rest[start..];
+        match placeholders.iter().find(|(name, _)| rest.starts_with(name)) {
+            Some((name, value)) => {
+                body.push_str(value);
+                rest = &rest[name.len()..];
+            }
+            None => {
+                body.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+    body.push_str(rest);
+    body
+}
+
 /// Extract PR number from a GitHub PR URL
 fn extract_pr_number_from_url(url: &str) -> Option<u64> {
     // URL format: https://github.com/owner/repo/pull/123
@@ -297,74 +835,39 @@ fn extract_pr_number_from_url(url: &str) -> Option<u64> {
 
 /// Execute a `gh` command (token-authed for `org`) with retry + exponential
 /// backoff on retryable network errors.
-fn retry_gh(
-    org: &str,
-    config: &Config,
-    args: &[&str],
-    max_retries: u32,
-) -> Result<std::process::Output> {
-    let mut last_error = None;
-
-    for attempt in 0..max_retries {
-        let output = run_checked(gh_command(org, config)?.args(args), subprocess_timeout())
-            .context("Failed to execute gh")?;
+fn retry_gh(org: &str, config: &Config, args: &[&str]) -> Result<std::process::Output> {
+    retry_output(|| run_gh(gh_command(org, config)?.args(args)))
+}
 
+/// Run a `gh` subprocess under the process-wide [`retry_policy`], backing off
+/// exponentially between attempts. A failed exit whose stderr
+/// [`is_retryable_error`] (rate limit, reset connection, 5xx) is retried, and
+/// so is a failure to run `gh` at all whose message looks transient (a
+/// subprocess timeout). Any other exit, success or not, is handed back for
+/// the caller to judge, so a 404 or auth failure still fails fast with its
+/// own message; once the attempts run out, the last transient failure is the
+/// error.
+fn retry_output<F>(mut run: F) -> Result<std::process::Output>
+where
+    F: FnMut() -> Result<std::process::Output>,
+{
+    let policy = retry_policy();
+    retry_with_backoff(policy.attempts(), policy.base_delay, || {
+        let output = run().context("Failed to execute gh")?;
         if output.status.success() {
             return Ok(output);
         }
-
         let error = String::from_utf8_lossy(&output.stderr);
-
-        // Check if this is a retryable error (network, timeout, rate limit)
-        if is_retryable_error(&error) && attempt < max_retries - 1 {
-            let delay = RETRY_BASE_DELAY_MS * 2u64.pow(attempt);
-            warn!(
-                "Attempt {} failed, retrying in {}ms: {}",
-                attempt + 1,
-                delay,
-                error.trim()
-            );
-            thread::sleep(Duration::from_millis(delay));
-            last_error = Some(error.to_string());
+        if is_retryable_error(&error) {
+            Err(eyre::eyre!("gh failed: {}", error.trim()))
         } else {
-            // Non-retryable error or last attempt
-            return Ok(output);
+            Ok(output)
         }
-    }
-
-    Err(eyre::eyre!(
-        "Command failed after {} attempts: {}",
-        max_retries,
-        last_error.unwrap_or_default()
-    ))
-}
-
-/// Check if an error message indicates a retryable condition
-fn is_retryable_error(error: &str) -> bool {
-    let retryable_patterns = [
-        "timeout",
-        "timed out",
-        "connection refused",
-        "connection reset",
-        "network",
-        "rate limit",
-        "too many requests",
-        "503",
-        "502",
-        "504",
-        "ETIMEDOUT",
-        "ECONNRESET",
-        "ENOTFOUND",
-    ];
-
-    let error_lower = error.to_lowercase();
-    retryable_patterns
-        .iter()
-        .any(|pattern| error_lower.contains(pattern))
+    })
 }
 
 /// PR information structure
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PrInfo {
     pub repo_slug: String,
     pub number: u64,
@@ -393,7 +896,8 @@ pub struct PrInfo {
 /// lazily-computed state: a freshly-opened PR returns it until the merge commit
 /// is enqueued. An unrecognized or absent value maps to `Unknown` so the
 /// mergeable gate fails CLOSED (never merges on uncertainty).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Mergeability {
     /// `MERGEABLE`: GitHub proved the PR merges cleanly.
     Mergeable,
@@ -426,7 +930,8 @@ pub fn is_mergeable(pr: &PrInfo) -> bool {
 /// PR state enumeration. GitHub's GraphQL `PullRequest.state` is one of
 /// OPEN/CLOSED/MERGED; `Merged` is distinct from `Closed` so `gx review sync`
 /// can tell a landed PR apart from an abandoned one (Phase 4 [F11]).
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum PrState {
     Open,
     Closed,
@@ -540,13 +1045,22 @@ pub fn list_prs_by_change_id(
     change_id_pattern: &str,
     config: &Config,
 ) -> Result<Vec<PrInfo>> {
-    debug!("list_prs_by_change_id: org={org} pattern={change_id_pattern}");
+    list_prs_by_change_id_limited(org, change_id_pattern, None, config)
+}
 
-    let search = pr_search_string(org, change_id_pattern);
-    let mut cursor: Option<String> = None;
-    let mut all = Vec::new();
+/// [`list_prs_by_change_id`] with an optional cap (`gx review ls --limit`):
+/// stops fetching pages once `limit` PRs are collected. Finish-line batches
+/// (`approve`/`delete`/`sync`) always use the uncapped form.
+pub fn list_prs_by_change_id_limited(
+    org: &str,
+    change_id_pattern: &str,
+    limit: Option<usize>,
+    config: &Config,
+) -> Result<Vec<PrInfo>> {
+    debug!("list_prs_by_change_id: org={org} pattern={change_id_pattern} limit={limit:?}");
 
-    loop {
+    let search = pr_search_string(org, change_id_pattern);
+    let all = collect_pr_pages(change_id_pattern, limit, |cursor| {
         let mut args = vec![
             "api".to_string(),
             "graphql".to_string(),
@@ -555,29 +1069,54 @@ pub fn list_prs_by_change_id(
             "-f".to_string(),
             format!("q={search}"),
         ];
-        if let Some(ref c) = cursor {
+        if let Some(c) = cursor {
             args.push("-f".to_string());
             args.push(format!("cursor={c}"));
         }
         let arg_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
 
-        let output = run_checked(
-            gh_command(org, config)?.args(&arg_refs),
-            subprocess_timeout(),
-        )
-        .context("Failed to execute gh api graphql")?;
+        let output =
+            retry_gh(org, config, &arg_refs).context("Failed to execute gh api graphql")?;
 
         if !output.status.success() {
             let error = String::from_utf8_lossy(&output.stderr);
             return Err(eyre::eyre!("Failed to search PRs: {}", error));
         }
 
-        let json_output =
-            String::from_utf8(output.stdout).context("Invalid UTF-8 in gh api graphql output")?;
+        String::from_utf8(output.stdout).context("Invalid UTF-8 in gh api graphql output")
+    })?;
 
-        let (mut page, page_info) = parse_graphql_prs_page(&json_output, change_id_pattern)?;
+    debug!("list_prs_by_change_id: {} PRs total", all.len());
+    Ok(all)
+}
+
+/// Drive a paginated PR search: `fetch_page` gets the cursor of the page to
+/// fetch (`None` for the first) and returns that page's raw GraphQL JSON.
+/// Pages are followed until `hasNextPage` is false, or until `limit` PRs are
+/// collected, in which case the result is truncated to exactly `limit`.
+fn collect_pr_pages<F>(
+    pattern: &str,
+    limit: Option<usize>,
+    mut fetch_page: F,
+) -> Result<Vec<PrInfo>>
+where
+    F: FnMut(Option<&str>) -> Result<String>,
+{
+    let mut cursor: Option<String> = None;
+    let mut all = Vec::new();
+
+    loop {
+        let json_output = fetch_page(cursor.as_deref())?;
+        let (mut page, page_info) = parse_graphql_prs_page(&json_output, pattern)?;
         all.append(&mut page);
 
+        if let Some(limit) = limit {
+            if all.len() >= limit {
+                all.truncate(limit);
+                break;
+            }
+        }
+
         match page_info {
             Some(info) if info.has_next_page => {
                 cursor = info.end_cursor;
@@ -589,7 +1128,6 @@ pub fn list_prs_by_change_id(
         }
     }
 
-    debug!("list_prs_by_change_id: {} PRs total", all.len());
     Ok(all)
 }
 
@@ -679,17 +1217,14 @@ pub fn approve_and_merge_pr(
     // (Phase 4, unchanged): previously the failure was only warned and the
     // merge proceeded, landing a PR that never got its approval.
     if !admin_override {
-        let approve_output = run_checked(
-            gh_command(org, config)?.args([
-                "pr",
-                "review",
-                &pr_number.to_string(),
-                "--repo",
-                repo_slug,
-                "--approve",
-            ]),
-            subprocess_timeout(),
-        )
+        let approve_output = run_gh(gh_command(org, config)?.args([
+            "pr",
+            "review",
+            &pr_number.to_string(),
+            "--repo",
+            repo_slug,
+            "--approve",
+        ]))
         .context("Failed to execute gh pr review --approve")?;
 
         if !approve_output.status.success() {
@@ -705,7 +1240,7 @@ pub fn approve_and_merge_pr(
         );
     }
 
-    // Then merge the PR
+    // Then merge the PR, with the org's merge method (squash unless configured)
     let pr_number_str = pr_number.to_string();
     let mut merge_args = vec![
         "pr",
@@ -713,7 +1248,7 @@ pub fn approve_and_merge_pr(
         &pr_number_str,
         "--repo",
         repo_slug,
-        "--squash",
+        config.for_org(org).merge_method.gh_flag(),
         "--delete-branch",
     ];
 
@@ -725,11 +1260,8 @@ pub fn approve_and_merge_pr(
         merge_args.push("--auto");
     }
 
-    let merge_output = run_checked(
-        gh_command(org, config)?.args(&merge_args),
-        subprocess_timeout(),
-    )
-    .context("Failed to execute gh pr merge")?;
+    let merge_output = run_gh(gh_command(org, config)?.args(&merge_args))
+        .context("Failed to execute gh pr merge")?;
 
     if merge_output.status.success() {
         info!("Successfully merged PR #{pr_number} in {repo_slug}");
@@ -740,20 +1272,75 @@ pub fn approve_and_merge_pr(
     }
 }
 
-/// Close a PR without merging
-pub fn close_pr(repo_slug: &str, pr_number: u64, config: &Config) -> Result<()> {
-    debug!("Closing PR #{pr_number} in {repo_slug}");
+/// A single PR's live state, as `gh pr view` reports it. Drafts are open PRs
+/// on GitHub, so `is_draft` rides alongside `PrState::Open`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrView {
+    pub state: PrState,
+    pub is_draft: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct GhPrView {
+    state: String,
+    #[serde(rename = "isDraft", default)]
+    is_draft: bool,
+}
 
-    let output = run_checked(
-        gh_command(org_of(repo_slug), config)?.args([
+/// Parse `gh pr view --json state,isDraft` output.
+fn parse_pr_view(json: &str) -> Result<PrView> {
+    let view: GhPrView =
+        serde_json::from_str(json.trim()).context("Failed to parse gh pr view JSON")?;
+    let state = match view.state.to_uppercase().as_str() {
+        "OPEN" => PrState::Open,
+        "MERGED" => PrState::Merged,
+        _ => PrState::Closed,
+    };
+    Ok(PrView {
+        state,
+        is_draft: view.is_draft,
+    })
+}
+
+/// Look up one PR's live state (open/draft/merged/closed). Used by
+/// `gx review status` to refresh the recorded state of each PR a change opened.
+pub fn get_pr_state(repo_slug: &str, pr_number: u64, config: &Config) -> Result<PrView> {
+    debug!("Reading state of PR #{pr_number} in {repo_slug}");
+
+    let number = pr_number.to_string();
+    let output = retry_gh(
+        org_of(repo_slug),
+        config,
+        &[
             "pr",
-            "close",
-            &pr_number.to_string(),
+            "view",
+            &number,
             "--repo",
             repo_slug,
-        ]),
-        subprocess_timeout(),
+            "--json",
+            "state,isDraft",
+        ],
     )
+    .context("Failed to execute gh pr view")?;
+
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        return Err(eyre::eyre!("Failed to read PR #{}: {}", pr_number, error));
+    }
+    parse_pr_view(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Close a PR without merging
+pub fn close_pr(repo_slug: &str, pr_number: u64, config: &Config) -> Result<()> {
+    debug!("Closing PR #{pr_number} in {repo_slug}");
+
+    let output = run_gh(gh_command(org_of(repo_slug), config)?.args([
+        "pr",
+        "close",
+        &pr_number.to_string(),
+        "--repo",
+        repo_slug,
+    ]))
     .context("Failed to execute gh pr close")?;
 
     if output.status.success() {
@@ -765,19 +1352,38 @@ pub fn close_pr(repo_slug: &str, pr_number: u64, config: &Config) -> Result<()>
     }
 }
 
+/// Reopen a closed (unmerged) PR
+pub fn reopen_pr(repo_slug: &str, pr_number: u64, config: &Config) -> Result<()> {
+    debug!("Reopening PR #{pr_number} in {repo_slug}");
+
+    let output = run_gh(gh_command(org_of(repo_slug), config)?.args([
+        "pr",
+        "reopen",
+        &pr_number.to_string(),
+        "--repo",
+        repo_slug,
+    ]))
+    .context("Failed to execute gh pr reopen")?;
+
+    if output.status.success() {
+        info!("Successfully reopened PR #{pr_number} in {repo_slug}");
+        Ok(())
+    } else {
+        let error = String::from_utf8_lossy(&output.stderr);
+        Err(eyre::eyre!("Failed to reopen PR #{}: {}", pr_number, error))
+    }
+}
+
 /// Delete a remote branch
 pub fn delete_remote_branch(repo_slug: &str, branch_name: &str, config: &Config) -> Result<()> {
     debug!("Deleting remote branch '{branch_name}' in {repo_slug}");
 
-    let output = run_checked(
-        gh_command(org_of(repo_slug), config)?.args([
-            "api",
-            &format!("repos/{repo_slug}/git/refs/heads/{branch_name}"),
-            "--method",
-            "DELETE",
-        ]),
-        subprocess_timeout(),
-    )
+    let output = run_gh(gh_command(org_of(repo_slug), config)?.args([
+        "api",
+        &format!("repos/{repo_slug}/git/refs/heads/{branch_name}"),
+        "--method",
+        "DELETE",
+    ]))
     .context("Failed to execute gh api DELETE")?;
 
     if output.status.success() {
@@ -802,15 +1408,12 @@ pub fn list_branches_with_prefix(
 ) -> Result<Vec<String>> {
     debug!("Listing branches with prefix '{prefix}' in {repo_slug}");
 
-    let output = run_checked(
-        gh_command(org_of(repo_slug), config)?.args([
-            "api",
-            "--paginate",
-            &format!("repos/{repo_slug}/branches"),
-            "--jq",
-            &format!(".[] | select(.name | startswith(\"{prefix}\")) | .name"),
-        ]),
-        subprocess_timeout(),
+    let endpoint = format!("repos/{repo_slug}/branches");
+    let jq = format!(".[] | select(.name | startswith(\"{prefix}\")) | .name");
+    let output = retry_gh(
+        org_of(repo_slug),
+        config,
+        &["api", "--paginate", &endpoint, "--jq", &jq],
     )
     .context("Failed to execute gh api branches")?;
 
@@ -833,15 +1436,74 @@ pub fn list_branches_with_prefix(
 pub fn list_open_pr_branches(repo_slug: &str, config: &Config) -> Result<Vec<String>> {
     debug!("Listing open-PR head branches in {repo_slug}");
 
-    let output = run_checked(
-        gh_command(org_of(repo_slug), config)?.args([
+    let endpoint = format!("repos/{repo_slug}/pulls?state=open&per_page=100");
+    let output = retry_gh(
+        org_of(repo_slug),
+        config,
+        &["api", "--paginate", &endpoint, "--jq", ".[].head.ref"],
+    )
+    .context("Failed to execute gh api pulls")?;
+
+    if output.status.success() {
+        let branches = String::from_utf8(output.stdout)
+            .context("Invalid UTF-8 in pulls output")?
+            .lines()
+            .map(|line| line.trim().to_string())
+            .filter(|line| !line.is_empty())
+            .collect();
+        Ok(branches)
+    } else {
+        let error = String::from_utf8_lossy(&output.stderr);
+        Err(eyre::eyre!("Failed to list open PRs: {}", error))
+    }
+}
+
+/// Committer date of the commit at the tip of `branch` in `repo_slug`. Used
+/// by `review purge --older-than` to age a branch by its last commit.
+pub fn branch_head_commit_date(
+    repo_slug: &str,
+    branch: &str,
+    config: &Config,
+) -> Result<chrono::DateTime<chrono::Utc>> {
+    debug!("Reading head commit date of {branch} in {repo_slug}");
+
+    let endpoint = format!("repos/{repo_slug}/commits/{branch}");
+    let output = retry_gh(
+        org_of(repo_slug),
+        config,
+        &["api", &endpoint, "--jq", ".commit.committer.date"],
+    )
+    .context("Failed to execute gh api commits")?;
+
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        return Err(eyre::eyre!(
+            "Failed to read {branch}'s head commit: {}",
+            error
+        ));
+    }
+    let date = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    chrono::DateTime::parse_from_rfc3339(&date)
+        .map(|d| d.with_timezone(&chrono::Utc))
+        .with_context(|| format!("Unexpected commit date {date:?} for {branch}"))
+}
+
+/// Head-ref branch names of merged PRs in a repo (paginated). Used by
+/// `review purge --merged-only`, which deletes only branches whose PR merged.
+pub fn list_merged_pr_branches(repo_slug: &str, config: &Config) -> Result<Vec<String>> {
+    debug!("Listing merged-PR head branches in {repo_slug}");
+
+    let endpoint = format!("repos/{repo_slug}/pulls?state=closed&per_page=100");
+    let output = retry_gh(
+        org_of(repo_slug),
+        config,
+        &[
             "api",
             "--paginate",
-            &format!("repos/{repo_slug}/pulls?state=open&per_page=100"),
+            &endpoint,
             "--jq",
-            ".[].head.ref",
-        ]),
-        subprocess_timeout(),
+            ".[] | select(.merged_at != null) | .head.ref",
+        ],
     )
     .context("Failed to execute gh api pulls")?;
 
@@ -855,7 +1517,7 @@ pub fn list_open_pr_branches(repo_slug: &str, config: &Config) -> Result<Vec<Str
         Ok(branches)
     } else {
         let error = String::from_utf8_lossy(&output.stderr);
-        Err(eyre::eyre!("Failed to list open PRs: {}", error))
+        Err(eyre::eyre!("Failed to list merged PRs: {}", error))
     }
 }
 
@@ -1,88 +1,129 @@
 use super::*;
 use local::test_utils::env_lock;
+use tempfile::TempDir;
 
-/// `read_token` resolves the persona env var NAME then reads that var's
-/// VALUE, and fails loudly (naming both var and org) when it is unset.
-/// Exercises the org-`scottidler` -> `$GITHUB_PAT_HOME` built-in floor, so
-/// `$GH_PERSONA` must be unset for the classification to apply; both env
-/// vars are saved and restored under the process-wide env lock.
-#[test]
-fn test_read_token_home_persona_set_and_unset() {
-    let _guard = env_lock();
-    let prior_persona = std::env::var("GH_PERSONA").ok();
-    let prior_home = std::env::var("GITHUB_PAT_HOME").ok();
-
-    // No GH_PERSONA override: scottidler classifies to the HOME floor.
-    unsafe { std::env::remove_var("GH_PERSONA") };
-
-    let config = Config::default();
-
-    // Set -> read_token returns the trimmed value verbatim.
-    unsafe { std::env::set_var("GITHUB_PAT_HOME", "  home-token-value  ") };
-    let token = read_token("scottidler", &config).unwrap();
-    assert_eq!(token, "home-token-value");
+/// Every env var a token can come from, plus `GH_CONFIG_DIR` for `gh`'s own
+/// login; saved, cleared and restored by [`with_token_env`].
+const TOKEN_VARS: [&str; 7] = [
+    "GH_PERSONA",
+    "GITHUB_PAT_HOME",
+    "GITHUB_PAT_WORK",
+    "GX_GITHUB_TOKEN",
+    "GITHUB_TOKEN",
+    "GH_TOKEN",
+    "GH_CONFIG_DIR",
+];
 
-    // Unset -> loud Err naming BOTH the var and the org that selected it.
-    unsafe { std::env::remove_var("GITHUB_PAT_HOME") };
-    let err = read_token("scottidler", &config).unwrap_err();
-    let msg = err.to_string();
-    assert!(
-        msg.contains("GITHUB_PAT_HOME"),
-        "error must name the missing var: {msg}"
-    );
-    assert!(
-        msg.contains("scottidler"),
-        "error must name the org that selected it: {msg}"
-    );
-
-    match prior_home {
-        Some(v) => unsafe { std::env::set_var("GITHUB_PAT_HOME", v) },
-        None => unsafe { std::env::remove_var("GITHUB_PAT_HOME") },
+/// Run `f` with every token source cleared (`gh` pointed at an empty config
+/// dir, so `gh auth token` finds no login) and then `vars` set, under the
+/// process-wide env lock; the prior environment is restored afterwards.
+fn with_token_env<F: FnOnce()>(vars: &[(&str, &str)], f: F) {
+    let _guard = env_lock();
+    let prior: Vec<(&str, Option<String>)> = TOKEN_VARS
+        .iter()
+        .map(|name| (*name, std::env::var(name).ok()))
+        .collect();
+    let gh_config = TempDir::new().unwrap();
+    for name in TOKEN_VARS {
+        unsafe { std::env::remove_var(name) };
     }
-    match prior_persona {
-        Some(v) => unsafe { std::env::set_var("GH_PERSONA", v) },
-        None => unsafe { std::env::remove_var("GH_PERSONA") },
+    unsafe { std::env::set_var("GH_CONFIG_DIR", gh_config.path()) };
+    for (name, value) in vars {
+        unsafe { std::env::set_var(name, value) };
+    }
+    f();
+    for (name, value) in prior {
+        match value {
+            Some(v) => unsafe { std::env::set_var(name, v) },
+            None => unsafe { std::env::remove_var(name) },
+        }
     }
 }
 
-/// The one behavior change of Phase 4: a mutating `gh_command` call whose
-/// selected persona var is unset must fail loudly (`Err`), never silently
-/// build an ambient-auth `Command` (design doc "Fail-loud vs the current
-/// swallow"). Exercises the same `scottidler` -> `$GITHUB_PAT_HOME` floor as
-/// above, but asserts on `gh_command` itself rather than `read_token`, so an
-/// accidental re-introduction of the old `match ... Err(e) => debug!(...)`
-/// swallow in `gh_command` fails this test even if `read_token` is untouched.
+/// `resolve_token` resolves the persona env var NAME then reads that var's
+/// VALUE, and fails loudly (naming both var and org) when no source has a
+/// token. Exercises the org-`scottidler` -> `$GITHUB_PAT_HOME` built-in
+/// floor, so `$GH_PERSONA` must be unset for the classification to apply.
 #[test]
-fn test_gh_command_fails_loud_when_persona_token_unset() {
-    let _guard = env_lock();
-    let prior_persona = std::env::var("GH_PERSONA").ok();
-    let prior_home = std::env::var("GITHUB_PAT_HOME").ok();
+fn test_resolve_token_home_persona_set_and_unset() {
+    let config = Config::default();
 
-    unsafe { std::env::remove_var("GH_PERSONA") };
-    unsafe { std::env::remove_var("GITHUB_PAT_HOME") };
+    // Set -> resolve_token returns the trimmed value verbatim.
+    with_token_env(&[("GITHUB_PAT_HOME", "  home-token-value  ")], || {
+        let token = resolve_token("scottidler", &config).unwrap();
+        assert_eq!(token, "home-token-value");
+    });
 
+    // Unset -> loud Err naming BOTH the var and the org that selected it,
+    // and pointing at `gh auth login`.
+    with_token_env(&[], || {
+        let err = resolve_token("scottidler", &config).unwrap_err();
+        let msg = err.to_string();
+        assert!(
+            msg.contains("GITHUB_PAT_HOME"),
+            "error must name the missing var: {msg}"
+        );
+        assert!(
+            msg.contains("scottidler"),
+            "error must name the org that selected it: {msg}"
+        );
+        assert!(
+            msg.contains("gh auth login"),
+            "error must point at gh auth login: {msg}"
+        );
+    });
+}
+
+/// `GX_GITHUB_TOKEN` beats the persona var, which beats the ambient
+/// `GITHUB_TOKEN`/`GH_TOKEN`.
+#[test]
+fn test_resolve_token_precedence() {
     let config = Config::default();
 
-    let err = gh_command("scottidler", &config)
-        .expect_err("gh_command must fail loudly, never fall back to ambient gh auth");
-    let msg = err.to_string();
-    assert!(
-        msg.contains("GITHUB_PAT_HOME"),
-        "error must name the missing var: {msg}"
+    with_token_env(
+        &[
+            ("GX_GITHUB_TOKEN", "gx"),
+            ("GITHUB_PAT_HOME", "persona"),
+            ("GITHUB_TOKEN", "ambient"),
+        ],
+        || assert_eq!(resolve_token("scottidler", &config).unwrap(), "gx"),
     );
-    assert!(
-        msg.contains("scottidler"),
-        "error must name the org that selected it: {msg}"
+    with_token_env(
+        &[("GITHUB_PAT_HOME", "persona"), ("GITHUB_TOKEN", "ambient")],
+        || assert_eq!(resolve_token("scottidler", &config).unwrap(), "persona"),
     );
+    with_token_env(&[("GITHUB_TOKEN", "ambient"), ("GH_TOKEN", "gh")], || {
+        assert_eq!(resolve_token("scottidler", &config).unwrap(), "ambient")
+    });
+    with_token_env(&[("GH_TOKEN", "gh")], || {
+        assert_eq!(resolve_token("scottidler", &config).unwrap(), "gh")
+    });
+}
 
-    match prior_home {
-        Some(v) => unsafe { std::env::set_var("GITHUB_PAT_HOME", v) },
-        None => unsafe { std::env::remove_var("GITHUB_PAT_HOME") },
-    }
-    match prior_persona {
-        Some(v) => unsafe { std::env::set_var("GH_PERSONA", v) },
-        None => unsafe { std::env::remove_var("GH_PERSONA") },
-    }
+/// The one behavior change of Phase 4: a mutating `gh_command` call with no
+/// token from any source must fail loudly (`Err`), never silently build an
+/// unauthenticated `Command` (design doc "Fail-loud vs the current swallow").
+/// Asserts on `gh_command` itself rather than `resolve_token`, so an
+/// accidental re-introduction of the old `match ... Err(e) => debug!(...)`
+/// swallow in `gh_command` fails this test even if `resolve_token` is
+/// untouched.
+#[test]
+fn test_gh_command_fails_loud_when_no_token_is_found() {
+    let config = Config::default();
+
+    with_token_env(&[], || {
+        let err = gh_command("scottidler", &config)
+            .expect_err("gh_command must fail loudly when no token is found");
+        let msg = err.to_string();
+        assert!(
+            msg.contains("GITHUB_PAT_HOME"),
+            "error must name the missing var: {msg}"
+        );
+        assert!(
+            msg.contains("scottidler"),
+            "error must name the org that selected it: {msg}"
+        );
+    });
 }
 
 /// v0.6.3 fix: repo listing must use `gh repo list <owner>` (GraphQL, returns
@@ -93,7 +134,7 @@ fn test_gh_command_fails_loud_when_persona_token_unset() {
 /// the args and fails these asserts.
 #[test]
 fn test_repo_list_uses_graphql_not_public_only_rest() {
-    let args = repo_list_args("scottidler", false);
+    let args = repo_list_args("scottidler", ArchivedFilter::Exclude, None);
 
     // GraphQL owner listing: `gh repo list scottidler ...`
     assert_eq!(args[0], "repo", "must call `gh repo list`: {args:?}");
@@ -116,11 +157,140 @@ fn test_repo_list_uses_graphql_not_public_only_rest() {
     );
 
     // include_archived => no --no-archived flag, so archived repos ride along.
-    let with_archived = repo_list_args("scottidler", true);
+    let with_archived = repo_list_args("scottidler", ArchivedFilter::Include, None);
     assert!(
         !with_archived.iter().any(|a| a == "--no-archived"),
         "include_archived must not pass --no-archived: {with_archived:?}"
     );
+
+    // archived_only => gh's --archived, never --no-archived.
+    let only_archived = repo_list_args("scottidler", ArchivedFilter::Only, None);
+    assert!(only_archived.iter().any(|a| a == "--archived"));
+    assert!(!only_archived.iter().any(|a| a == "--no-archived"));
+
+    // Every listing asks for the per-repo archived flag.
+    for args in [&args, &with_archived, &only_archived] {
+        assert!(
+            args.iter()
+                .any(|a| a == "nameWithOwner,isArchived,pushedAt"),
+            "listing must request isArchived: {args:?}"
+        );
+    }
+}
+
+/// A mocked org listing with a mix of archived and active repos: each filter
+/// selects from the real `isArchived` flag, whatever gh was asked for.
+#[test]
+fn test_select_listed_repos_by_archived_flag() {
+    let listing = r#"[
+        {"nameWithOwner": "acme/api", "isArchived": false},
+        {"nameWithOwner": "acme/legacy", "isArchived": true},
+        {"nameWithOwner": "acme/web", "isArchived": false},
+        {"nameWithOwner": "acme/old-cli", "isArchived": true}
+    ]"#;
+
+    assert_eq!(
+        select_listed_repos(listing, ArchivedFilter::Exclude, None).unwrap(),
+        ["acme/api", "acme/web"]
+    );
+    assert_eq!(
+        select_listed_repos(listing, ArchivedFilter::Include, None).unwrap(),
+        ["acme/api", "acme/legacy", "acme/web", "acme/old-cli"]
+    );
+    assert_eq!(
+        select_listed_repos(listing, ArchivedFilter::Only, None).unwrap(),
+        ["acme/legacy", "acme/old-cli"]
+    );
+
+    // `gh search repos` reports the slug as `fullName`.
+    let search = r#"[{"fullName": "acme/legacy", "isArchived": true}]"#;
+    assert_eq!(
+        select_listed_repos(search, ArchivedFilter::Only, None).unwrap(),
+        ["acme/legacy"]
+    );
+    assert!(select_listed_repos(search, ArchivedFilter::Exclude, None)
+        .unwrap()
+        .is_empty());
+}
+
+/// `--since`: a mocked listing with stale, recent and never-pushed repos keeps
+/// only the ones pushed after the cutoff.
+#[test]
+fn test_select_listed_repos_since_drops_stale_and_unpushed() {
+    let listing = r#"[
+        {"nameWithOwner": "acme/api", "isArchived": false, "pushedAt": "2026-10-01T12:00:00Z"},
+        {"nameWithOwner": "acme/stale", "isArchived": false, "pushedAt": "2025-01-01T00:00:00Z"},
+        {"nameWithOwner": "acme/empty", "isArchived": false, "pushedAt": null},
+        {"nameWithOwner": "acme/web", "isArchived": false, "pushedAt": "2026-10-14T08:30:00Z"}
+    ]"#;
+    let now = chrono::DateTime::parse_from_rfc3339("2026-10-15T00:00:00Z")
+        .unwrap()
+        .with_timezone(&chrono::Utc);
+    let since = now - local::utils::parse_duration("30d").unwrap();
+
+    assert_eq!(
+        select_listed_repos(listing, ArchivedFilter::Exclude, Some(since)).unwrap(),
+        ["acme/api", "acme/web"]
+    );
+    // Without --since every repo is kept, pushed or not.
+    assert_eq!(
+        select_listed_repos(listing, ArchivedFilter::Exclude, None)
+            .unwrap()
+            .len(),
+        4
+    );
+}
+
+#[test]
+fn test_archived_filter_from_flags() {
+    assert_eq!(
+        ArchivedFilter::from_flags(false, false),
+        ArchivedFilter::Exclude
+    );
+    assert_eq!(
+        ArchivedFilter::from_flags(true, false),
+        ArchivedFilter::Include
+    );
+    assert_eq!(
+        ArchivedFilter::from_flags(false, true),
+        ArchivedFilter::Only
+    );
+}
+
+/// `--search` resolves the token owner from the query's `org:`/`user:`
+/// qualifier; a query with neither is refused rather than searching all of
+/// GitHub under an arbitrary persona.
+#[test]
+fn test_search_owner_from_qualifier() {
+    assert_eq!(
+        search_owner("org:tatari-tv topic:service"),
+        Some("tatari-tv")
+    );
+    assert_eq!(
+        search_owner("language:rust user:scottidler"),
+        Some("scottidler")
+    );
+    assert_eq!(search_owner("topic:service"), None);
+    assert_eq!(search_owner("org: topic:service"), None);
+}
+
+#[test]
+fn test_search_repo_args_shape() {
+    let args = search_repo_args("org:tatari-tv -topic:legacy", ArchivedFilter::Exclude, None);
+
+    assert_eq!(args[0], "search");
+    assert_eq!(args[1], "repos");
+    assert!(args.iter().any(|a| a == "--archived=false"), "{args:?}");
+
+    // Query terms follow `--` so a negated qualifier is never a gh flag.
+    let sep = args.iter().position(|a| a == "--").unwrap();
+    assert_eq!(&args[sep + 1..], ["org:tatari-tv", "-topic:legacy"]);
+
+    let with_archived = search_repo_args("org:tatari-tv", ArchivedFilter::Include, None);
+    assert!(!with_archived.iter().any(|a| a.starts_with("--archived")));
+
+    let only_archived = search_repo_args("org:tatari-tv", ArchivedFilter::Only, None);
+    assert!(only_archived.iter().any(|a| a == "--archived=true"));
 }
 
 #[test]
@@ -418,6 +588,115 @@ fn test_parse_graphql_page_returns_page_info() {
     assert_eq!(info.end_cursor.as_deref(), Some("CURSOR123"));
 }
 
+/// One GraphQL search page holding GX PRs `numbers`, linking to `next` when
+/// there is a following page.
+fn pr_page(numbers: &[u64], next: Option<&str>) -> String {
+    let nodes: Vec<serde_json::Value> = numbers
+        .iter()
+        .map(|n| {
+            serde_json::json!({
+                "number": n,
+                "title": format!("GX-{n}: PR"),
+                "headRefName": format!("GX-{n}"),
+                "author": {"login": "u"},
+                "state": "OPEN",
+                "url": format!("https://github.com/o/r{n}/pull/{n}"),
+                "repository": {"nameWithOwner": format!("o/r{n}")},
+                "baseRefName": "main"
+            })
+        })
+        .collect();
+    serde_json::json!({"data": {"search": {
+        "pageInfo": {"hasNextPage": next.is_some(), "endCursor": next},
+        "nodes": nodes
+    }}})
+    .to_string()
+}
+
+/// Three pages of results must all be collected, each fetched with the
+/// previous page's cursor; a listing that stopped at page one is the silent
+/// truncation this guards against.
+#[test]
+fn test_collect_pr_pages_follows_every_page() {
+    let mut cursors = Vec::new();
+    let prs = collect_pr_pages("GX-", None, |cursor| {
+        cursors.push(cursor.map(str::to_string));
+        Ok(match cursor {
+            None => pr_page(&[1, 2], Some("c1")),
+            Some("c1") => pr_page(&[3, 4], Some("c2")),
+            Some("c2") => pr_page(&[5], None),
+            Some(other) => panic!("unexpected cursor {other}"),
+        })
+    })
+    .unwrap();
+
+    let numbers: Vec<u64> = prs.iter().map(|pr| pr.number).collect();
+    assert_eq!(numbers, [1, 2, 3, 4, 5]);
+    assert_eq!(
+        cursors,
+        [None, Some("c1".to_string()), Some("c2".to_string())]
+    );
+}
+
+#[test]
+fn test_collect_pr_pages_stops_at_limit() {
+    let mut fetches = 0;
+    let prs = collect_pr_pages("GX-", Some(3), |cursor| {
+        fetches += 1;
+        Ok(match cursor {
+            None => pr_page(&[1, 2], Some("c1")),
+            Some("c1") => pr_page(&[3, 4], Some("c2")),
+            Some(other) => panic!("fetched past the limit: {other}"),
+        })
+    })
+    .unwrap();
+
+    assert_eq!(prs.len(), 3);
+    assert_eq!(fetches, 2);
+}
+
+#[test]
+fn test_listing_limit_is_capped_at_ceiling() {
+    let at = |args: &[String]| {
+        let i = args.iter().position(|a| a == "--limit").unwrap();
+        args[i + 1].clone()
+    };
+    assert_eq!(
+        at(&repo_list_args("o", ArchivedFilter::Include, None)),
+        REPO_LIST_LIMIT.to_string()
+    );
+    assert_eq!(
+        at(&repo_list_args("o", ArchivedFilter::Include, Some(50))),
+        "50"
+    );
+    assert_eq!(
+        at(&search_repo_args(
+            "org:o",
+            ArchivedFilter::Include,
+            Some(5000)
+        )),
+        REPO_SEARCH_LIMIT.to_string()
+    );
+}
+
+#[test]
+fn test_parse_pr_view_states() {
+    let view = |json| parse_pr_view(json).unwrap();
+    assert_eq!(
+        view(r#"{"state":"OPEN","isDraft":true}"#),
+        PrView {
+            state: PrState::Open,
+            is_draft: true
+        }
+    );
+    assert_eq!(
+        view(r#"{"state":"MERGED","isDraft":false}"#).state,
+        PrState::Merged
+    );
+    assert_eq!(view(r#"{"state":"CLOSED"}"#).state, PrState::Closed);
+    assert!(parse_pr_view("not json").is_err());
+}
+
 #[test]
 fn test_search_query_uses_variables() {
     // The query is parameterized ($q, $cursor), never string-interpolated ([A13]).
@@ -426,6 +705,43 @@ fn test_search_query_uses_variables() {
     assert!(PR_SEARCH_QUERY.contains("hasNextPage"));
 }
 
+#[test]
+fn test_render_pr_body_interpolates_every_placeholder() {
+    let body = render_pr_body(
+        "{change_id} in {repo}\n\n{commit_message}\n\nFiles:\n{files}",
+        "Bump deps",
+        "GX-42",
+        "tatari-tv/api",
+        &["go.mod".to_string(), "go.sum".to_string()],
+    );
+    assert_eq!(
+        body,
+        "GX-42 in tatari-tv/api\n\nBump deps\n\nFiles:\n- `go.mod`\n- `go.sum`"
+    );
+}
+
+#[test]
+fn test_render_pr_body_leaves_other_text_alone() {
+    assert_eq!(
+        render_pr_body("plain {unknown}", "m", "GX-1", "o/r", &[]),
+        "plain {unknown}"
+    );
+}
+
+#[test]
+fn test_render_pr_body_does_not_expand_placeholders_in_values() {
+    assert_eq!(
+        render_pr_body(
+            "{commit_message} ({repo})",
+            "Document {files} and {repo}",
+            "GX-1",
+            "o/r",
+            &["a.md".to_string()],
+        ),
+        "Document {files} and {repo} (o/r)"
+    );
+}
+
 #[test]
 fn test_pr_body_template_substitution() {
     let config = local::config::Config::default();
@@ -575,3 +891,246 @@ fn test_approve_and_merge_pr_admin_override_skips_approve_and_merges_with_admin(
     }
     drop(_guard);
 }
+
+#[test]
+fn test_api_gate_never_exceeds_its_limit() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let gate = ApiGate::new(3);
+    let current = AtomicUsize::new(0);
+    let peak = AtomicUsize::new(0);
+    std::thread::scope(|scope| {
+        for _ in 0..16 {
+            scope.spawn(|| {
+                let _permit = gate.acquire();
+                let now = current.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                std::thread::sleep(std::time::Duration::from_millis(10));
+                current.fetch_sub(1, Ordering::SeqCst);
+            });
+        }
+    });
+    let peak = peak.load(Ordering::SeqCst);
+    assert!(peak <= 3, "{peak} calls ran at once past a limit of 3");
+    assert!(peak >= 1);
+}
+
+#[test]
+fn test_api_gate_limit_floor_is_one() {
+    let gate = ApiGate::new(0);
+    drop(gate.acquire());
+    let _again = gate.acquire();
+}
+
+#[test]
+fn test_pr_create_args_include_reviewers_assignees_and_labels() {
+    let metadata = PrMetadata {
+        reviewers: vec!["octocat".to_string(), "tatari-tv/platform".to_string()],
+        assignees: vec!["alice".to_string()],
+        labels: vec!["automated".to_string(), "dependencies".to_string()],
+        create_labels: false,
+    };
+    let args = pr_create_args(
+        "tatari-tv/api",
+        "GX-1",
+        "GX-1",
+        "body",
+        "main",
+        true,
+        &metadata,
+    );
+    assert_eq!(
+        args,
+        [
+            "pr",
+            "create",
+            "--repo",
+            "tatari-tv/api",
+            "--head",
+            "GX-1",
+            "--title",
+            "GX-1",
+            "--body",
+            "body",
+            "--base",
+            "main",
+            "--draft",
+            "--reviewer",
+            "octocat",
+            "--reviewer",
+            "tatari-tv/platform",
+            "--assignee",
+            "alice",
+            "--label",
+            "automated",
+            "--label",
+            "dependencies",
+        ]
+    );
+}
+
+#[test]
+fn test_pr_create_args_without_metadata_adds_no_flags() {
+    let args = pr_create_args(
+        "tatari-tv/api",
+        "GX-1",
+        "GX-1",
+        "body",
+        "main",
+        false,
+        &PrMetadata::default(),
+    );
+    assert!(!args
+        .iter()
+        .any(|a| a == "--reviewer" || a == "--assignee" || a == "--label" || a == "--draft"));
+}
+
+#[test]
+fn test_pr_create_args_forward_the_base_branch() {
+    let args = pr_create_args(
+        "tatari-tv/api",
+        "GX-1",
+        "GX-1",
+        "body",
+        "develop",
+        false,
+        &PrMetadata::default(),
+    );
+    let base = args.iter().position(|a| a == "--base").unwrap();
+    assert_eq!(args[base + 1], "develop");
+}
+
+#[test]
+fn test_is_missing_label_error() {
+    assert!(is_missing_label_error(
+        "could not add label: 'automated' not found"
+    ));
+    assert!(!is_missing_label_error(
+        "could not request reviewer: 'x' not found"
+    ));
+    assert!(!is_missing_label_error("a pull request already exists"));
+}
+
+/// Installs a fake `gh` that logs each call to `$GX_TEST_GH_LOG` and refuses
+/// `pr create --label ...` until `gh label create` has run (a marker file
+/// next to the log), printing a PR URL once it succeeds.
+fn install_label_shim(dir: &std::path::Path) {
+    let gh_path = dir.join("gh");
+    let script = r#"#!/bin/sh
+echo "$@" >> "$GX_TEST_GH_LOG"
+marker="$GX_TEST_GH_LOG.label"
+if [ "$1" = "label" ] && [ "$2" = "create" ]; then
+  touch "$marker"
+  exit 0
+fi
+if [ "$1" = "pr" ] && [ "$2" = "create" ]; then
+  case " $* " in
+    *" --label "*)
+      if [ ! -f "$marker" ]; then
+        echo "could not add label: 'automated' not found" >&2
+        exit 1
+      fi
+      ;;
+  esac
+  echo "https://github.com/scottidler/gx/pull/7"
+  exit 0
+fi
+exit 1
+"#;
+    std::fs::write(&gh_path, script).unwrap();
+    use std::os::unix::fs::PermissionsExt;
+    let mut perms = std::fs::metadata(&gh_path).unwrap().permissions();
+    perms.set_mode(0o755);
+    std::fs::set_permissions(&gh_path, perms).unwrap();
+}
+
+/// Run `create_pr` with an `automated` label and an `octocat` reviewer against
+/// the label shim; returns the result and the logged gh calls.
+fn create_pr_with_label_shim(create_labels: bool) -> (Result<CreatePrResult>, String) {
+    let _guard = env_lock();
+    let prior_path = std::env::var("PATH").ok();
+    let prior_home = std::env::var("GITHUB_PAT_HOME").ok();
+    let prior_log = std::env::var("GX_TEST_GH_LOG").ok();
+
+    let shim_dir = tempfile::TempDir::new().unwrap();
+    install_label_shim(shim_dir.path());
+    let new_path = format!(
+        "{}:{}",
+        shim_dir.path().display(),
+        prior_path.clone().unwrap_or_default()
+    );
+    unsafe { std::env::set_var("PATH", &new_path) };
+    unsafe { std::env::set_var("GITHUB_PAT_HOME", "dummy-token-not-a-secret") };
+    let log_path = shim_dir.path().join("gh.log");
+    unsafe { std::env::set_var("GX_TEST_GH_LOG", &log_path) };
+
+    let metadata = PrMetadata {
+        reviewers: vec!["octocat".to_string()],
+        labels: vec!["automated".to_string()],
+        create_labels,
+        ..Default::default()
+    };
+    let config = local::config::Config::default();
+    let result = create_pr(
+        "scottidler/gx",
+        "GX-labels",
+        "Update docs",
+        "main",
+        false,
+        &metadata,
+        &config,
+    );
+    let log = std::fs::read_to_string(&log_path).unwrap_or_default();
+
+    match prior_path {
+        Some(v) => unsafe { std::env::set_var("PATH", v) },
+        None => unsafe { std::env::remove_var("PATH") },
+    }
+    match prior_home {
+        Some(v) => unsafe { std::env::set_var("GITHUB_PAT_HOME", v) },
+        None => unsafe { std::env::remove_var("GITHUB_PAT_HOME") },
+    }
+    match prior_log {
+        Some(v) => unsafe { std::env::set_var("GX_TEST_GH_LOG", v) },
+        None => unsafe { std::env::remove_var("GX_TEST_GH_LOG") },
+    }
+    (result, log)
+}
+
+#[test]
+fn test_create_pr_creates_a_missing_label_with_create_labels() {
+    let (result, log) = create_pr_with_label_shim(true);
+    let result = result.expect("the PR must be created once the label exists");
+    assert_eq!(result.number, 7);
+    assert!(result.warning.is_none(), "got: {:?}", result.warning);
+    assert!(
+        log.contains("label create automated --repo scottidler/gx"),
+        "the missing label must be created: {log}"
+    );
+    let last_create = log
+        .lines()
+        .filter(|l| l.starts_with("pr create"))
+        .last()
+        .unwrap();
+    assert!(last_create.contains("--label automated"), "{last_create}");
+}
+
+#[test]
+fn test_create_pr_drops_a_missing_label_to_a_warning() {
+    let (result, log) = create_pr_with_label_shim(false);
+    let result = result.expect("a missing label must not fail the PR");
+    assert_eq!(result.number, 7);
+    let warning = result.warning.expect("the dropped label is a warning");
+    assert!(warning.contains("automated"), "{warning}");
+    assert!(!log.contains("label create"), "{log}");
+    let last_create = log
+        .lines()
+        .filter(|l| l.starts_with("pr create"))
+        .last()
+        .unwrap();
+    assert!(!last_create.contains("--label"), "{last_create}");
+    assert!(
+        last_create.contains("--reviewer octocat"),
+        "only the label is dropped: {last_create}"
+    );
+}
@@ -56,6 +56,7 @@ fn remote_label(status: &RemoteStatus) -> String {
         RemoteStatus::NoRemote => "no-remote".to_string(),
         RemoteStatus::NoUpstream => "no-upstream".to_string(),
         RemoteStatus::DetachedHead => "detached".to_string(),
+        RemoteStatus::NoBranch => "no-branch".to_string(),
         RemoteStatus::Error(e) => format!("error: {e}"),
     }
 }
@@ -86,7 +87,14 @@ pub fn status(
     Ok(repos
         .iter()
         .map(|repo| {
-            let rs = crate::git::get_repo_status_with_options(repo, false, no_remote);
+            let rs = crate::git::get_repo_status_with_options(
+                repo,
+                false,
+                no_remote,
+                None,
+                None,
+                local::git::UntrackedFiles::default(),
+            );
             RepoStatusSummary {
                 slug: repo.slug.clone(),
                 branch: rs.branch,
@@ -200,9 +208,9 @@ pub fn review_status() -> Result<Vec<ReviewChange>> {
     Ok(out)
 }
 
-pub fn doctor() -> Result<crate::doctor::DoctorReport> {
+pub fn doctor(config: &Config) -> Result<crate::doctor::DoctorReport> {
     debug!("logic::doctor");
-    crate::doctor::collect_report()
+    crate::doctor::collect_report(config)
 }
 
 // ---- intel-catalog read-only tools (design doc 2026-07-17-gx-intel-catalog) --
@@ -181,7 +181,8 @@ impl GxMcpServer {
     )]
     async fn doctor(&self, _params: Parameters<NoArgs>) -> Result<CallToolResult, ErrorData> {
         info!("gx-mcp tool: doctor");
-        run_blocking(logic::doctor).await
+        let config = self.config.clone();
+        run_blocking(move || logic::doctor(&config)).await
     }
 
     // ---- intel catalog, read-only (default enabled) ---------------------
@@ -1,14 +1,16 @@
+use crate::cli::ColorChoice;
 use crate::create::{CreateAction, CreateResult};
 use crate::git::{CheckoutAction, CheckoutResult, CloneAction, CloneResult};
 use crate::review::{ReviewAction, ReviewResult};
 use colored::*;
 use eyre::{Context, Result};
-use local::config::OutputVerbosity;
+use local::config::{OutputVerbosity, ThemeConfig, ThemeName};
 use local::git::{RemoteStatus, RepoStatus};
-use local::repo::Layout;
+use local::repo::{Layout, Repo};
 use local::subprocess::{run_checked, subprocess_timeout};
+use std::borrow::Cow;
 use std::io::{self, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use unicode_display_width::width as unicode_width;
 
 /// Catppuccin Mocha palette roles, matching Scott's starship prompt
@@ -71,11 +73,132 @@ pub fn pad_to_width(s: &str, target_width: usize) -> String {
     }
 }
 
+/// Whether output should be colored under `--color`: `always` forces it;
+/// otherwise a set (non-empty) `NO_COLOR` or a stdout that is not a terminal
+/// turns it off, and `never` always does.
+pub fn should_use_color(choice: ColorChoice) -> bool {
+    use std::io::IsTerminal;
+
+    let no_color_env = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+    choice.resolve(io::stdout().is_terminal(), no_color_env)
+}
+
+/// Resolve `--color` (see [`should_use_color`]) and install the result as
+/// `colored`'s global override, so every `.green()` in every command agrees
+/// with it. Returns whether colors are on.
+pub fn apply_color_choice(choice: ColorChoice) -> bool {
+    let enabled = should_use_color(choice);
+    colored::control::set_override(enabled);
+    enabled
+}
+
+/// Whether a command should draw a `--progress` counter: only when asked for,
+/// when stderr is a terminal, and never under `--quiet` or machine-readable
+/// (`--json`) output.
+pub fn progress_wanted(requested: bool, quiet: bool, json: bool) -> bool {
+    use std::io::IsTerminal;
+
+    requested && !quiet && !json && io::stderr().is_terminal()
+}
+
+/// Warn on stderr about discovered repos that share a slug
+/// ([`local::repo::detect_slug_collisions`]), unless `quiet`.
+pub fn warn_slug_collisions(repos: &[Repo], quiet: bool) {
+    if quiet {
+        return;
+    }
+    for (slug, paths) in local::repo::detect_slug_collisions(repos) {
+        eprintln!("⚠️  {}", local::repo::slug_collision_message(&slug, &paths));
+    }
+}
+
+/// A live `[done/total] label...` counter on stderr for a parallel run. Rayon
+/// workers call [`ProgressReporter::tick`] as each task finishes; the count is
+/// an atomic, so ticks from any thread are never lost. A disabled reporter
+/// still counts but draws nothing.
+#[derive(Debug)]
+pub struct ProgressReporter {
+    total: usize,
+    label: &'static str,
+    done: std::sync::atomic::AtomicUsize,
+    enabled: bool,
+    /// Serializes drawing, so a result printed by one worker never lands on
+    /// a counter half-drawn by another.
+    draw: std::sync::Mutex<()>,
+}
+
+impl ProgressReporter {
+    pub fn new(total: usize, label: &'static str, enabled: bool) -> Self {
+        Self {
+            total,
+            label,
+            done: std::sync::atomic::AtomicUsize::new(0),
+            enabled,
+            draw: std::sync::Mutex::new(()),
+        }
+    }
+
+    /// Record one finished task and redraw the counter. Returns the new count.
+    pub fn tick(&self) -> usize {
+        let done = self.done.fetch_add(1, std::sync::atomic::Ordering::Relaxed) + 1;
+        if self.enabled {
+            let _draw = self.draw.lock().unwrap_or_else(|e| e.into_inner());
+            let mut stderr = io::stderr().lock();
+            let _ = write!(stderr, "\r\x1b[K{}", self.render(done));
+            let _ = stderr.flush();
+        }
+        done
+    }
+
+    /// Run `print`, which writes a result to stdout, with the counter line
+    /// cleared first and redrawn after, so streamed results and the counter
+    /// never share a terminal line.
+    pub fn print_above(&self, print: impl FnOnce()) {
+        if !self.enabled {
+            print();
+            return;
+        }
+        let _draw = self.draw.lock().unwrap_or_else(|e| e.into_inner());
+        let _ = write!(io::stderr(), "\r\x1b[K");
+        let _ = io::stderr().flush();
+        print();
+        let _ = io::stdout().flush();
+        let done = self.done();
+        if done > 0 {
+            let _ = write!(io::stderr(), "{}", self.render(done));
+            let _ = io::stderr().flush();
+        }
+    }
+
+    /// Tasks finished so far.
+    pub fn done(&self) -> usize {
+        self.done.load(std::sync::atomic::Ordering::Relaxed)
+    }
+
+    /// The counter line for `done` finished tasks.
+    pub fn render(&self, done: usize) -> String {
+        format!("[{done}/{}] {}...", self.total, self.label)
+    }
+
+    /// End the counter line so the results that follow start on a fresh line.
+    pub fn finish(&self) {
+        if self.enabled && self.done() > 0 {
+            eprintln!();
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct StatusOptions {
     pub verbosity: OutputVerbosity,
     pub use_emoji: bool,
     pub use_colors: bool,
+    /// Global `--quiet`: per-repo lines are suppressed; summaries still print.
+    pub quiet: bool,
+    /// The configured status symbols (`theme:`); `--no-emoji` overrides it.
+    pub theme: Theme,
+    /// What the repo column shows: the slug, or the repo's path.
+    pub path_display: RepoPathDisplay,
 }
 
 impl Default for StatusOptions {
@@ -84,10 +207,195 @@ impl Default for StatusOptions {
             verbosity: OutputVerbosity::Summary,
             use_emoji: true,
             use_colors: true,
+            quiet: false,
+            theme: Theme::default(),
+            path_display: RepoPathDisplay::default(),
+        }
+    }
+}
+
+impl StatusOptions {
+    /// The theme status markers are drawn with: the configured one, or the
+    /// plain theme under `--no-emoji`.
+    pub fn theme(&self) -> Cow<'_, Theme> {
+        if self.use_emoji {
+            Cow::Borrowed(&self.theme)
+        } else {
+            Cow::Owned(Theme::from_name(ThemeName::Plain))
+        }
+    }
+}
+
+/// What the repo column names each repo by (`gx status --relative-paths` /
+/// `--absolute-paths`). The slug is ambiguous when two orgs have a repo of
+/// the same name, and a path can be `cd`'d into.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum RepoPathDisplay {
+    /// The repo's `org/repo` slug.
+    #[default]
+    Slug,
+    /// The repo's path relative to this discovery root (`.` for the root
+    /// itself); a repo outside it falls back to its full path.
+    Relative(PathBuf),
+    /// The repo's path as discovered (absolute).
+    Absolute,
+}
+
+impl RepoPathDisplay {
+    /// The name shown for the repo at `repo_path` with `repo_slug`.
+    pub fn name(&self, repo_path: &Path, repo_slug: &str) -> String {
+        match self {
+            Self::Slug => repo_slug.to_string(),
+            Self::Relative(root) => match repo_path.strip_prefix(root) {
+                Ok(rel) if rel.as_os_str().is_empty() => ".".to_string(),
+                Ok(rel) => rel.display().to_string(),
+                Err(_) => repo_path.display().to_string(),
+            },
+            Self::Absolute => repo_path.display().to_string(),
         }
     }
 }
 
+/// The symbol `gx status` draws for each repo state. `ahead`/`behind`
+/// interpolate `{n}`, `diverged` `{ahead}` and `{behind}`, and
+/// `remote_error` `{e}`. Built from [`ThemeName`] plus the `theme:` overrides.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Theme {
+    pub error: String,
+    pub untracked: String,
+    pub modified: String,
+    pub added: String,
+    pub deleted: String,
+    pub staged: String,
+    /// A submodule pointer moved, under `--include-submodules-in-status`.
+    pub submodule: String,
+    pub up_to_date: String,
+    pub ahead: String,
+    pub behind: String,
+    pub diverged: String,
+    /// No remote, no upstream, or a detached HEAD.
+    pub no_upstream: String,
+    /// `status --branch` named a branch the repo lacks.
+    pub no_branch: String,
+    pub remote_error: String,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::from_name(ThemeName::Emoji)
+    }
+}
+
+impl Theme {
+    /// One of the built-in themes.
+    pub fn from_name(name: ThemeName) -> Self {
+        match name {
+            ThemeName::Emoji => Self {
+                error: "❌".to_string(),
+                untracked: "❓".to_string(),
+                modified: "📝".to_string(),
+                added: "➕".to_string(),
+                deleted: "❌".to_string(),
+                staged: "🎯".to_string(),
+                submodule: "🧩".to_string(),
+                up_to_date: "🟢".to_string(),
+                ahead: "↑{n}".to_string(),
+                behind: "↓{n}".to_string(),
+                diverged: "🔀 {ahead}↑{behind}↓".to_string(),
+                no_upstream: "📍".to_string(),
+                no_branch: "🚫".to_string(),
+                remote_error: "🚨 {e}".to_string(),
+            },
+            ThemeName::Plain => Self {
+                error: "ERROR".to_string(),
+                untracked: "?".to_string(),
+                modified: "M".to_string(),
+                added: "A".to_string(),
+                deleted: "D".to_string(),
+                staged: "S".to_string(),
+                submodule: "@".to_string(),
+                up_to_date: "=".to_string(),
+                ahead: "↑{n}".to_string(),
+                behind: "↓{n}".to_string(),
+                diverged: "±{ahead}↑{behind}↓".to_string(),
+                no_upstream: "~".to_string(),
+                no_branch: "-".to_string(),
+                remote_error: "!{e}".to_string(),
+            },
+            ThemeName::Ascii => Self {
+                error: "ERROR".to_string(),
+                untracked: "?".to_string(),
+                modified: "M".to_string(),
+                added: "A".to_string(),
+                deleted: "D".to_string(),
+                staged: "S".to_string(),
+                submodule: "@".to_string(),
+                up_to_date: "=".to_string(),
+                ahead: "+{n}".to_string(),
+                behind: "-{n}".to_string(),
+                diverged: "+{ahead}-{behind}".to_string(),
+                no_upstream: "~".to_string(),
+                no_branch: "x".to_string(),
+                remote_error: "!{e}".to_string(),
+            },
+        }
+    }
+
+    /// The configured theme: the `theme:` block's base with its overrides
+    /// applied, or the emoji theme when there is no block.
+    pub fn from_config(config: Option<&ThemeConfig>) -> Self {
+        let Some(config) = config else {
+            return Self::default();
+        };
+        let mut theme = Self::from_name(config.name.unwrap_or_default());
+        let overrides = [
+            (&mut theme.error, &config.error),
+            (&mut theme.untracked, &config.untracked),
+            (&mut theme.modified, &config.modified),
+            (&mut theme.added, &config.added),
+            (&mut theme.deleted, &config.deleted),
+            (&mut theme.staged, &config.staged),
+            (&mut theme.submodule, &config.submodule),
+            (&mut theme.up_to_date, &config.up_to_date),
+            (&mut theme.ahead, &config.ahead),
+            (&mut theme.behind, &config.behind),
+            (&mut theme.diverged, &config.diverged),
+            (&mut theme.no_upstream, &config.no_upstream),
+            (&mut theme.no_branch, &config.no_branch),
+            (&mut theme.remote_error, &config.remote_error),
+        ];
+        for (slot, value) in overrides {
+            if let Some(value) = value {
+                *slot = value.clone();
+            }
+        }
+        theme
+    }
+
+    /// Every marker this theme can draw, at its widest plausible counts, for
+    /// sizing the status column.
+    fn widest_markers(&self) -> Vec<String> {
+        vec![
+            self.error.clone(),
+            self.untracked.clone(),
+            self.modified.clone(),
+            self.added.clone(),
+            self.deleted.clone(),
+            self.staged.clone(),
+            self.submodule.clone(),
+            self.up_to_date.clone(),
+            self.no_upstream.clone(),
+            self.no_branch.clone(),
+            self.remote_error.replace("{e}", "abc"),
+            self.ahead.replace("{n}", "9999"),
+            self.behind.replace("{n}", "9999"),
+            self.diverged
+                .replace("{ahead}", "999")
+                .replace("{behind}", "999"),
+        ]
+    }
+}
+
 /// Unified display trait for consistent formatting across different result types
 pub trait UnifiedDisplay {
     fn get_branch(&self) -> Option<&str>;
@@ -105,6 +413,78 @@ pub trait UnifiedDisplay {
     fn layout_view(&self) -> Option<LayoutView<'_>> {
         None
     }
+
+    /// Lines added/removed, shown after the repo in detailed mode. Only
+    /// `RepoStatus` has one, and only under `gx status --diff-stat`.
+    fn get_diff_stat(&self) -> Option<(u32, u32)> {
+        None
+    }
+
+    /// Untracked `(files, dirs)`, shown after the repo in detailed mode when
+    /// anything is untracked. Only `RepoStatus` has them.
+    fn get_untracked(&self) -> Option<(u32, u32)> {
+        None
+    }
+
+    /// Moved submodule pointers, shown after the repo in detailed mode. Only
+    /// `RepoStatus` has them, and only under `--include-submodules-in-status`.
+    fn get_submodule_changes(&self) -> Option<u32> {
+        None
+    }
+
+    /// HEAD's commit subject, shown last in detailed mode. Only `RepoStatus`
+    /// has one.
+    fn get_commit_subject(&self) -> Option<&str> {
+        None
+    }
+}
+
+/// The remote-status marker a clean repo shows (`🟢`, `↑3`, `🔀 1↑2↓`, ...);
+/// also used per branch by `status --ahead-behind-all`.
+fn remote_status_marker(status: &RemoteStatus, theme: &Theme) -> String {
+    match status {
+        RemoteStatus::UpToDate => theme.up_to_date.clone(),
+        RemoteStatus::Ahead(n) => theme.ahead.replace("{n}", &n.to_string()),
+        RemoteStatus::Behind(n) => theme.behind.replace("{n}", &n.to_string()),
+        RemoteStatus::Diverged(ahead, behind) => theme
+            .diverged
+            .replace("{ahead}", &ahead.to_string())
+            .replace("{behind}", &behind.to_string()),
+        RemoteStatus::NoRemote | RemoteStatus::NoUpstream | RemoteStatus::DetachedHead => {
+            theme.no_upstream.clone()
+        }
+        RemoteStatus::NoBranch => theme.no_branch.clone(),
+        RemoteStatus::Error(e) => theme
+            .remote_error
+            .replace("{e}", &e.chars().take(3).collect::<String>()),
+    }
+}
+
+/// A repo's status marker: its error, its most telling local change, or -
+/// when clean - its remote status.
+fn repo_status_marker(status: &RepoStatus, theme: &Theme) -> String {
+    if status.error.is_some() {
+        theme.error.clone()
+    } else if !status.is_clean {
+        let changes = &status.changes;
+        if changes.has_untracked() {
+            theme.untracked.clone()
+        } else if changes.modified > 0 {
+            theme.modified.clone()
+        } else if status.submodule_changes > 0 {
+            theme.submodule.clone()
+        } else if changes.added > 0 {
+            theme.added.clone()
+        } else if changes.deleted > 0 {
+            theme.deleted.clone()
+        } else if changes.staged > 0 {
+            theme.staged.clone()
+        } else {
+            theme.modified.clone()
+        }
+    } else {
+        remote_status_marker(&status.remote_status, theme)
+    }
 }
 
 /// Implementation of UnifiedDisplay for RepoStatus
@@ -121,99 +501,26 @@ impl UnifiedDisplay for RepoStatus {
         &self.repo
     }
 
+    fn get_diff_stat(&self) -> Option<(u32, u32)> {
+        self.diff_stat
+    }
+
+    fn get_untracked(&self) -> Option<(u32, u32)> {
+        self.changes
+            .has_untracked()
+            .then_some((self.changes.untracked, self.changes.untracked_dirs))
+    }
+
+    fn get_submodule_changes(&self) -> Option<u32> {
+        (self.submodule_changes > 0).then_some(self.submodule_changes)
+    }
+
+    fn get_commit_subject(&self) -> Option<&str> {
+        self.commit_subject.as_deref()
+    }
+
     fn get_emoji(&self, opts: &StatusOptions) -> String {
-        if self.error.is_some() {
-            if opts.use_emoji {
-                "❌".to_string()
-            } else {
-                "ERROR".to_string()
-            }
-        } else if !self.is_clean {
-            // File change status logic
-            if self.changes.untracked > 0 {
-                if opts.use_emoji {
-                    "❓".to_string()
-                } else {
-                    "?".to_string()
-                }
-            } else if self.changes.modified > 0 {
-                if opts.use_emoji {
-                    "📝".to_string()
-                } else {
-                    "M".to_string()
-                }
-            } else if self.changes.added > 0 {
-                if opts.use_emoji {
-                    "➕".to_string()
-                } else {
-                    "A".to_string()
-                }
-            } else if self.changes.deleted > 0 {
-                if opts.use_emoji {
-                    "❌".to_string()
-                } else {
-                    "D".to_string()
-                }
-            } else if self.changes.staged > 0 {
-                if opts.use_emoji {
-                    "🎯".to_string()
-                } else {
-                    "S".to_string()
-                }
-            } else if opts.use_emoji {
-                "📝".to_string()
-            } else {
-                "M".to_string()
-            }
-        } else {
-            // Remote status logic for clean repos
-            match &self.remote_status {
-                RemoteStatus::UpToDate => {
-                    if opts.use_emoji {
-                        "🟢".to_string()
-                    } else {
-                        "=".to_string()
-                    }
-                }
-                RemoteStatus::Ahead(n) => format!("↑{n}"),
-                RemoteStatus::Behind(n) => format!("↓{n}"),
-                RemoteStatus::Diverged(ahead, behind) => {
-                    if opts.use_emoji {
-                        format!("🔀 {ahead}↑{behind}↓")
-                    } else {
-                        format!("±{ahead}↑{behind}↓")
-                    }
-                }
-                RemoteStatus::NoRemote => {
-                    if opts.use_emoji {
-                        "📍".to_string()
-                    } else {
-                        "~".to_string()
-                    }
-                }
-                RemoteStatus::NoUpstream => {
-                    if opts.use_emoji {
-                        "📍".to_string()
-                    } else {
-                        "~".to_string()
-                    }
-                }
-                RemoteStatus::DetachedHead => {
-                    if opts.use_emoji {
-                        "📍".to_string()
-                    } else {
-                        "~".to_string()
-                    }
-                }
-                RemoteStatus::Error(e) => {
-                    if opts.use_emoji {
-                        format!("🚨 {}", e.chars().take(3).collect::<String>())
-                    } else {
-                        format!("!{}", e.chars().take(3).collect::<String>())
-                    }
-                }
-            }
-        }
+        repo_status_marker(self, &opts.theme())
     }
 
     fn get_error(&self) -> Option<&str> {
@@ -229,6 +536,24 @@ impl UnifiedDisplay for RepoStatus {
     }
 }
 
+/// The `checkout --sync-only` marker: commits fast-forwarded, local commits
+/// still ahead, or in sync.
+fn synced_emoji(pulled: u32, ahead: u32, opts: &StatusOptions) -> String {
+    if pulled > 0 {
+        if opts.use_emoji {
+            format!("⏩{pulled}")
+        } else {
+            format!("FF{pulled}")
+        }
+    } else if ahead > 0 {
+        format!("↑{ahead}")
+    } else if opts.use_emoji {
+        "🟢".to_string()
+    } else {
+        "=".to_string()
+    }
+}
+
 /// Implementation of UnifiedDisplay for CheckoutResult
 impl UnifiedDisplay for CheckoutResult {
     fn get_branch(&self) -> Option<&str> {
@@ -259,6 +584,13 @@ impl UnifiedDisplay for CheckoutResult {
                         "OK".to_string()
                     }
                 }
+                CheckoutAction::Switched => {
+                    if opts.use_emoji {
+                        "🔁".to_string()
+                    } else {
+                        "SWITCH".to_string()
+                    }
+                }
                 CheckoutAction::CreatedFromRemote => {
                     if opts.use_emoji {
                         "✨".to_string()
@@ -266,7 +598,7 @@ impl UnifiedDisplay for CheckoutResult {
                         "NEW".to_string()
                     }
                 }
-                CheckoutAction::Stashed => {
+                CheckoutAction::Stashed { .. } => {
                     if opts.use_emoji {
                         "📦".to_string()
                     } else {
@@ -280,6 +612,28 @@ impl UnifiedDisplay for CheckoutResult {
                         "WARN".to_string()
                     }
                 }
+                CheckoutAction::AddedWorktree => {
+                    if opts.use_emoji {
+                        "🌳".to_string()
+                    } else {
+                        "TREE".to_string()
+                    }
+                }
+                CheckoutAction::Synced { pulled, ahead } => synced_emoji(pulled, ahead, opts),
+                CheckoutAction::Detached => {
+                    if opts.use_emoji {
+                        "📌".to_string()
+                    } else {
+                        "DETACH".to_string()
+                    }
+                }
+                CheckoutAction::Skipped { .. } => {
+                    if opts.use_emoji {
+                        "⏭️".to_string()
+                    } else {
+                        "SKIP".to_string()
+                    }
+                }
             }
         }
     }
@@ -304,98 +658,7 @@ impl UnifiedDisplay for &RepoStatus {
     }
 
     fn get_emoji(&self, opts: &StatusOptions) -> String {
-        if self.error.is_some() {
-            if opts.use_emoji {
-                "❌".to_string()
-            } else {
-                "ERROR".to_string()
-            }
-        } else if !self.is_clean {
-            // File change status logic
-            if self.changes.untracked > 0 {
-                if opts.use_emoji {
-                    "❓".to_string()
-                } else {
-                    "?".to_string()
-                }
-            } else if self.changes.modified > 0 {
-                if opts.use_emoji {
-                    "📝".to_string()
-                } else {
-                    "M".to_string()
-                }
-            } else if self.changes.added > 0 {
-                if opts.use_emoji {
-                    "➕".to_string()
-                } else {
-                    "A".to_string()
-                }
-            } else if self.changes.deleted > 0 {
-                if opts.use_emoji {
-                    "❌".to_string()
-                } else {
-                    "D".to_string()
-                }
-            } else if self.changes.staged > 0 {
-                if opts.use_emoji {
-                    "🎯".to_string()
-                } else {
-                    "S".to_string()
-                }
-            } else if opts.use_emoji {
-                "📝".to_string()
-            } else {
-                "M".to_string()
-            }
-        } else {
-            // Remote status logic for clean repos
-            match &self.remote_status {
-                RemoteStatus::UpToDate => {
-                    if opts.use_emoji {
-                        "🟢".to_string()
-                    } else {
-                        "=".to_string()
-                    }
-                }
-                RemoteStatus::Ahead(n) => format!("↑{n}"),
-                RemoteStatus::Behind(n) => format!("↓{n}"),
-                RemoteStatus::Diverged(ahead, behind) => {
-                    if opts.use_emoji {
-                        format!("🔀 {ahead}↑{behind}↓")
-                    } else {
-                        format!("±{ahead}↑{behind}↓")
-                    }
-                }
-                RemoteStatus::NoRemote => {
-                    if opts.use_emoji {
-                        "📍".to_string()
-                    } else {
-                        "~".to_string()
-                    }
-                }
-                RemoteStatus::NoUpstream => {
-                    if opts.use_emoji {
-                        "📍".to_string()
-                    } else {
-                        "~".to_string()
-                    }
-                }
-                RemoteStatus::DetachedHead => {
-                    if opts.use_emoji {
-                        "📍".to_string()
-                    } else {
-                        "~".to_string()
-                    }
-                }
-                RemoteStatus::Error(e) => {
-                    if opts.use_emoji {
-                        format!("🚨 {}", e.chars().take(3).collect::<String>())
-                    } else {
-                        format!("!{}", e.chars().take(3).collect::<String>())
-                    }
-                }
-            }
-        }
+        repo_status_marker(self, &opts.theme())
     }
 
     fn get_error(&self) -> Option<&str> {
@@ -433,6 +696,13 @@ impl UnifiedDisplay for &CheckoutResult {
                         "OK".to_string()
                     }
                 }
+                CheckoutAction::Switched => {
+                    if opts.use_emoji {
+                        "🔁".to_string()
+                    } else {
+                        "SWITCH".to_string()
+                    }
+                }
                 CheckoutAction::CreatedFromRemote => {
                     if opts.use_emoji {
                         "✨".to_string()
@@ -440,7 +710,7 @@ impl UnifiedDisplay for &CheckoutResult {
                         "NEW".to_string()
                     }
                 }
-                CheckoutAction::Stashed => {
+                CheckoutAction::Stashed { .. } => {
                     if opts.use_emoji {
                         "📦".to_string()
                     } else {
@@ -454,6 +724,28 @@ impl UnifiedDisplay for &CheckoutResult {
                         "WARN".to_string()
                     }
                 }
+                CheckoutAction::AddedWorktree => {
+                    if opts.use_emoji {
+                        "🌳".to_string()
+                    } else {
+                        "TREE".to_string()
+                    }
+                }
+                CheckoutAction::Synced { pulled, ahead } => synced_emoji(pulled, ahead, opts),
+                CheckoutAction::Detached => {
+                    if opts.use_emoji {
+                        "📌".to_string()
+                    } else {
+                        "DETACH".to_string()
+                    }
+                }
+                CheckoutAction::Skipped { .. } => {
+                    if opts.use_emoji {
+                        "⏭️".to_string()
+                    } else {
+                        "SKIP".to_string()
+                    }
+                }
             }
         }
     }
@@ -466,7 +758,7 @@ impl UnifiedDisplay for &CheckoutResult {
 /// Implementation of UnifiedDisplay for CreateResult
 impl UnifiedDisplay for CreateResult {
     fn get_branch(&self) -> Option<&str> {
-        Some(&self.change_id)
+        Some(self.branch.as_deref().unwrap_or(&self.change_id))
     }
 
     fn get_commit_sha(&self) -> Option<&str> {
@@ -522,6 +814,20 @@ impl UnifiedDisplay for CreateResult {
                         "PR".to_string()
                     }
                 }
+                CreateAction::Reverted => {
+                    if opts.use_emoji {
+                        "⏪".to_string()
+                    } else {
+                        "REVERT".to_string()
+                    }
+                }
+                CreateAction::Skipped => {
+                    if opts.use_emoji {
+                        "⏭️".to_string()
+                    } else {
+                        "SKIP".to_string()
+                    }
+                }
             }
         }
     }
@@ -534,7 +840,7 @@ impl UnifiedDisplay for CreateResult {
 /// Implementation of UnifiedDisplay for &CreateResult
 impl UnifiedDisplay for &CreateResult {
     fn get_branch(&self) -> Option<&str> {
-        Some(&self.change_id)
+        Some(self.branch.as_deref().unwrap_or(&self.change_id))
     }
 
     fn get_commit_sha(&self) -> Option<&str> {
@@ -590,6 +896,20 @@ impl UnifiedDisplay for &CreateResult {
                         "PR".to_string()
                     }
                 }
+                CreateAction::Reverted => {
+                    if opts.use_emoji {
+                        "⏪".to_string()
+                    } else {
+                        "REVERT".to_string()
+                    }
+                }
+                CreateAction::Skipped => {
+                    if opts.use_emoji {
+                        "⏭️".to_string()
+                    } else {
+                        "SKIP".to_string()
+                    }
+                }
             }
         }
     }
@@ -651,6 +971,13 @@ impl UnifiedDisplay for ReviewResult {
                         "DELETE".to_string()
                     }
                 }
+                ReviewAction::Reopened => {
+                    if opts.use_emoji {
+                        "🔓".to_string()
+                    } else {
+                        "REOPEN".to_string()
+                    }
+                }
                 ReviewAction::Purged => {
                     if opts.use_emoji {
                         "🧹".to_string()
@@ -718,6 +1045,13 @@ impl UnifiedDisplay for &ReviewResult {
                         "DELETE".to_string()
                     }
                 }
+                ReviewAction::Reopened => {
+                    if opts.use_emoji {
+                        "🔓".to_string()
+                    } else {
+                        "REOPEN".to_string()
+                    }
+                }
                 ReviewAction::Purged => {
                     if opts.use_emoji {
                         "🧹".to_string()
@@ -777,14 +1111,26 @@ impl AlignmentWidths {
     }
 }
 
-/// Format repository path with separate colors for path and repo slug
-fn format_repo_path_with_colors(_repo_path: &Path, repo_slug: &str, use_colors: bool) -> String {
-    // Always use the repo slug for consistent display
-    let display_path = repo_slug.to_string();
+/// Format repository path with separate colors for path and repo slug; in a
+/// path mode the repo's directory name takes the slug's color
+fn format_repo_path_with_colors(
+    repo_path: &Path,
+    repo_slug: &str,
+    path_display: &RepoPathDisplay,
+    use_colors: bool,
+) -> String {
+    let display_path = path_display.name(repo_path, repo_slug);
 
     if use_colors {
-        // Find where the repo slug appears in the display path
-        if let Some(slug_start) = display_path.rfind(repo_slug) {
+        let highlight = match path_display {
+            RepoPathDisplay::Slug => repo_slug,
+            _ => repo_path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or(repo_slug),
+        };
+        // Find where the highlighted part appears in the display path
+        if let Some(slug_start) = display_path.rfind(highlight) {
             let path_prefix = &display_path[..slug_start];
             let slug_portion = &display_path[slug_start..];
 
@@ -912,23 +1258,154 @@ fn render_unified_line<T: UnifiedDisplay>(
 
     let repo = item.get_repo();
 
-    match item.layout_view() {
+    let line = match item.layout_view() {
         None => {
             let branch_display = if opts.use_colors {
                 format!("{:>width$}", branch.magenta(), width = widths.branch_width)
             } else {
                 format!("{:>width$}", branch, width = widths.branch_width)
             };
-            let repo_display =
-                format_repo_path_with_colors(&repo.path, &repo.slug, opts.use_colors);
+            let repo_display = format_repo_path_with_colors(
+                &repo.path,
+                &repo.slug,
+                &opts.path_display,
+                opts.use_colors,
+            );
             format!("{branch_display} {sha_display} {emoji_display} {repo_display}")
         }
         Some(view) => {
             let branch_display =
                 format_layout_branch(branch, &view, opts.use_colors, widths.branch_width);
-            let repo_display = format_layout_identity(&repo.slug, &view, opts.use_colors);
+            let repo_name = match (&opts.path_display, &view) {
+                (RepoPathDisplay::Slug, _) => repo.slug.clone(),
+                (mode, LayoutView::Flat) => mode.name(&repo.path, &repo.slug),
+                // A worktree's leaf is glued on after the glyph, so name the
+                // directory holding it.
+                (mode, _) => mode.name(repo.path.parent().unwrap_or(&repo.path), &repo.slug),
+            };
+            let repo_display = format_layout_identity(&repo_name, &view, opts.use_colors);
             format!("{branch_display} {sha_display} {emoji_display} {repo_display}")
         }
+    };
+
+    if !matches!(
+        opts.verbosity,
+        OutputVerbosity::Detailed | OutputVerbosity::Full
+    ) {
+        return line;
+    }
+    let mut line = line;
+    if let Some(stat) = item.get_diff_stat() {
+        line = format!("{line} {}", format_diff_stat(stat, opts.use_colors));
+    }
+    if let Some(untracked) = item.get_untracked() {
+        line = format!("{line} ({})", format_untracked(untracked));
+    }
+    if let Some(moved) = item.get_submodule_changes() {
+        let noun = if moved == 1 {
+            "submodule"
+        } else {
+            "submodules"
+        };
+        line = format!("{line} ({moved} {noun} moved)");
+    }
+    if let Some(subject) = item.get_commit_subject() {
+        let room = terminal_columns().saturating_sub(visible_width(&line) + 1);
+        if let Some(subject) = truncate_to_width(subject, room) {
+            line = if opts.use_colors {
+                format!("{line} {}", subject.bright_black())
+            } else {
+                format!("{line} {subject}")
+            };
+        }
+    }
+    line
+}
+
+/// Columns assumed when neither the terminal nor `$COLUMNS` says.
+const DEFAULT_COLUMNS: usize = 120;
+
+/// The terminal width: stdout's TTY size, else `$COLUMNS` (shells rarely
+/// export it, but it covers piped output), else [`DEFAULT_COLUMNS`].
+fn terminal_columns() -> usize {
+    terminal_size::terminal_size()
+        .map(|(terminal_size::Width(width), _)| usize::from(width))
+        .filter(|&c| c > 0)
+        .or_else(|| {
+            std::env::var("COLUMNS")
+                .ok()
+                .and_then(|c| c.parse().ok())
+                .filter(|&c| c > 0)
+        })
+        .unwrap_or(DEFAULT_COLUMNS)
+}
+
+/// Display width of `s` with its ANSI color sequences left out.
+fn visible_width(s: &str) -> usize {
+    let mut plain = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // Skip `ESC [ params letter`.
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            plain.push(c);
+        }
+    }
+    calculate_display_width(&plain)
+}
+
+/// `s` cut to at most `max` display columns, ending in `…` when cut; `None`
+/// when there is no room at all. Widths come from [`calculate_display_width`],
+/// so an emoji never straddles the limit.
+fn truncate_to_width(s: &str, max: usize) -> Option<String> {
+    if max == 0 {
+        return None;
+    }
+    if calculate_display_width(s) <= max {
+        return Some(s.to_string());
+    }
+    let mut cut = String::new();
+    let mut width = 0;
+    for c in s.chars() {
+        let w = calculate_display_width(c.encode_utf8(&mut [0; 4]));
+        if width + w > max - 1 {
+            break;
+        }
+        cut.push(c);
+        width += w;
+    }
+    cut.push('…');
+    Some(cut)
+}
+
+/// `3 files, 1 dir untracked`, leaving out a zero count.
+fn format_untracked((files, dirs): (u32, u32)) -> String {
+    let plural = |n: u32, one: &str, many: &str| format!("{n} {}", if n == 1 { one } else { many });
+    let parts: Vec<String> = [
+        (files > 0).then(|| plural(files, "file", "files")),
+        (dirs > 0).then(|| plural(dirs, "dir", "dirs")),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    format!("{} untracked", parts.join(", "))
+}
+
+/// `+added/-removed`, green/red when colored.
+fn format_diff_stat((added, removed): (u32, u32), use_colors: bool) -> String {
+    if use_colors {
+        format!(
+            "{}/{}",
+            format!("+{added}").green(),
+            format!("-{removed}").red()
+        )
+    } else {
+        format!("+{added}/-{removed}")
     }
 }
 
@@ -938,6 +1415,9 @@ pub fn display_unified_format<T: UnifiedDisplay>(
     opts: &StatusOptions,
     widths: &AlignmentWidths,
 ) {
+    if opts.quiet {
+        return;
+    }
     println!("{}", render_unified_line(item, opts, widths));
 
     // Handle error display
@@ -987,7 +1467,8 @@ fn render_review_line(
     // Repository path/slug
     let repo = &result.repo;
     let repo_slug = &repo.slug;
-    let repo_display = format_repo_path_with_colors(&repo.path, repo_slug, opts.use_colors);
+    let repo_display =
+        format_repo_path_with_colors(&repo.path, repo_slug, &opts.path_display, opts.use_colors);
 
     // Final format: <change_id> <PR#> <emoji> <repo>
     let mut out = format!("{branch_display} {pr_display} {emoji_display} {repo_display}");
@@ -1011,6 +1492,9 @@ pub fn display_review_result(
     opts: &StatusOptions,
     widths: &AlignmentWidths,
 ) {
+    if opts.quiet {
+        return;
+    }
     println!("{}", render_review_line(result, opts, widths));
 }
 
@@ -1044,6 +1528,21 @@ pub fn display_review_results(results: &[ReviewResult], opts: &StatusOptions) {
     }
 }
 
+/// Note each bare repo (a mirror) a work-tree command is skipping, so it
+/// neither errors nor silently disappears. Silent under `--quiet`.
+pub fn display_bare_skips(bare: &[Repo], opts: &StatusOptions) {
+    if opts.quiet {
+        return;
+    }
+    for repo in bare {
+        if opts.use_emoji {
+            println!("⏭️  {} (skipped: bare repository)", repo.slug);
+        } else {
+            println!("{} (skipped: bare repository)", repo.slug);
+        }
+    }
+}
+
 /// Display unified summary matching status format (clean/dirty/errors)
 pub fn display_unified_summary(
     clean_count: usize,
@@ -1061,26 +1560,198 @@ pub fn display_unified_summary(
         return;
     }
 
-    let summary = if opts.use_emoji {
-        format!("\n📊 {clean_count} clean, {dirty_count} dirty, {error_count} errors")
-    } else {
-        format!("\nSummary: {clean_count} clean, {dirty_count} dirty, {error_count} errors")
-    };
+    println!(
+        "\n{}",
+        summary_line(clean_count, dirty_count, error_count, opts)
+    );
+}
 
+/// The `📊 N clean, N dirty, N errors` line of [`display_unified_summary`],
+/// on its own (`gx status --count-only` prints just this).
+pub fn summary_line(
+    clean_count: usize,
+    dirty_count: usize,
+    error_count: usize,
+    opts: &StatusOptions,
+) -> String {
     if opts.use_colors {
-        println!(
-            "\n📊 {} clean, {} dirty, {} errors",
+        format!(
+            "📊 {} clean, {} dirty, {} errors",
             clean_count.to_string().green(),
             dirty_count.to_string().yellow(),
             error_count.to_string().red()
-        );
+        )
+    } else if opts.use_emoji {
+        format!("📊 {clean_count} clean, {dirty_count} dirty, {error_count} errors")
+    } else {
+        format!("Summary: {clean_count} clean, {dirty_count} dirty, {error_count} errors")
+    }
+}
+
+/// The bucket a status result is listed under by `gx status --group`. Each
+/// repo lands in exactly one: an error outranks a remote state, which
+/// outranks local changes. Declaration order is display order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum StatusGroup {
+    Errors,
+    Diverged,
+    Behind,
+    Ahead,
+    Dirty,
+    /// No upstream, remote, or local branch to compare against (or the
+    /// remote check itself failed).
+    Untracked,
+    Clean,
+}
+
+impl StatusGroup {
+    /// Classify one status result.
+    pub fn of(result: &RepoStatus) -> Self {
+        if result.error.is_some() {
+            return StatusGroup::Errors;
+        }
+        match &result.remote_status {
+            RemoteStatus::Diverged(..) => StatusGroup::Diverged,
+            RemoteStatus::Behind(_) => StatusGroup::Behind,
+            RemoteStatus::Ahead(_) => StatusGroup::Ahead,
+            _ if !result.is_clean => StatusGroup::Dirty,
+            RemoteStatus::UpToDate => StatusGroup::Clean,
+            RemoteStatus::NoRemote
+            | RemoteStatus::NoUpstream
+            | RemoteStatus::DetachedHead
+            | RemoteStatus::NoBranch
+            | RemoteStatus::Error(_) => StatusGroup::Untracked,
+        }
+    }
+
+    fn header(self, use_emoji: bool) -> &'static str {
+        match (self, use_emoji) {
+            (StatusGroup::Errors, true) => "🚨 Errors:",
+            (StatusGroup::Errors, false) => "Errors:",
+            (StatusGroup::Diverged, true) => "🔀 Diverged:",
+            (StatusGroup::Diverged, false) => "Diverged:",
+            (StatusGroup::Behind, true) => "↓ Behind:",
+            (StatusGroup::Behind, false) => "Behind:",
+            (StatusGroup::Ahead, true) => "↑ Ahead:",
+            (StatusGroup::Ahead, false) => "Ahead:",
+            (StatusGroup::Dirty, true) => "📝 Dirty:",
+            (StatusGroup::Dirty, false) => "Dirty:",
+            (StatusGroup::Untracked, true) => "📍 No upstream:",
+            (StatusGroup::Untracked, false) => "No upstream:",
+            (StatusGroup::Clean, true) => "🟢 Clean:",
+            (StatusGroup::Clean, false) => "Clean:",
+        }
+    }
+
+    fn colorize(self, header: &str) -> ColoredString {
+        match self {
+            StatusGroup::Errors => header.red().bold(),
+            StatusGroup::Diverged => header.magenta().bold(),
+            StatusGroup::Behind | StatusGroup::Dirty => header.yellow().bold(),
+            StatusGroup::Ahead => header.cyan().bold(),
+            StatusGroup::Untracked => header.bold(),
+            StatusGroup::Clean => header.green().bold(),
+        }
+    }
+}
+
+/// The non-empty [`StatusGroup`] buckets for `results`, in display order,
+/// each with its repo slugs sorted.
+pub fn group_status_results(results: &[RepoStatus]) -> Vec<(StatusGroup, Vec<&str>)> {
+    let mut buckets: std::collections::BTreeMap<StatusGroup, Vec<&str>> =
+        std::collections::BTreeMap::new();
+    for result in results {
+        buckets
+            .entry(StatusGroup::of(result))
+            .or_default()
+            .push(result.repo.slug.as_str());
+    }
+    buckets
+        .into_iter()
+        .map(|(group, mut slugs)| {
+            slugs.sort_unstable();
+            (group, slugs)
+        })
+        .collect()
+}
+
+/// Print `results` grouped by [`StatusGroup`] (`gx status --group`): one
+/// header per non-empty bucket with its repo slugs beneath. Printed ahead of
+/// the one-line [`display_unified_summary`], which still follows.
+pub fn display_grouped_summary(results: &[RepoStatus], opts: &StatusOptions) {
+    for (group, slugs) in group_status_results(results) {
+        let header = group.header(opts.use_emoji);
+        if opts.use_colors {
+            println!("\n{}", group.colorize(header));
+        } else {
+            println!("\n{header}");
+        }
+        for slug in slugs {
+            println!("  {slug}");
+        }
+    }
+}
+
+/// One `gx status --porcelain` line, tab-separated:
+/// `<slug> <branch> <sha> <state> <modified> <added> <deleted> <untracked>
+/// <staged> <ahead> <behind>`. `state` is `clean`, `dirty` or `error`; an
+/// unknown branch or sha is `-`; `untracked` counts git's `??` entries (a
+/// wholly-untracked directory is one). Never colored, never emoji: this
+/// format is a stable interface, so columns are only ever appended.
+pub fn porcelain_line(result: &RepoStatus) -> String {
+    let state = if result.error.is_some() {
+        "error"
+    } else if result.is_clean {
+        "clean"
     } else {
-        println!("{summary}");
+        "dirty"
+    };
+    let (ahead, behind) = match result.remote_status {
+        RemoteStatus::Ahead(n) => (n, 0),
+        RemoteStatus::Behind(n) => (0, n),
+        RemoteStatus::Diverged(a, b) => (a, b),
+        _ => (0, 0),
+    };
+    let changes = &result.changes;
+    [
+        result.repo.slug.clone(),
+        result.branch.clone().unwrap_or_else(|| "-".to_string()),
+        result.commit_sha.clone().unwrap_or_else(|| "-".to_string()),
+        state.to_string(),
+        changes.modified.to_string(),
+        changes.added.to_string(),
+        changes.deleted.to_string(),
+        (changes.untracked + changes.untracked_dirs).to_string(),
+        changes.staged.to_string(),
+        ahead.to_string(),
+        behind.to_string(),
+    ]
+    .join("\t")
+}
+
+/// Print `results` as [`porcelain_line`]s (`gx status --porcelain`), sorted
+/// by slug so the output does not depend on which repo finished first.
+pub fn display_porcelain(results: &[RepoStatus]) {
+    let mut sorted: Vec<&RepoStatus> = results.iter().collect();
+    sorted.sort_by(|a, b| a.repo.slug.cmp(&b.repo.slug));
+    for result in sorted {
+        println!("{}", porcelain_line(result));
+    }
+}
+
+/// Exit with the error count (capped at 255) when any repo failed, so CI sees
+/// a partial failure. `fail_on_error` is false under `--no-fail-on-error`.
+pub fn exit_on_errors(error_count: usize, fail_on_error: bool) {
+    if error_count > 0 && fail_on_error {
+        std::process::exit(error_count.min(255) as i32);
     }
 }
 
 /// Display a single clone result immediately (for streaming output like slam)
-pub fn display_clone_result_immediate(result: &CloneResult) -> Result<()> {
+pub fn display_clone_result_immediate(result: &CloneResult, opts: &StatusOptions) -> Result<()> {
+    if opts.quiet {
+        return Ok(());
+    }
     match &result.error {
         Some(err) => {
             println!(
@@ -1096,6 +1767,9 @@ pub fn display_clone_result_immediate(result: &CloneResult) -> Result<()> {
                 CloneAction::Stashed => ("📥", "Updated (stashed)"),
                 CloneAction::DirectoryNotGitRepo => ("🏠", "Directory exists but not git"),
                 CloneAction::DifferentRemote => ("🔗", "Different remote URL"),
+                CloneAction::Mirrored => ("🪞", "Mirrored"),
+                CloneAction::MirrorUpdated => ("🪞", "Mirror updated"),
+                CloneAction::Skipped => ("⏭️", "Skipped (already cloned)"),
             };
             println!("{} {}", emoji, result.repo_slug.cyan().bold());
         }
@@ -1105,26 +1779,29 @@ pub fn display_clone_result_immediate(result: &CloneResult) -> Result<()> {
 }
 
 /// Display a single checkout result immediately (for streaming output like slam)
-pub fn display_checkout_result_immediate(result: &CheckoutResult) -> Result<()> {
-    let opts = StatusOptions::default(); // Use default options for immediate display
+pub fn display_checkout_result_immediate(
+    result: &CheckoutResult,
+    opts: &StatusOptions,
+) -> Result<()> {
     let widths = AlignmentWidths::calculate(std::slice::from_ref(result));
 
-    display_unified_format(result, &opts, &widths);
+    display_unified_format(result, opts, &widths);
+    if let (CheckoutAction::Stashed { stash_ref }, None, false) =
+        (&result.action, &result.error, opts.quiet)
+    {
+        println!("    auto-stash {stash_ref} left in place (git stash pop {stash_ref} to restore)");
+    }
+    if let (CheckoutAction::Skipped { reason }, false) = (&result.action, opts.quiet) {
+        println!("    {reason}");
+    }
     io::stdout().flush().context("Failed to flush stdout")?;
     Ok(())
 }
 
 /// Get current branch name quickly (no network calls, no status parsing)
 fn get_current_branch_name_fast(repo: &local::repo::Repo) -> String {
-    use std::process::Command;
-
     run_checked(
-        Command::new("git").args([
-            "-C",
-            &repo.path.to_string_lossy(),
-            "branch",
-            "--show-current",
-        ]),
+        local::git::git_command(&repo.path).args(["branch", "--show-current"]),
         subprocess_timeout(),
     )
     .map(|output| {
@@ -1138,7 +1815,10 @@ fn get_current_branch_name_fast(repo: &local::repo::Repo) -> String {
 }
 
 /// Calculate alignment widths quickly using fast git commands (no expensive operations)
-pub fn calculate_alignment_widths_fast(repos: &[local::repo::Repo]) -> AlignmentWidths {
+pub fn calculate_alignment_widths_fast(
+    repos: &[local::repo::Repo],
+    theme: &Theme,
+) -> AlignmentWidths {
     use rayon::prelude::*;
 
     // Branch width: Fast git command, no network calls
@@ -1154,7 +1834,7 @@ pub fn calculate_alignment_widths_fast(repos: &[local::repo::Repo]) -> Alignment
 
     // Emoji width: Calculate based on all possible emoji patterns that could appear
     // This is fast because we're not doing git operations, just measuring known emoji patterns
-    let emoji_width = calculate_max_possible_emoji_width();
+    let emoji_width = calculate_max_possible_emoji_width(theme);
 
     AlignmentWidths {
         branch_width,
@@ -1165,7 +1845,7 @@ pub fn calculate_alignment_widths_fast(repos: &[local::repo::Repo]) -> Alignment
 
 /// Calculate the maximum possible emoji width for all patterns that could appear in status output
 /// This is fast because it only measures a small set of known patterns, no git operations
-fn calculate_max_possible_emoji_width() -> usize {
+fn calculate_max_possible_emoji_width(theme: &Theme) -> usize {
     // All possible emoji patterns that could appear in gx status output
     let possible_patterns = vec![
         // Simple status emojis (2 width each)
@@ -1209,15 +1889,38 @@ fn calculate_max_possible_emoji_width() -> usize {
         "🧹",
     ];
 
-    // Find the maximum width among all possible patterns
+    // Find the maximum width among all possible patterns, the theme's included
+    let themed = theme.widest_markers();
     possible_patterns
         .iter()
-        .map(|pattern| calculate_display_width(pattern))
+        .copied()
+        .chain(themed.iter().map(String::as_str))
+        .map(calculate_display_width)
         .max()
         .unwrap_or(2) // Fallback to minimum
         .max(2) // Ensure at least 2 for readability
 }
 
+/// The `status --ahead-behind-all` listing under a repo: one indented line per
+/// local branch, `<branch> <marker> <upstream>`, with branch names padded to
+/// a common width. A branch without an upstream says so.
+pub fn render_branch_sync(syncs: &[local::git::BranchSync], opts: &StatusOptions) -> String {
+    let width = syncs
+        .iter()
+        .map(|s| calculate_display_width(&s.branch))
+        .max()
+        .unwrap_or(0);
+    syncs
+        .iter()
+        .map(|s| {
+            let marker = remote_status_marker(&s.remote_status, &opts.theme());
+            let upstream = s.upstream.as_deref().unwrap_or("(no upstream)");
+            format!("    {} {marker} {upstream}", pad_to_width(&s.branch, width))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 /// Display a single status result immediately with pre-calculated alignment
 pub fn display_status_result_immediate(
     result: &local::git::RepoStatus,
@@ -1235,6 +1938,11 @@ pub fn display_status_result_immediate(
     if should_display {
         // Use existing unified formatting with fixed widths
         display_unified_format(result, opts, widths);
+        if let Some(syncs) = result.branch_sync.as_deref().filter(|_| !opts.quiet) {
+            if !syncs.is_empty() {
+                println!("{}", render_branch_sync(syncs, opts));
+            }
+        }
 
         // Ensure immediate visibility
         io::stdout().flush().context("Failed to flush stdout")?;
@@ -1297,6 +2005,10 @@ mod tests {
             changes: StatusChanges::default(),
             remote_status: RemoteStatus::UpToDate,
             error: None,
+            diff_stat: None,
+            branch_sync: None,
+            submodule_changes: 0,
+            commit_subject: None,
         }
     }
 
@@ -1314,9 +2026,90 @@ mod tests {
             changes: StatusChanges::default(),
             remote_status: RemoteStatus::UpToDate,
             error: None,
+            diff_stat: None,
+            branch_sync: None,
+            submodule_changes: 0,
+            commit_subject: None,
         }
     }
 
+    #[test]
+    fn test_custom_theme_changes_status_marker() {
+        let mut status = flat_repo_status("main");
+        let config: local::config::ThemeConfig =
+            serde_yaml::from_str("name: ascii\nup-to-date: ok\n").unwrap();
+        let opts = StatusOptions {
+            theme: Theme::from_config(Some(&config)),
+            ..Default::default()
+        };
+
+        assert_eq!(StatusOptions::default().theme.up_to_date, "🟢");
+        assert_eq!(status.get_emoji(&opts), "ok");
+        status.remote_status = RemoteStatus::Diverged(2, 3);
+        assert_eq!(status.get_emoji(&opts), "+2-3");
+        status.remote_status = RemoteStatus::Behind(4);
+        assert_eq!(status.get_emoji(&opts), "-4");
+
+        // --no-emoji still draws the plain theme, whatever is configured.
+        let plain = StatusOptions {
+            use_emoji: false,
+            ..opts
+        };
+        assert_eq!(status.get_emoji(&plain), "↓4");
+
+        // The status column widens to fit a long custom marker.
+        let wide = Theme {
+            up_to_date: "in-sync-with-remote".to_string(),
+            ..Theme::default()
+        };
+        assert_eq!(calculate_max_possible_emoji_width(&wide), 19);
+    }
+
+    #[test]
+    fn test_group_status_results_buckets_slugs() {
+        let status = |slug: &str, remote_status: RemoteStatus, is_clean: bool| RepoStatus {
+            repo: Repo::from_slug(slug.to_string()),
+            is_clean,
+            remote_status,
+            ..flat_repo_status("main")
+        };
+        let mut errored = status("org/broken", RemoteStatus::Behind(2), true);
+        errored.error = Some("git status failed".to_string());
+        let results = vec![
+            status("org/lagging-b", RemoteStatus::Behind(3), true),
+            status("org/forked", RemoteStatus::Diverged(1, 2), true),
+            errored,
+            status("org/lagging-a", RemoteStatus::Behind(1), false),
+            status("org/wip", RemoteStatus::UpToDate, false),
+            status("org/pushing", RemoteStatus::Ahead(4), true),
+            status("org/local-only", RemoteStatus::NoUpstream, true),
+            status("org/other-branch", RemoteStatus::NoBranch, true),
+            status("org/tidy", RemoteStatus::UpToDate, true),
+        ];
+
+        let groups = group_status_results(&results);
+
+        assert_eq!(
+            groups,
+            vec![
+                (StatusGroup::Errors, vec!["org/broken"]),
+                (StatusGroup::Diverged, vec!["org/forked"]),
+                (StatusGroup::Behind, vec!["org/lagging-a", "org/lagging-b"]),
+                (StatusGroup::Ahead, vec!["org/pushing"]),
+                (StatusGroup::Dirty, vec!["org/wip"]),
+                (
+                    StatusGroup::Untracked,
+                    vec!["org/local-only", "org/other-branch"]
+                ),
+                (StatusGroup::Clean, vec!["org/tidy"]),
+            ]
+        );
+        // Empty buckets are left out.
+        assert!(group_status_results(&results[..1])
+            .iter()
+            .all(|(group, _)| *group == StatusGroup::Behind));
+    }
+
     fn checkout_result_fixture() -> CheckoutResult {
         CheckoutResult {
             repo: Repo::from_slug("scottidler/otto".to_string()),
@@ -1337,9 +2130,11 @@ mod tests {
             pr_number: None,
             pr_url: None,
             original_branch: None,
+            branch: None,
             base_sha: None,
             diff: None,
             error: None,
+            warning: None,
         }
     }
 
@@ -1422,6 +2217,8 @@ mod tests {
             verbosity: OutputVerbosity::Summary,
             use_emoji: true,
             use_colors: false,
+            quiet: false,
+            ..Default::default()
         };
         let widths = AlignmentWidths::calculate(std::slice::from_ref(&result));
         let line = render_unified_line(&result, &opts, &widths);
@@ -1441,6 +2238,8 @@ mod tests {
             verbosity: OutputVerbosity::Summary,
             use_emoji: true,
             use_colors: false,
+            quiet: false,
+            ..Default::default()
         };
         let widths = AlignmentWidths::calculate(std::slice::from_ref(&result));
         let line = render_unified_line(&result, &opts, &widths);
@@ -1453,6 +2252,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn status_repo_column_shows_slug_or_path_per_mode() {
+        let flat = flat_repo_status("main");
+        let worktree = bare_repo_status("main", Some("feature-x"));
+        let cases = [
+            (
+                RepoPathDisplay::Slug,
+                "scottidler/otto",
+                "tatari-tv/clyde\u{2248}main",
+            ),
+            (
+                RepoPathDisplay::Relative(PathBuf::from("/repos")),
+                "scottidler/otto",
+                "tatari-tv/clyde\u{2248}main",
+            ),
+            (
+                RepoPathDisplay::Relative(PathBuf::from("/repos/scottidler")),
+                "otto",
+                "/repos/tatari-tv/clyde\u{2248}main",
+            ),
+            (
+                RepoPathDisplay::Absolute,
+                "/repos/scottidler/otto",
+                "/repos/tatari-tv/clyde\u{2248}main",
+            ),
+        ];
+        let widths = AlignmentWidths::calculate(&[flat.clone(), worktree.clone()]);
+        for (path_display, flat_name, worktree_name) in cases {
+            let opts = StatusOptions {
+                use_colors: false,
+                path_display,
+                ..Default::default()
+            };
+            let line = render_unified_line(&flat, &opts, &widths);
+            assert!(line.contains(&format!(" {flat_name}")), "{line}");
+            let line = render_unified_line(&worktree, &opts, &widths);
+            assert!(line.contains(&format!(" {worktree_name}")), "{line}");
+        }
+
+        // The non-status path (checkout/create/review) follows the same mode.
+        let root = RepoPathDisplay::Relative(PathBuf::from("/repos/scottidler/otto"));
+        assert_eq!(
+            format_repo_path_with_colors(&flat.repo.path, &flat.repo.slug, &root, false),
+            "."
+        );
+    }
+
     #[test]
     fn status_diverged_detached_head_shows_head_at_sha() {
         let result = bare_repo_status("main", Some("HEAD@abc1234"));
@@ -1460,6 +2306,8 @@ mod tests {
             verbosity: OutputVerbosity::Summary,
             use_emoji: true,
             use_colors: false,
+            quiet: false,
+            ..Default::default()
         };
         let widths = AlignmentWidths::calculate(std::slice::from_ref(&result));
         let line = render_unified_line(&result, &opts, &widths);
@@ -1475,6 +2323,8 @@ mod tests {
             verbosity: OutputVerbosity::Summary,
             use_emoji: true,
             use_colors: false,
+            quiet: false,
+            ..Default::default()
         };
         let widths = AlignmentWidths::calculate(std::slice::from_ref(&result));
         let line = render_unified_line(&result, &opts, &widths);
@@ -1484,6 +2334,120 @@ mod tests {
         assert!(line.contains("main"));
     }
 
+    #[test]
+    fn status_diff_stat_follows_slug_in_detailed_mode_only() {
+        let mut result = flat_repo_status("main");
+        result.diff_stat = Some((12, 3));
+        let widths = AlignmentWidths::calculate(std::slice::from_ref(&result));
+        let mut opts = StatusOptions {
+            verbosity: OutputVerbosity::Detailed,
+            use_emoji: true,
+            use_colors: false,
+            quiet: false,
+            ..Default::default()
+        };
+
+        let line = render_unified_line(&result, &opts, &widths);
+        assert!(line.ends_with("scottidler/otto +12/-3"), "got: {line}");
+
+        opts.verbosity = OutputVerbosity::Summary;
+        let line = render_unified_line(&result, &opts, &widths);
+        assert!(!line.contains("+12/-3"), "got: {line}");
+    }
+
+    #[test]
+    fn status_untracked_files_and_dirs_are_reported_separately_in_detailed_mode() {
+        let mut result = flat_repo_status("main");
+        result.is_clean = false;
+        result.changes.untracked = 3;
+        result.changes.untracked_dirs = 1;
+        let widths = AlignmentWidths::calculate(std::slice::from_ref(&result));
+        let mut opts = StatusOptions {
+            verbosity: OutputVerbosity::Detailed,
+            use_emoji: true,
+            use_colors: false,
+            quiet: false,
+            ..Default::default()
+        };
+
+        let line = render_unified_line(&result, &opts, &widths);
+        assert!(
+            line.ends_with("scottidler/otto (3 files, 1 dir untracked)"),
+            "got: {line}"
+        );
+
+        result.changes.untracked = 0;
+        let line = render_unified_line(&result, &opts, &widths);
+        assert!(line.ends_with("(1 dir untracked)"), "got: {line}");
+
+        opts.verbosity = OutputVerbosity::Summary;
+        let line = render_unified_line(&result, &opts, &widths);
+        assert!(!line.contains("untracked"), "got: {line}");
+    }
+
+    #[test]
+    fn status_commit_subject_follows_the_line_in_detailed_mode_only() {
+        let mut result = flat_repo_status("main");
+        result.commit_subject = Some("Fix the flaky test".to_string());
+        let widths = AlignmentWidths::calculate(std::slice::from_ref(&result));
+        let mut opts = StatusOptions {
+            verbosity: OutputVerbosity::Detailed,
+            use_emoji: true,
+            use_colors: false,
+            quiet: false,
+            ..Default::default()
+        };
+
+        let line = render_unified_line(&result, &opts, &widths);
+        assert!(
+            line.ends_with("scottidler/otto Fix the flaky test"),
+            "got: {line}"
+        );
+
+        opts.verbosity = OutputVerbosity::Summary;
+        let line = render_unified_line(&result, &opts, &widths);
+        assert!(!line.contains("flaky"), "got: {line}");
+    }
+
+    #[test]
+    fn test_truncate_to_width_counts_display_columns() {
+        assert_eq!(truncate_to_width("short", 10).as_deref(), Some("short"));
+        assert_eq!(truncate_to_width("exactly", 7).as_deref(), Some("exactly"));
+        assert_eq!(truncate_to_width("truncated", 6).as_deref(), Some("trunc…"));
+        // 🚀 is two columns wide: it either fits whole or is left out.
+        assert_eq!(
+            truncate_to_width("🚀🚀 launch", 5).as_deref(),
+            Some("🚀🚀…")
+        );
+        assert_eq!(truncate_to_width("🚀🚀 launch", 4).as_deref(), Some("🚀…"));
+        assert_eq!(truncate_to_width("anything", 0), None);
+
+        assert_eq!(visible_width("\x1b[1;32mgreen\x1b[0m 🚀"), 8);
+    }
+
+    #[test]
+    fn status_moved_submodules_get_their_own_marker() {
+        let mut result = flat_repo_status("main");
+        result.is_clean = false;
+        result.submodule_changes = 2;
+        let widths = AlignmentWidths::calculate(std::slice::from_ref(&result));
+        let opts = StatusOptions {
+            verbosity: OutputVerbosity::Detailed,
+            use_emoji: true,
+            use_colors: false,
+            quiet: false,
+            ..Default::default()
+        };
+
+        assert_eq!(result.get_emoji(&opts), "🧩");
+        let line = render_unified_line(&result, &opts, &widths);
+        assert!(line.ends_with("(2 submodules moved)"), "got: {line}");
+
+        // An edited file still outranks the moved pointer.
+        result.changes.modified = 1;
+        assert_eq!(result.get_emoji(&opts), "📝");
+    }
+
     // ---- render: status rows, use_colors=true (Catppuccin roles) ----
 
     #[test]
@@ -1494,6 +2458,8 @@ mod tests {
                 verbosity: OutputVerbosity::Summary,
                 use_emoji: true,
                 use_colors: true,
+                quiet: false,
+                ..Default::default()
             };
             let widths = AlignmentWidths::calculate(std::slice::from_ref(&result));
             let line = render_unified_line(&result, &opts, &widths);
@@ -1522,6 +2488,8 @@ mod tests {
                 verbosity: OutputVerbosity::Summary,
                 use_emoji: true,
                 use_colors: true,
+                quiet: false,
+                ..Default::default()
             };
             let widths = AlignmentWidths::calculate(std::slice::from_ref(&result));
             let line = render_unified_line(&result, &opts, &widths);
@@ -1568,6 +2536,8 @@ mod tests {
                 verbosity: OutputVerbosity::Summary,
                 use_emoji: true,
                 use_colors,
+                quiet: false,
+                ..Default::default()
             };
             let widths = AlignmentWidths::calculate(std::slice::from_ref(&result));
             let rendered = render_unified_line(&result, &opts, &widths);
@@ -1592,6 +2562,8 @@ mod tests {
                     verbosity: OutputVerbosity::Summary,
                     use_emoji: true,
                     use_colors,
+                    quiet: false,
+                    ..Default::default()
                 };
                 let widths = AlignmentWidths::calculate(std::slice::from_ref(&result));
                 let rendered = render_unified_line(&result, &opts, &widths);
@@ -1619,6 +2591,8 @@ mod tests {
                     verbosity: OutputVerbosity::Summary,
                     use_emoji: true,
                     use_colors,
+                    quiet: false,
+                    ..Default::default()
                 };
                 let widths = AlignmentWidths::calculate(std::slice::from_ref(&result));
                 let rendered = render_review_line(&result, &opts, &widths);
@@ -1654,7 +2628,12 @@ mod tests {
         let emoji = result.get_emoji(opts);
         let emoji_display = pad_to_width(&emoji, widths.emoji_width);
         let repo = &result.repo;
-        let repo_display = format_repo_path_with_colors(&repo.path, &repo.slug, opts.use_colors);
+        let repo_display = format_repo_path_with_colors(
+            &repo.path,
+            &repo.slug,
+            &opts.path_display,
+            opts.use_colors,
+        );
         let mut out = format!("{branch_display} {pr_display} {emoji_display} {repo_display}");
         if let Some(error) = &result.error {
             let error_msg = if opts.use_colors {
@@ -1695,7 +2674,63 @@ mod tests {
         let emoji = item.get_emoji(opts);
         let emoji_display = pad_to_width(&emoji, widths.emoji_width);
         let repo = item.get_repo();
-        let repo_display = format_repo_path_with_colors(&repo.path, &repo.slug, opts.use_colors);
+        let repo_display = format_repo_path_with_colors(
+            &repo.path,
+            &repo.slug,
+            &opts.path_display,
+            opts.use_colors,
+        );
         format!("{branch_display} {sha_display} {emoji_display} {repo_display}")
     }
+
+    #[test]
+    fn test_progress_reporter_counts_parallel_ticks() {
+        use rayon::prelude::*;
+
+        let progress = ProgressReporter::new(200, "cloning", false);
+        assert_eq!(progress.render(progress.done()), "[0/200] cloning...");
+
+        (0..200).into_par_iter().for_each(|_| {
+            progress.tick();
+        });
+
+        assert_eq!(progress.done(), 200);
+        assert_eq!(progress.render(progress.done()), "[200/200] cloning...");
+        // `--progress` is never drawn under --quiet or --json.
+        assert!(!progress_wanted(true, true, false));
+        assert!(!progress_wanted(true, false, true));
+        assert!(!progress_wanted(false, false, false));
+    }
+
+    #[test]
+    fn test_render_branch_sync_marks_each_branch() {
+        use local::git::BranchSync;
+
+        let syncs = vec![
+            BranchSync {
+                branch: "main".to_string(),
+                upstream: Some("origin/main".to_string()),
+                remote_status: RemoteStatus::UpToDate,
+            },
+            BranchSync {
+                branch: "old-feature".to_string(),
+                upstream: Some("origin/old-feature".to_string()),
+                remote_status: RemoteStatus::Behind(12),
+            },
+            BranchSync {
+                branch: "scratch".to_string(),
+                upstream: None,
+                remote_status: RemoteStatus::NoUpstream,
+            },
+        ];
+        let opts = StatusOptions {
+            use_emoji: false,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            render_branch_sync(&syncs, &opts),
+            "    main        = origin/main\n    old-feature ↓12 origin/old-feature\n    scratch     ~ (no upstream)"
+        );
+    }
 }
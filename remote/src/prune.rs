@@ -0,0 +1,234 @@
+//! Prune subcommand implementation
+//!
+//! Deletes stale gx branches from local clones: branches matching a prefix
+//! (default `GX-`) whose upstream is gone or that are already merged into the
+//! default branch. The local counterpart to `review purge`, which deletes the
+//! remote branches.
+
+use crate::cli::Cli;
+use crate::confirm::{confirm_destructive, DestructiveOp};
+use crate::output;
+use eyre::{Context, Result};
+use local::config::Config;
+use local::repo::{discover_repos, filter_repos, Repo};
+use local::utils::get_nproc;
+use log::{debug, info};
+use rayon::prelude::*;
+
+/// Outcome of pruning one repo.
+#[derive(Debug, Clone)]
+pub struct PruneResult {
+    pub repo: Repo,
+    /// Branches deleted (or, under `--dry-run`, that would be).
+    pub pruned: Vec<String>,
+    /// Prefix-matching branches left alone (unmerged, or checked out).
+    pub kept: usize,
+    pub error: Option<String>,
+}
+
+/// A per-repo prune plan: the prefix-matching branches to delete and how many
+/// are kept.
+struct PrunePlan {
+    repo: Repo,
+    to_delete: Vec<String>,
+    kept: usize,
+    error: Option<String>,
+}
+
+/// Process the prune subcommand
+pub fn process_prune_command(
+    cli: &Cli,
+    config: &Config,
+    prefix: &str,
+    patterns: &[String],
+    merged_only: bool,
+    dry_run: bool,
+    yes: bool,
+) -> Result<()> {
+    info!("Pruning local {prefix}* branches (merged_only={merged_only}, dry_run={dry_run})");
+
+    let start_dir = std::env::current_dir().context("Failed to get current directory")?;
+    let max_depth = cli
+        .max_depth
+        .or_else(|| local::utils::get_max_depth_from_config(config))
+        .unwrap_or(3);
+
+    let repos = discover_repos(&start_dir, max_depth, &config.ignore_patterns())
+        .context("Failed to discover repositories")?;
+    output::warn_slug_collisions(&repos, cli.quiet);
+    let filtered_repos = filter_repos(repos, patterns);
+
+    if filtered_repos.is_empty() {
+        println!("🔍 No repositories found matching the criteria");
+        return Ok(());
+    }
+
+    let parallel_jobs = cli
+        .parallel
+        .or_else(|| local::utils::get_jobs_from_config(config))
+        .unwrap_or_else(|| get_nproc().unwrap_or(4));
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(parallel_jobs)
+        .build()
+        .context("Failed to create thread pool")?;
+
+    let plan: Vec<PrunePlan> = pool.install(|| {
+        filtered_repos
+            .par_iter()
+            .map(|repo| build_prune_plan(repo, prefix, merged_only))
+            .collect()
+    });
+
+    let total: usize = plan.iter().map(|p| p.to_delete.len()).sum();
+
+    if dry_run {
+        let results: Vec<PruneResult> = plan.into_iter().map(PrunePlan::into_result).collect();
+        display_prune_results(&results, true, cli.quiet);
+        return Ok(());
+    }
+
+    let threshold = config.cleanup_confirm_threshold();
+    if total > 0 && total >= threshold && !confirm_destructive(DestructiveOp::Cleanup, total, yes)?
+    {
+        println!("Aborted; no branches deleted.");
+        return Ok(());
+    }
+
+    let results: Vec<PruneResult> =
+        pool.install(|| plan.into_par_iter().map(prune_repo_branches).collect());
+    display_prune_results(&results, false, cli.quiet);
+
+    Ok(())
+}
+
+impl PrunePlan {
+    /// The plan as a result, with nothing deleted yet (dry run).
+    fn into_result(self) -> PruneResult {
+        PruneResult {
+            repo: self.repo,
+            pruned: self.to_delete,
+            kept: self.kept,
+            error: self.error,
+        }
+    }
+}
+
+/// Work out which of one repo's local branches to prune.
+fn build_prune_plan(repo: &Repo, prefix: &str, merged_only: bool) -> PrunePlan {
+    debug!("build_prune_plan: repo={} prefix={prefix}", repo.slug);
+    let plan = || -> Result<(Vec<String>, usize)> {
+        let default_branch = local::git::get_default_branch_local(repo)?;
+        let branches = local::git::list_local_branches(&repo.path)?;
+        let merged = local::git::list_merged_branches(&repo.path, &default_branch)?;
+        let current = local::git::get_current_branch_name(&repo.path).unwrap_or_default();
+        Ok(select_prunable(
+            &branches,
+            &merged,
+            prefix,
+            merged_only,
+            &[current.as_str(), default_branch.as_str()],
+        ))
+    };
+
+    match plan() {
+        Ok((to_delete, kept)) => PrunePlan {
+            repo: repo.clone(),
+            to_delete,
+            kept,
+            error: None,
+        },
+        Err(e) => PrunePlan {
+            repo: repo.clone(),
+            to_delete: Vec::new(),
+            kept: 0,
+            error: Some(format!("{e:#}")),
+        },
+    }
+}
+
+/// Pick the branches to prune from `branches` (`(name, upstream_gone)`),
+/// returning them with the count of prefix-matching branches kept. A branch is
+/// prunable when it matches `prefix` and is merged into the default branch,
+/// or (unless `merged_only`) its upstream is gone. Branches in `protected`
+/// (the checked-out and default branches) are never pruned.
+fn select_prunable(
+    branches: &[(String, bool)],
+    merged: &[String],
+    prefix: &str,
+    merged_only: bool,
+    protected: &[&str],
+) -> (Vec<String>, usize) {
+    let mut to_delete = Vec::new();
+    let mut kept = 0;
+    for (name, upstream_gone) in branches {
+        if !name.starts_with(prefix) {
+            continue;
+        }
+        let stale = merged.contains(name) || (!merged_only && *upstream_gone);
+        if stale && !protected.contains(&name.as_str()) {
+            to_delete.push(name.clone());
+        } else {
+            kept += 1;
+        }
+    }
+    (to_delete, kept)
+}
+
+/// Delete the branches in one repo's prune plan. A failed delete stops that
+/// repo (the rest of its branches are counted as kept) and is reported.
+fn prune_repo_branches(plan: PrunePlan) -> PruneResult {
+    let mut pruned = Vec::new();
+    let mut kept = plan.kept;
+    let mut error = plan.error;
+    for branch in &plan.to_delete {
+        if error.is_some() {
+            kept += 1;
+            continue;
+        }
+        match local::git::delete_local_branch(&plan.repo.path, branch) {
+            Ok(()) => pruned.push(branch.clone()),
+            Err(e) => {
+                kept += 1;
+                error = Some(format!("{e:#}"));
+            }
+        }
+    }
+    PruneResult {
+        repo: plan.repo,
+        pruned,
+        kept,
+        error,
+    }
+}
+
+/// Print each repo's pruned branches (unless `quiet`) and a totals line.
+fn display_prune_results(results: &[PruneResult], dry_run: bool, quiet: bool) {
+    let verb = if dry_run { "would prune" } else { "pruned" };
+    if !quiet {
+        for result in results {
+            for branch in &result.pruned {
+                println!("  🧹 {} {}", result.repo.slug, branch);
+            }
+            if let Some(error) = &result.error {
+                println!("  ❌ {}: {}", result.repo.slug, error);
+            }
+        }
+    }
+
+    let pruned: usize = results.iter().map(|r| r.pruned.len()).sum();
+    let kept: usize = results.iter().map(|r| r.kept).sum();
+    let errors = results.iter().filter(|r| r.error.is_some()).count();
+    println!(
+        "\n📊 {pruned} branch(es) {verb}, {kept} kept across {} repo(s){}",
+        results.len(),
+        if errors > 0 {
+            format!(", {errors} error(s)")
+        } else {
+            String::new()
+        }
+    );
+}
+
+#[cfg(test)]
+mod tests;
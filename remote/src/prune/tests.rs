@@ -0,0 +1,78 @@
+use super::*;
+use local::test_utils::{create_minimal_test_repo, run_git_command};
+use tempfile::TempDir;
+
+fn branch(name: &str, upstream_gone: bool) -> (String, bool) {
+    (name.to_string(), upstream_gone)
+}
+
+#[test]
+fn test_select_prunable_takes_merged_and_gone_prefix_branches() {
+    let branches = [
+        branch("main", false),
+        branch("GX-merged", false),
+        branch("GX-gone", true),
+        branch("GX-active", false),
+        branch("feature", true),
+    ];
+    let merged = ["main".to_string(), "GX-merged".to_string()];
+
+    let (to_delete, kept) = select_prunable(&branches, &merged, "GX-", false, &["main"]);
+    assert_eq!(to_delete, ["GX-merged", "GX-gone"]);
+    assert_eq!(
+        kept, 1,
+        "only GX-active is kept; non-prefix branches aren't counted"
+    );
+}
+
+#[test]
+fn test_select_prunable_merged_only_keeps_gone_but_unmerged() {
+    let branches = [branch("GX-merged", false), branch("GX-gone", true)];
+    let merged = ["GX-merged".to_string()];
+
+    let (to_delete, kept) = select_prunable(&branches, &merged, "GX-", true, &[]);
+    assert_eq!(to_delete, ["GX-merged"]);
+    assert_eq!(kept, 1);
+}
+
+#[test]
+fn test_select_prunable_never_takes_the_checked_out_branch() {
+    let branches = [branch("GX-current", true)];
+    let merged = ["GX-current".to_string()];
+
+    let (to_delete, kept) = select_prunable(&branches, &merged, "GX-", false, &["GX-current"]);
+    assert!(to_delete.is_empty());
+    assert_eq!(kept, 1);
+}
+
+#[test]
+fn test_prune_deletes_merged_branches_and_keeps_unmerged() {
+    let temp = TempDir::new().unwrap();
+    let path = create_minimal_test_repo(temp.path(), "gx");
+
+    // GX-merged sits at the default branch's tip; GX-unmerged has its own commit.
+    run_git_command(&["branch", "GX-merged"], &path);
+    run_git_command(&["checkout", "--quiet", "-b", "GX-unmerged"], &path);
+    std::fs::write(path.join("new.txt"), "unmerged work\n").unwrap();
+    run_git_command(&["add", "new.txt"], &path);
+    run_git_command(&["commit", "--quiet", "-m", "unmerged"], &path);
+    run_git_command(&["checkout", "--quiet", "-"], &path);
+    // A merged branch without the prefix is not gx's to prune.
+    run_git_command(&["branch", "topic"], &path);
+
+    let repo = Repo::new(path.clone()).unwrap();
+    let plan = build_prune_plan(&repo, "GX-", true);
+    assert_eq!(plan.error, None);
+    assert_eq!(plan.to_delete, ["GX-merged"]);
+
+    let result = prune_repo_branches(plan);
+    assert_eq!(result.error, None);
+    assert_eq!(result.pruned, ["GX-merged"]);
+    assert_eq!(result.kept, 1);
+
+    let remaining = local::git::list_local_branches(&path).unwrap();
+    let names: Vec<&str> = remaining.iter().map(|(name, _)| name.as_str()).collect();
+    assert!(!names.contains(&"GX-merged"), "got {names:?}");
+    assert!(names.contains(&"GX-unmerged"), "got {names:?}");
+    assert!(names.contains(&"topic"), "got {names:?}");
+}
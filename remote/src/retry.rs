@@ -0,0 +1,193 @@
+//! `gx retry`: re-run the last batch command on just the repos that failed.
+//!
+//! `checkout`, `clone` and a committing `create` each call [`record_last_run`]
+//! once their results are in, persisting the invocation (arguments and
+//! working directory) and the slugs of the repos that failed to
+//! `$XDG_DATA_HOME/gx/last-run.json`. Every such run overwrites the record,
+//! so a retry only ever replays the most recent command. Read-only commands
+//! (`status`, a dry-run `create`) record nothing.
+//!
+//! The retry spawns the gx binary again rather than re-dispatching in
+//! process: the child parses its own arguments, installs its own thread
+//! pools, and records its own outcome, so a second `gx retry` picks up
+//! whatever still failed.
+
+use chrono::{DateTime, Utc};
+use eyre::{Context, Result};
+use local::file;
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The flag a retry restricts the re-run with; stripped from recorded
+/// arguments so retries don't stack.
+const REPOS_FROM_FILE: &str = "--repos-from-file";
+
+/// Never persisted: the retry resolves its token afresh.
+const TOKEN: &str = "--token";
+
+/// Never persisted: main has already applied it by the time a run is
+/// recorded, so the recorded `cwd` is its target and replaying a relative
+/// `--cwd` from there would resolve it twice.
+const CWD: &str = "--cwd";
+
+/// The persisted record of the last batch run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct LastRun {
+    /// The gx arguments, without the program name.
+    pub args: Vec<String>,
+    /// The directory gx ran in: the `--cwd` target when one was given.
+    pub cwd: PathBuf,
+    /// Slugs of the repos that failed, sorted.
+    pub failed: Vec<String>,
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// `$XDG_DATA_HOME/gx`, where the last-run record and retry list live.
+fn data_dir() -> Result<PathBuf> {
+    Ok(local::config::xdg_data_dir()
+        .ok_or_else(|| eyre::eyre!("Could not determine data dir (set HOME or XDG_DATA_HOME)"))?
+        .join("gx"))
+}
+
+fn last_run_path() -> Result<PathBuf> {
+    Ok(data_dir()?.join("last-run.json"))
+}
+
+/// Record this process's invocation and the repos that failed. Never fails
+/// the command that calls it: a record that can't be written is only logged.
+pub fn record_last_run<S: AsRef<str>>(failed: &[S]) {
+    let mut failed: Vec<String> = failed.iter().map(|s| s.as_ref().to_string()).collect();
+    failed.sort();
+    failed.dedup();
+    let record = || -> Result<()> {
+        let Some(args) = recorded_args(std::env::args_os().skip(1)) else {
+            warn!("Not recording last run for `gx retry`: an argument is not valid UTF-8");
+            return Ok(());
+        };
+        let run = LastRun {
+            args,
+            cwd: std::env::current_dir()?,
+            failed,
+            recorded_at: Utc::now(),
+        };
+        write_last_run(&run)
+    };
+    if let Err(e) = record() {
+        warn!("Failed to record last run for `gx retry`: {e:#}");
+    }
+}
+
+/// `args` as they are persisted: any `--token` value dropped, so a secret
+/// never lands in the last-run record, and any `--cwd` dropped, since the
+/// record's `cwd` already is its target. `None` when an argument is not
+/// valid UTF-8 and so can't be stored in the JSON record.
+fn recorded_args(args: impl Iterator<Item = OsString>) -> Option<Vec<String>> {
+    let args: Vec<String> = args
+        .map(|arg| arg.into_string().ok())
+        .collect::<Option<_>>()?;
+    let mut out = Vec::new();
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        if arg == TOKEN || arg == CWD {
+            iter.next();
+        } else if !arg.starts_with(&format!("{TOKEN}=")) && !arg.starts_with(&format!("{CWD}=")) {
+            out.push(arg);
+        }
+    }
+    Some(out)
+}
+
+/// Write `run` as the last-run record (atomic).
+pub fn write_last_run(run: &LastRun) -> Result<()> {
+    let path = last_run_path()?;
+    debug!(
+        "write_last_run: path={} failed={}",
+        path.display(),
+        run.failed.len()
+    );
+    let json = serde_json::to_string_pretty(run).context("Failed to serialize last run")?;
+    file::atomic_write(&path, json.as_bytes())
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// The last-run record, or `None` when nothing has been recorded yet.
+pub fn read_last_run() -> Result<Option<LastRun>> {
+    let path = last_run_path()?;
+    if !path.exists() {
+        return Ok(None);
+    }
+    let json = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let run = serde_json::from_str(&json)
+        .with_context(|| format!("Failed to parse {}", path.display()))?;
+    Ok(Some(run))
+}
+
+/// The arguments to re-run `args` restricted to the repos in `repo_list`:
+/// any earlier `--repos-from-file` is dropped and the new one appended.
+pub fn retry_args(args: &[String], repo_list: &std::path::Path) -> Vec<String> {
+    let mut out = Vec::with_capacity(args.len() + 2);
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == REPOS_FROM_FILE {
+            iter.next();
+        } else if !arg.starts_with(&format!("{REPOS_FROM_FILE}=")) {
+            out.push(arg.clone());
+        }
+    }
+    out.push(REPOS_FROM_FILE.to_string());
+    out.push(repo_list.display().to_string());
+    out
+}
+
+/// Process the retry subcommand
+pub fn process_retry_command(dry_run: bool) -> Result<()> {
+    let Some(run) = read_last_run()? else {
+        println!("No previous run recorded; nothing to retry");
+        return Ok(());
+    };
+    let command = format!("gx {}", run.args.join(" "));
+    if run.failed.is_empty() {
+        println!("Last run (`{command}`) had no failures; nothing to retry");
+        return Ok(());
+    }
+
+    let repo_list = data_dir()?.join("retry-repos.txt");
+    file::atomic_write(
+        &repo_list,
+        format!("{}\n", run.failed.join("\n")).as_bytes(),
+    )
+    .with_context(|| format!("Failed to write {}", repo_list.display()))?;
+    let args = retry_args(&run.args, &repo_list);
+
+    println!(
+        "🔁 Retrying {} failed repo(s) from `{command}` in {}",
+        run.failed.len(),
+        run.cwd.display()
+    );
+    for slug in &run.failed {
+        println!("  {slug}");
+    }
+    if dry_run {
+        println!("Would run: gx {}", args.join(" "));
+        return Ok(());
+    }
+
+    let exe = std::env::current_exe().context("Failed to locate the gx binary")?;
+    let status = Command::new(exe)
+        .args(&args)
+        .current_dir(&run.cwd)
+        .status()
+        .context("Failed to re-run the last command")?;
+    if !status.success() {
+        std::process::exit(status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,105 @@
+use super::*;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn with_data_home<F: FnOnce()>(dir: &Path, f: F) {
+    let guard = local::test_utils::env_lock();
+    let prior = std::env::var("XDG_DATA_HOME").ok();
+    unsafe { std::env::set_var("XDG_DATA_HOME", dir) };
+    f();
+    match prior {
+        Some(v) => unsafe { std::env::set_var("XDG_DATA_HOME", v) },
+        None => unsafe { std::env::remove_var("XDG_DATA_HOME") },
+    }
+    drop(guard);
+}
+
+fn args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|a| a.to_string()).collect()
+}
+
+#[test]
+fn test_retry_args_appends_the_repo_list() {
+    let retried = retry_args(&args(&["checkout", "release"]), Path::new("/d/retry.txt"));
+    assert_eq!(
+        retried,
+        ["checkout", "release", "--repos-from-file", "/d/retry.txt"]
+    );
+}
+
+#[test]
+fn test_retry_args_replaces_an_earlier_repo_list() {
+    let recorded = args(&[
+        "--repos-from-file",
+        "/old.txt",
+        "status",
+        "--repos-from-file=/older.txt",
+    ]);
+    let retried = retry_args(&recorded, Path::new("/new.txt"));
+    assert_eq!(retried, ["status", "--repos-from-file", "/new.txt"]);
+}
+
+fn os_args(args: &[&str]) -> impl Iterator<Item = OsString> {
+    args.iter()
+        .map(OsString::from)
+        .collect::<Vec<_>>()
+        .into_iter()
+}
+
+#[test]
+fn test_token_is_never_recorded() {
+    let recorded = recorded_args(os_args(&[
+        "--token",
+        "s3cret",
+        "status",
+        "--token=s3cret",
+        "-d",
+    ]));
+    assert_eq!(recorded.unwrap(), ["status", "-d"]);
+}
+
+#[test]
+fn test_retry_of_a_relative_cwd_run_does_not_reapply_it() {
+    // main has already changed into `sub` when the run is recorded, so the
+    // record's cwd is `sub` and the retry must not `--cwd sub` again.
+    let recorded = recorded_args(os_args(&[
+        "--cwd",
+        "sub",
+        "checkout",
+        "--cwd=sub",
+        "release",
+    ]))
+    .unwrap();
+    assert_eq!(recorded, ["checkout", "release"]);
+    let retried = retry_args(&recorded, Path::new("/d/retry.txt"));
+    assert!(!retried.iter().any(|a| a.starts_with("--cwd")));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_non_utf8_argument_is_not_recorded() {
+    use std::os::unix::ffi::OsStringExt;
+    let args = vec![
+        OsString::from("checkout"),
+        OsString::from("--root"),
+        OsString::from_vec(b"/tmp/\xff".to_vec()),
+    ];
+    assert_eq!(recorded_args(args.into_iter()), None);
+}
+
+#[test]
+fn test_record_last_run_roundtrips_sorted_failures() {
+    let temp = TempDir::new().unwrap();
+    with_data_home(temp.path(), || {
+        assert_eq!(read_last_run().unwrap(), None);
+
+        record_last_run(&["org/b", "org/a", "org/b"]);
+        let run = read_last_run().unwrap().expect("run was recorded");
+        assert_eq!(run.failed, ["org/a", "org/b"]);
+        assert_eq!(run.cwd, std::env::current_dir().unwrap());
+
+        // Every run overwrites the record, including a clean one.
+        record_last_run::<&str>(&[]);
+        assert!(read_last_run().unwrap().unwrap().failed.is_empty());
+    });
+}
@@ -1,8 +1,8 @@
-use crate::cli::Cli;
+use crate::cli::{Cli, PrStateFilter};
 use crate::confirm::{confirm_destructive, DestructiveOp};
 use crate::git;
 use crate::github::{self, PrInfo};
-use crate::output::{display_review_results, StatusOptions};
+use crate::output::{self, display_review_results, StatusOptions};
 use crate::ssh::SshUrlBuilder;
 use crate::state::StateManager;
 use eyre::{Context, Result};
@@ -11,6 +11,7 @@ use local::repo::{discover_repos, filter_repos, Repo};
 use local::user_org::UserOrgContext;
 use log::{debug, info, trace, warn};
 use rayon::prelude::*;
+use serde::Serialize;
 use std::path::Path;
 
 /// Preflight-complete-or-abort PR discovery for a finish-line batch (design doc
@@ -64,9 +65,38 @@ pub enum ReviewAction {
     Cloned,   // Repository cloned/updated
     Approved, // PR approved and merged
     Deleted,  // PR closed and branch deleted
+    Reopened, // Closed PR reopened (branch re-pushed if needed)
     Purged,   // All GX branches cleaned up
 }
 
+/// Which PRs `review ls` lists: `--limit`, `--state` and `--author`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PrListFilter<'a> {
+    /// Stop collecting after this many PRs; `None` is no limit.
+    pub limit: Option<usize>,
+    pub state: PrStateFilter,
+    /// Only PRs opened by this GitHub login.
+    pub author: Option<&'a str>,
+}
+
+impl PrListFilter<'_> {
+    /// Keep the PRs in `state` opened by `author` (GitHub logins compare
+    /// case-insensitively), in listing order.
+    fn keep(&self, prs: Vec<PrInfo>) -> Vec<PrInfo> {
+        prs.into_iter()
+            .filter(|pr| match self.state {
+                PrStateFilter::Open => pr.state == github::PrState::Open,
+                PrStateFilter::Closed => pr.state != github::PrState::Open,
+                PrStateFilter::All => true,
+            })
+            .filter(|pr| {
+                self.author
+                    .is_none_or(|login| pr.author.eq_ignore_ascii_case(login))
+            })
+            .collect()
+    }
+}
+
 /// Process review ls command - list PRs by change ID
 pub fn process_review_ls_command(
     cli: &Cli,
@@ -74,6 +104,8 @@ pub fn process_review_ls_command(
     org: Option<&str>,
     _patterns: &[String],
     change_ids: &[String],
+    json: bool,
+    filter: PrListFilter<'_>,
 ) -> Result<()> {
     // Discover repositories for auto-detection
     let current_dir = std::env::current_dir()?;
@@ -119,12 +151,21 @@ pub fn process_review_ls_command(
 
     info!("Listing PRs for patterns: {search_patterns:?}");
 
-    let mut all_results = Vec::new();
+    let mut all_prs = Vec::new();
 
     // Process each org and pattern combination
     for context in &user_org_contexts {
         for pattern in &search_patterns {
-            match github::list_prs_by_change_id(&context.user_or_org, pattern, config) {
+            let remaining = filter.limit.map(|n| n.saturating_sub(all_prs.len()));
+            if remaining == Some(0) {
+                break;
+            }
+            match github::list_prs_by_change_id_limited(
+                &context.user_or_org,
+                pattern,
+                remaining,
+                config,
+            ) {
                 Ok(prs) => {
                     info!(
                         "Found {} PRs for pattern '{}' in org '{}'",
@@ -133,28 +174,7 @@ pub fn process_review_ls_command(
                         context.user_or_org
                     );
 
-                    for pr in prs {
-                        // Create a pseudo-repo for display purposes
-                        let repo = create_repo_from_slug(&pr.repo_slug);
-
-                        let result = ReviewResult {
-                            repo,
-                            change_id: pr.branch.clone(),
-                            pr_number: Some(pr.number),
-                            action: ReviewAction::Listed,
-                            error: None,
-                        };
-
-                        all_results.push(result);
-
-                        // Display PR info
-                        println!("PR #{}: {} ({})", pr.number, pr.title, pr.state_string());
-                        println!("  Repository: {}", pr.repo_slug);
-                        println!("  Branch: {}", pr.branch);
-                        println!("  Author: {}", pr.author);
-                        println!("  URL: {}", pr.url);
-                        println!();
-                    }
+                    all_prs.extend(prs);
                 }
                 Err(e) => {
                     log::warn!(
@@ -168,6 +188,31 @@ pub fn process_review_ls_command(
         }
     }
 
+    let all_prs = filter.keep(all_prs);
+    if json {
+        println!("{}", render_pr_listing_json(&all_prs)?);
+        return Ok(());
+    }
+
+    let mut all_results = Vec::new();
+    for pr in &all_prs {
+        println!("PR #{}: {} ({})", pr.number, pr.title, pr.state_string());
+        println!("  Repository: {}", pr.repo_slug);
+        println!("  Branch: {}", pr.branch);
+        println!("  Author: {}", pr.author);
+        println!("  URL: {}", pr.url);
+        println!();
+
+        // A pseudo-repo for the unified display
+        all_results.push(ReviewResult {
+            repo: create_repo_from_slug(&pr.repo_slug),
+            change_id: pr.branch.clone(),
+            pr_number: Some(pr.number),
+            action: ReviewAction::Listed,
+            error: None,
+        });
+    }
+
     // Display unified results
     let opts = StatusOptions {
         verbosity: if cli.verbose {
@@ -176,15 +221,51 @@ pub fn process_review_ls_command(
             local::config::OutputVerbosity::Summary
         },
         use_emoji: true,
-        use_colors: true,
+        use_colors: output::should_use_color(cli.color),
+        quiet: cli.quiet,
+        ..Default::default()
     };
 
     display_review_results(&all_results, &opts);
     display_review_summary(&all_results, &opts);
+    output::exit_on_errors(count_review_errors(&all_results), !cli.no_fail_on_error);
 
     Ok(())
 }
 
+/// One `review ls --json` row. A flat projection of `PrInfo` with stable
+/// snake_case keys; `change_id` is the PR's head branch, as in the human
+/// listing.
+#[derive(Debug, Serialize)]
+struct PrListing<'a> {
+    change_id: &'a str,
+    repo_slug: &'a str,
+    pr_number: u64,
+    branch: &'a str,
+    author: &'a str,
+    state: &'a github::PrState,
+    url: &'a str,
+    title: &'a str,
+}
+
+/// Render PRs as the pretty-printed JSON array `review ls --json` prints.
+fn render_pr_listing_json(prs: &[PrInfo]) -> Result<String> {
+    let rows: Vec<PrListing> = prs
+        .iter()
+        .map(|pr| PrListing {
+            change_id: &pr.branch,
+            repo_slug: &pr.repo_slug,
+            pr_number: pr.number,
+            branch: &pr.branch,
+            author: &pr.author,
+            state: &pr.state,
+            url: &pr.url,
+            title: &pr.title,
+        })
+        .collect();
+    serde_json::to_string_pretty(&rows).context("Failed to serialize PR listing")
+}
+
 /// Process review clone command - clone repositories with PRs
 pub fn process_review_clone_command(
     cli: &Cli,
@@ -258,10 +339,7 @@ pub fn process_review_clone_command(
     let base_dir = cli.cwd.as_deref().unwrap_or(&current_dir);
 
     // Determine parallelism
-    let parallel_jobs = cli
-        .parallel
-        .or_else(|| local::utils::get_jobs_from_config(config))
-        .unwrap_or_else(num_cpus::get);
+    let parallel_jobs = review_jobs(cli, config, &user_org_contexts);
 
     // Set up thread pool
     let pool = rayon::ThreadPoolBuilder::new()
@@ -298,11 +376,14 @@ pub fn process_review_clone_command(
             local::config::OutputVerbosity::Summary
         },
         use_emoji: true,
-        use_colors: true,
+        use_colors: output::should_use_color(cli.color),
+        quiet: cli.quiet,
+        ..Default::default()
     };
 
     display_review_results(&results, &opts);
     display_review_summary(&results, &opts);
+    output::exit_on_errors(count_review_errors(&results), !cli.no_fail_on_error);
 
     Ok(())
 }
@@ -509,10 +590,7 @@ pub fn process_review_approve_command(
     }
 
     // Determine parallelism
-    let parallel_jobs = cli
-        .parallel
-        .or_else(|| local::utils::get_jobs_from_config(config))
-        .unwrap_or_else(num_cpus::get);
+    let parallel_jobs = review_jobs(cli, config, &user_org_contexts);
 
     // Set up thread pool
     let pool = rayon::ThreadPoolBuilder::new()
@@ -521,12 +599,23 @@ pub fn process_review_approve_command(
         .context("Failed to create thread pool")?;
 
     // Merge only the proven-mergeable PRs in parallel.
+    let progress = output::ProgressReporter::new(
+        mergeable_prs.len(),
+        "merging",
+        output::progress_wanted(cli.progress, cli.quiet, false),
+    );
     let results: Vec<ReviewResult> = pool.install(|| {
         mergeable_prs
             .par_iter()
-            .map(|pr| approve_and_merge_pr(pr, change_id, admin_override, auto_merge, config))
+            .map(|pr| {
+                let result =
+                    approve_and_merge_pr(pr, change_id, admin_override, auto_merge, config);
+                progress.tick();
+                result
+            })
             .collect()
     });
+    progress.finish();
 
     // Single race-free state update (load once, apply merged/failed AND the
     // mergeability skips, save once) under the change-level lock (Phase 7 [F6])
@@ -541,12 +630,15 @@ pub fn process_review_approve_command(
             local::config::OutputVerbosity::Summary
         },
         use_emoji: true,
-        use_colors: true,
+        use_colors: output::should_use_color(cli.color),
+        quiet: cli.quiet,
+        ..Default::default()
     };
 
     display_review_results(&results, &opts);
     display_review_summary(&results, &opts);
     print_skip_hints(&skipped);
+    output::exit_on_errors(count_review_errors(&results), !cli.no_fail_on_error);
 
     Ok(())
 }
@@ -620,10 +712,7 @@ pub fn process_review_delete_command(
     }
 
     // Determine parallelism
-    let parallel_jobs = cli
-        .parallel
-        .or_else(|| local::utils::get_jobs_from_config(config))
-        .unwrap_or_else(num_cpus::get);
+    let parallel_jobs = review_jobs(cli, config, &user_org_contexts);
 
     // Set up thread pool
     let pool = rayon::ThreadPoolBuilder::new()
@@ -632,12 +721,22 @@ pub fn process_review_delete_command(
         .context("Failed to create thread pool")?;
 
     // Process PRs in parallel
+    let progress = output::ProgressReporter::new(
+        open_prs.len(),
+        "deleting",
+        output::progress_wanted(cli.progress, cli.quiet, false),
+    );
     let results: Vec<ReviewResult> = pool.install(|| {
         open_prs
             .par_iter()
-            .map(|pr| delete_pr_and_branch(pr, change_id, config))
+            .map(|pr| {
+                let result = delete_pr_and_branch(pr, change_id, config);
+                progress.tick();
+                result
+            })
             .collect()
     });
+    progress.finish();
 
     // Single race-free state update: load once, mark closed, save once ([A10]),
     // under the change-level lock (Phase 7 [F6]).
@@ -667,15 +766,136 @@ pub fn process_review_delete_command(
             local::config::OutputVerbosity::Summary
         },
         use_emoji: true,
-        use_colors: true,
+        use_colors: output::should_use_color(cli.color),
+        quiet: cli.quiet,
+        ..Default::default()
+    };
+
+    display_review_results(&results, &opts);
+    display_review_summary(&results, &opts);
+    output::exit_on_errors(count_review_errors(&results), !cli.no_fail_on_error);
+
+    Ok(())
+}
+
+/// Process review reopen command - reopen a change's closed (unmerged) PRs,
+/// e.g. after an accidental `review delete`. A PR whose branch was deleted
+/// gets it re-pushed first from the repo's local clone recorded in the
+/// change state, when that clone still has it. Open and merged PRs are left
+/// alone.
+pub fn process_review_reopen_command(
+    cli: &Cli,
+    config: &Config,
+    org: Option<&str>,
+    change_id: &str,
+) -> Result<()> {
+    info!("Reopening PRs for change ID: {change_id}");
+
+    let current_dir = std::env::current_dir()?;
+    let start_dir = cli.cwd.as_deref().unwrap_or(&current_dir);
+    let max_depth = cli
+        .max_depth
+        .or_else(|| config.repo_discovery.as_ref().and_then(|rd| rd.max_depth))
+        .unwrap_or(3);
+
+    let repos = local::repo::discover_repos(start_dir, max_depth, &config.ignore_patterns())
+        .context("Failed to discover repositories")?;
+
+    let user_org_contexts =
+        local::user_org::determine_user_orgs(org, cli.user_org.as_deref(), &repos, config)?;
+
+    if user_org_contexts.is_empty() {
+        eprintln!("Error: No organization detected. Use --org <org> to specify one.");
+        return Ok(());
+    }
+
+    let prs = discover_all_prs(&user_org_contexts, change_id, config)?;
+    let closed_prs = closed_prs(&prs);
+
+    if closed_prs.is_empty() {
+        println!("No closed PRs found for change ID: {change_id}");
+        return Ok(());
+    }
+
+    println!("Found {} closed PRs to reopen:", closed_prs.len());
+    for pr in &closed_prs {
+        println!("  PR #{}: {} ({})", pr.number, pr.title, pr.repo_slug);
+    }
+
+    // The local clones the change was made from, for re-pushing a deleted branch.
+    let state = StateManager::new()
+        .ok()
+        .and_then(|manager| manager.load(change_id).ok().flatten());
+    let local_path = |slug: &str| {
+        state
+            .as_ref()
+            .and_then(|s| s.repositories.get(slug))
+            .and_then(|r| r.local_path.as_deref())
+            .map(std::path::PathBuf::from)
+    };
+
+    let parallel_jobs = review_jobs(cli, config, &user_org_contexts);
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(parallel_jobs)
+        .build()
+        .context("Failed to create thread pool")?;
+
+    let results: Vec<ReviewResult> = pool.install(|| {
+        closed_prs
+            .par_iter()
+            .map(|pr| {
+                let path = local_path(&pr.repo_slug);
+                reopen_pr_and_branch(pr, change_id, path.as_deref(), config)
+            })
+            .collect()
+    });
+
+    // Single race-free state update, as in `review delete`.
+    match crate::lock::ChangeLock::acquire(change_id) {
+        Ok(_change_lock) => {
+            if let Ok(manager) = StateManager::new() {
+                if let Ok(Some(mut state)) = manager.load(change_id) {
+                    for result in &results {
+                        if result.error.is_none() {
+                            state.mark_reopened(&result.repo.slug);
+                        }
+                    }
+                    if let Err(e) = manager.save(&state) {
+                        warn!("Failed to save change state after reopen: {e}");
+                    }
+                }
+            }
+        }
+        Err(e) => warn!("Failed to acquire change lock for {change_id}: {e}"),
+    }
+
+    let opts = StatusOptions {
+        verbosity: if cli.verbose {
+            local::config::OutputVerbosity::Detailed
+        } else {
+            local::config::OutputVerbosity::Summary
+        },
+        use_emoji: true,
+        use_colors: output::should_use_color(cli.color),
+        quiet: cli.quiet,
+        ..Default::default()
     };
 
     display_review_results(&results, &opts);
     display_review_summary(&results, &opts);
+    output::exit_on_errors(count_review_errors(&results), !cli.no_fail_on_error);
 
     Ok(())
 }
 
+/// The PRs `review reopen` acts on: closed without merging. Open PRs need
+/// nothing and a merged PR cannot be reopened.
+fn closed_prs(prs: &[PrInfo]) -> Vec<&PrInfo> {
+    prs.iter()
+        .filter(|pr| pr.state == github::PrState::Closed)
+        .collect()
+}
+
 /// Process review sync command - true-up recorded change state against
 /// GitHub PR reality (Phase 4 [F11], F14). Reconciles merged/closed PRs into
 /// `mark_merged`/`mark_closed` so `gx cleanup`/`gx rollback cleanup` see the
@@ -789,15 +1009,156 @@ pub(crate) fn sync_change_state(
     Ok((merged, closed, state.status.clone()))
 }
 
-/// Process review purge command - clean up all GX branches and PRs
+/// Process review status command - show a change's per-repo PR state and its
+/// aggregate merge progress. Recorded open/draft PRs are refreshed against
+/// GitHub first (one `gh pr view` each) and the updated state is saved; a PR
+/// whose lookup fails keeps its recorded status, so the command still renders
+/// offline.
+pub fn process_review_status_command(config: &Config, change_id: &str) -> Result<()> {
+    info!("Showing review status for change ID: {change_id}");
+
+    let state = {
+        // Held across load-refresh-save, like `sync_change_state`.
+        let _change_lock = crate::lock::ChangeLock::acquire(change_id)
+            .with_context(|| format!("Failed to acquire change lock for {change_id}"))?;
+        let manager = StateManager::new()?;
+        let mut state = manager
+            .load(change_id)?
+            .ok_or_else(|| eyre::eyre!("No change state recorded for {change_id}"))?;
+
+        let refreshed = refresh_pr_states(&mut state, |slug, number| {
+            github::get_pr_state(slug, number, config)
+        });
+        if refreshed > 0 {
+            manager.save(&state)?;
+        }
+        state
+    };
+
+    print!("{}", render_review_status(&state));
+    Ok(())
+}
+
+/// Refresh every recorded PR that is still open (or draft, or skipped by
+/// `approve`) from `lookup`, applying `mark_merged`/`mark_closed` or the live
+/// open/draft flag. Returns how many rows changed. Lookup failures are logged
+/// and leave the row as recorded.
+fn refresh_pr_states<F>(state: &mut crate::state::ChangeState, mut lookup: F) -> usize
+where
+    F: FnMut(&str, u64) -> Result<github::PrView>,
+{
+    use crate::state::RepoChangeStatus;
+
+    let live: Vec<(String, u64, Option<String>, RepoChangeStatus)> = state
+        .repositories
+        .values()
+        .filter(|r| {
+            matches!(
+                r.status,
+                RepoChangeStatus::PrOpen
+                    | RepoChangeStatus::PrDraft
+                    | RepoChangeStatus::Skipped { .. }
+            )
+        })
+        .filter_map(|r| {
+            r.pr_number
+                .map(|n| (r.repo_slug.clone(), n, r.pr_url.clone(), r.status.clone()))
+        })
+        .collect();
+
+    let mut changed = 0;
+    for (slug, number, url, before) in live {
+        let view = match lookup(&slug, number) {
+            Ok(view) => view,
+            Err(e) => {
+                warn!("Could not refresh PR #{number} in {slug}: {e}");
+                continue;
+            }
+        };
+        match view.state {
+            github::PrState::Merged => state.mark_merged(&slug),
+            github::PrState::Closed => state.mark_closed(&slug),
+            // A skipped PR that is still open keeps its skip reason.
+            github::PrState::Open if matches!(before, RepoChangeStatus::Skipped { .. }) => {}
+            github::PrState::Open => {
+                state.set_pr_info(&slug, number, url.unwrap_or_default(), view.is_draft)
+            }
+        }
+        if state.repositories[&slug].status != before {
+            changed += 1;
+        }
+    }
+    changed
+}
+
+/// Short per-repo label for `review status`.
+fn repo_status_label(status: &crate::state::RepoChangeStatus) -> &'static str {
+    use crate::state::RepoChangeStatus;
+    match status {
+        RepoChangeStatus::Proposed => "proposed",
+        RepoChangeStatus::BranchCreated => "no PR",
+        RepoChangeStatus::PrOpen => "open",
+        RepoChangeStatus::PrDraft => "draft",
+        RepoChangeStatus::PrMerged => "merged",
+        RepoChangeStatus::PrClosed => "closed",
+        RepoChangeStatus::RevertPrOpen => "revert PR open",
+        RepoChangeStatus::Reverted => "reverted",
+        RepoChangeStatus::Failed => "failed",
+        RepoChangeStatus::CleanedUp => "cleaned up",
+        RepoChangeStatus::Skipped { .. } => "skipped",
+    }
+}
+
+/// Render `review status`: one line per repo, then the aggregate progress
+/// line (`3/5 merged`) with the change's overall `ChangeStatus`.
+fn render_review_status(state: &crate::state::ChangeState) -> String {
+    use crate::state::RepoChangeStatus;
+
+    let mut out = format!("{}\n", state.change_id);
+    for repo in state.repositories.values() {
+        let pr = repo
+            .pr_number
+            .map(|n| format!(" PR #{n}"))
+            .unwrap_or_default();
+        out.push_str(&format!(
+            "  {:<8} {}{}\n",
+            repo_status_label(&repo.status),
+            repo.repo_slug,
+            pr
+        ));
+    }
+    let merged = state
+        .repositories
+        .values()
+        .filter(|r| r.status == RepoChangeStatus::PrMerged)
+        .count();
+    out.push_str(&format!(
+        "{merged}/{} merged (aggregate status: {:?})\n",
+        state.repositories.len(),
+        state.status
+    ));
+    out
+}
+
+/// Process review purge command - clean up all GX branches and PRs. With
+/// `merged_only`, only branches whose PR has merged are deleted.
 pub fn process_review_purge_command(
     cli: &Cli,
     config: &Config,
     org: Option<&str>,
     patterns: &[String],
     yes: bool,
+    merged_only: bool,
+    older_than: Option<&str>,
 ) -> Result<()> {
-    info!("Purging gx branches for org: {org:?}");
+    info!(
+        "Purging gx branches for org: {org:?} (merged_only={merged_only}, older_than={older_than:?})"
+    );
+    // Parse before any discovery or API call, so a typo fails fast.
+    let cutoff = older_than
+        .map(|s| local::utils::parse_duration(s).map(|age| chrono::Utc::now() - age))
+        .transpose()
+        .context("Invalid --older-than")?;
 
     // Discover repositories
     let current_dir = std::env::current_dir()?;
@@ -830,15 +1191,17 @@ pub fn process_review_purge_command(
 
     // Build the purge plan: per repo, the gx-created (GX-) branches with NO open
     // PR are deletable; branches that still have an open PR are refused ([A12], Q3).
+    // `--merged-only` further keeps every branch whose PR has not merged.
     let plan: Vec<PurgePlan> = pool.install(|| {
         filtered_repos
             .par_iter()
-            .map(|repo| build_purge_plan(repo, config))
+            .map(|repo| build_purge_plan(repo, config, merged_only, cutoff))
             .collect()
     });
 
     let total_deletable: usize = plan.iter().map(|p| p.to_delete.len()).sum();
     let total_blocked: usize = plan.iter().map(|p| p.blocked.len()).sum();
+    let total_recent: usize = plan.iter().map(|p| p.too_recent.len()).sum();
 
     // Show the resolved plan.
     println!("Purge plan:");
@@ -847,16 +1210,37 @@ pub fn process_review_purge_command(
             println!("  delete  {} {}", p.repo.slug, b);
         }
         for b in &p.blocked {
+            if merged_only {
+                println!("  keep    {} {} (PR not merged)", p.repo.slug, b);
+            } else {
+                println!(
+                    "  skip    {} {} (open PR; run `gx review delete` first)",
+                    p.repo.slug, b
+                );
+            }
+        }
+        for b in &p.too_recent {
             println!(
-                "  skip    {} {} (open PR; run `gx review delete` first)",
-                p.repo.slug, b
+                "  keep    {} {} (last commit newer than {})",
+                p.repo.slug,
+                b,
+                older_than.unwrap_or_default()
             );
         }
         if let Some(err) = &p.error {
             println!("  error   {}: {}", p.repo.slug, err);
         }
     }
-    println!("{total_deletable} branch(es) to delete, {total_blocked} skipped (open PR).");
+    if merged_only {
+        println!(
+            "{total_deletable} merged branch(es) to delete, {total_blocked} kept (open or unmerged PR)."
+        );
+    } else {
+        println!("{total_deletable} branch(es) to delete, {total_blocked} skipped (open PR).");
+    }
+    if let Some(older_than) = older_than {
+        println!("{total_recent} branch(es) kept: last commit newer than {older_than}.");
+    }
 
     if total_deletable == 0 {
         return Ok(());
@@ -868,10 +1252,10 @@ pub fn process_review_purge_command(
     }
 
     // Execute deletions in parallel.
-    let results: Vec<ReviewResult> = pool.install(|| {
+    let (results, deleted): (Vec<ReviewResult>, Vec<usize>) = pool.install(|| {
         plan.par_iter()
             .map(|p| purge_repo_branches(p, config))
-            .collect()
+            .unzip()
     });
 
     let opts = StatusOptions {
@@ -881,27 +1265,43 @@ pub fn process_review_purge_command(
             local::config::OutputVerbosity::Summary
         },
         use_emoji: true,
-        use_colors: true,
+        use_colors: output::should_use_color(cli.color),
+        quiet: cli.quiet,
+        ..Default::default()
     };
 
     display_review_results(&results, &opts);
     display_review_summary(&results, &opts);
+    if merged_only {
+        let deleted: usize = deleted.iter().sum();
+        println!("   {deleted} merged branch(es) deleted, {total_blocked} kept open");
+    }
+    output::exit_on_errors(count_review_errors(&results), !cli.no_fail_on_error);
 
     Ok(())
 }
 
 /// A per-repo purge plan: which gx branches can be deleted, which are blocked by
-/// an open PR, and any error gathering the lists.
+/// an open PR, which are too recent for `--older-than`, and any error gathering
+/// the lists.
 struct PurgePlan {
     repo: Repo,
     to_delete: Vec<String>,
     blocked: Vec<String>,
+    too_recent: Vec<String>,
     error: Option<String>,
 }
 
 /// Compute the purge plan for one repo: gx-created (`GX-`) branches partitioned
-/// into deletable (no open PR) vs. blocked (open PR).
-fn build_purge_plan(repo: &Repo, config: &Config) -> PurgePlan {
+/// into deletable (no open PR) vs. blocked (open PR). With `merged_only`, a
+/// branch is deletable only if its PR merged. With a `cutoff`, a deletable
+/// branch whose last commit is newer than it is kept.
+fn build_purge_plan(
+    repo: &Repo,
+    config: &Config,
+    merged_only: bool,
+    cutoff: Option<chrono::DateTime<chrono::Utc>>,
+) -> PurgePlan {
     let slug = &repo.slug;
     let branches = match github::list_branches_with_prefix(slug, "GX-", config) {
         Ok(b) => b,
@@ -910,6 +1310,7 @@ fn build_purge_plan(repo: &Repo, config: &Config) -> PurgePlan {
                 repo: repo.clone(),
                 to_delete: Vec::new(),
                 blocked: Vec::new(),
+                too_recent: Vec::new(),
                 error: Some(format!("Failed to list branches: {e}")),
             };
         }
@@ -921,23 +1322,96 @@ fn build_purge_plan(repo: &Repo, config: &Config) -> PurgePlan {
                 repo: repo.clone(),
                 to_delete: Vec::new(),
                 blocked: Vec::new(),
+                too_recent: Vec::new(),
                 error: Some(format!("Failed to list open PRs: {e}")),
             };
         }
     };
 
-    let (blocked, to_delete): (Vec<String>, Vec<String>) = branches
-        .into_iter()
-        .partition(|b| open_pr_branches.contains(b));
+    let merged_pr_branches = if merged_only {
+        match github::list_merged_pr_branches(slug, config) {
+            Ok(b) => Some(b),
+            Err(e) => {
+                return PurgePlan {
+                    repo: repo.clone(),
+                    to_delete: Vec::new(),
+                    blocked: Vec::new(),
+                    too_recent: Vec::new(),
+                    error: Some(format!("Failed to list merged PRs: {e}")),
+                };
+            }
+        }
+    } else {
+        None
+    };
+
+    let (to_delete, blocked) =
+        partition_purge_branches(branches, &open_pr_branches, merged_pr_branches.as_deref());
+
+    let (to_delete, too_recent) = match cutoff {
+        Some(cutoff) => match partition_by_age(to_delete, cutoff, |branch| {
+            github::branch_head_commit_date(slug, branch, config)
+        }) {
+            Ok(split) => split,
+            Err(e) => {
+                return PurgePlan {
+                    repo: repo.clone(),
+                    to_delete: Vec::new(),
+                    blocked,
+                    too_recent: Vec::new(),
+                    error: Some(format!("Failed to age branches: {e}")),
+                };
+            }
+        },
+        None => (to_delete, Vec::new()),
+    };
 
     PurgePlan {
         repo: repo.clone(),
         to_delete,
         blocked,
+        too_recent,
         error: None,
     }
 }
 
+/// Split branches into `(older, too_recent)` by the date `last_commit`
+/// reports for each against `cutoff`. Any lookup failure fails the whole
+/// split: an unknown age never makes a branch deletable.
+fn partition_by_age<F>(
+    branches: Vec<String>,
+    cutoff: chrono::DateTime<chrono::Utc>,
+    mut last_commit: F,
+) -> Result<(Vec<String>, Vec<String>)>
+where
+    F: FnMut(&str) -> Result<chrono::DateTime<chrono::Utc>>,
+{
+    let mut older = Vec::new();
+    let mut too_recent = Vec::new();
+    for branch in branches {
+        if last_commit(&branch)? < cutoff {
+            older.push(branch);
+        } else {
+            too_recent.push(branch);
+        }
+    }
+    Ok((older, too_recent))
+}
+
+/// Split a repo's gx branches into `(to_delete, blocked)`. A branch with an
+/// open PR is always blocked; when `merged` is given (`--merged-only`), a
+/// branch is also blocked unless its PR merged. A branch reused by a newer,
+/// still-open PR stays blocked even if an older PR on it merged.
+fn partition_purge_branches(
+    branches: Vec<String>,
+    open_pr_branches: &[String],
+    merged: Option<&[String]>,
+) -> (Vec<String>, Vec<String>) {
+    branches.into_iter().partition(|b| {
+        !open_pr_branches.contains(b) && merged.is_none_or(|merged| merged.contains(b))
+    })
+}
+
 /// Prompt for confirmation before purging. Fails closed on non-interactive stdin.
 fn confirm_purge(count: usize) -> Result<bool> {
     use std::io::{IsTerminal, Write};
@@ -956,17 +1430,19 @@ fn confirm_purge(count: usize) -> Result<bool> {
     Ok(answer == "y" || answer == "yes")
 }
 
-/// Delete the deletable branches in one repo's purge plan.
-fn purge_repo_branches(plan: &PurgePlan, config: &Config) -> ReviewResult {
+/// Delete the deletable branches in one repo's purge plan, returning the
+/// result and how many branches were actually deleted.
+fn purge_repo_branches(plan: &PurgePlan, config: &Config) -> (ReviewResult, usize) {
     let repo = plan.repo.clone();
     if let Some(err) = &plan.error {
-        return ReviewResult {
+        let result = ReviewResult {
             repo,
             change_id: "PURGE".to_string(),
             pr_number: None,
             action: ReviewAction::Purged,
             error: Some(err.clone()),
         };
+        return (result, 0);
     }
 
     let mut errors = Vec::new();
@@ -979,7 +1455,7 @@ fn purge_repo_branches(plan: &PurgePlan, config: &Config) -> ReviewResult {
     }
     info!("Purged {} gx branches from {}", deleted, plan.repo.slug);
 
-    ReviewResult {
+    let result = ReviewResult {
         repo,
         change_id: "PURGE".to_string(),
         pr_number: None,
@@ -989,7 +1465,8 @@ fn purge_repo_branches(plan: &PurgePlan, config: &Config) -> ReviewResult {
         } else {
             Some(errors.join("; "))
         },
-    }
+    };
+    (result, deleted)
 }
 
 /// Clone a repository for a specific PR
@@ -1166,6 +1643,61 @@ fn delete_pr_and_branch(pr: &PrInfo, change_id: &str, config: &Config) -> Review
     }
 }
 
+/// Reopen one closed PR. GitHub refuses to reopen a PR whose head branch is
+/// gone, so a branch missing from the remote is first pushed again from
+/// `local_path` (the clone the change state recorded) when it has it.
+fn reopen_pr_and_branch(
+    pr: &PrInfo,
+    change_id: &str,
+    local_path: Option<&Path>,
+    config: &Config,
+) -> ReviewResult {
+    let repo = create_repo_from_slug(&pr.repo_slug);
+    let result = |error: Option<String>| ReviewResult {
+        repo: repo.clone(),
+        change_id: change_id.to_string(),
+        pr_number: Some(pr.number),
+        action: ReviewAction::Reopened,
+        error,
+    };
+
+    if let Some(path) = local_path.filter(|path| path.exists()) {
+        if let Err(e) = restore_remote_branch(path, &pr.branch) {
+            warn!(
+                "Failed to re-push branch {} for PR #{}: {e}",
+                pr.branch, pr.number
+            );
+            return result(Some(format!("Failed to re-push branch {}: {e}", pr.branch)));
+        }
+    }
+
+    match github::reopen_pr(&pr.repo_slug, pr.number, config) {
+        Ok(()) => result(None),
+        Err(e) => {
+            warn!("Failed to reopen PR #{}: {}", pr.number, e);
+            result(Some(format!("Failed to reopen PR: {e}")))
+        }
+    }
+}
+
+/// Push `branch` from `repo_path` if the remote no longer has it and the
+/// local clone does. Returns whether it pushed.
+fn restore_remote_branch(repo_path: &Path, branch: &str) -> Result<bool> {
+    if git::remote_branch_exists_probe(repo_path, branch)? {
+        return Ok(false);
+    }
+    if !local::git::branch_exists_locally(repo_path, branch)? {
+        debug!(
+            "restore_remote_branch: {branch} is on neither the remote nor {}",
+            repo_path.display()
+        );
+        return Ok(false);
+    }
+    git::push_branch(repo_path, branch, false)?;
+    info!("Re-pushed branch {branch} from {}", repo_path.display());
+    Ok(true)
+}
+
 /// Create a pseudo-repo from a repository slug
 fn create_repo_from_slug(repo_slug: &str) -> Repo {
     Repo::from_slug(repo_slug.to_string())
@@ -1180,6 +1712,21 @@ fn extract_repo_name(repo_slug: &str) -> String {
         .to_string()
 }
 
+/// Parallel jobs for a review run: `--parallel`, else the org's `jobs` when
+/// the run targets a single org, else the global `jobs`, else one per CPU.
+fn review_jobs(cli: &Cli, config: &Config, user_org_contexts: &[UserOrgContext]) -> usize {
+    let org_jobs = match user_org_contexts {
+        [context] => config.for_org(&context.user_or_org).jobs,
+        _ => local::utils::get_jobs_from_config(config),
+    };
+    cli.parallel.or(org_jobs).unwrap_or_else(num_cpus::get)
+}
+
+/// Count review results carrying an error.
+fn count_review_errors(results: &[ReviewResult]) -> usize {
+    results.iter().filter(|r| r.error.is_some()).count()
+}
+
 /// Display summary of review results
 fn display_review_summary(results: &[ReviewResult], opts: &StatusOptions) {
     let total = results.len();
@@ -1202,6 +1749,10 @@ fn display_review_summary(results: &[ReviewResult], opts: &StatusOptions) {
         .iter()
         .filter(|r| matches!(r.action, ReviewAction::Deleted))
         .count();
+    let reopened = results
+        .iter()
+        .filter(|r| matches!(r.action, ReviewAction::Reopened))
+        .count();
     let purged = results
         .iter()
         .filter(|r| matches!(r.action, ReviewAction::Purged))
@@ -1221,6 +1772,9 @@ fn display_review_summary(results: &[ReviewResult], opts: &StatusOptions) {
         if deleted > 0 {
             println!("   ❌ {deleted} PRs deleted");
         }
+        if reopened > 0 {
+            println!("   🔓 {reopened} PRs reopened");
+        }
         if purged > 0 {
             println!("   🧹 {purged} repositories purged");
         }
@@ -1241,6 +1795,9 @@ fn display_review_summary(results: &[ReviewResult], opts: &StatusOptions) {
         if deleted > 0 {
             println!("   {deleted} PRs deleted");
         }
+        if reopened > 0 {
+            println!("   {reopened} PRs reopened");
+        }
         if purged > 0 {
             println!("   {purged} repositories purged");
         }
@@ -1286,6 +1843,202 @@ mod tests {
         assert_eq!(extract_repo_name(""), "");
     }
 
+    /// A change with one repo in each state `review status` distinguishes.
+    fn mixed_state() -> ChangeState {
+        let mut state = ChangeState::new("GX-status".to_string(), None);
+        for (i, slug) in ["org/a", "org/b", "org/c", "org/d", "org/e"]
+            .iter()
+            .enumerate()
+        {
+            state.add_repository(slug.to_string(), "GX-status".to_string());
+            state.set_pr_info(
+                slug,
+                i as u64 + 1,
+                format!("https://github.com/{slug}/pull/{}", i + 1),
+                *slug == "org/c",
+            );
+        }
+        state.mark_merged("org/a");
+        state.mark_merged("org/b");
+        state.mark_closed("org/e");
+        state
+    }
+
+    #[test]
+    fn test_render_review_status_aggregate_line() {
+        let out = render_review_status(&mixed_state());
+        let lines: Vec<&str> = out.lines().collect();
+
+        assert_eq!(lines[0], "GX-status");
+        assert_eq!(lines[1], "  merged   org/a PR #1");
+        assert_eq!(lines[3], "  draft    org/c PR #3");
+        assert_eq!(lines[4], "  open     org/d PR #4");
+        assert_eq!(lines[5], "  closed   org/e PR #5");
+        assert_eq!(
+            lines.last().copied(),
+            Some("2/5 merged (aggregate status: PartiallyMerged)")
+        );
+    }
+
+    #[test]
+    fn test_refresh_pr_states_applies_live_states() {
+        let mut state = mixed_state();
+        let mut looked_up = Vec::new();
+        let changed = refresh_pr_states(&mut state, |slug, _| {
+            looked_up.push(slug.to_string());
+            match slug {
+                "org/c" => Ok(github::PrView {
+                    state: github::PrState::Merged,
+                    is_draft: false,
+                }),
+                "org/d" => Err(eyre::eyre!("gh unavailable")),
+                other => panic!("refreshed a settled PR: {other}"),
+            }
+        });
+
+        // Only the open/draft rows are looked up; the failed lookup is kept.
+        assert_eq!(looked_up, ["org/c", "org/d"]);
+        assert_eq!(changed, 1);
+        assert_eq!(
+            state.repositories["org/c"].status,
+            RepoChangeStatus::PrMerged
+        );
+        assert_eq!(state.repositories["org/d"].status, RepoChangeStatus::PrOpen);
+        assert!(render_review_status(&state)
+            .ends_with("3/5 merged (aggregate status: PartiallyMerged)\n"));
+    }
+
+    #[test]
+    fn test_filter_prs_by_state_and_author() {
+        let pr = |number, author: &str, state| PrInfo {
+            repo_slug: format!("tatari-tv/repo-{number}"),
+            number,
+            title: "Bump version".to_string(),
+            branch: "GX-2026-07-12".to_string(),
+            author: author.to_string(),
+            state,
+            url: format!("https://github.com/tatari-tv/repo-{number}/pull/{number}"),
+            merged_at: None,
+            merge_commit_oid: None,
+            base_ref_name: "main".to_string(),
+            mergeable: github::Mergeability::Unknown,
+        };
+        let mixed = vec![
+            pr(1, "octocat", github::PrState::Open),
+            pr(2, "hubot", github::PrState::Open),
+            pr(3, "octocat", github::PrState::Merged),
+            pr(4, "hubot", github::PrState::Closed),
+            pr(5, "octocat", github::PrState::Closed),
+        ];
+        let numbers = |state, author| -> Vec<u64> {
+            PrListFilter {
+                state,
+                author,
+                ..Default::default()
+            }
+            .keep(mixed.clone())
+            .iter()
+            .map(|pr| pr.number)
+            .collect()
+        };
+
+        assert_eq!(numbers(PrStateFilter::Open, None), [1, 2]);
+        assert_eq!(numbers(PrStateFilter::Closed, None), [3, 4, 5]);
+        assert_eq!(numbers(PrStateFilter::All, None), [1, 2, 3, 4, 5]);
+        assert_eq!(numbers(PrStateFilter::All, Some("octocat")), [1, 3, 5]);
+        assert_eq!(numbers(PrStateFilter::Open, Some("HUBOT")), [2]);
+        assert_eq!(numbers(PrStateFilter::Closed, Some("octocat")), [3, 5]);
+        assert!(numbers(PrStateFilter::Open, Some("nobody")).is_empty());
+    }
+
+    #[test]
+    fn test_render_pr_listing_json_round_trips_fields() {
+        let pr = |number, branch: &str, state| PrInfo {
+            repo_slug: "tatari-tv/frontend".to_string(),
+            number,
+            title: "Bump version".to_string(),
+            branch: branch.to_string(),
+            author: "octocat".to_string(),
+            state,
+            url: format!("https://github.com/tatari-tv/frontend/pull/{number}"),
+            merged_at: None,
+            merge_commit_oid: None,
+            base_ref_name: "main".to_string(),
+            mergeable: github::Mergeability::Unknown,
+        };
+        // What `list_prs_by_change_id` would hand back for `GX-2026`.
+        let prs = vec![
+            pr(7, "GX-2026-07-12", github::PrState::Open),
+            pr(9, "GX-2026-07-13", github::PrState::Merged),
+        ];
+
+        let json = render_pr_listing_json(&prs).unwrap();
+        let rows: Vec<serde_json::Value> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(
+            rows[0],
+            serde_json::json!({
+                "change_id": "GX-2026-07-12",
+                "repo_slug": "tatari-tv/frontend",
+                "pr_number": 7,
+                "branch": "GX-2026-07-12",
+                "author": "octocat",
+                "state": "open",
+                "url": "https://github.com/tatari-tv/frontend/pull/7",
+                "title": "Bump version",
+            })
+        );
+        assert_eq!(rows[1]["state"], "merged");
+        assert_eq!(rows[1]["pr_number"], 9);
+        assert_eq!(render_pr_listing_json(&[]).unwrap(), "[]");
+    }
+
+    #[test]
+    fn test_partition_by_age_keeps_recent_and_fails_closed() {
+        let now = chrono::Utc::now();
+        let cutoff = now - local::utils::parse_duration("7d").unwrap();
+        let age = |branch: &str| -> Result<chrono::DateTime<chrono::Utc>> {
+            match branch {
+                "GX-old" => Ok(now - chrono::Duration::days(30)),
+                "GX-new" => Ok(now - chrono::Duration::days(1)),
+                _ => Err(eyre::eyre!("no such branch")),
+            }
+        };
+
+        let (older, recent) =
+            partition_by_age(vec!["GX-old".into(), "GX-new".into()], cutoff, age).unwrap();
+        assert_eq!(older, ["GX-old"]);
+        assert_eq!(recent, ["GX-new"]);
+
+        assert!(partition_by_age(vec!["GX-old".into(), "GX-gone".into()], cutoff, age).is_err());
+    }
+
+    #[test]
+    fn test_partition_purge_branches_all_vs_merged_only() {
+        let branches = || {
+            vec![
+                "GX-merged".to_string(),
+                "GX-open".to_string(),
+                "GX-abandoned".to_string(),
+                "GX-reopened".to_string(),
+            ]
+        };
+        let open = vec!["GX-open".to_string(), "GX-reopened".to_string()];
+        let merged = vec!["GX-merged".to_string(), "GX-reopened".to_string()];
+
+        // Default purge: everything without an open PR goes.
+        let (to_delete, blocked) = partition_purge_branches(branches(), &open, None);
+        assert_eq!(to_delete, ["GX-merged", "GX-abandoned"]);
+        assert_eq!(blocked, ["GX-open", "GX-reopened"]);
+
+        // --merged-only: the abandoned (closed, unmerged) branch is kept, and
+        // an open PR still wins over an older merged one.
+        let (to_delete, blocked) = partition_purge_branches(branches(), &open, Some(&merged));
+        assert_eq!(to_delete, ["GX-merged"]);
+        assert_eq!(blocked, ["GX-open", "GX-abandoned", "GX-reopened"]);
+    }
+
     #[test]
     fn test_create_repo_from_slug() {
         let repo = create_repo_from_slug("owner/test-repo");
@@ -1905,4 +2658,97 @@ exit 0
         }
         drop(guard);
     }
+
+    /// A gh spy shim for `review reopen`: discovery returns one open, one
+    /// closed and one merged PR; every other call is logged to
+    /// `$GX_TEST_MUTATION_LOG` and succeeds.
+    const GH_REOPEN_SPY_SHIM: &str = r#"#!/bin/sh
+if [ "$1" = "api" ] && [ "$2" = "graphql" ]; then
+cat <<'JSON'
+{"data":{"search":{"pageInfo":{"hasNextPage":false,"endCursor":null},"nodes":[
+{"number": 21, "title": "GX-reopen-shim: open", "headRefName": "GX-reopen-shim",
+ "author": {"login": "tester"}, "state": "OPEN",
+ "url": "https://github.com/gx-testing/open/pull/21",
+ "repository": {"nameWithOwner": "gx-testing/open"},
+ "mergedAt": null, "mergeCommit": null, "baseRefName": "main", "mergeable": "MERGEABLE"},
+{"number": 22, "title": "GX-reopen-shim: closed", "headRefName": "GX-reopen-shim",
+ "author": {"login": "tester"}, "state": "CLOSED",
+ "url": "https://github.com/gx-testing/closed/pull/22",
+ "repository": {"nameWithOwner": "gx-testing/closed"},
+ "mergedAt": null, "mergeCommit": null, "baseRefName": "main", "mergeable": "UNKNOWN"},
+{"number": 23, "title": "GX-reopen-shim: merged", "headRefName": "GX-reopen-shim",
+ "author": {"login": "tester"}, "state": "MERGED",
+ "url": "https://github.com/gx-testing/merged/pull/23",
+ "repository": {"nameWithOwner": "gx-testing/merged"},
+ "mergedAt": "2026-01-01T00:00:00Z", "mergeCommit": {"oid": "abc"}, "baseRefName": "main", "mergeable": "UNKNOWN"}
+]}}}
+JSON
+  exit 0
+fi
+echo "$@" >> "$GX_TEST_MUTATION_LOG"
+exit 0
+"#;
+
+    /// `review reopen` runs `gh pr reopen <n> --repo <slug>` for the closed PR
+    /// only: the open PR needs nothing and the merged one cannot be reopened.
+    #[test]
+    fn test_review_reopen_reopens_only_closed_prs() {
+        use clap::Parser;
+        let guard = local::test_utils::env_lock();
+        let prior_path = std::env::var("PATH").ok();
+        let prior_tok = std::env::var("GITHUB_PAT_HOME").ok();
+        let prior_mut = std::env::var("GX_TEST_MUTATION_LOG").ok();
+        let prior_data_home = std::env::var("XDG_DATA_HOME").ok();
+
+        let shim_dir = TempDir::new().unwrap();
+        install_shim(shim_dir.path(), GH_REOPEN_SPY_SHIM);
+        let new_path = format!(
+            "{}:{}",
+            shim_dir.path().display(),
+            prior_path.clone().unwrap_or_default()
+        );
+        unsafe { std::env::set_var("PATH", &new_path) };
+        unsafe { std::env::set_var("GITHUB_PAT_HOME", "dummy-token-not-a-secret") };
+        let mut_log = shim_dir.path().join("mutations.log");
+        unsafe { std::env::set_var("GX_TEST_MUTATION_LOG", &mut_log) };
+        let data_home = TempDir::new().unwrap();
+        unsafe { std::env::set_var("XDG_DATA_HOME", data_home.path()) };
+
+        let work = TempDir::new().unwrap();
+        let cwd = work.path().to_string_lossy().to_string();
+        let cli = Cli::parse_from(["gx", "--cwd", &cwd, "review", "reopen", "GX-reopen-shim"]);
+        let result = process_review_reopen_command(
+            &cli,
+            &Config::default(),
+            Some("gx-testing"),
+            "GX-reopen-shim",
+        );
+
+        let log = std::fs::read_to_string(&mut_log).unwrap_or_default();
+
+        match prior_path {
+            Some(v) => unsafe { std::env::set_var("PATH", v) },
+            None => unsafe { std::env::remove_var("PATH") },
+        }
+        match prior_tok {
+            Some(v) => unsafe { std::env::set_var("GITHUB_PAT_HOME", v) },
+            None => unsafe { std::env::remove_var("GITHUB_PAT_HOME") },
+        }
+        match prior_mut {
+            Some(v) => unsafe { std::env::set_var("GX_TEST_MUTATION_LOG", v) },
+            None => unsafe { std::env::remove_var("GX_TEST_MUTATION_LOG") },
+        }
+        match prior_data_home {
+            Some(v) => unsafe { std::env::set_var("XDG_DATA_HOME", v) },
+            None => unsafe { std::env::remove_var("XDG_DATA_HOME") },
+        }
+        drop(guard);
+
+        assert!(result.is_ok(), "{result:?}");
+        assert_eq!(
+            log.lines().collect::<Vec<_>>(),
+            ["pr reopen 22 --repo gx-testing/closed"],
+            "only the closed PR may be reopened"
+        );
+    }
 }
@@ -21,6 +21,7 @@ fn step_kind(step: &crate::transaction::RollbackStep) -> &'static str {
         ResetCommit { .. } => "reset-commit",
         RestoreBackup { .. } => "restore-backup",
         RemoveCreatedFile { .. } => "remove-created-file",
+        DeleteLocalTag { .. } => "delete-local-tag",
     }
 }
 
@@ -360,7 +361,7 @@ fn cleanup_recovery_states(
     let mut states_to_clean = Vec::new();
 
     if let Some(ref duration_str) = older_than {
-        let cutoff_duration = parse_duration(duration_str)?;
+        let cutoff_duration = local::utils::parse_duration(duration_str)?;
         let cutoff_time = Utc::now() - cutoff_duration;
 
         for state in &states {
@@ -439,7 +440,7 @@ fn cleanup_recovery_states(
 
     // Also clean up old change states if a duration was specified
     if let Some(duration_str) = older_than.as_ref() {
-        if let Ok(cutoff_duration) = parse_duration(duration_str) {
+        if let Ok(cutoff_duration) = local::utils::parse_duration(duration_str) {
             let days = cutoff_duration.num_days() as u64;
             if days > 0 {
                 if let Ok(state_manager) = StateManager::new() {
@@ -478,35 +479,6 @@ fn format_duration(duration: Duration) -> String {
     }
 }
 
-/// Parse duration string (e.g., "7d", "24h", "30m")
-fn parse_duration(duration_str: &str) -> Result<Duration> {
-    if duration_str.is_empty() {
-        return Err(eyre::eyre!("Duration string cannot be empty"));
-    }
-
-    let (number_part, unit_part) =
-        if let Some(pos) = duration_str.chars().position(|c| c.is_alphabetic()) {
-            duration_str.split_at(pos)
-        } else {
-            return Err(eyre::eyre!("Duration must include a unit (d, h, m, s)"));
-        };
-
-    let number: i64 = number_part
-        .parse()
-        .map_err(|_| eyre::eyre!("Invalid number in duration: {}", number_part))?;
-
-    match unit_part.to_lowercase().as_str() {
-        "s" | "sec" | "second" | "seconds" => Ok(Duration::seconds(number)),
-        "m" | "min" | "minute" | "minutes" => Ok(Duration::minutes(number)),
-        "h" | "hr" | "hour" | "hours" => Ok(Duration::hours(number)),
-        "d" | "day" | "days" => Ok(Duration::days(number)),
-        _ => Err(eyre::eyre!(
-            "Invalid duration unit: {}. Use s, m, h, or d",
-            unit_part
-        )),
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -518,16 +490,4 @@ mod tests {
         assert_eq!(format_duration(Duration::hours(2)), "2h");
         assert_eq!(format_duration(Duration::days(3)), "3d");
     }
-
-    #[test]
-    fn test_parse_duration() {
-        assert_eq!(parse_duration("30s").unwrap(), Duration::seconds(30));
-        assert_eq!(parse_duration("5m").unwrap(), Duration::minutes(5));
-        assert_eq!(parse_duration("2h").unwrap(), Duration::hours(2));
-        assert_eq!(parse_duration("7d").unwrap(), Duration::days(7));
-
-        assert!(parse_duration("").is_err());
-        assert!(parse_duration("30").is_err());
-        assert!(parse_duration("30x").is_err());
-    }
 }
@@ -28,6 +28,8 @@ fn write_mutating_recovery(data_home: &Path, tx_id: &str, path: &Path) {
         created_at: "2026-07-12T00:00:00Z".to_string(),
         phase: Phase::Mutating,
         branch: None,
+        existing_branch: false,
+        commit_sha: None,
         steps: vec![StepEntry::pending(
             crate::transaction::RollbackStep::RemoveCreatedFile {
                 path: path.to_path_buf(),
@@ -89,6 +91,8 @@ fn test_validate_recovery_state_flags_missing_repo_path() {
         created_at: "2026-07-12T00:00:00Z".to_string(),
         phase: Phase::Mutating,
         branch: None,
+        existing_branch: false,
+        commit_sha: None,
         steps: vec![],
     };
 
@@ -111,6 +115,8 @@ fn test_validate_recovery_state_passes_for_real_git_repo() {
         created_at: "2026-07-12T00:00:00Z".to_string(),
         phase: Phase::Mutating,
         branch: None,
+        existing_branch: false,
+        commit_sha: None,
         steps: vec![StepEntry::pending(
             crate::transaction::RollbackStep::RemoveCreatedFile {
                 path: repo.path().join("whatever.txt"),
@@ -1,31 +1,60 @@
 use eyre::{Context, Result};
-use local::subprocess::{run_checked, subprocess_timeout};
+use local::config::CloneProtocol;
 use log::debug;
 use std::process::Command;
 
+/// Validate a repository slug: exactly `org/repo`, both parts non-empty.
+fn validate_slug(repo_slug: &str) -> Result<()> {
+    let parts: Vec<&str> = repo_slug.split('/').collect();
+    if parts.len() != 2 {
+        return Err(eyre::eyre!(
+            "Invalid repository slug format. Expected 'org/repo', got '{}'",
+            repo_slug
+        ));
+    }
+
+    if parts[0].is_empty() || parts[1].is_empty() {
+        return Err(eyre::eyre!(
+            "Repository slug parts cannot be empty: '{}'",
+            repo_slug
+        ));
+    }
+
+    Ok(())
+}
+
+/// Clone URL for a slug under either protocol.
+pub struct RemoteUrlBuilder;
+
+impl RemoteUrlBuilder {
+    /// Convert repository slug to a clone URL in `protocol`'s scheme
+    pub fn build_url(repo_slug: &str, protocol: CloneProtocol) -> Result<String> {
+        match protocol {
+            CloneProtocol::Ssh => SshUrlBuilder::build_ssh_url(repo_slug),
+            CloneProtocol::Https => HttpsUrlBuilder::build_https_url(repo_slug),
+        }
+    }
+}
+
+/// HTTPS URL construction. Authentication is left to git's credential helper
+/// (e.g. `gh auth setup-git`); no token is ever embedded in the URL.
+pub struct HttpsUrlBuilder;
+
+impl HttpsUrlBuilder {
+    /// Convert repository slug to HTTPS URL
+    pub fn build_https_url(repo_slug: &str) -> Result<String> {
+        validate_slug(repo_slug)?;
+        Ok(format!("https://github.com/{repo_slug}.git"))
+    }
+}
+
 /// SSH URL construction and validation
 pub struct SshUrlBuilder;
 
 impl SshUrlBuilder {
     /// Convert repository slug to SSH URL
     pub fn build_ssh_url(repo_slug: &str) -> Result<String> {
-        // Validate repo slug format (should be "org/repo")
-        let parts: Vec<&str> = repo_slug.split('/').collect();
-        if parts.len() != 2 {
-            return Err(eyre::eyre!(
-                "Invalid repository slug format. Expected 'org/repo', got '{}'",
-                repo_slug
-            ));
-        }
-
-        // Validate parts are not empty
-        if parts[0].is_empty() || parts[1].is_empty() {
-            return Err(eyre::eyre!(
-                "Repository slug parts cannot be empty: '{}'",
-                repo_slug
-            ));
-        }
-
+        validate_slug(repo_slug)?;
         Ok(format!("git@github.com:{repo_slug}.git"))
     }
 
@@ -67,25 +96,9 @@ impl SshUrlBuilder {
 pub struct SshCommandDetector;
 
 impl SshCommandDetector {
-    /// Get SSH command from git configuration
+    /// Get SSH command from `$GIT_SSH_COMMAND` or git configuration
     pub fn get_ssh_command() -> Result<String> {
-        let output = run_checked(
-            Command::new("git").args(["config", "--get", "core.sshCommand"]),
-            subprocess_timeout(),
-        )
-        .context("Failed to execute git config command")?;
-
-        if output.status.success() {
-            let ssh_command = String::from_utf8_lossy(&output.stdout).trim().to_string();
-            if !ssh_command.is_empty() {
-                debug!("Using SSH command from git config: {ssh_command}");
-                return Ok(ssh_command);
-            }
-        }
-
-        // Fall back to default SSH
-        debug!("Using default SSH command");
-        Ok("ssh".to_string())
+        Ok(local::git::configured_ssh_command(None))
     }
 
     /// Test SSH connectivity to GitHub
@@ -202,6 +215,28 @@ mod tests {
             .contains("Invalid repository slug format"));
     }
 
+    #[test]
+    fn test_build_https_url() {
+        assert_eq!(
+            HttpsUrlBuilder::build_https_url("scottidler/gx").unwrap(),
+            "https://github.com/scottidler/gx.git"
+        );
+        assert!(HttpsUrlBuilder::build_https_url("invalid").is_err());
+        assert!(HttpsUrlBuilder::build_https_url("org/").is_err());
+    }
+
+    #[test]
+    fn test_remote_url_builder_follows_protocol() {
+        assert_eq!(
+            RemoteUrlBuilder::build_url("tatari-tv/api", CloneProtocol::Ssh).unwrap(),
+            "git@github.com:tatari-tv/api.git"
+        );
+        assert_eq!(
+            RemoteUrlBuilder::build_url("tatari-tv/api", CloneProtocol::Https).unwrap(),
+            "https://github.com/tatari-tv/api.git"
+        );
+    }
+
     #[test]
     fn test_validate_ssh_url_valid() {
         let result = SshUrlBuilder::validate_ssh_url("git@github.com:scottidler/gx.git");
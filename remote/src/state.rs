@@ -19,10 +19,12 @@ use std::path::PathBuf;
 /// addendum #3): the new `ChangeStatus::Proposed` aggregate variant. Bumped
 /// 3 -> 4 for the production-hardening doc Phase 4: the new
 /// `RepoChangeStatus::Skipped { reason }` variant (`review approve` skips a
-/// non-mergeable PR). Combined with `deny_unknown_fields`, an OLDER gx reading
-/// a state file that carries a newer variant fails loudly on the unknown enum
-/// variant (fail closed, correct) rather than silently mis-loading it.
-const CHANGE_STATE_VERSION: u32 = 4;
+/// non-mergeable PR). Bumped 4 -> 5 for `gx create revert`: the new
+/// `RepoChangeStatus::Reverted` variant. Combined with `deny_unknown_fields`,
+/// an OLDER gx reading a state file that carries a newer variant fails loudly
+/// on the unknown enum variant (fail closed, correct) rather than silently
+/// mis-loading it.
+const CHANGE_STATE_VERSION: u32 = 5;
 
 /// Default `version` for a change state file that predates the field (serde
 /// fills this in for version-less files written by an older gx), matching
@@ -73,9 +75,29 @@ pub struct RepoChangeState {
     /// Local path to the repository
     pub local_path: Option<String>,
 
-    /// Branch name created for this change
+    /// Branch the change was committed on: the one created for it, or the
+    /// user's own branch under `--target-existing-branch`
     pub branch_name: String,
 
+    /// `branch_name` is the user's own branch, committed onto in place: undo
+    /// reverts `commit_sha` on it and never deletes it
+    #[serde(default)]
+    pub existing_branch: bool,
+
+    /// The commit gx made on `branch_name`
+    #[serde(default)]
+    pub commit_sha: Option<String>,
+
+    /// The tag gx created on that commit (`create --tag`); undo deletes it
+    /// from the remote and locally
+    #[serde(default)]
+    pub tag: Option<String>,
+
+    /// The `<change-id>-revert` branch `gx create revert --force` pushed the
+    /// revert of a merged change to; undo and cleanup delete it
+    #[serde(default)]
+    pub revert_branch: Option<String>,
+
     /// Original branch before the change
     pub original_branch: Option<String>,
 
@@ -145,6 +167,10 @@ pub enum RepoChangeStatus {
     /// Phase 6 [F4]): the merged work is reversed via a revert PR, never by
     /// touching the base branch. This is the terminal undo state for a merged row.
     RevertPrOpen,
+    /// `gx create revert` reverted the change's commits and pushed the result
+    /// (onto the change branch, or a `<change-id>-revert` branch when merged).
+    /// Recorded so a second revert never reverts the revert.
+    Reverted,
     /// Operation failed
     Failed,
     /// Local branch cleaned up
@@ -180,6 +206,10 @@ impl ChangeState {
             repo_slug: repo_slug.clone(),
             local_path: None,
             branch_name,
+            existing_branch: false,
+            commit_sha: None,
+            tag: None,
+            revert_branch: None,
             original_branch: None,
             base_sha: None,
             pr_number: None,
@@ -248,6 +278,15 @@ impl ChangeState {
         }
     }
 
+    /// Mark a closed PR as open again (`gx review reopen`).
+    pub fn mark_reopened(&mut self, repo_slug: &str) {
+        if let Some(repo) = self.repositories.get_mut(repo_slug) {
+            repo.status = RepoChangeStatus::PrOpen;
+            self.updated_at = Utc::now();
+            self.update_overall_status();
+        }
+    }
+
     /// Mark a repository's PR as SKIPPED by `review approve` because it was not
     /// proven-mergeable (production-hardening doc, Phase 4). The PR stays open
     /// on GitHub; this records that gx deliberately did NOT merge it (and why),
@@ -270,6 +309,17 @@ impl ChangeState {
         }
     }
 
+    /// Mark a repository's change as reverted by `gx create revert`, recording
+    /// the separate branch the revert was pushed to, if any.
+    pub fn mark_reverted(&mut self, repo_slug: &str, revert_branch: Option<String>) {
+        if let Some(repo) = self.repositories.get_mut(repo_slug) {
+            repo.status = RepoChangeStatus::Reverted;
+            repo.revert_branch = revert_branch;
+            repo.error = None;
+            self.updated_at = Utc::now();
+        }
+    }
+
     /// Mark a repository as cleaned up
     pub fn mark_cleaned_up(&mut self, repo_slug: &str) {
         if let Some(repo) = self.repositories.get_mut(repo_slug) {
@@ -352,7 +402,9 @@ impl ChangeState {
         self.repositories
             .values()
             .filter(|r| {
-                r.status == RepoChangeStatus::PrMerged || r.status == RepoChangeStatus::PrClosed
+                r.status == RepoChangeStatus::PrMerged
+                    || r.status == RepoChangeStatus::PrClosed
+                    || (r.status == RepoChangeStatus::Reverted && r.revert_branch.is_some())
             })
             .filter(|r| r.status != RepoChangeStatus::CleanedUp)
             .collect()
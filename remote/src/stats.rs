@@ -0,0 +1,122 @@
+//! `--stats`: where a parallel run's wall time went.
+//!
+//! A [`RunStats`] is shared by reference with the rayon workers: the command
+//! times its fleet-level phases (discovery, filtering, ...) with
+//! [`RunStats::phase`], each worker times its repo with [`RunStats::repo`],
+//! and the command prints [`RunStats::render`] on stderr once the run is
+//! done. Timing is always collected (one `Instant` and a short lock per
+//! repo); only the printing is behind the flag.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// How many of the slowest repos the summary lists.
+pub const SLOWEST: usize = 5;
+
+/// Wall-clock timings for one gx run.
+#[derive(Debug)]
+pub struct RunStats {
+    started: Instant,
+    /// Fleet-level phases, in the order they ran.
+    phases: Mutex<Vec<(&'static str, Duration)>>,
+    /// Per-repo operation times, in completion order.
+    repos: Mutex<Vec<(String, Duration)>>,
+}
+
+impl Default for RunStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RunStats {
+    /// Start the run's wall clock now.
+    pub fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            phases: Mutex::new(Vec::new()),
+            repos: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Run `f` and record its duration as the phase `name`.
+    pub fn phase<T>(&self, name: &'static str, f: impl FnOnce() -> T) -> T {
+        let started = Instant::now();
+        let value = f();
+        self.record_phase(name, started.elapsed());
+        value
+    }
+
+    /// Run `f` and record its duration against `slug`.
+    pub fn repo<T>(&self, slug: &str, f: impl FnOnce() -> T) -> T {
+        let started = Instant::now();
+        let value = f();
+        self.record_repo(slug, started.elapsed());
+        value
+    }
+
+    pub fn record_phase(&self, name: &'static str, duration: Duration) {
+        self.phases
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push((name, duration));
+    }
+
+    pub fn record_repo(&self, slug: &str, duration: Duration) {
+        self.repos
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push((slug.to_string(), duration));
+    }
+
+    /// The `n` slowest repos, slowest first; ties keep completion order.
+    pub fn slowest(&self, n: usize) -> Vec<(String, Duration)> {
+        let mut repos = self.repos.lock().unwrap_or_else(|e| e.into_inner()).clone();
+        repos.sort_by(|a, b| b.1.cmp(&a.1));
+        repos.truncate(n);
+        repos
+    }
+
+    /// Mean per-repo time; `None` before any repo is recorded.
+    pub fn average(&self) -> Option<Duration> {
+        let repos = self.repos.lock().unwrap_or_else(|e| e.into_inner());
+        let total: Duration = repos.iter().map(|(_, d)| *d).sum();
+        u32::try_from(repos.len())
+            .ok()
+            .filter(|&count| count > 0)
+            .map(|count| total / count)
+    }
+
+    /// The summary, with the total wall time measured from [`RunStats::new`].
+    pub fn render(&self) -> String {
+        self.render_with_total(self.started.elapsed())
+    }
+
+    fn render_with_total(&self, total: Duration) -> String {
+        let mut lines = vec![format!("⏱️  Stats: {} total", secs(total))];
+        for (name, duration) in self.phases.lock().unwrap_or_else(|e| e.into_inner()).iter() {
+            lines.push(format!("   {name:<10} {}", secs(*duration)));
+        }
+        let count = self.repos.lock().unwrap_or_else(|e| e.into_inner()).len();
+        if let Some(average) = self.average() {
+            lines.push(format!(
+                "   {:<10} {count} timed, average {}",
+                "repos",
+                secs(average)
+            ));
+            lines.push(format!("   slowest {SLOWEST}:"));
+            for (slug, duration) in self.slowest(SLOWEST) {
+                lines.push(format!("     {:>7}  {slug}", secs(duration)));
+            }
+        }
+        lines.join("\n")
+    }
+}
+
+/// `1.234s`-style seconds with two decimals.
+fn secs(duration: Duration) -> String {
+    format!("{:.2}s", duration.as_secs_f64())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,80 @@
+use super::*;
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+#[test]
+fn test_run_stats_aggregates_per_repo_durations() {
+    let stats = RunStats::new();
+    assert_eq!(stats.average(), None);
+    assert!(stats.slowest(SLOWEST).is_empty());
+
+    for (slug, millis) in [
+        ("org/a", 100),
+        ("org/b", 700),
+        ("org/c", 300),
+        ("org/d", 200),
+        ("org/e", 500),
+        ("org/f", 400),
+    ] {
+        stats.record_repo(slug, ms(millis));
+    }
+
+    assert_eq!(
+        stats.average(),
+        Some(ms(366) + Duration::from_nanos(666_666))
+    );
+    let slowest: Vec<String> = stats.slowest(3).into_iter().map(|(s, _)| s).collect();
+    assert_eq!(slowest, vec!["org/b", "org/e", "org/f"]);
+    assert_eq!(stats.slowest(SLOWEST).len(), SLOWEST);
+    assert_eq!(stats.slowest(10).len(), 6);
+}
+
+#[test]
+fn test_run_stats_records_from_parallel_workers() {
+    use rayon::prelude::*;
+
+    let stats = RunStats::new();
+    let slugs: Vec<String> = (0..32).map(|i| format!("org/repo-{i}")).collect();
+    slugs.par_iter().for_each(|slug| stats.repo(slug, || ()));
+
+    let mut recorded: Vec<String> = stats
+        .slowest(usize::MAX)
+        .into_iter()
+        .map(|(s, _)| s)
+        .collect();
+    recorded.sort();
+    let mut expected = slugs.clone();
+    expected.sort();
+    assert_eq!(recorded, expected);
+}
+
+#[test]
+fn test_render_lists_phases_then_slowest_repos() {
+    let stats = RunStats::new();
+    assert_eq!(stats.phase("discovery", || 7), 7);
+    stats.record_phase("filtering", ms(10));
+    stats.record_repo("org/fast", ms(250));
+    stats.record_repo("org/slow", ms(1_250));
+
+    let rendered = stats.render_with_total(ms(2_000));
+    let lines: Vec<&str> = rendered.lines().collect();
+    assert_eq!(lines[0], "⏱️  Stats: 2.00s total");
+    assert!(lines[1].starts_with("   discovery  "));
+    assert_eq!(lines[2], "   filtering  0.01s");
+    assert_eq!(lines[3], "   repos      2 timed, average 0.75s");
+    assert_eq!(lines[4], "   slowest 5:");
+    assert_eq!(lines[5], "       1.25s  org/slow");
+    assert_eq!(lines[6], "       0.25s  org/fast");
+}
+
+#[test]
+fn test_render_without_repos_has_no_repo_lines() {
+    let stats = RunStats::new();
+    stats.record_phase("discovery", ms(30));
+    assert_eq!(
+        stats.render_with_total(ms(40)),
+        "⏱️  Stats: 0.04s total\n   discovery  0.03s"
+    );
+}
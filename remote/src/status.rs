@@ -4,13 +4,15 @@
 
 use crate::cli::Cli;
 use crate::output::StatusOptions;
-use crate::{git, output};
+use crate::stats::RunStats;
+use crate::{git, github, output};
 use eyre::{Context, Result};
 use local::config::{Config, OutputVerbosity};
 use local::repo;
-use local::utils::{get_jobs_from_config, get_max_depth_from_config, get_nproc};
+use local::utils::get_nproc;
 use log::{debug, info};
 use rayon::prelude::*;
+use std::collections::HashSet;
 use std::env;
 use std::sync::Mutex;
 
@@ -21,7 +23,40 @@ pub struct StatusCommandOptions<'a> {
     pub use_colors: bool,
     pub patterns: &'a [String],
     pub fetch_first: bool,
+    /// Size of the pre-status fetch pool; `None` = [`default_fetch_jobs`].
+    pub fetch_jobs: Option<usize>,
     pub no_remote: bool,
+    /// GitHub search query; when set, only local clones of its results are shown.
+    pub search: Option<&'a str>,
+    /// Say so (on stderr) when discovery walked up from the working directory.
+    pub root_notice: bool,
+    /// Count added/removed lines per repo (one more git call per repo).
+    pub diff_stat: bool,
+    /// Only display repos whose status check errored; the summary still
+    /// counts every repo scanned.
+    pub only_errors: bool,
+    /// Compare against `<remote>/<branch>` instead of the upstream.
+    pub remote: Option<&'a str>,
+    /// Report ahead/behind for this local branch instead of the checked-out one.
+    pub branch: Option<&'a str>,
+    /// List repo slugs under per-state headers ahead of the summary line.
+    pub group: bool,
+    /// Also report ahead/behind for every local branch, under each repo.
+    pub ahead_behind_all: bool,
+    /// Print only [`output::display_porcelain`]'s stable tab-separated lines.
+    pub porcelain: bool,
+    /// How the working-tree scan reports untracked files (`-u<mode>`).
+    pub untracked_files: local::git::UntrackedFiles,
+    /// Count moved submodule pointers apart from `modified`.
+    pub include_submodules: bool,
+    /// Print only the summary counts (see [`render_counts`]).
+    pub count_only: bool,
+    /// With `count_only`, print the counts as a JSON object.
+    pub json: bool,
+    /// Name repos by their path relative to the discovery root, not the slug.
+    pub relative_paths: bool,
+    /// Name repos by their absolute path, not the slug.
+    pub absolute_paths: bool,
 }
 
 /// Process the status subcommand
@@ -52,13 +87,16 @@ pub fn process_status_command(
             .and_then(|rs| rs.enabled)
             .unwrap_or(true);
 
+    let org_config = config.for_user_org(cli.user_org.as_deref());
+
     // Determine jobs
     let jobs = cli
         .parallel
-        .or_else(|| get_jobs_from_config(config))
+        .or(org_config.jobs)
         .unwrap_or_else(|| get_nproc().unwrap_or(4));
 
     debug!("Using jobs: {jobs}");
+    let stats = RunStats::new();
 
     // Set rayon thread pool size
     rayon::ThreadPoolBuilder::new()
@@ -67,35 +105,90 @@ pub fn process_status_command(
         .context("Failed to initialize thread pool")?;
 
     // Determine max depth
-    let max_depth = cli
-        .max_depth
-        .or_else(|| get_max_depth_from_config(config))
-        .unwrap_or(2);
+    let max_depth = cli.max_depth.or(org_config.max_depth).unwrap_or(2);
 
     debug!("Using max depth: {max_depth}");
 
     // 1. Discover repositories
     let start_dir = env::current_dir().context("Failed to get current directory")?;
-    let repos = repo::discover_repos(&start_dir, max_depth, &config.ignore_patterns())
+    if options.root_notice {
+        if let Some(root) = repo::discovery_settings().walked_up_root(&start_dir) {
+            eprintln!("{}", root_notice(&root, &start_dir));
+        }
+    }
+    let path_display = if options.relative_paths {
+        output::RepoPathDisplay::Relative(repo::discovery_settings().search_root(&start_dir))
+    } else if options.absolute_paths {
+        output::RepoPathDisplay::Absolute
+    } else {
+        output::RepoPathDisplay::Slug
+    };
+    let mut repos = stats
+        .phase("discovery", || {
+            repo::discover_repos_including_bare(&start_dir, max_depth, &config.ignore_patterns())
+        })
         .context("Failed to discover repositories")?;
+    output::warn_slug_collisions(&repos, cli.quiet);
 
     info!("Discovered {} repositories", repos.len());
 
+    // 1b. Narrow to local clones of the GitHub search results. Search hits
+    //     with no local clone are simply absent: status never clones.
+    if let Some(query) = options.search {
+        let matched: HashSet<String> =
+            github::search_repos(query, github::ArchivedFilter::Include, None, None, config)
+                .context("Failed to search repositories on GitHub")?
+                .into_iter()
+                .map(|slug| slug.to_lowercase())
+                .collect();
+        repos.retain(|r| matched.contains(&r.slug.to_lowercase()));
+        info!(
+            "{} local repositories match search {query:?} ({} search hits)",
+            repos.len(),
+            matched.len()
+        );
+    }
+
     // 2. Filter repositories
-    let filtered_repos = repo::filter_repos(repos, options.patterns);
+    let filtered_repos = stats.phase("filtering", || repo::filter_repos(repos, options.patterns));
     info!("Filtered to {} repositories", filtered_repos.len());
 
     if filtered_repos.is_empty() {
-        println!("🔍 No repositories found matching the criteria");
+        if options.count_only {
+            let opts = StatusOptions {
+                use_emoji: options.use_emoji,
+                use_colors: options.use_colors,
+                ..Default::default()
+            };
+            println!("{}", render_counts(0, 0, 0, options.json, &opts));
+        } else if !options.porcelain {
+            println!("🔍 No repositories found matching the criteria");
+        }
         return Ok(());
     }
+    // Bare repos (mirrors) have no work tree to report on.
+    let (filtered_repos, bare_repos) = repo::partition_bare(filtered_repos);
 
-    // 3. Use the fast calculation that now properly handles all possible emoji patterns
-    let widths = output::calculate_alignment_widths_fast(&filtered_repos);
+    // 2b. Fetch first, on its own pool: fetching is network-bound, so it is
+    //     sized apart from the CPU-bound status pool (`--fetch-jobs`).
+    let fetch_in_status = if effective_fetch_first && !effective_no_remote {
+        let fetch_jobs = options
+            .fetch_jobs
+            .unwrap_or_else(|| default_fetch_jobs(jobs));
+        debug!("Using fetch jobs: {fetch_jobs}");
+        stats.phase("fetch", || {
+            prefetch_repos(&filtered_repos, fetch_jobs, |repo| {
+                git::fetch_for_status(repo, options.remote)
+            })
+        })?;
+        false
+    } else {
+        effective_fetch_first
+    };
 
-    // 4. Create status options
-    let verbosity = if options.detailed {
-        // CLI --detailed flag overrides config
+    // 3. Create status options
+    let verbosity = if options.detailed || options.diff_stat {
+        // CLI --detailed flag overrides config; --diff-stat is shown in detailed mode
         OutputVerbosity::Detailed
     } else {
         // Use config verbosity or default
@@ -110,14 +203,52 @@ pub fn process_status_command(
         verbosity,
         use_emoji: options.use_emoji,
         use_colors: options.use_colors,
+        quiet: cli.quiet || options.porcelain,
+        theme: output::Theme::from_config(config.theme.as_ref()),
+        path_display,
     };
+    if !options.count_only {
+        output::display_bare_skips(&bare_repos, &status_opts);
+    }
+
+    // 4. Use the fast calculation that handles every marker the theme can draw
+    let widths = output::calculate_alignment_widths_fast(&filtered_repos, &status_opts.theme());
 
     // 5. Process repositories in parallel with streaming output
     let results = Mutex::new(Vec::new());
 
     filtered_repos.par_iter().for_each(|repo| {
-        let result =
-            git::get_repo_status_with_options(repo, effective_fetch_first, effective_no_remote);
+        let started = std::time::Instant::now();
+        let mut result = git::get_repo_status_with_options(
+            repo,
+            fetch_in_status,
+            effective_no_remote,
+            options.remote,
+            options.branch,
+            options.untracked_files,
+        );
+        if options.diff_stat && result.error.is_none() {
+            match local::git::get_diff_stat(repo) {
+                Ok(stat) => result.diff_stat = Some(stat),
+                Err(e) => debug!("diff stat unavailable for {}: {e}", repo.slug),
+            }
+        }
+        if options.ahead_behind_all && result.error.is_none() {
+            match local::git::all_branch_sync(repo) {
+                Ok(syncs) => result.branch_sync = Some(syncs),
+                Err(e) => debug!("branch sync unavailable for {}: {e}", repo.slug),
+            }
+        }
+        if matches!(verbosity, OutputVerbosity::Detailed | OutputVerbosity::Full) {
+            result.commit_subject = local::git::get_commit_subject(repo);
+        }
+        if options.include_submodules && result.error.is_none() {
+            match local::git::count_moved_submodules(repo) {
+                Ok(moved) => split_moved_submodules(&mut result, moved),
+                Err(e) => debug!("submodule status unavailable for {}: {e}", repo.slug),
+            }
+        }
+        stats.record_repo(&repo.slug, started.elapsed());
 
         // Store for final summary. Poison-recovery (not the primary fix - the
         // panic hook in `main` is - but belt-and-suspenders: if a future
@@ -129,6 +260,12 @@ pub fn process_status_command(
             .push(result.clone());
 
         // Display immediately with pre-calculated alignment
+        if options.porcelain
+            || options.count_only
+            || (options.only_errors && result.error.is_none())
+        {
+            return;
+        }
         if let Err(e) = output::display_status_result_immediate(&result, &status_opts, &widths) {
             log::error!("Failed to display status result: {e}");
         }
@@ -137,16 +274,82 @@ pub fn process_status_command(
     // 6. Final summary
     let results_vec = results.into_inner().unwrap_or_else(|e| e.into_inner());
     let (clean_count, dirty_count, error_count) = categorize_status_results(&results_vec);
-    output::display_unified_summary(clean_count, dirty_count, error_count, &status_opts);
+    if options.porcelain {
+        output::display_porcelain(&results_vec);
+    } else if options.count_only {
+        println!(
+            "{}",
+            render_counts(
+                clean_count,
+                dirty_count,
+                error_count,
+                options.json,
+                &status_opts
+            )
+        );
+    } else {
+        if options.group {
+            output::display_grouped_summary(&results_vec, &status_opts);
+        }
+        output::display_unified_summary(clean_count, dirty_count, error_count, &status_opts);
+    }
+    if cli.stats {
+        eprintln!("{}", stats.render());
+    }
 
     // 7. Exit with error count
-    if error_count > 0 {
-        std::process::exit(error_count.min(255) as i32);
+    output::exit_on_errors(error_count, !cli.no_fail_on_error);
+
+    Ok(())
+}
+
+/// `gx status --count-only`: the summary line alone, or with `json` the bare
+/// `{"clean":N,"dirty":M,"errors":K}` object, no emoji or color.
+fn render_counts(
+    clean: usize,
+    dirty: usize,
+    errors: usize,
+    json: bool,
+    opts: &StatusOptions,
+) -> String {
+    if json {
+        serde_json::json!({ "clean": clean, "dirty": dirty, "errors": errors }).to_string()
+    } else {
+        output::summary_line(clean, dirty, errors, opts)
     }
+}
 
+/// Upper bound on the default fetch pool: past this, parallel fetches stop
+/// helping and start tripping GitHub's rate limits.
+const MAX_DEFAULT_FETCH_JOBS: usize = 16;
+
+/// Default `--fetch-jobs`: the status job count, capped at
+/// [`MAX_DEFAULT_FETCH_JOBS`].
+fn default_fetch_jobs(jobs: usize) -> usize {
+    jobs.clamp(1, MAX_DEFAULT_FETCH_JOBS)
+}
+
+/// Run `fetch` over every repo on a dedicated `fetch_jobs`-thread pool, ahead
+/// of (and separate from) the global status pool.
+fn prefetch_repos<F>(repos: &[repo::Repo], fetch_jobs: usize, fetch: F) -> Result<()>
+where
+    F: Fn(&repo::Repo) + Sync,
+{
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(fetch_jobs.max(1))
+        .build()
+        .context("Failed to create fetch thread pool")?;
+    pool.install(|| repos.par_iter().for_each(&fetch));
     Ok(())
 }
 
+/// Count `moved` submodule pointers as `submodule_changes` rather than
+/// `changes.modified`, where porcelain had already counted each of them.
+fn split_moved_submodules(result: &mut local::git::RepoStatus, moved: u32) {
+    result.changes.modified = result.changes.modified.saturating_sub(moved);
+    result.submodule_changes = moved;
+}
+
 /// Categorize status results into clean/dirty/error counts
 fn categorize_status_results(results: &[local::git::RepoStatus]) -> (usize, usize, usize) {
     let mut clean_count = 0;
@@ -165,3 +368,16 @@ fn categorize_status_results(results: &[local::git::RepoStatus]) -> (usize, usiz
 
     (clean_count, dirty_count, error_count)
 }
+
+/// The one-line notice for a scan that walked up from `start_dir` to `root`:
+/// `Scanning from /home/me/work (walked up from ./subdir)`.
+fn root_notice(root: &std::path::Path, start_dir: &std::path::Path) -> String {
+    let from = match start_dir.strip_prefix(root) {
+        Ok(relative) => format!("./{}", relative.display()),
+        Err(_) => start_dir.display().to_string(),
+    };
+    format!("Scanning from {} (walked up from {from})", root.display())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,77 @@
+use super::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn test_default_fetch_jobs_follows_jobs_up_to_cap() {
+    assert_eq!(default_fetch_jobs(4), 4);
+    assert_eq!(default_fetch_jobs(64), MAX_DEFAULT_FETCH_JOBS);
+    assert_eq!(default_fetch_jobs(0), 1);
+}
+
+#[test]
+fn test_prefetch_repos_runs_on_a_pool_of_fetch_jobs_threads() {
+    let repos: Vec<repo::Repo> = (0..10)
+        .map(|i| repo::Repo::from_slug(format!("testorg/repo{i}")))
+        .collect();
+    let fetched = AtomicUsize::new(0);
+    let widest_pool = AtomicUsize::new(0);
+
+    prefetch_repos(&repos, 3, |_| {
+        fetched.fetch_add(1, Ordering::SeqCst);
+        widest_pool.fetch_max(rayon::current_num_threads(), Ordering::SeqCst);
+    })
+    .unwrap();
+
+    assert_eq!(fetched.load(Ordering::SeqCst), repos.len());
+    assert_eq!(widest_pool.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn test_render_counts_plain_and_json() {
+    let opts = StatusOptions {
+        use_emoji: false,
+        use_colors: false,
+        ..Default::default()
+    };
+    assert_eq!(
+        render_counts(3, 2, 1, false, &opts),
+        "Summary: 3 clean, 2 dirty, 1 errors"
+    );
+    let json = render_counts(3, 2, 1, true, &opts);
+    assert_eq!(json, r#"{"clean":3,"dirty":2,"errors":1}"#);
+}
+
+#[test]
+fn test_split_moved_submodules_takes_pointers_out_of_modified() {
+    let mut result = local::git::RepoStatus {
+        repo: repo::Repo {
+            path: std::path::PathBuf::from("/repos/scottidler/otto"),
+            name: "otto".to_string(),
+            slug: "scottidler/otto".to_string(),
+            layout: repo::Layout::Flat,
+        },
+        branch: Some("main".to_string()),
+        commit_sha: None,
+        is_clean: false,
+        changes: local::git::StatusChanges {
+            modified: 3,
+            ..Default::default()
+        },
+        remote_status: local::git::RemoteStatus::UpToDate,
+        error: None,
+        diff_stat: None,
+        branch_sync: None,
+        submodule_changes: 0,
+        commit_subject: None,
+    };
+
+    split_moved_submodules(&mut result, 2);
+    assert_eq!(result.changes.modified, 1);
+    assert_eq!(result.submodule_changes, 2);
+    assert!(!result.is_clean);
+
+    // Never underflows if porcelain reported fewer modified entries.
+    split_moved_submodules(&mut result, 4);
+    assert_eq!(result.changes.modified, 0);
+    assert_eq!(result.submodule_changes, 4);
+}
@@ -66,6 +66,15 @@ pub enum RollbackStep {
     },
     /// Remove a file gx created (for `gx add`).
     RemoveCreatedFile { path: PathBuf },
+    /// Delete a local tag gx created (`create --tag`). `tag_existed` records
+    /// whether the tag pre-existed gx's run; if so, rollback must NOT delete
+    /// it. Like the branch, a pushed tag is shared work that only `gx undo`
+    /// reverses; rollback never deletes it from the remote.
+    DeleteLocalTag {
+        repo: PathBuf,
+        tag: String,
+        tag_existed: bool,
+    },
 }
 
 /// Per-step journal status. The interpreter rewrites the recovery file after
@@ -200,6 +209,14 @@ pub struct RecoveryState {
     /// need not re-derive it. Defaults to `None` on pre-field files.
     #[serde(default)]
     pub branch: Option<String>,
+    /// `branch` is the user's own, committed onto in place
+    /// (`--target-existing-branch`): gx did not create it, so nothing may
+    /// delete it, and `gx undo` reverts `commit_sha` on it instead.
+    #[serde(default)]
+    pub existing_branch: bool,
+    /// The commit gx made, once made.
+    #[serde(default)]
+    pub commit_sha: Option<String>,
     pub steps: Vec<StepEntry>,
 }
 
@@ -252,6 +269,10 @@ pub struct Transaction {
     /// The GX branch name (set once the branch is created), recorded so recovery
     /// need not re-derive it.
     branch: Option<String>,
+    /// Whether `branch` is the user's own ([`RecoveryState::existing_branch`]).
+    existing_branch: bool,
+    /// The commit gx made, once made.
+    commit_sha: Option<String>,
     /// Whether recovery state is persisted (true only for real, committing runs).
     persist: bool,
     finalized: bool,
@@ -281,6 +302,8 @@ impl Transaction {
             stash_sha: None,
             phase: Phase::Mutating,
             branch: None,
+            existing_branch: false,
+            commit_sha: None,
             persist,
             finalized: false,
         }
@@ -302,6 +325,18 @@ impl Transaction {
         self.branch = Some(branch);
     }
 
+    /// Record the user's own branch, committed onto in place: like
+    /// [`Self::set_branch`], but marked so recovery and undo never delete it.
+    pub fn set_existing_branch(&mut self, branch: String) {
+        self.branch = Some(branch);
+        self.existing_branch = true;
+    }
+
+    /// Record the commit gx made. Persisted with the next state write.
+    pub fn set_commit_sha(&mut self, sha: String) {
+        self.commit_sha = Some(sha);
+    }
+
     /// Stamp the lifecycle phase and persist it write-ahead - called BEFORE the
     /// operation the phase guards (e.g. `Pushing` before `git push`). No-op
     /// persistence when `persist` is false.
@@ -359,6 +394,8 @@ impl Transaction {
             created_at: self.created_at.clone(),
             phase: self.phase,
             branch: self.branch.clone(),
+            existing_branch: self.existing_branch,
+            commit_sha: self.commit_sha.clone(),
             steps: self.steps.iter().cloned().map(StepEntry::pending).collect(),
         }
     }
@@ -709,6 +746,21 @@ pub fn execute_step(step: &RollbackStep) -> Result<()> {
             }
             Ok(())
         }
+        RollbackStep::DeleteLocalTag {
+            repo,
+            tag,
+            tag_existed,
+        } => {
+            if *tag_existed {
+                debug!("Tag {tag} pre-existed gx's run; not deleting");
+                return Ok(());
+            }
+            // Idempotent: the tag may never have been created.
+            if local::git::tag_exists_locally(repo, tag)? {
+                local::git::delete_local_tag(repo, tag)?;
+            }
+            Ok(())
+        }
     }
 }
 
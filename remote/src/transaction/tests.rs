@@ -74,6 +74,11 @@ fn test_rollback_step_serialize_roundtrip() {
         RollbackStep::RemoveCreatedFile {
             path: PathBuf::from("/f"),
         },
+        RollbackStep::DeleteLocalTag {
+            repo: PathBuf::from("/r"),
+            tag: "v1.2.3".to_string(),
+            tag_existed: false,
+        },
     ];
     let json = serde_json::to_string(&steps).unwrap();
     let back: Vec<RollbackStep> = serde_json::from_str(&json).unwrap();
@@ -249,6 +254,8 @@ fn test_kill9_recovery_restores_branch_and_file() {
             created_at: "2026-06-11T00:00:00Z".to_string(),
             phase: Phase::Mutating,
             branch: Some("GX-kill".to_string()),
+            existing_branch: false,
+            commit_sha: None,
             steps: vec![
                 StepEntry::pending(RollbackStep::RestoreBackup {
                     backup,
@@ -448,6 +455,8 @@ fn test_rollback_retains_artifacts_on_failed_step() {
             created_at: "2026-07-11T00:00:00Z".to_string(),
             phase: Phase::Mutating,
             branch: None,
+            existing_branch: false,
+            commit_sha: None,
             steps: vec![
                 StepEntry::pending(RollbackStep::RestoreBackup {
                     backup: a_backup.clone(),
@@ -529,6 +538,8 @@ fn test_popstash_applied_state_skips_reapply() {
             created_at: "2026-07-11T00:00:00Z".to_string(),
             phase: Phase::Mutating,
             branch: None,
+            existing_branch: false,
+            commit_sha: None,
             steps: vec![StepEntry {
                 step: RollbackStep::PopStash {
                     repo: repo.path().to_path_buf(),
@@ -614,6 +625,8 @@ fn test_legacy_step_skipped_on_execute() {
             created_at: "2026-07-11T00:00:00Z".to_string(),
             phase: Phase::Mutating,
             branch: Some("GX-legacy".to_string()),
+            existing_branch: false,
+            commit_sha: None,
             steps: vec![StepEntry::pending(RollbackStep::LegacyDeleteRemoteBranch {
                 repo: repo.path().to_path_buf(),
                 branch: "GX-legacy".to_string(),
@@ -655,6 +668,8 @@ fn test_popstash_by_message_restores_stash() {
             created_at: "2026-07-11T00:00:00Z".to_string(),
             phase: Phase::Mutating,
             branch: None,
+            existing_branch: false,
+            commit_sha: None,
             steps: vec![StepEntry::pending(RollbackStep::PopStashByMessage {
                 repo: repo.path().to_path_buf(),
                 message: message.to_string(),
@@ -695,6 +710,8 @@ fn test_popstash_by_message_no_matching_stash_is_noop() {
             created_at: "2026-07-11T00:00:00Z".to_string(),
             phase: Phase::Mutating,
             branch: None,
+            existing_branch: false,
+            commit_sha: None,
             steps: vec![StepEntry::pending(RollbackStep::PopStashByMessage {
                 repo: repo.path().to_path_buf(),
                 message: "GX auto-stash for GX-never".to_string(),
@@ -747,6 +764,8 @@ fn test_execute_finalizing_phase_keeps_pushed_branch() {
             created_at: "2026-07-11T00:00:00Z".to_string(),
             phase: Phase::Finalizing,
             branch: Some("GX-fin".to_string()),
+            existing_branch: false,
+            commit_sha: None,
             steps: vec![
                 StepEntry::pending(RollbackStep::PopStash {
                     repo: repo.clone(),
@@ -831,6 +850,8 @@ fn test_execute_pushing_phase_no_remote_full_reverse() {
             created_at: "2026-07-11T00:00:00Z".to_string(),
             phase: Phase::Pushing,
             branch: Some("GX-push".to_string()),
+            existing_branch: false,
+            commit_sha: None,
             steps: vec![
                 StepEntry::pending(RollbackStep::SwitchBranch {
                     repo: repo.clone(),
@@ -892,6 +913,8 @@ fn test_execute_pushing_phase_with_remote_keeps_work() {
             created_at: "2026-07-11T00:00:00Z".to_string(),
             phase: Phase::Pushing,
             branch: Some("GX-push".to_string()),
+            existing_branch: false,
+            commit_sha: None,
             steps: vec![
                 StepEntry::pending(RollbackStep::SwitchBranch {
                     repo: repo.clone(),
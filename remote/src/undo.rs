@@ -12,7 +12,7 @@ pub mod core;
 pub use core::{OutcomeKind, UndoAction, UndoOutcome, UndoPlan};
 
 use crate::cli::Cli;
-use crate::output::{display_review_results, StatusOptions};
+use crate::output::{self, display_review_results, StatusOptions};
 use crate::review::{ReviewAction, ReviewResult};
 use crate::state::RepoChangeStatus;
 use eyre::{Context, Result};
@@ -32,6 +32,7 @@ fn state_label(plan: &UndoPlan) -> &'static str {
         Some(RepoChangeStatus::RevertPrOpen) => "revert PR open",
         Some(RepoChangeStatus::BranchCreated) => "pushed, no PR",
         Some(RepoChangeStatus::Failed) => "failed",
+        Some(RepoChangeStatus::Reverted) => "reverted",
         // A bare (unapplied) proposal: undo is local-only (delete artifacts).
         Some(RepoChangeStatus::Proposed) => "proposed",
         // `review approve` skipped this (still-open) PR as not-mergeable; undo
@@ -42,7 +43,7 @@ fn state_label(plan: &UndoPlan) -> &'static str {
 
 /// Human label for a plan entry's action column.
 fn action_label(plan: &UndoPlan) -> String {
-    match &plan.action {
+    let label = match &plan.action {
         UndoAction::ClosePr { pr_number } => {
             format!("close PR #{pr_number} -> delete remote branch -> delete local branch")
         }
@@ -57,10 +58,18 @@ fn action_label(plan: &UndoPlan) -> String {
         UndoAction::UnverifiedOffline => {
             "merge state unverified offline; skipped (re-run `gx undo` online)".to_string()
         }
+        UndoAction::RevertCommit { pr_number, .. } => match pr_number {
+            Some(n) => format!("close PR #{n} -> revert commit on branch (branch kept)"),
+            None => "revert commit on branch (branch kept)".to_string(),
+        },
         UndoAction::AlreadyGone => "already gone; skip".to_string(),
         UndoAction::CleanupProposal => {
             "bare proposal; delete proposal artifacts (local only, no remote)".to_string()
         }
+    };
+    match &plan.tag {
+        Some(tag) if plan.action.removes_tag() => format!("delete tag {tag} -> {label}"),
+        _ => label,
     }
 }
 
@@ -137,7 +146,9 @@ fn render_results(outcomes: &[UndoOutcome], cli: &Cli) {
             local::config::OutputVerbosity::Summary
         },
         use_emoji: true,
-        use_colors: true,
+        use_colors: output::should_use_color(cli.color),
+        quiet: cli.quiet,
+        ..Default::default()
     };
     display_review_results(&results, &opts);
 
@@ -47,6 +47,13 @@ pub enum UndoAction {
     /// report it, touch NO remote, and leave the repo for a re-run online
     /// (post-audit hardening). Recovery-file draining (local-only) still runs.
     UnverifiedOffline,
+    /// Committed onto the user's own branch (`--target-existing-branch`): close
+    /// the PR gx opened (if any), then `git revert` gx's commit on that branch
+    /// and push. The branch itself is the user's and is NEVER deleted.
+    RevertCommit {
+        sha: Option<String>,
+        pr_number: Option<u64>,
+    },
     /// Already gone (cleaned up): record and skip.
     AlreadyGone,
     /// A bare (unapplied) proposal (`RepoChangeStatus::Proposed`): LOCAL-ONLY.
@@ -57,6 +64,20 @@ pub enum UndoAction {
     CleanupProposal,
 }
 
+impl UndoAction {
+    /// Whether this action reverses the change itself, and so also deletes
+    /// the change's pushed tag (a merged change keeps its tagged history).
+    pub fn removes_tag(&self) -> bool {
+        matches!(
+            self,
+            UndoAction::ClosePr { .. }
+                | UndoAction::DeleteRemoteAndLocal
+                | UndoAction::DeleteLocal
+                | UndoAction::RevertCommit { .. }
+        )
+    }
+}
+
 /// One repo's undo plan: the campaign action plus any live recovery files to
 /// drain first.
 #[derive(Debug, Clone)]
@@ -77,6 +98,12 @@ pub struct UndoPlan {
     /// The base branch the merged PR landed on (from the reconcile). The revert
     /// branch is cut from this branch's head; `None` unless merged.
     pub base_ref_name: Option<String>,
+    /// The tag gx pushed on the change's commit (`create --tag`), deleted from
+    /// the remote and locally along with the branch. `None` when untagged.
+    pub tag: Option<String>,
+    /// The commit gx created `tag` on: the remote tag is deleted only while
+    /// it still points there.
+    pub tag_commit: Option<String>,
 }
 
 /// Outcome of undoing one repo, used to render results and true up state.
@@ -143,9 +170,11 @@ fn classify_action(status: &RepoChangeStatus, pr_number: Option<u64>) -> UndoAct
         }
         // A pushed branch with no PR (BranchCreated), an already-closed PR whose
         // branch may linger, or a failed repo: delete the pushed branch.
-        RepoChangeStatus::PrClosed | RepoChangeStatus::BranchCreated | RepoChangeStatus::Failed => {
-            UndoAction::DeleteRemoteAndLocal
-        }
+        // A reverted change still has its pushed branch (carrying the revert).
+        RepoChangeStatus::PrClosed
+        | RepoChangeStatus::BranchCreated
+        | RepoChangeStatus::Failed
+        | RepoChangeStatus::Reverted => UndoAction::DeleteRemoteAndLocal,
     }
 }
 
@@ -166,6 +195,23 @@ fn recovery_only_action(phase: Phase) -> UndoAction {
     }
 }
 
+/// Rewrite a branch-deleting action for a change committed onto the user's own
+/// branch: gx's commit is reverted instead, since the branch predates gx.
+/// Actions that delete nothing (revert PR, proposal cleanup, skip) are kept.
+fn existing_branch_action(action: UndoAction, commit_sha: Option<String>) -> UndoAction {
+    match action {
+        UndoAction::ClosePr { pr_number } => UndoAction::RevertCommit {
+            sha: commit_sha,
+            pr_number: Some(pr_number),
+        },
+        UndoAction::DeleteRemoteAndLocal | UndoAction::DeleteLocal => UndoAction::RevertCommit {
+            sha: commit_sha,
+            pr_number: None,
+        },
+        other => other,
+    }
+}
+
 /// True when a plan entry has real work: a non-`AlreadyGone` action, or a
 /// recovery file to drain. `AlreadyGone` with no recovery is informational.
 /// `UnverifiedOffline` is actionable so it is REPORTED (never silently dropped).
@@ -182,6 +228,7 @@ fn is_remote_mutating(action: &UndoAction) -> bool {
         UndoAction::ClosePr { .. }
             | UndoAction::DeleteRemoteAndLocal
             | UndoAction::RequiresRevert { .. }
+            | UndoAction::RevertCommit { .. }
     )
 }
 
@@ -240,6 +287,14 @@ fn build_plan(
         // it back as `UnverifiedOffline` rather than risk deleting the branch of
         // a PR that is actually merged (post-audit hardening).
         let mut action = classify_action(&repo_state.status, repo_state.pr_number);
+        // A recorded revert branch is gx's own, so it is deleted either way.
+        if repo_state.existing_branch && repo_state.revert_branch.is_none() {
+            action = match repo_state.status {
+                // gx's commit is already reverted on the user's branch.
+                RepoChangeStatus::Reverted => UndoAction::AlreadyGone,
+                _ => existing_branch_action(action, repo_state.commit_sha.clone()),
+            };
+        }
         if failed_orgs.contains(org_of(&repo_state.repo_slug)) && is_remote_mutating(&action) {
             debug!(
                 "build_plan: {} merge state unverified (org fetch failed) -> UnverifiedOffline",
@@ -248,16 +303,26 @@ fn build_plan(
             action = UndoAction::UnverifiedOffline;
         }
 
+        // A merged change reverted with `gx create revert --force`: the branch
+        // left to delete is the revert branch, and the merged change keeps its tag.
+        let (branch, tag) = match &repo_state.revert_branch {
+            Some(revert_branch) => (revert_branch.clone(), None),
+            None => (repo_state.branch_name.clone(), repo_state.tag.clone()),
+        };
+        let tag_commit = tag.as_ref().and(repo_state.commit_sha.clone());
+
         plans.push(UndoPlan {
             slug: repo_state.repo_slug.clone(),
             repo_path,
-            branch: Some(repo_state.branch_name.clone()),
+            branch: Some(branch),
             pr_number: repo_state.pr_number.or_else(|| merged.map(|p| p.number)),
             status: Some(repo_state.status.clone()),
             action,
             recovery_tx_ids,
             merge_commit_oid: merged.and_then(|p| p.merge_commit_oid.clone()),
             base_ref_name: merged.map(|p| p.base_ref_name.clone()),
+            tag,
+            tag_commit,
         });
     }
 
@@ -281,16 +346,29 @@ fn build_plan(
         let slug = local::repo::Repo::new(rec.repo_path.clone())
             .map(|r| r.slug)
             .unwrap_or(leaf);
+        // On the user's own branch a pre-push crash is fully reversed by the
+        // drain (it resets the commit); anything later may be shared, so the
+        // commit is reverted - the branch is never deleted.
+        let action = match (rec.existing_branch, rec.phase) {
+            (true, Phase::Mutating) => UndoAction::AlreadyGone,
+            (true, _) => {
+                existing_branch_action(recovery_only_action(rec.phase), rec.commit_sha.clone())
+            }
+            (false, phase) => recovery_only_action(phase),
+        };
         plans.push(UndoPlan {
             slug,
             repo_path: Some(rec.repo_path.clone()),
             branch: rec.branch.clone(),
             pr_number: None,
             status: None,
-            action: recovery_only_action(rec.phase),
+            action,
             recovery_tx_ids: vec![rec.transaction_id.clone()],
             merge_commit_oid: None,
             base_ref_name: None,
+            // The tag is pushed only after finalize removes the recovery file.
+            tag: None,
+            tag_commit: None,
         });
     }
 
@@ -439,6 +517,31 @@ fn remove_local_branch(plan: &UndoPlan) -> Result<(), String> {
     }
 }
 
+/// Delete the change's tag: from the remote (a no-op when absent there or
+/// pointing at another commit) and then locally ([`UndoAction::removes_tag`]).
+fn remove_tag(plan: &UndoPlan) -> Result<(), String> {
+    let Some(tag) = plan.tag.as_deref() else {
+        return Ok(());
+    };
+    let path = match &plan.repo_path {
+        Some(path) if local::bare::is_git_path(path) => path,
+        Some(path) => return Err(format!("recorded local path missing: {}", path.display())),
+        None => return Err("no local path recorded".to_string()),
+    };
+    let commit = plan
+        .tag_commit
+        .as_deref()
+        .ok_or_else(|| format!("no commit recorded for tag {tag}; remote tag left in place"))?;
+    git::delete_remote_tag(path, tag, commit)
+        .map_err(|e| format!("failed to delete remote tag {tag}: {e}"))?;
+    match local::git::tag_exists_locally(path, tag) {
+        Ok(true) => local::git::delete_local_tag(path, tag)
+            .map_err(|e| format!("failed to delete local tag {tag}: {e}")),
+        Ok(false) => Ok(()),
+        Err(e) => Err(format!("failed to check local tag {tag}: {e}")),
+    }
+}
+
 /// Undo one repo: drain any live recovery file FIRST (via the same rollback
 /// interpreter `gx rollback execute` uses), then perform the campaign action,
 /// all under the per-repo lock.
@@ -497,7 +600,14 @@ fn undo_one(plan: &UndoPlan, change_id: &str, config: &Config) -> UndoOutcome {
         }
     }
 
-    // 2. Campaign action.
+    // 2. The change's pushed tag, for the actions that reverse the change.
+    if plan.action.removes_tag() {
+        if let Err(e) = remove_tag(plan) {
+            return outcome(OutcomeKind::Failed(e));
+        }
+    }
+
+    // 3. Campaign action.
     match &plan.action {
         UndoAction::AlreadyGone => outcome(OutcomeKind::Skipped),
         // A bare proposal: LOCAL-ONLY. Delete the proposal artifacts for this
@@ -539,7 +649,53 @@ fn undo_one(plan: &UndoPlan, change_id: &str, config: &Config) -> UndoOutcome {
             Ok(()) => outcome(OutcomeKind::Undone),
             Err(e) => outcome(OutcomeKind::Failed(e)),
         },
+        UndoAction::RevertCommit { sha, pr_number } => {
+            if let Some(n) = pr_number {
+                if let Err(e) = github::close_pr(&plan.slug, *n, config) {
+                    return outcome(OutcomeKind::Failed(format!(
+                        "failed to close PR #{n}: {e}"
+                    )));
+                }
+            }
+            match revert_on_branch(plan, sha.as_deref()) {
+                Ok(()) => outcome(OutcomeKind::Undone),
+                Err(e) => outcome(OutcomeKind::Failed(e)),
+            }
+        }
+    }
+}
+
+/// Revert gx's commit on the user's own branch (`--target-existing-branch`)
+/// and push the revert. The branch must be checked out, since the revert
+/// commits onto the working tree; it is never deleted or force-pushed.
+fn revert_on_branch(plan: &UndoPlan, sha: Option<&str>) -> Result<(), String> {
+    let branch = plan
+        .branch
+        .as_deref()
+        .ok_or_else(|| "no branch recorded".to_string())?;
+    let sha = sha.ok_or_else(|| format!("no commit recorded on {branch}; cannot revert"))?;
+    let path = match &plan.repo_path {
+        Some(path) if local::bare::is_git_path(path) => path,
+        Some(path) => return Err(format!("recorded local path missing: {}", path.display())),
+        None => return Err("no local path recorded".to_string()),
+    };
+    debug!(
+        "revert_on_branch: slug={} branch={branch} sha={sha}",
+        plan.slug
+    );
+
+    let current = local::git::get_current_branch_name(path)
+        .map_err(|e| format!("failed to read the current branch: {e}"))?;
+    if current != branch {
+        return Err(format!(
+            "{branch} is not checked out (on {current}); check out {branch} and re-run"
+        ));
     }
+    local::git::revert_commit(path, sha, None).map_err(|e| {
+        format!("revert of {sha} on {branch} failed; left for manual resolution: {e}")
+    })?;
+    git::push_branch(path, branch, false)
+        .map_err(|e| format!("failed to push the revert on {branch}: {e}"))
 }
 
 /// Revert a merged PR (Phase 6 [F4]): cut a `revert/<change-id>` branch from the
@@ -651,7 +807,7 @@ fn revert_merged(plan: &UndoPlan, change_id: &str, config: &Config) -> OutcomeKi
         ));
     }
 
-    if let Err(e) = git::push_branch(&repo_path, &revert_branch) {
+    if let Err(e) = git::push_branch(&repo_path, &revert_branch, false) {
         return OutcomeKind::Failed(format!("failed to push revert branch {revert_branch}: {e}"));
     }
 
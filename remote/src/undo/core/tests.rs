@@ -113,6 +113,8 @@ fn build_plan_associates_recovery_file_by_path() {
         created_at: "2026-07-11T00:00:00Z".to_string(),
         phase: Phase::Mutating,
         branch: Some("GX-assoc".to_string()),
+        existing_branch: false,
+        commit_sha: None,
         steps: vec![],
     };
 
@@ -141,6 +143,8 @@ fn build_plan_adds_recovery_only_repo_as_committed_local_only() {
         created_at: "2026-07-11T00:00:00Z".to_string(),
         phase: Phase::Mutating,
         branch: Some("GX-orphan".to_string()),
+        existing_branch: false,
+        commit_sha: None,
         steps: vec![],
     };
 
@@ -173,6 +177,8 @@ fn build_plan_classifies_recovery_only_repo_by_phase() {
         created_at: "2026-07-11T00:00:00Z".to_string(),
         phase,
         branch: Some("GX-phase".to_string()),
+        existing_branch: false,
+        commit_sha: None,
         steps: vec![],
     };
 
@@ -215,6 +221,106 @@ fn build_plan_classifies_recovery_only_repo_by_phase() {
     );
 }
 
+#[test]
+fn build_plan_reverts_commit_on_existing_branch_instead_of_deleting() {
+    // `--target-existing-branch` committed onto the user's own branch: undo
+    // reverts gx's commit there and never deletes the branch, whether the
+    // repo is in state or recorded only in a recovery file.
+    let mut state = ChangeState::new("GX-existing".to_string(), None);
+    for slug in ["org/pushed", "org/open"] {
+        state.add_repository(slug.to_string(), "feature".to_string());
+        let rs = state.repositories.get_mut(slug).unwrap();
+        rs.existing_branch = true;
+        rs.commit_sha = Some("abc123".to_string());
+    }
+    state.set_pr_info(
+        "org/open",
+        12,
+        "https://github.com/org/open/pull/12".to_string(),
+        false,
+    );
+
+    let make_rec = |tx: &str, phase: Phase| RecoveryState {
+        version: 1,
+        transaction_id: tx.to_string(),
+        change_id: "GX-existing".to_string(),
+        repo_path: PathBuf::from(format!("/nonexistent/gx-existing-test/{tx}")),
+        created_at: "2026-07-11T00:00:00Z".to_string(),
+        phase,
+        branch: Some("feature".to_string()),
+        existing_branch: true,
+        commit_sha: Some("def456".to_string()),
+        steps: vec![],
+    };
+    let recs = vec![
+        make_rec("tx-mutating", Phase::Mutating),
+        make_rec("tx-pushed", Phase::Pushed),
+    ];
+
+    let plan = build_plan(&state, &recs, &[], &BTreeSet::new());
+    let by_slug = |s: &str| plan.iter().find(|p| p.slug == s).unwrap();
+    let by_tx = |tx: &str| {
+        plan.iter()
+            .find(|p| p.recovery_tx_ids == vec![tx.to_string()])
+            .unwrap()
+    };
+
+    assert_eq!(by_slug("org/pushed").branch.as_deref(), Some("feature"));
+    assert_eq!(
+        by_slug("org/pushed").action,
+        UndoAction::RevertCommit {
+            sha: Some("abc123".to_string()),
+            pr_number: None
+        }
+    );
+    assert_eq!(
+        by_slug("org/open").action,
+        UndoAction::RevertCommit {
+            sha: Some("abc123".to_string()),
+            pr_number: Some(12)
+        }
+    );
+    assert_eq!(
+        by_tx("tx-mutating").action,
+        UndoAction::AlreadyGone,
+        "the drain alone resets an unpushed commit on the user's branch"
+    );
+    assert_eq!(
+        by_tx("tx-pushed").action,
+        UndoAction::RevertCommit {
+            sha: Some("def456".to_string()),
+            pr_number: None
+        }
+    );
+}
+
+#[test]
+fn build_plan_deletes_the_revert_branch_of_a_force_reverted_change() {
+    // `gx create revert --force` reverted a merged change onto a new revert
+    // branch: undo deletes that branch (keeping the merged change's tag). A
+    // change reverted in place on the user's own branch has nothing left.
+    let mut state = ChangeState::new("GX-reverted".to_string(), None);
+    state.add_repository("org/merged".to_string(), "GX-reverted".to_string());
+    state.repositories.get_mut("org/merged").unwrap().tag = Some("v1".to_string());
+    state.mark_reverted("org/merged", Some("GX-reverted-revert".to_string()));
+    state.add_repository("org/existing".to_string(), "feature".to_string());
+    state
+        .repositories
+        .get_mut("org/existing")
+        .unwrap()
+        .existing_branch = true;
+    state.mark_reverted("org/existing", None);
+
+    let plan = build_plan(&state, &[], &[], &BTreeSet::new());
+    let by_slug = |s: &str| plan.iter().find(|p| p.slug == s).unwrap();
+
+    let merged = by_slug("org/merged");
+    assert_eq!(merged.action, UndoAction::DeleteRemoteAndLocal);
+    assert_eq!(merged.branch.as_deref(), Some("GX-reverted-revert"));
+    assert_eq!(merged.tag, None);
+    assert_eq!(by_slug("org/existing").action, UndoAction::AlreadyGone);
+}
+
 #[test]
 fn build_plan_holds_remote_action_when_org_unverified() {
     // FIX 2 (post-audit hardening): when a repo's org fetch FAILED during
@@ -249,6 +355,8 @@ fn build_plan_holds_remote_action_when_org_unverified() {
         created_at: "2026-07-11T00:00:00Z".to_string(),
         phase: Phase::Mutating,
         branch: Some("GX-unverified".to_string()),
+        existing_branch: false,
+        commit_sha: None,
         steps: vec![],
     };
 
@@ -332,6 +440,8 @@ fn undo_one_unverified_offline_touches_no_remote_and_reports() {
         recovery_tx_ids: vec![],
         merge_commit_oid: None,
         base_ref_name: None,
+        tag: None,
+        tag_commit: None,
     };
 
     let outcome = undo_one(&plan, "GX-unverified", &Config::default());
@@ -369,6 +479,8 @@ fn needs_action_skips_already_gone_without_recovery() {
         recovery_tx_ids: vec![],
         merge_commit_oid: None,
         base_ref_name: None,
+        tag: None,
+        tag_commit: None,
     };
     assert!(!needs_action(&gone));
 
@@ -550,6 +662,8 @@ fn undo_one_drains_mutating_recovery_stash_before_deleting_branch() {
         created_at: "2026-07-11T00:00:00Z".to_string(),
         phase: Phase::Mutating,
         branch: Some("GX-drain".to_string()),
+        existing_branch: false,
+        commit_sha: None,
         steps: vec![StepEntry::pending(RollbackStep::PopStash {
             repo: repo.to_path_buf(),
             stash_sha,
@@ -572,6 +686,8 @@ fn undo_one_drains_mutating_recovery_stash_before_deleting_branch() {
         recovery_tx_ids: vec![tx_id.to_string()],
         merge_commit_oid: None,
         base_ref_name: None,
+        tag: None,
+        tag_commit: None,
     };
 
     let outcome = undo_one(&plan, "GX-drain", &Config::default());
@@ -670,6 +786,8 @@ fn undo_one_treats_never_pushed_remote_branch_as_no_op() {
         recovery_tx_ids: vec![],
         merge_commit_oid: None,
         base_ref_name: None,
+        tag: None,
+        tag_commit: None,
     };
 
     let outcome = undo_one(&plan, "GX-neverpushed", &Config::default());
@@ -756,6 +874,8 @@ fn undo_one_recovery_only_pushed_deletes_remote_and_local() {
         created_at: "2026-07-11T00:00:00Z".to_string(),
         phase: Phase::Pushed,
         branch: Some("GX-recovery-only".to_string()),
+        existing_branch: false,
+        commit_sha: None,
         steps: vec![],
     };
     let recovery_dir = data_home.path().join("gx").join("recovery");
@@ -809,6 +929,8 @@ exit 1
         recovery_tx_ids: vec![tx_id.to_string()],
         merge_commit_oid: None,
         base_ref_name: None,
+        tag: None,
+        tag_commit: None,
     };
 
     let outcome = undo_one(&plan, "GX-recovery-only", &Config::default());
@@ -873,6 +995,183 @@ fn write_revert_shim(dir: &std::path::Path) {
     fs::set_permissions(&gh, perms).unwrap();
 }
 
+#[test]
+#[cfg(unix)]
+fn undo_one_reverts_commit_on_existing_branch_and_keeps_it() {
+    let guard = local::test_utils::env_lock();
+    let prior_data_home = std::env::var("XDG_DATA_HOME").ok();
+    let data_home = TempDir::new().unwrap();
+    unsafe { std::env::set_var("XDG_DATA_HOME", data_home.path()) };
+
+    let workspace = TempDir::new().unwrap();
+    let remotes = TempDir::new().unwrap();
+    let (repo, bare) = repo_with_remote(workspace.path(), remotes.path(), "repo");
+    // gx's commit, made and pushed straight onto the user's `main`.
+    fs::write(repo.join("data.md"), "new value\n").unwrap();
+    run_git_command(&["commit", "--quiet", "-am", "GX-existing"], &repo);
+    run_git_command(&["push", "--quiet", "origin", "main"], &repo);
+    let sha = rev_parse(&repo, "HEAD");
+
+    let plan = UndoPlan {
+        slug: "org/repo".to_string(),
+        repo_path: Some(repo.clone()),
+        branch: Some("main".to_string()),
+        pr_number: None,
+        status: Some(RepoChangeStatus::BranchCreated),
+        action: UndoAction::RevertCommit {
+            sha: Some(sha.clone()),
+            pr_number: None,
+        },
+        recovery_tx_ids: vec![],
+        merge_commit_oid: None,
+        base_ref_name: None,
+        tag: None,
+        tag_commit: None,
+    };
+    let outcome = undo_one(&plan, "GX-existing", &Config::default());
+
+    assert_eq!(outcome.kind, OutcomeKind::Undone, "{:?}", outcome.kind);
+    assert!(local::git::branch_exists_locally(&repo, "main").unwrap());
+    assert_eq!(
+        fs::read_to_string(repo.join("data.md")).unwrap(),
+        "old value\n"
+    );
+    let head = rev_parse(&repo, "HEAD");
+    assert_ne!(head, sha, "the revert is a new commit, not a reset");
+    assert_eq!(rev_parse_bare(&bare, "main"), head, "the revert is pushed");
+
+    match prior_data_home {
+        Some(v) => unsafe { std::env::set_var("XDG_DATA_HOME", v) },
+        None => unsafe { std::env::remove_var("XDG_DATA_HOME") },
+    }
+    drop(guard);
+}
+
+#[test]
+#[cfg(unix)]
+fn undo_one_deletes_the_pushed_tag_from_remote_and_local() {
+    let guard = local::test_utils::env_lock();
+    let prior_data_home = std::env::var("XDG_DATA_HOME").ok();
+    let data_home = TempDir::new().unwrap();
+    unsafe { std::env::set_var("XDG_DATA_HOME", data_home.path()) };
+
+    let workspace = TempDir::new().unwrap();
+    let remotes = TempDir::new().unwrap();
+    let (repo, bare) = repo_with_remote(workspace.path(), remotes.path(), "repo");
+    // `create --tag`: a local GX branch whose commit is tagged, tag pushed.
+    run_git_command(&["branch", "GX-tagged"], &repo);
+    run_git_command(&["tag", "-a", "v1.2.3", "-m", "GX-tagged"], &repo);
+    run_git_command(&["push", "--quiet", "origin", "refs/tags/v1.2.3"], &repo);
+    assert!(!rev_parse_bare(&bare, "refs/tags/v1.2.3").is_empty());
+
+    let plan = UndoPlan {
+        slug: "org/repo".to_string(),
+        repo_path: Some(repo.clone()),
+        branch: Some("GX-tagged".to_string()),
+        pr_number: None,
+        status: None,
+        action: UndoAction::DeleteLocal,
+        recovery_tx_ids: vec![],
+        merge_commit_oid: None,
+        base_ref_name: None,
+        tag: Some("v1.2.3".to_string()),
+        tag_commit: Some(rev_parse_bare(&bare, "refs/tags/v1.2.3^{commit}")),
+    };
+    let outcome = undo_one(&plan, "GX-tagged", &Config::default());
+
+    assert_eq!(outcome.kind, OutcomeKind::Undone, "{:?}", outcome.kind);
+    let remote_tags = run_git_command(
+        &[
+            "--git-dir",
+            bare.to_str().unwrap(),
+            "tag",
+            "--list",
+            "v1.2.3",
+        ],
+        remotes.path(),
+    );
+    assert!(
+        String::from_utf8_lossy(&remote_tags.stdout)
+            .trim()
+            .is_empty(),
+        "the pushed tag must be deleted from the remote"
+    );
+    assert!(!local::git::tag_exists_locally(&repo, "v1.2.3").unwrap());
+    assert!(!local::git::branch_exists_locally(&repo, "GX-tagged").unwrap());
+
+    // A re-run finds the tag already gone on both sides: a no-op, not a failure.
+    let again = undo_one(&plan, "GX-tagged", &Config::default());
+    assert_eq!(again.kind, OutcomeKind::Undone, "{:?}", again.kind);
+
+    match prior_data_home {
+        Some(v) => unsafe { std::env::set_var("XDG_DATA_HOME", v) },
+        None => unsafe { std::env::remove_var("XDG_DATA_HOME") },
+    }
+    drop(guard);
+}
+
+#[test]
+#[cfg(unix)]
+fn undo_one_keeps_a_remote_tag_gx_did_not_push() {
+    let guard = local::test_utils::env_lock();
+    let prior_data_home = std::env::var("XDG_DATA_HOME").ok();
+    let data_home = TempDir::new().unwrap();
+    unsafe { std::env::set_var("XDG_DATA_HOME", data_home.path()) };
+
+    let workspace = TempDir::new().unwrap();
+    let remotes = TempDir::new().unwrap();
+    let (repo, bare) = repo_with_remote(workspace.path(), remotes.path(), "repo");
+    // Someone else's release tag already on origin at main's head.
+    let release = rev_parse_bare(&bare, "refs/heads/main");
+    run_git_command(
+        &[
+            "--git-dir",
+            bare.to_str().unwrap(),
+            "tag",
+            "v1.2.3",
+            &release,
+        ],
+        remotes.path(),
+    );
+    // `create --tag v1.2.3` on a new commit: the tag push was rejected.
+    run_git_command(&["checkout", "--quiet", "-b", "GX-rejected"], &repo);
+    run_git_command(&["commit", "--quiet", "--allow-empty", "-m", "gx"], &repo);
+    run_git_command(&["tag", "-a", "v1.2.3", "-m", "GX-rejected"], &repo);
+    let gx_commit = String::from_utf8_lossy(&run_git_command(&["rev-parse", "HEAD"], &repo).stdout)
+        .trim()
+        .to_string();
+    run_git_command(&["checkout", "--quiet", "main"], &repo);
+
+    let plan = UndoPlan {
+        slug: "org/repo".to_string(),
+        repo_path: Some(repo.clone()),
+        branch: Some("GX-rejected".to_string()),
+        pr_number: None,
+        status: None,
+        action: UndoAction::DeleteLocal,
+        recovery_tx_ids: vec![],
+        merge_commit_oid: None,
+        base_ref_name: None,
+        tag: Some("v1.2.3".to_string()),
+        tag_commit: Some(gx_commit),
+    };
+    let outcome = undo_one(&plan, "GX-rejected", &Config::default());
+
+    assert_eq!(outcome.kind, OutcomeKind::Undone, "{:?}", outcome.kind);
+    assert_eq!(
+        rev_parse_bare(&bare, "refs/tags/v1.2.3"),
+        release,
+        "a remote tag gx never pushed must be left in place"
+    );
+    assert!(!local::git::tag_exists_locally(&repo, "v1.2.3").unwrap());
+
+    match prior_data_home {
+        Some(v) => unsafe { std::env::set_var("XDG_DATA_HOME", v) },
+        None => unsafe { std::env::remove_var("XDG_DATA_HOME") },
+    }
+    drop(guard);
+}
+
 /// Create a repo on `main` with data.md = "old value", wired to a fresh bare
 /// remote and pushed. Returns (repo_path, bare_path).
 #[cfg(unix)]
@@ -930,6 +1229,8 @@ fn run_revert(
         recovery_tx_ids: vec![],
         merge_commit_oid: Some(oid.to_string()),
         base_ref_name: Some("main".to_string()),
+        tag: None,
+        tag_commit: None,
     };
 
     let prior_path = std::env::var("PATH").ok();
@@ -74,6 +74,18 @@ fn install_panic_hook() {
     }));
 }
 
+/// Resolve `--root` to an absolute directory. Runs after `--cwd` has been
+/// applied, so a relative PATH is taken relative to `--cwd`.
+fn discovery_root(root: &std::path::Path) -> Result<PathBuf> {
+    let resolved = env::current_dir()
+        .context("Failed to get current directory")?
+        .join(root);
+    if !resolved.is_dir() {
+        return Err(eyre::eyre!("--root {} is not a directory", root.display()));
+    }
+    Ok(resolved)
+}
+
 fn run() -> Result<()> {
     use clap::{CommandFactory, FromArgMatches};
 
@@ -83,7 +95,15 @@ fn run() -> Result<()> {
         "Logs are written to: {}\nRun `gx doctor` to check required tools.",
         remote::doctor::log_path().display()
     );
-    let matches = Cli::command().after_help(after_help).get_matches();
+    // Expand a config alias (`gx fe` -> `gx status -p frontend --detailed`)
+    // before the real parse. A config that fails to load here expands
+    // nothing; the load below reports it.
+    let args: Vec<std::ffi::OsString> = env::args_os().collect();
+    let aliases = Config::load(remote::alias::config_path_from_args(&args).as_ref())
+        .map(|config| config.aliases())
+        .unwrap_or_default();
+    let args = remote::alias::expand_aliases(args, &aliases)?;
+    let matches = Cli::command().after_help(after_help).get_matches_from(args);
     let cli = Cli::from_arg_matches(&matches)?;
 
     // ONLY change directory if user explicitly provided --cwd. Done before any
@@ -100,7 +120,8 @@ fn run() -> Result<()> {
     // and `remote`'s mcp handler - one type, not two.
     if let Commands::Mcp(cmd) = &cli.command {
         let config = local::config::Config::load(cli.config.as_ref())
-            .context("Failed to load configuration")?;
+            .context("Failed to load configuration")?
+            .with_overrides(&cli.config_overrides)?;
         let io = mcp_io::mcp_io!();
         std::process::exit(cmd.run(&io, || {
             Ok::<_, std::convert::Infallible>(remote::mcp::server::GxMcpServer::new(config))
@@ -119,13 +140,67 @@ fn run() -> Result<()> {
         info!("Changed working directory to: {}", cwd.display());
     }
 
-    // Load configuration
-    let config = Config::load(cli.config.as_ref()).context("Failed to load configuration")?;
+    // `gx config` inspects the config itself, so it runs before the load
+    // below: `validate` must be able to report a file that does not parse.
+    if let Commands::Config { action } = &cli.command {
+        return remote::config::process_config_command(&cli, action);
+    }
+
+    // Load configuration, then layer this run's `--set` overrides on top
+    let config = Config::load(cli.config.as_ref())
+        .context("Failed to load configuration")?
+        .with_overrides(&cli.config_overrides)?;
+
+    // Refuse to start on a missing or too-old git/gh (`min-tool-versions`),
+    // before any operation can fail confusingly. `gx doctor` is exempt: it is
+    // where the user goes to see exactly that.
+    if !cli.skip_tool_check && !matches!(cli.command, Commands::Doctor { .. }) {
+        remote::doctor::require_tool_versions(&config.min_tool_versions())?;
+    }
 
     // Install the configured git/gh subprocess timeout before any command spins
     // up a rayon pool: the deep git/gh call sites read it via
-    // `subprocess::subprocess_timeout()` (Phase 2).
-    local::subprocess::init_subprocess_timeout(config.subprocess_timeout());
+    // `subprocess::subprocess_timeout()` (Phase 2). `--timeout` beats config.
+    let subprocess_timeout = match cli.timeout.as_deref() {
+        Some(timeout) => local::utils::parse_timeout(timeout)?,
+        None => config.subprocess_timeout(),
+    };
+    local::subprocess::init_subprocess_timeout(subprocess_timeout);
+    // Retry policy for transient clone/gh failures; `--retries` beats config.
+    let retry_policy = config.retry_policy();
+    local::utils::init_retry_policy(local::utils::RetryPolicy {
+        retries: cli.retries.unwrap_or(retry_policy.retries),
+        ..retry_policy
+    });
+    // Same for the discovery ignore-dirs / slug-fallback lists, repo limit,
+    // `--repos-from-file` restriction and `--no-walk-up` / `--root` scope,
+    // read deep inside `Repo::new` and `discover_repos`. `--max-repos` beats
+    // `repo-discovery.max-repos`.
+    local::repo::init_discovery_settings(local::repo::DiscoverySettings {
+        ignore_dirs: config.ignore_dirs(),
+        slug_fallback_skip: config.slug_fallback_skip(),
+        max_repos: cli.max_repos.or_else(|| config.max_repos()),
+        only_slugs: cli
+            .repos_from_file
+            .as_deref()
+            .map(local::repo::read_repo_list)
+            .transpose()?,
+        walk_up: !cli.no_walk_up,
+        root: cli.root.as_deref().map(discovery_root).transpose()?,
+        include_bare: false,
+    });
+
+    // Throttle gh calls independently of the rayon pool size; local git work
+    // stays fully parallel. `--api-concurrency` beats config.
+    remote::github::init_api_concurrency(
+        cli.api_concurrency
+            .unwrap_or_else(|| config.api_concurrency()),
+    );
+
+    // `--token` heads the token precedence read by `github::resolve_token`.
+    if let Some(token) = &cli.token {
+        remote::github::init_token_override(token.clone());
+    }
 
     info!("Starting with config from: {:?}", cli.config);
 
@@ -69,6 +69,10 @@ fn create_test_status(emoji_type: &str, repo_slug: &str) -> RepoStatus {
         changes: StatusChanges::default(),
         remote_status,
         error: None,
+        diff_stat: None,
+        branch_sync: None,
+        submodule_changes: 0,
+        commit_subject: None,
     }
 }
 
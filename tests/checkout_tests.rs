@@ -244,7 +244,7 @@ fn test_checkout_specific_branch_all_repos() {
         );
     }
     let stdout = String::from_utf8(output.stdout).unwrap();
-    assert!(stdout.contains("📥"));
+    assert!(stdout.contains("🔁"));
     assert!(stdout.contains("📊"));
 
     // Verify all repos switched to feature branch
@@ -286,7 +286,7 @@ fn test_checkout_with_multiple_patterns() {
         );
     }
     let stdout = String::from_utf8(output.stdout).unwrap();
-    assert!(stdout.contains("📥"));
+    assert!(stdout.contains("🔁"));
     assert!(stdout.contains("📊"));
 
     // Verify both repos switched to feature branch
@@ -313,7 +313,7 @@ fn test_checkout_pattern_filters_correctly() {
 
     assert!(output.status.success());
     let stdout = String::from_utf8(output.stdout).unwrap();
-    assert!(stdout.contains("📥"));
+    assert!(stdout.contains("🔁"));
     assert!(stdout.contains("📊"));
 
     // Verify only frontend switched, backend stayed on main
@@ -539,3 +539,102 @@ fn test_checkout_stash_with_default_branch() {
     // Should be back on main branch
     assert_eq!(get_current_branch(&frontend_path), "main");
 }
+
+#[test]
+fn test_checkout_worktree_leaves_primary_branch_unchanged() {
+    let workspace = create_full_test_workspace();
+    let frontend_path = workspace.path().join("frontend");
+    let worktrees = tempfile::TempDir::new().unwrap();
+
+    run_git_command(&["checkout", "-b", "review-me"], &frontend_path);
+    run_git_command(&["checkout", "main"], &frontend_path);
+
+    let output = run_gx_command(
+        &[
+            "checkout",
+            "review-me",
+            "--worktree",
+            worktrees.path().to_str().unwrap(),
+            "-p",
+            "frontend",
+        ],
+        workspace.path(),
+    );
+    assert!(output.status.success(), "Command should have succeeded");
+
+    // The worktree appears at <dir>/<repo> on the requested branch...
+    let worktree_path = worktrees.path().join("frontend");
+    assert!(worktree_path.join(".git").exists());
+    assert_eq!(get_current_branch(&worktree_path), "review-me");
+
+    // ...while the primary checkout never moved.
+    assert_eq!(get_current_branch(&frontend_path), "main");
+}
+
+#[test]
+fn test_checkout_worktree_existing_path_gives_cleanup_guidance() {
+    let workspace = create_full_test_workspace();
+    let frontend_path = workspace.path().join("frontend");
+    let worktrees = tempfile::TempDir::new().unwrap();
+    std::fs::create_dir(worktrees.path().join("frontend")).unwrap();
+
+    run_git_command(&["checkout", "-b", "review-me"], &frontend_path);
+    run_git_command(&["checkout", "main"], &frontend_path);
+
+    let output = run_gx_command(
+        &[
+            "checkout",
+            "review-me",
+            "--worktree",
+            worktrees.path().to_str().unwrap(),
+            "-p",
+            "frontend",
+        ],
+        workspace.path(),
+    );
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("worktree remove"));
+    assert_eq!(get_current_branch(&frontend_path), "main");
+}
+
+#[test]
+fn test_checkout_failure_exits_nonzero() {
+    let workspace = create_full_test_workspace();
+
+    let output = run_gx_command(
+        &["checkout", "no-such-branch", "-p", "frontend"],
+        workspace.path(),
+    );
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(
+        output.status.code(),
+        Some(1),
+        "exit code should equal the error count\nSTDOUT:\n{stdout}"
+    );
+    assert!(stdout.contains("1 errors"), "got: {stdout}");
+}
+
+#[test]
+fn test_checkout_failure_with_no_fail_on_error_exits_zero() {
+    let workspace = create_full_test_workspace();
+
+    let output = run_gx_command(
+        &[
+            "--no-fail-on-error",
+            "checkout",
+            "no-such-branch",
+            "-p",
+            "frontend",
+        ],
+        workspace.path(),
+    );
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(output.status.success(), "got: {stdout}");
+    assert!(
+        stdout.contains("1 errors"),
+        "the summary still counts it: {stdout}"
+    );
+}
@@ -264,3 +264,137 @@ fn test_create_sub_preserves_executable_mode() {
         "the GX commit must not change run.sh's mode, got: {summary:?}"
     );
 }
+
+#[test]
+fn test_create_trailers_land_in_the_pushed_commit_message() {
+    let workspace = TempDir::new().unwrap();
+    let remotes = TempDir::new().unwrap();
+    let data_home = TempDir::new().unwrap();
+    make_repo(workspace.path(), remotes.path(), "service", "main");
+
+    let change_id = "GX-trailers";
+    let output = Command::new(gx_binary())
+        .args([
+            "--cwd",
+            workspace.path().to_str().unwrap(),
+            "--log-level",
+            "off",
+            "create",
+            "--files",
+            "**/*.md",
+            "--change-id",
+            change_id,
+            "--commit",
+            "e2e: trailers",
+            "--trailer",
+            "Refs: OPS-42",
+            "--trailer",
+            "Reviewed-by: Someone <someone@example.com>",
+            "--change-id-trailer",
+            "--yes",
+            "sub",
+            "old",
+            "new",
+        ])
+        .env("XDG_DATA_HOME", data_home.path())
+        .output()
+        .expect("gx failed to spawn");
+    assert!(
+        output.status.success(),
+        "gx create failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // Read the message back from the pushed branch on the remote.
+    let bare = remotes.path().join("service.git");
+    let log = Command::new("git")
+        .args([
+            "--git-dir",
+            bare.to_str().unwrap(),
+            "log",
+            "-1",
+            "--format=%B",
+            change_id,
+        ])
+        .output()
+        .unwrap();
+    assert!(log.status.success());
+    let message = String::from_utf8_lossy(&log.stdout);
+    let lines: Vec<&str> = message.trim_end().lines().collect();
+    assert_eq!(lines[0], "e2e: trailers");
+    // The trailers close the message in order, `Change-Id` last.
+    assert_eq!(
+        lines[lines.len() - 3..],
+        [
+            "Refs: OPS-42",
+            "Reviewed-by: Someone <someone@example.com>",
+            "Change-Id: GX-trailers",
+        ]
+    );
+}
+
+#[test]
+fn test_create_dry_run_json_round_trips_results() {
+    let workspace = TempDir::new().unwrap();
+    let remotes = TempDir::new().unwrap();
+    let data_home = TempDir::new().unwrap();
+
+    let repo = make_repo(workspace.path(), remotes.path(), "docs", "main");
+
+    let output = Command::new(gx_binary())
+        .args([
+            "--cwd",
+            workspace.path().to_str().unwrap(),
+            "--log-level",
+            "off",
+            "create",
+            "--files",
+            "*.md",
+            "--json",
+            "sub",
+            "old",
+            "new",
+        ])
+        .env("XDG_DATA_HOME", data_home.path())
+        .output()
+        .expect("gx failed to spawn");
+    assert!(
+        output.status.success(),
+        "gx create --json failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // stdout is exactly the JSON array: no human lines, no pattern analysis.
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let results: Vec<remote::create::CreateResult> = serde_json::from_str(&stdout)
+        .unwrap_or_else(|e| panic!("stdout must be the JSON results ({e}): {stdout}"));
+    assert_eq!(results.len(), 1);
+    let result = &results[0];
+    assert!(matches!(
+        result.action,
+        remote::create::CreateAction::DryRun
+    ));
+    assert_eq!(result.files_affected, vec!["data.md".to_string()]);
+    assert!(
+        result.error.is_none(),
+        "unexpected error: {:?}",
+        result.error
+    );
+    let stats = result
+        .substitution_stats
+        .as_ref()
+        .expect("a sub dry run must carry substitution_stats");
+    assert_eq!(stats.files_changed, 1);
+    assert_eq!(stats.total_matches, 1);
+
+    // The raw JSON uses the documented field names and kebab-case actions.
+    let raw: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(raw[0]["action"], "dry-run");
+    assert_eq!(raw[0]["substitution_stats"]["files_changed"], 1);
+
+    // A dry run changes nothing.
+    assert_eq!(
+        std::fs::read_to_string(repo.join("data.md")).unwrap(),
+        "old value\n"
+    );
+}
@@ -115,6 +115,26 @@ fn test_global_max_depth_option() {
     assert!(stdout.contains("deep-repo"));
 }
 
+#[test]
+fn test_global_max_repos_option() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let names: Vec<String> = (1..=5).map(|i| format!("capped-repo-{i}")).collect();
+    for name in &names {
+        create_test_repo(temp_dir.path(), name, true);
+    }
+
+    let output = run_gx_command(&["--max-repos", "2", "status"], temp_dir.path());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    let shown = names.iter().filter(|n| stdout.contains(n.as_str())).count();
+    assert_eq!(shown, 2, "expected 2 repos, got stdout:\n{stdout}");
+    assert!(
+        stderr.contains("--max-repos") && stderr.contains("truncated"),
+        "expected truncation warning, got stderr:\n{stderr}"
+    );
+}
+
 #[test]
 fn test_config_file_option() {
     let temp_dir = tempfile::TempDir::new().unwrap();
@@ -290,6 +310,73 @@ fn test_rollback_validate_error_has_no_eyre_location_trailer() {
     assert!(!stderr.contains("Location:"));
 }
 
+/// Write a `mutating` recovery file for `tx_id`, created `age_days` ago,
+/// under `data_home` (what `gx create` leaves behind after a crash).
+fn write_recovery_file(data_home: &std::path::Path, tx_id: &str, age_days: i64) {
+    use remote::transaction::{Phase, RecoveryState, RollbackStep, StepEntry};
+    let created_at = chrono::Utc::now() - chrono::Duration::days(age_days);
+    let recovery = RecoveryState {
+        version: 1,
+        transaction_id: tx_id.to_string(),
+        change_id: "GX-rollback-flow".to_string(),
+        repo_path: data_home.join("repo"),
+        created_at: created_at.to_rfc3339(),
+        phase: Phase::Mutating,
+        branch: None,
+        existing_branch: false,
+        commit_sha: None,
+        steps: vec![StepEntry::pending(RollbackStep::RemoveCreatedFile {
+            path: data_home.join("repo").join("new.txt"),
+        })],
+    };
+    let recovery_dir = data_home.join("gx").join("recovery");
+    std::fs::create_dir_all(&recovery_dir).unwrap();
+    std::fs::write(
+        recovery_dir.join(format!("{tx_id}.json")),
+        serde_json::to_string_pretty(&recovery).unwrap(),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_rollback_list_then_cleanup_older_than() {
+    let data_home = tempfile::TempDir::new().unwrap();
+    write_recovery_file(data_home.path(), "gx-tx-old", 10);
+    write_recovery_file(data_home.path(), "gx-tx-fresh", 0);
+    let gx = |args: &[&str]| {
+        std::process::Command::new(get_gx_binary_path())
+            .args(args)
+            .env("XDG_DATA_HOME", data_home.path())
+            .current_dir(data_home.path())
+            .output()
+            .expect("Failed to execute gx command")
+    };
+
+    let listed = gx(&["rollback", "list"]);
+    assert!(listed.status.success());
+    let stdout = String::from_utf8(listed.stdout).unwrap();
+    assert!(stdout.contains("gx-tx-old") && stdout.contains("gx-tx-fresh"));
+    assert!(stdout.contains("Total recovery states: 2"), "{stdout}");
+
+    let cleaned = gx(&["rollback", "cleanup", "--older-than", "7d"]);
+    assert!(cleaned.status.success());
+    let stdout = String::from_utf8(cleaned.stdout).unwrap();
+    assert!(
+        stdout.contains("Cleaning up 1 recovery states older than 7d"),
+        "{stdout}"
+    );
+
+    let listed = String::from_utf8(gx(&["rollback", "list"]).stdout).unwrap();
+    assert!(!listed.contains("gx-tx-old"), "{listed}");
+    assert!(listed.contains("gx-tx-fresh"), "{listed}");
+
+    let rejected = gx(&["rollback", "cleanup", "--older-than", "7x"]);
+    assert!(!rejected.status.success());
+    assert!(String::from_utf8(rejected.stderr)
+        .unwrap()
+        .contains("Invalid duration unit"));
+}
+
 #[test]
 fn test_logging_functionality() {
     let temp_dir = tempfile::TempDir::new().unwrap();
@@ -304,3 +391,51 @@ fn test_logging_functionality() {
     // Log file should be created (though we can't easily test the content in integration tests)
     // This mainly tests that the logging setup doesn't crash the application
 }
+
+#[test]
+fn test_set_override_changes_discovery_depth() {
+    let workspace = tempfile::TempDir::new().unwrap();
+    let config = workspace.path().join("gx.yml");
+    std::fs::write(&config, "{}\n").unwrap();
+    create_minimal_test_repo(workspace.path(), "shallow");
+    let nested = workspace.path().join("a/b/c/d/e");
+    std::fs::create_dir_all(&nested).unwrap();
+    create_minimal_test_repo(&nested, "deep");
+
+    let status = |extra: &[&str]| {
+        let mut args = vec!["--config", config.to_str().unwrap()];
+        args.extend_from_slice(extra);
+        args.extend_from_slice(&["status", "--porcelain", "--no-remote"]);
+        let output = run_gx_command(&args, workspace.path());
+        assert!(
+            output.status.success(),
+            "gx {args:?} failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    // The default depth (3) stops well short of the deep repo...
+    let default = status(&[]);
+    assert!(default.contains("testorg/shallow"), "{default}");
+    assert!(!default.contains("testorg/deep"), "{default}");
+
+    // ...and `--set` raises it for this run only.
+    let overridden = status(&["--set", "repo_discovery.max_depth=10"]);
+    assert!(overridden.contains("testorg/deep"), "{overridden}");
+    assert!(overridden.contains("testorg/shallow"), "{overridden}");
+
+    // A bad key fails clearly instead of being ignored.
+    let output = run_gx_command(
+        &[
+            "--config",
+            config.to_str().unwrap(),
+            "--set",
+            "repo-discovery.max-dpeth=10",
+            "status",
+        ],
+        workspace.path(),
+    );
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("max-dpeth"));
+}
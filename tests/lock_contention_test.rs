@@ -85,6 +85,8 @@ fn setup_repo_and_recovery(tx_id: &str) -> (TempDir, TempDir, String) {
         created_at: "2026-07-11T00:00:00Z".to_string(),
         phase: Phase::Mutating,
         branch: Some("main".to_string()),
+        existing_branch: false,
+        commit_sha: None,
         steps: vec![StepEntry::pending(RollbackStep::SwitchBranch {
             repo: repo.to_path_buf(),
             branch: "main".to_string(),
@@ -1,5 +1,4 @@
 use local::test_utils::*;
-use std::process::Command;
 
 #[test]
 fn test_status_discovers_repositories() {
@@ -18,6 +17,29 @@ fn test_status_discovers_repositories() {
     assert!(output.status.success());
 }
 
+#[test]
+fn test_status_skips_bare_repositories() {
+    let workspace = create_test_workspace();
+    let cloned = run_git_command(
+        &["clone", "--mirror", "frontend", "frontend-mirror.git"],
+        workspace.path(),
+    );
+    assert!(cloned.status.success());
+
+    let output = run_gx_command(&["status"], workspace.path());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    assert!(
+        stdout.contains("frontend-mirror (skipped: bare repository)"),
+        "bare repo should be noted as skipped. stdout:\n{stdout}"
+    );
+    assert!(
+        !stderr.contains("frontend-mirror"),
+        "bare repo must not error: {stderr}"
+    );
+}
+
 #[test]
 fn test_status_shows_commit_hash() {
     let workspace = create_test_workspace();
@@ -85,7 +107,7 @@ fn test_status_parallel_option() {
     let workspace = create_test_workspace();
 
     // Use the current directory to run gx with global flags
-    let output = Command::new(get_gx_binary_path())
+    let output = gx_command()
         .args(["--jobs", "2", "status"])
         .current_dir(workspace.path())
         .output()
@@ -112,3 +134,249 @@ fn test_status_help_output() {
     // Should succeed
     assert!(output.status.success());
 }
+
+#[test]
+fn test_status_notes_walk_up_from_inside_a_repo() {
+    let workspace = create_test_workspace();
+    let inside = workspace.path().join("frontend");
+
+    let output = run_gx_command(&["status", "--no-remote"], &inside);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    // Siblings are scanned, and the walk-up is said out loud.
+    assert!(stdout.contains("backend"), "stdout:\n{stdout}");
+    assert!(
+        stderr.contains("Scanning from") && stderr.contains("(walked up from ./frontend)"),
+        "stderr:\n{stderr}"
+    );
+
+    let output = run_gx_command(&["status", "--no-remote", "--no-root-notice"], &inside);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(!stderr.contains("Scanning from"), "stderr:\n{stderr}");
+
+    // Scanning from the workspace itself never walks up.
+    let output = run_gx_command(&["status", "--no-remote"], workspace.path());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(!stderr.contains("Scanning from"), "stderr:\n{stderr}");
+}
+
+#[test]
+fn test_status_only_errors_hides_healthy_repos_but_counts_them() {
+    let workspace = create_test_workspace();
+    // A truncated index makes `git status` fail in backend only.
+    std::fs::write(workspace.path().join("backend/.git/index"), b"garbage").unwrap();
+
+    let output = run_gx_command(
+        &["status", "--no-remote", "--only-errors"],
+        workspace.path(),
+    );
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(stdout.contains("backend"), "stdout:\n{stdout}");
+    assert!(!stdout.contains("frontend"), "stdout:\n{stdout}");
+    assert!(!stdout.contains("testorg/api"), "stdout:\n{stdout}");
+    assert!(
+        stdout.contains("3 clean, 1 dirty, 1 errors"),
+        "healthy repos still count toward the summary:\n{stdout}"
+    );
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn test_quiet_prints_the_summary_but_no_repo_lines() {
+    let workspace = create_test_workspace();
+
+    let output = run_gx_command(&["--quiet", "status", "--no-remote"], workspace.path());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    for repo in ["frontend", "backend", "api", "docs", "dirty-repo"] {
+        assert!(!stdout.contains(repo), "{repo} listed:\n{stdout}");
+    }
+    assert!(
+        stdout.contains("4 clean, 1 dirty, 0 errors"),
+        "stdout:\n{stdout}"
+    );
+}
+
+#[test]
+fn test_config_alias_runs_like_the_full_command() {
+    let workspace = create_test_workspace();
+    let config_dir = tempfile::TempDir::new().unwrap();
+    let config = config_dir.path().join("gx.yml");
+    std::fs::write(
+        &config,
+        "aliases:\n  fe: [status, -p, frontend, --detailed]\n",
+    )
+    .unwrap();
+    let config = config.to_str().unwrap();
+
+    let aliased = run_gx_command(&["-c", config, "fe", "--no-remote"], workspace.path());
+    let full = run_gx_command(
+        &[
+            "-c",
+            config,
+            "status",
+            "-p",
+            "frontend",
+            "--detailed",
+            "--no-remote",
+        ],
+        workspace.path(),
+    );
+
+    assert!(aliased.status.success(), "{aliased:?}");
+    let stdout = String::from_utf8(aliased.stdout).unwrap();
+    assert!(stdout.contains("frontend"), "got: {stdout}");
+    assert!(!stdout.contains("backend"), "got: {stdout}");
+    assert_eq!(stdout, String::from_utf8(full.stdout).unwrap());
+}
+
+#[test]
+fn test_status_porcelain_columns() {
+    let workspace = create_test_workspace();
+
+    let output = run_gx_command(
+        &[
+            "status",
+            "--porcelain",
+            "--no-remote",
+            "-p",
+            "frontend",
+            "-p",
+            "dirty-repo",
+        ],
+        workspace.path(),
+    );
+    assert!(
+        output.status.success(),
+        "gx status --porcelain should succeed"
+    );
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.is_ascii(), "porcelain must be plain ASCII: {stdout}");
+    let rows: Vec<Vec<&str>> = stdout.lines().map(|l| l.split('\t').collect()).collect();
+    assert_eq!(rows.len(), 2, "one line per repo, nothing else:\n{stdout}");
+    for row in &rows {
+        assert_eq!(row.len(), 11, "11 tab-separated columns: {row:?}");
+        assert_eq!(row[2].len(), 7, "short sha: {row:?}");
+    }
+
+    // Sorted by slug: dirty-repo before frontend.
+    let dirty = &rows[0];
+    assert_eq!(dirty[0], "testorg/dirty-repo");
+    assert_eq!(dirty[3], "dirty");
+    assert_eq!(&dirty[4..], ["0", "0", "0", "1", "0", "0", "0"]);
+
+    let clean = &rows[1];
+    assert_eq!(clean[0], "testorg/frontend");
+    assert_eq!(clean[1], dirty[1], "both repos are on the init branch");
+    assert_eq!(clean[3], "clean");
+    assert_eq!(&clean[4..], ["0", "0", "0", "0", "0", "0", "0"]);
+}
+
+#[test]
+fn test_timeout_kills_hung_git_and_reports_repo() {
+    let workspace = create_test_workspace();
+    // An fsmonitor hook that sleeps makes `git status` in this one repo hang.
+    let hung = run_git_command(
+        &["config", "core.fsmonitor", "sleep 60; false"],
+        &workspace.path().join("frontend"),
+    );
+    assert!(hung.status.success());
+
+    let started = std::time::Instant::now();
+    let output = run_gx_command(
+        &["--timeout", "2s", "status", "--no-remote"],
+        workspace.path(),
+    );
+    let elapsed = started.elapsed();
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        elapsed < std::time::Duration::from_secs(30),
+        "the hung git must be killed at the timeout, took {elapsed:?}"
+    );
+    assert!(
+        !output.status.success(),
+        "a timed-out repo is a failure. stdout:\n{stdout}\nstderr:\n{stderr}"
+    );
+    assert!(
+        format!("{stdout}{stderr}").contains("timed out after 2s"),
+        "the timeout should be reported. stdout:\n{stdout}\nstderr:\n{stderr}"
+    );
+    assert!(
+        stdout.contains("backend"),
+        "other repos still report: {stdout}"
+    );
+}
+
+#[test]
+fn test_status_count_only_prints_just_the_summary() {
+    let workspace = create_test_workspace();
+
+    let output = run_gx_command(
+        &["status", "--count-only", "--no-remote", "--no-emoji"],
+        workspace.path(),
+    );
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(
+        stdout.lines().collect::<Vec<_>>(),
+        ["Summary: 4 clean, 1 dirty, 0 errors"],
+        "only the summary line:\n{stdout}"
+    );
+
+    let output = run_gx_command(
+        &["status", "--count-only", "--json", "--no-remote"],
+        workspace.path(),
+    );
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.trim(), r#"{"clean":4,"dirty":1,"errors":0}"#);
+
+    // --json is only for --count-only.
+    let output = run_gx_command(&["status", "--json"], workspace.path());
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_status_color_modes_resolve_use_colors() {
+    let workspace = create_test_workspace();
+    // Whether `gx <args>` (stdout piped, as here) prints any ANSI escape.
+    let colored = |args: &[&str], no_color_env: bool| {
+        let mut cmd = gx_command();
+        cmd.args(args).current_dir(workspace.path());
+        if no_color_env {
+            cmd.env("NO_COLOR", "1");
+        } else {
+            cmd.env_remove("NO_COLOR");
+        }
+        let output = cmd.output().unwrap();
+        assert!(output.status.success(), "{args:?}");
+        String::from_utf8(output.stdout).unwrap().contains('\x1b')
+    };
+
+    // auto: stdout is a pipe, so no color.
+    assert!(!colored(&["status", "--no-remote"], false));
+    assert!(colored(
+        &["--color", "always", "status", "--no-remote"],
+        false
+    ));
+    assert!(!colored(
+        &["--color", "never", "status", "--no-remote"],
+        false
+    ));
+    // NO_COLOR only affects auto; always still forces color.
+    assert!(!colored(&["status", "--no-remote"], true));
+    assert!(colored(
+        &["--color", "always", "status", "--no-remote"],
+        true
+    ));
+    // The deprecated --no-color means never.
+    assert!(!colored(
+        &["--color", "always", "status", "--no-remote", "--no-color"],
+        false
+    ));
+}
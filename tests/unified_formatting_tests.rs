@@ -16,6 +16,10 @@ fn test_unified_display_trait_for_repo_status() {
         changes: local::git::StatusChanges::default(),
         remote_status: local::git::RemoteStatus::UpToDate,
         error: None,
+        diff_stat: None,
+        branch_sync: None,
+        submodule_changes: 0,
+        commit_subject: None,
     };
 
     // Test trait methods
@@ -63,6 +67,10 @@ fn test_alignment_widths_calculation() {
         changes: local::git::StatusChanges::default(),
         remote_status: local::git::RemoteStatus::UpToDate,
         error: None,
+        diff_stat: None,
+        branch_sync: None,
+        submodule_changes: 0,
+        commit_subject: None,
     };
 
     let status2 = RepoStatus {
@@ -73,6 +81,10 @@ fn test_alignment_widths_calculation() {
         changes: local::git::StatusChanges::default(),
         remote_status: local::git::RemoteStatus::UpToDate,
         error: None,
+        diff_stat: None,
+        branch_sync: None,
+        submodule_changes: 0,
+        commit_subject: None,
     };
 
     let items = vec![&status1, &status2];
@@ -99,6 +111,10 @@ fn test_unified_format_consistency() {
         changes: local::git::StatusChanges::default(),
         remote_status: local::git::RemoteStatus::UpToDate,
         error: None,
+        diff_stat: None,
+        branch_sync: None,
+        submodule_changes: 0,
+        commit_subject: None,
     };
 
     let checkout = CheckoutResult {
@@ -142,6 +158,10 @@ fn test_error_handling_in_unified_display() {
         changes: local::git::StatusChanges::default(),
         remote_status: local::git::RemoteStatus::UpToDate,
         error: Some("Git command failed".to_string()),
+        diff_stat: None,
+        branch_sync: None,
+        submodule_changes: 0,
+        commit_subject: None,
     };
 
     let error_checkout = CheckoutResult {
@@ -175,6 +195,10 @@ fn test_no_emoji_mode() {
         changes: local::git::StatusChanges::default(),
         remote_status: local::git::RemoteStatus::UpToDate,
         error: None,
+        diff_stat: None,
+        branch_sync: None,
+        submodule_changes: 0,
+        commit_subject: None,
     };
 
     let checkout = CheckoutResult {
@@ -189,6 +213,7 @@ fn test_no_emoji_mode() {
         verbosity: OutputVerbosity::Summary,
         use_emoji: false,
         use_colors: false,
+        ..Default::default()
     };
 
     // Should use text instead of emojis
@@ -209,6 +234,10 @@ fn test_emoji_width_calculation_with_complex_combinations() {
         changes: local::git::StatusChanges::default(),
         remote_status: local::git::RemoteStatus::UpToDate, // 🟢 (2 chars)
         error: None,
+        diff_stat: None,
+        branch_sync: None,
+        submodule_changes: 0,
+        commit_subject: None,
     };
 
     let ahead_status = RepoStatus {
@@ -219,6 +248,10 @@ fn test_emoji_width_calculation_with_complex_combinations() {
         changes: local::git::StatusChanges::default(),
         remote_status: local::git::RemoteStatus::Ahead(15), // ↑15 (3 width)
         error: None,
+        diff_stat: None,
+        branch_sync: None,
+        submodule_changes: 0,
+        commit_subject: None,
     };
 
     let diverged_status = RepoStatus {
@@ -229,6 +262,10 @@ fn test_emoji_width_calculation_with_complex_combinations() {
         changes: local::git::StatusChanges::default(),
         remote_status: local::git::RemoteStatus::Diverged(5, 3), // 🔀5↑3↓ (6 chars)
         error: None,
+        diff_stat: None,
+        branch_sync: None,
+        submodule_changes: 0,
+        commit_subject: None,
     };
 
     let error_status = RepoStatus {
@@ -239,6 +276,10 @@ fn test_emoji_width_calculation_with_complex_combinations() {
         changes: local::git::StatusChanges::default(),
         remote_status: local::git::RemoteStatus::Error("timeout".to_string()), // 🚨 tim (6 width)
         error: None,
+        diff_stat: None,
+        branch_sync: None,
+        submodule_changes: 0,
+        commit_subject: None,
     };
 
     let items = vec![